@@ -10,6 +10,7 @@ use denom_staking_impl::query::{
     query_claims, query_denom_config, query_members, query_releasable_claims, query_total_weight,
     query_user_weight,
 };
+use membership_common::unlocking_extensions::extend_unlocking;
 use membership_common::weight_change_hooks::{add_weight_change_hook, remove_weight_change_hook};
 
 // version info for migration info
@@ -47,6 +48,7 @@ pub fn execute(
         ExecuteMsg::UpdateUnlockingPeriod(msg) => update_unlocking_period(ctx, msg)?,
         ExecuteMsg::AddWeightChangeHook(msg) => add_weight_change_hook(ctx, msg)?,
         ExecuteMsg::RemoveWeightChangeHook(msg) => remove_weight_change_hook(ctx, msg)?,
+        ExecuteMsg::ExtendUnlocking(msg) => extend_unlocking(ctx, msg)?,
         ExecuteMsg::Stake { user } => stake_denom(ctx, user)?,
     };
 
@@ -13,8 +13,8 @@ use crate::v1_structs::QueryV1Msg::{
     ProposalVotes, Proposals, ReleasableClaims, StakedNfts, TotalStakedAmount, UserStake,
 };
 use crate::v1_structs::{
-    CreateProposalV1Msg, Cw20HookV1Msg, Cw721HookV1Msg, DaoInfoResponseV1, ExecuteMsgsV1Msg,
-    ExecuteV1Msg, ProposalActionV1, ProposalResponseV1, ProposalsResponseV1,
+    CreateProposalV1Msg, Cw20HookV1Msg, Cw721HookV1Msg, DaoInfoResponseV1, DistributeFundsV1Msg,
+    ExecuteMsgsV1Msg, ExecuteV1Msg, ProposalActionV1, ProposalResponseV1, ProposalsResponseV1,
     TreasuryV1_0_0MigrationMsg, UnstakeCw20V1Msg, UnstakeCw721V1Msg, UnstakeV1Msg, UpgradeDaoV1Msg,
     UserStakeV1Params,
 };
@@ -50,7 +50,9 @@ use poll_engine::state::PollHelpers;
 use poll_engine_api::api::{Poll, PollRejectionReason, PollStatus, VotingScheme};
 use EnterpriseFacadeError::UnsupportedOperation;
 use ExecuteV1Msg::ExecuteProposal;
-use PollRejectionReason::{QuorumAndThresholdNotReached, QuorumNotReached, ThresholdNotReached};
+use PollRejectionReason::{
+    MinimumYesVotesNotReached, QuorumAndThresholdNotReached, QuorumNotReached, ThresholdNotReached,
+};
 use V2MigrationStage::MigrationNotStarted;
 
 /// Facade implementation for v0.5.0 of Enterprise (pre-contract-rewrite).
@@ -534,6 +536,7 @@ impl EnterpriseFacadeV1 {
             quorum: gov_config.quorum,
             threshold: gov_config.threshold,
             veto_threshold: Some(gov_config.veto_threshold),
+            minimum_yes_votes: None,
             results: response.results.clone(),
         };
 
@@ -607,10 +610,33 @@ impl EnterpriseFacadeV1 {
                 Ok(ModifyMultisigMembership(msg.into()))
             }
             ProposalAction::DistributeFunds(msg) => Ok(DistributeFunds(msg.into())),
+            ProposalAction::DistributeFromTreasury(msg) => {
+                Ok(DistributeFunds(DistributeFundsV1Msg { funds: msg.assets }))
+            }
             ProposalAction::UpdateMinimumWeightForRewards(msg) => {
                 Ok(UpdateMinimumWeightForRewards(msg.into()))
             }
-            ProposalAction::DeployCrossChainTreasury(_) => {
+            ProposalAction::DeployCrossChainTreasury(_)
+            | ProposalAction::BurnTokens(_)
+            | ProposalAction::UpdateVetoCouncil(_)
+            | ProposalAction::MintTokenfactoryDenom(_)
+            | ProposalAction::SetDenomAdmin(_)
+            | ProposalAction::UpdateEnterpriseFactory(_)
+            | ProposalAction::SetSelfAdmin(_)
+            | ProposalAction::UpdateCouncilPermissions(_)
+            | ProposalAction::GrantSpendingAllowance(_)
+            | ProposalAction::RevokeSpendingAllowance(_)
+            | ProposalAction::EditCouncilMembers(_)
+            | ProposalAction::ExecuteInterchainAccountMsgs(_)
+            | ProposalAction::ManageAssetBundles(_)
+            | ProposalAction::ScheduleCouncilElections(_)
+            | ProposalAction::UpdateDistributionFee(_)
+            | ProposalAction::SetDistributorClaimsPaused(_)
+            | ProposalAction::TransferNft(_)
+            | ProposalAction::SetDistributorEmissionSchedule(_)
+            | ProposalAction::DistributeToVoters(_)
+            | ProposalAction::RegisterInterchainAccount(_)
+            | ProposalAction::UpdateAssetWhitelistWithBundle(_) => {
                 Err(StdError::generic_err("unsupported proposal action"))
             }
         }
@@ -670,7 +696,8 @@ impl EnterpriseFacadeV1 {
                             PollStatus::Rejected { reason } => match reason {
                                 QuorumNotReached
                                 | ThresholdNotReached
-                                | QuorumAndThresholdNotReached => ProposalStatus::InProgress,
+                                | QuorumAndThresholdNotReached
+                                | MinimumYesVotesNotReached => ProposalStatus::InProgress,
                                 _ => ProposalStatus::InProgressCanExecuteEarly,
                             },
                         }
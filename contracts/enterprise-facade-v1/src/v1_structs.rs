@@ -107,6 +107,30 @@ impl From<UpdateGovConfigV1Msg> for UpdateGovConfigMsg {
             unlocking_period: value.unlocking_period,
             minimum_deposit: value.minimum_deposit,
             allow_early_proposal_execution: value.allow_early_proposal_execution,
+            invalid_action_policy: NoChange,
+            proposal_creation_policy: NoChange,
+            max_open_proposals_per_proposer: NoChange,
+            governance_token_whitelist_policy: NoChange,
+            treasury_address: NoChange,
+            description_format: NoChange,
+            accepted_deposit_assets: NoChange,
+            auto_execute_on_pass: NoChange,
+            action_type_cooldowns: NoChange,
+            voting_extension: NoChange,
+            minimum_yes_votes: NoChange,
+            voter_unlocking_extensions: NoChange,
+            proposal_expiration_bounds: NoChange,
+            private_proposals: NoChange,
+            max_distribution_assets: NoChange,
+            max_execution_budget: NoChange,
+            proposal_notifier: NoChange,
+            action_type_thresholds: NoChange,
+            manual_deposit_refunds: NoChange,
+            council_action_requirements: NoChange,
+            allow_vote_changes: NoChange,
+            restrict_late_joiner_votes: NoChange,
+            expected_current_config: None,
+            expected_unlocking_period: None,
         }
     }
 }
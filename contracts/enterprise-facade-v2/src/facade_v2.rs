@@ -362,6 +362,7 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                 &enterprise_governance_controller_api::msg::QueryMsg::Proposal(
                     enterprise_governance_controller_api::api::ProposalParams {
                         proposal_id: params.proposal_id,
+                        viewer: None,
                     },
                 ),
             )?;
@@ -386,6 +387,8 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                         filter: params.filter.map(map_proposal_filter),
                         start_after: params.start_after,
                         limit: params.limit,
+                        viewer: None,
+                        title_contains: None,
                     },
                 ),
             )?;
@@ -777,6 +780,11 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                     description: params.description,
                     proposal_actions: params.proposal_actions,
                     deposit_owner: params.deposit_owner,
+                    execution_atomicity: None,
+                    expires_at: None,
+                    execution_condition: None,
+                    execution_budget: None,
+                    metadata: None,
                 },
             ))?,
             vec![],
@@ -814,6 +822,11 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                     description: params.create_proposal_msg.description,
                     proposal_actions: params.create_proposal_msg.proposal_actions,
                     deposit_owner: params.create_proposal_msg.deposit_owner,
+                    execution_atomicity: None,
+                    expires_at: None,
+                    execution_condition: None,
+                    execution_budget: None,
+                    metadata: None,
                 },
             ))?,
             funds: coins(params.deposit_amount.u128(), denom_config.denom),
@@ -855,6 +868,11 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                                     description: params.create_proposal_msg.description,
                                     proposal_actions: params.create_proposal_msg.proposal_actions,
                                     deposit_owner: params.create_proposal_msg.deposit_owner,
+                                    execution_atomicity: None,
+                                    expires_at: None,
+                                    execution_condition: None,
+                                    execution_budget: None,
+                                    metadata: None,
                                 },
                             ),
                         )?,
@@ -944,6 +962,11 @@ impl EnterpriseFacade for EnterpriseFacadeV2 {
                         description: params.description,
                         proposal_actions: params.proposal_actions,
                         deposit_owner: params.deposit_owner,
+                        execution_atomicity: None,
+                        expires_at: None,
+                        execution_condition: None,
+                        execution_budget: None,
+                        metadata: None,
                     },
                 ),
             )?,
@@ -1308,6 +1331,13 @@ fn map_proposal_status(
         enterprise_governance_controller_api::api::ProposalStatus::Executed => {
             ProposalStatus::Executed
         }
+        enterprise_governance_controller_api::api::ProposalStatus::Vetoed => ProposalStatus::Vetoed,
+        enterprise_governance_controller_api::api::ProposalStatus::ConditionNotMet => {
+            ProposalStatus::ConditionNotMet
+        }
+        enterprise_governance_controller_api::api::ProposalStatus::ExecutionFailed => {
+            ProposalStatus::ExecutionFailed
+        }
     }
 }
 
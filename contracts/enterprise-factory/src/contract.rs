@@ -21,7 +21,7 @@ use cosmwasm_std::WasmMsg::Instantiate;
 use cosmwasm_std::{
     coins, entry_point, to_json_binary, wasm_execute, wasm_instantiate, Addr, BankMsg, Binary,
     CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult, SubMsg,
-    Uint128, Uint64, WasmMsg,
+    Uint64, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_asset::AssetInfo;
@@ -598,13 +598,15 @@ pub fn reply(mut deps: DepsMut, env: Env, msg: Reply) -> DaoResult<Response> {
 
             let council_members = create_dao_msg
                 .dao_council
-                .map(|council| council.members)
+                .map(|council| council.member_weights())
                 .unwrap_or_default()
                 .into_iter()
-                .map(|member| multisig_membership_api::api::UserWeight {
-                    user: member,
-                    weight: Uint128::one(),
-                })
+                .map(
+                    |(member, weight)| multisig_membership_api::api::UserWeight {
+                        user: member,
+                        weight,
+                    },
+                )
                 .collect();
             let council_membership_submsg = instantiate_multisig_membership_contract(
                 deps.branch(),
@@ -744,6 +746,7 @@ pub fn reply(mut deps: DepsMut, env: Env, msg: Reply) -> DaoResult<Response> {
                         enterprise_contract: enterprise_contract.to_string(),
                         initial_weights,
                         minimum_eligible_weight: create_dao_msg.minimum_weight_for_rewards,
+                        weight_oracle: None,
                     })?,
                     funds: vec![],
                     label: "Funds distributor".to_string(),
@@ -0,0 +1,130 @@
+use crate::contract::{DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
+use common::cw::QueryContext;
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{StdResult, Storage, SubMsg};
+use cw_asset::AssetInfoUnchecked;
+use cw_storage_plus::{Bound, Map};
+use enterprise_governance_controller_api::api::{
+    AssetBundle, AssetBundleChange, AssetBundlesParams, AssetBundlesResponse,
+    ManageAssetBundlesMsg, ProposalAction, UpdateAssetWhitelistProposalActionMsg,
+    UpdateAssetWhitelistWithBundleMsg,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    InvalidArgument, NoSuchAssetBundle,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+
+/// Asset bundles registered through governance, keyed by their name.
+pub const ASSET_BUNDLES: Map<String, Vec<AssetInfoUnchecked>> = Map::new("asset_bundles");
+
+pub fn manage_asset_bundles(
+    storage: &mut dyn Storage,
+    msg: ManageAssetBundlesMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    for name in msg.remove {
+        ASSET_BUNDLES.remove(storage, name);
+    }
+
+    for bundle in msg.register {
+        ASSET_BUNDLES.save(storage, bundle.name, &bundle.assets)?;
+    }
+
+    Ok(vec![])
+}
+
+/// Fills in the bundle's currently registered assets, failing if the message already states
+/// assets other than those.
+fn resolve_asset_bundle(
+    storage: &dyn Storage,
+    msg: UpdateAssetWhitelistWithBundleMsg,
+) -> GovernanceControllerResult<UpdateAssetWhitelistWithBundleMsg> {
+    let assets = load_asset_bundle(storage, &msg.bundle)?;
+
+    if msg.assets.as_ref().is_some_and(|given| given != &assets) {
+        return Err(InvalidArgument {
+            msg: format!(
+                "Given assets do not match the registered assets of bundle {}",
+                msg.bundle
+            ),
+        });
+    }
+
+    Ok(UpdateAssetWhitelistWithBundleMsg {
+        assets: Some(assets),
+        ..msg
+    })
+}
+
+/// Fills in the registered assets of bundles referenced by the given actions.
+pub fn resolve_asset_bundles(
+    storage: &dyn Storage,
+    proposal_actions: Vec<ProposalAction>,
+) -> GovernanceControllerResult<Vec<ProposalAction>> {
+    proposal_actions
+        .into_iter()
+        .map(|proposal_action| match proposal_action {
+            ProposalAction::UpdateAssetWhitelistWithBundle(msg) => Ok(
+                ProposalAction::UpdateAssetWhitelistWithBundle(resolve_asset_bundle(storage, msg)?),
+            ),
+            proposal_action => Ok(proposal_action),
+        })
+        .collect()
+}
+
+/// Turns the bundle whitelist change into a regular asset whitelist change.
+/// Uses the assets filled in when the proposal was created, falling back to the bundle's
+/// currently registered assets for proposals created before they were filled in.
+pub fn expand_asset_bundle(
+    storage: &dyn Storage,
+    msg: &UpdateAssetWhitelistWithBundleMsg,
+) -> GovernanceControllerResult<UpdateAssetWhitelistProposalActionMsg> {
+    let assets = match &msg.assets {
+        Some(assets) => assets.clone(),
+        None => load_asset_bundle(storage, &msg.bundle)?,
+    };
+
+    let (add, remove) = match msg.change {
+        AssetBundleChange::Add => (assets, vec![]),
+        AssetBundleChange::Remove => (vec![], assets),
+    };
+
+    Ok(UpdateAssetWhitelistProposalActionMsg {
+        remote_treasury_target: msg.remote_treasury_target.clone(),
+        add,
+        remove,
+    })
+}
+
+fn load_asset_bundle(
+    storage: &dyn Storage,
+    name: &str,
+) -> GovernanceControllerResult<Vec<AssetInfoUnchecked>> {
+    ASSET_BUNDLES
+        .may_load(storage, name.to_string())?
+        .ok_or_else(|| NoSuchAssetBundle {
+            name: name.to_string(),
+        })
+}
+
+pub fn query_asset_bundles(
+    qctx: QueryContext,
+    params: AssetBundlesParams,
+) -> GovernanceControllerResult<AssetBundlesResponse> {
+    let limit = params
+        .limit
+        .map_or(DEFAULT_QUERY_LIMIT as usize, |limit| limit as usize)
+        .min(MAX_QUERY_LIMIT as usize);
+
+    let bundles = ASSET_BUNDLES
+        .range(
+            qctx.deps.storage,
+            params.start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(name, assets)| AssetBundle { name, assets }))
+        .collect::<StdResult<Vec<AssetBundle>>>()?;
+
+    Ok(AssetBundlesResponse { bundles })
+}
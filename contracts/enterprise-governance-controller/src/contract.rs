@@ -1,28 +1,61 @@
-use crate::proposals::{get_proposal_actions, set_proposal_executed, PROPOSAL_INFOS};
+use crate::asset_bundles::{
+    expand_asset_bundle, manage_asset_bundles, query_asset_bundles, resolve_asset_bundles,
+};
+use crate::council_elections::{
+    nominate_council_candidate, query_council_election_schedule, schedule_council_elections,
+    start_council_election, update_council_nominations,
+};
+use crate::execution_budget::{record_execution_outflow, validate_execution_budget};
+use crate::execution_condition::is_execution_condition_met;
+use crate::ica::{register_interchain_account_msg, send_tx_msg, DEFAULT_ICA_TIMEOUT_NANOS};
+use crate::proposals::{
+    apply_action_type_thresholds, assert_action_type_cooldowns, asset_key, count_open_proposals,
+    default_proposal_ends_at, get_proposal_actions, index_proposal_assets, proposal_actions_hash,
+    proposal_deposit_status, record_action_type_proposals, set_proposal_executed,
+    BATCH_CREATED_PROPOSAL_IDS, BATCH_PROPOSALS_BEING_CREATED, CONDITION_NOT_MET_PROPOSALS,
+    COUNCIL_VOTES, DEPOSITOR_DEPOSITS, EXECUTION_FAILED_PROPOSALS, OPEN_PROPOSALS,
+    PROPOSALS_BY_ASSET, PROPOSAL_ACTION_OUTCOMES, PROPOSAL_INFOS, PROPOSAL_VOTING_EXTENSIONS,
+    REFUNDABLE_DEPOSITS, VETOED_PROPOSALS, VETO_VOTES,
+};
 use crate::state::{
-    ProposalBeingVotedOn, ProposalExecutabilityStatus, State, COUNCIL_GOV_CONFIG, CREATION_DATE,
-    ENTERPRISE_CONTRACT, GOV_CONFIG, INITIAL_CROSS_CHAIN_TREASURIES, STATE,
+    PendingVoterUnlockingExtension, ProposalBeingVotedOn, ProposalExecutabilityStatus, State,
+    COUNCIL_GOV_CONFIG, CREATION_DATE, ENTERPRISE_CONTRACT, GOV_CONFIG,
+    INITIAL_CROSS_CHAIN_TREASURIES, MEMBER_JOINED_AT, PENDING_VOTER_UNLOCKING_EXTENSIONS, STATE,
+    VETO_COUNCIL, VETO_COUNCIL_UPDATES,
+};
+use crate::token_factory::{change_admin_msg, mint_msg};
+use crate::treasury_distributions::{
+    assert_treasury_distribution_received, record_pending_treasury_distribution,
 };
 use crate::validate::{
-    apply_gov_config_changes, validate_dao_council, validate_dao_gov_config,
-    validate_modify_multisig_membership, validate_proposal_actions, validate_unlocking_period,
-    validate_upgrade_dao,
+    adds_governance_token_to_whitelist, apply_gov_config_changes, assert_expected_gov_config,
+    query_treasury_spending_allowance, validate_allowed_council_proposal_types,
+    validate_council_candidate, validate_council_meets_changed_requirements, validate_dao_council,
+    validate_dao_gov_config, validate_edit_council_members, validate_execution_condition,
+    validate_modify_multisig_membership, validate_proposal_action, validate_proposal_actions,
+    validate_proposal_description, validate_proposal_expiration, validate_proposal_metadata,
+    validate_set_self_admin, validate_spending_allowance_expiry, validate_unlocking_period,
+    validate_update_council_permissions, validate_update_enterprise_factory, validate_upgrade_dao,
+    validate_veto_council,
 };
-use common::commons::ModifyValue::Change;
+use crate::voter_rewards::distribute_to_voters_msgs;
+use common::commons::ModifyValue::{Change, NoChange};
 use common::cw::{Context, Pagination, QueryContext};
+use cosmwasm_std::Order::Ascending;
 use cosmwasm_std::{
-    entry_point, from_json, to_json_binary, wasm_execute, Addr, Binary, CosmosMsg, Deps, DepsMut,
-    Env, MessageInfo, Reply, Response, StdError, StdResult, SubMsg, SubMsgResult, Timestamp,
-    Uint128, Uint64,
+    entry_point, from_json, to_json_binary, to_json_string, wasm_execute, Addr, Binary, Coin,
+    CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult,
+    Storage, SubMsg, SubMsgResult, Timestamp, Uint128, Uint64,
 };
 use cw2::set_contract_version;
 use cw20::Cw20ReceiveMsg;
 use cw721::Cw721ExecuteMsg::TransferNft;
 use cw721::Cw721QueryMsg::OwnerOf;
 use cw721::{Approval, OwnerOfResponse};
-use cw_asset::Asset;
-use cw_utils::Expiration;
+use cw_asset::{Asset, AssetInfo};
+use cw_storage_plus::Bound;
 use cw_utils::Expiration::Never;
+use cw_utils::{Duration, Expiration};
 use denom_staking_api::api::DenomConfigResponse;
 use denom_staking_api::msg::QueryMsg::DenomConfig;
 use enterprise_governance_api::msg::ExecuteMsg::UpdateVotes;
@@ -34,30 +67,58 @@ use enterprise_governance_controller_api::api::ProposalAction::{
 };
 use enterprise_governance_controller_api::api::ProposalType::{Council, General};
 use enterprise_governance_controller_api::api::{
-    CastVoteMsg, ConfigResponse, CreateProposalMsg, CreateProposalWithNftDepositMsg,
-    DistributeFundsMsg, ExecuteEnterpriseMsgsMsg, ExecuteMsgsMsg, ExecuteProposalMsg,
-    ExecuteTreasuryMsgsMsg, GovConfig, GovConfigResponse, MemberVoteParams, MemberVoteResponse,
-    ModifyMultisigMembershipMsg, Proposal, ProposalAction, ProposalActionType, ProposalDeposit,
-    ProposalDepositAsset, ProposalId, ProposalInfo, ProposalParams, ProposalResponse,
-    ProposalStatus, ProposalStatusFilter, ProposalStatusParams, ProposalStatusResponse,
-    ProposalType, ProposalVotesParams, ProposalVotesResponse, ProposalsParams, ProposalsResponse,
-    RequestFundingFromDaoMsg, UpdateAssetWhitelistProposalActionMsg, UpdateCouncilMsg,
+    AcceptedDepositAsset, BurnTokensMsg, CanExecuteActionParams, CanExecuteActionResponse,
+    CastVoteMsg, ClaimDepositMsg, ConfigResponse, CouncilGovConfig, CouncilProposalTally,
+    CouncilVote, CouncilVotesParams, CouncilVotesResponse, CreateProposalMsg,
+    CreateProposalWithNftDepositMsg, CreateProposalsMsg, CreateProposalsResponse, DaoCouncilSpec,
+    DecodedMsg, DecodedProposalAction, DecodedProposalActionsResponse, DistributeFromTreasuryMsg,
+    DistributeFundsMsg, DistributeToVotersMsg, EditCouncilMembersMsg, EmissionSource,
+    ExecuteEnterpriseMsgsMsg, ExecuteInterchainAccountMsgsMsg, ExecuteMsgsMsg,
+    ExecuteProposalActionMsg, ExecuteProposalMsg, ExecuteTreasuryMsgsMsg, ExecutionAtomicity,
+    ExpiringProposal, ExpiringProposalsParams, ExpiringProposalsResponse, ExtendProposalVotingMsg,
+    ExtendVoterUnlockingMsg, GovConfig, GovConfigResponse, GrantSpendingAllowanceMsg,
+    InvalidProposalActionPolicy, IsMemberParams, IsMemberResponse, MemberVoteParams,
+    MemberVoteResponse, MintTokenfactoryDenomMsg, ModifyMultisigMembershipMsg,
+    NominateCouncilCandidateMsg, PendingCouncilProposalsParams, PendingCouncilProposalsResponse,
+    ProjectedProposalOutcome, Proposal, ProposalAction, ProposalActionOutcome,
+    ProposalActionOutcomesResponse, ProposalActionType, ProposalCreatedNotification,
+    ProposalCreationPolicy, ProposalDeposit, ProposalDepositAsset, ProposalId, ProposalInfo,
+    ProposalNotifierMsg, ProposalParams, ProposalResponse, ProposalStatus,
+    ProposalStatusDetailResponse, ProposalStatusFilter, ProposalStatusParams,
+    ProposalStatusResponse, ProposalType, ProposalVotesParams, ProposalVotesResponse,
+    ProposalsAffectingAssetParams, ProposalsAffectingAssetResponse, ProposalsParams,
+    ProposalsResponse, ProposerDeposit, ProposerDepositsParams, ProposerDepositsResponse,
+    RegisterInterchainAccountMsg, RequestFundingFromDaoMsg, RevokeSpendingAllowanceMsg,
+    SetDenomAdminMsg, SetDistributorClaimsPausedMsg, SetDistributorEmissionScheduleMsg,
+    SetSelfAdminMsg, SupportedProposalAction, SupportedProposalActionsResponse, TransferNftMsg,
+    TriggerCouncilElectionMsg, UpdateAssetWhitelistProposalActionMsg, UpdateCouncilMsg,
+    UpdateCouncilPermissionsMsg, UpdateDistributionFeeMsg, UpdateEnterpriseFactoryMsg,
     UpdateGovConfigMsg, UpdateMinimumWeightForRewardsMsg, UpdateNftWhitelistProposalActionMsg,
+    UpdateVetoCouncilMsg, VerifyProposalHashParams, VerifyProposalHashResponse,
+    VetoCouncilResponse, VetoProposalMsg, VoterWeightParams, VoterWeightResponse,
 };
 use enterprise_governance_controller_api::error::GovernanceControllerError::{
-    CustomError, DuplicateNftDeposit, InsufficientProposalDeposit, InvalidCosmosMessage,
-    InvalidDepositType, NoDaoCouncil, NoSuchProposal, NoVotesAvailable, NoVotingPower,
-    ProposalAlreadyExecuted, ProposalCannotBeExecutedYet, RestrictedUser, Std, Unauthorized,
-    UnsupportedCouncilProposalAction, UnsupportedOperationForDaoType, WrongProposalType,
+    CannotBurnNativeAsset, CouncilActionNoLongerAllowed, CustomError, DuplicateNftDeposit,
+    InsufficientProposalDeposit, InvalidArgument, InvalidCosmosMessage, InvalidDepositType,
+    NativeSpendingAllowanceNotSupported, NoDaoCouncil, NoPendingVoterUnlockingExtension,
+    NoRefundableDeposit, NoSuchProposal, NoVetoCouncil, NoVotesAvailable, NoVotingPower,
+    NotAuthorizedToPropose, NotDaoMember, ProposalAlreadyExecuted, ProposalCannotBeExecutedYet,
+    ProposalInChallengeWindow, ProposalNotChallengeable, ProposalVetoed, ProposalVotingEnded,
+    RestrictedUser, Std, TooManyOpenProposals, UnacceptedDepositAsset, Unauthorized,
+    UnsupportedCouncilProposalAction, UnsupportedCw1155Asset, UnsupportedOperationForDaoType,
+    VoteChangesNotAllowed, VotingExtensionNotAllowed, VotingExtensionTooLong, WrongProposalType,
 };
 use enterprise_governance_controller_api::error::GovernanceControllerResult;
 use enterprise_governance_controller_api::msg::{
     Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
 };
 use enterprise_governance_controller_api::response::{
-    execute_cast_council_vote_response, execute_cast_vote_response,
+    execute_cast_council_vote_response, execute_cast_vote_response, execute_claim_deposit_response,
     execute_create_council_proposal_response, execute_create_proposal_response,
-    execute_execute_proposal_response, execute_weights_changed_response, instantiate_response,
+    execute_create_proposals_response, execute_execute_proposal_response,
+    execute_extend_proposal_voting_response, execute_extend_voter_unlocking_response,
+    execute_nominate_council_candidate_response, execute_trigger_council_election_response,
+    execute_veto_proposal_response, execute_weights_changed_response, instantiate_response,
     reply_create_poll_response,
 };
 use enterprise_outposts_api::api::{
@@ -72,30 +133,39 @@ use enterprise_treasury_api::api::{
     ExecuteCosmosMsgsMsg, SpendMsg, UpdateAssetWhitelistMsg, UpdateNftWhitelistMsg,
 };
 use enterprise_treasury_api::msg::ExecuteMsg::{ExecuteCosmosMsgs, Spend};
-use funds_distributor_api::api::{UpdateMinimumEligibleWeightMsg, UpdateUserWeightsMsg};
+use funds_distributor_api::api::{
+    DistributionFee, UpdateConfigMsg, UpdateMinimumEligibleWeightMsg, UpdateUserWeightsMsg,
+};
 use membership_common_api::api::{
-    TotalWeightParams, TotalWeightResponse, UserWeightChange, UserWeightParams, UserWeightResponse,
-    WeightsChangedMsg,
+    ExtendUnlockingMsg, TotalWeightParams, TotalWeightResponse, UserWeightChange, UserWeightParams,
+    UserWeightResponse, WeightsChangedMsg,
 };
 use multisig_membership_api::api::{SetMembersMsg, UpdateMembersMsg};
 use multisig_membership_api::msg::ExecuteMsg::{SetMembers, UpdateMembers};
 use nft_staking_api::api::{NftConfigResponse, NftTokenId};
 use nft_staking_api::msg::QueryMsg::NftConfig;
 use poll_engine_api::api::{
-    CastVoteParams, CreatePollParams, EndPollParams, Poll, PollId, PollParams, PollRejectionReason,
-    PollResponse, PollStatus, PollStatusFilter, PollStatusResponse, PollVoterParams,
-    PollVoterResponse, PollVotersParams, PollVotersResponse, PollsParams, PollsResponse,
-    UpdateVotesParams, VotingScheme,
+    CastVoteParams, CreatePollParams, EndPollParams, ExtendPollParams, Poll, PollId, PollParams,
+    PollRejectionReason, PollResponse, PollStatus, PollStatusFilter, PollStatusResponse,
+    PollVoterParams, PollVoterResponse, PollVotersParams, PollVotersResponse, PollsParams,
+    PollsResponse, UpdateVotesParams, VoteOutcome, VotingScheme,
 };
 use poll_engine_api::error::PollError::PollInProgress;
 use std::cmp::min;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use strum::IntoEnumIterator;
 use token_staking_api::api::TokenConfigResponse;
 use token_staking_api::msg::QueryMsg::TokenConfig;
 use DaoType::{Denom, Multisig, Nft, Token};
 use Expiration::{AtHeight, AtTime};
 use PollRejectionReason::{IsRejectingOutcome, IsVetoOutcome, QuorumNotReached};
-use ProposalAction::{DeployCrossChainTreasury, ExecuteTreasuryMsgs};
+use ProposalAction::{
+    BurnTokens, DeployCrossChainTreasury, DistributeFromTreasury, EditCouncilMembers,
+    ExecuteInterchainAccountMsgs, ExecuteTreasuryMsgs, GrantSpendingAllowance, ManageAssetBundles,
+    MintTokenfactoryDenom, RevokeSpendingAllowance, ScheduleCouncilElections, SetDenomAdmin,
+    SetDistributorClaimsPaused, SetSelfAdmin, UpdateAssetWhitelistWithBundle,
+    UpdateCouncilPermissions, UpdateDistributionFee, UpdateEnterpriseFactory, UpdateVetoCouncil,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:enterprise-governance-controller";
@@ -105,12 +175,26 @@ pub const CREATE_POLL_REPLY_ID: u64 = 1;
 pub const END_POLL_REPLY_ID: u64 = 2;
 pub const EXECUTE_PROPOSAL_ACTIONS_REPLY_ID: u64 = 3;
 pub const CAST_VOTE_REPLY_ID: u64 = 4;
+/// Replies to individually executed actions of best-effort proposals have this bit set in their ID,
+/// with the rest of the ID identifying the proposal and the action's index within it.
+pub const EXECUTE_PROPOSAL_ACTION_REPLY_ID_FLAG: u64 = 1 << 63;
+/// Number of the lowest reply ID bits holding the action's index.
+const ACTION_INDEX_REPLY_ID_BITS: u32 = 16;
+pub const AUTO_EXECUTE_PROPOSAL_REPLY_ID: u64 = 6;
+pub const NOTIFY_PROPOSAL_CREATED_REPLY_ID: u64 = 7;
+pub const DISTRIBUTE_FROM_TREASURY_REPLY_ID: u64 = 8;
+
+/// Maximum number of proposals that can be created in a single batch.
+const MAX_BATCH_PROPOSALS: u8 = 10;
 
 const PROPOSAL_ACTIONS_EXECUTION_STATUS: &str = "status";
 
 pub const DEFAULT_QUERY_LIMIT: u8 = 50;
 pub const MAX_QUERY_LIMIT: u8 = 100;
 
+/// Maximum number of stored proposals a filtering query looks at in a single page.
+const MAX_SCANNED_PROPOSALS: usize = 500;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -132,10 +216,11 @@ pub fn instantiate(
     let enterprise_contract = deps.api.addr_validate(&msg.enterprise_contract)?;
     ENTERPRISE_CONTRACT.save(deps.storage, &enterprise_contract)?;
 
-    validate_dao_gov_config(&msg.dao_type, &msg.gov_config)?;
+    validate_dao_gov_config(deps.as_ref(), &msg.dao_type, &msg.gov_config)?;
     GOV_CONFIG.save(deps.storage, &msg.gov_config)?;
 
-    let council_gov_config = validate_dao_council(deps.as_ref(), msg.council_gov_config)?;
+    let council_gov_config =
+        validate_dao_council(deps.as_ref(), &msg.gov_config, msg.council_gov_config)?;
     COUNCIL_GOV_CONFIG.save(deps.storage, &council_gov_config)?;
 
     for (proposal_id, proposal_info) in msg.proposal_infos.unwrap_or_default() {
@@ -162,6 +247,7 @@ pub fn execute(
     let ctx = &mut Context { deps, env, info };
     match msg {
         ExecuteMsg::CreateProposal(msg) => determine_deposit_and_create_proposal(ctx, msg),
+        ExecuteMsg::CreateProposals(msg) => create_proposals(ctx, msg),
         ExecuteMsg::CreateProposalWithNftDeposit(msg) => create_proposal_with_nft_deposit(ctx, msg),
         ExecuteMsg::CreateCouncilProposal(msg) => create_council_proposal(ctx, msg),
         ExecuteMsg::CastVote(msg) => cast_vote(ctx, msg),
@@ -169,7 +255,14 @@ pub fn execute(
         ExecuteMsg::ExecuteProposal(msg) => execute_proposal(ctx, msg),
         ExecuteMsg::Receive(msg) => receive_cw20(ctx, msg),
         ExecuteMsg::WeightsChanged(msg) => weights_changed(ctx, msg),
+        ExecuteMsg::VetoProposal(msg) => veto_proposal(ctx, msg),
+        ExecuteMsg::ExtendProposalVoting(msg) => extend_proposal_voting(ctx, msg),
+        ExecuteMsg::NominateCouncilCandidate(msg) => nominate_candidate(ctx, msg),
+        ExecuteMsg::TriggerCouncilElection(msg) => trigger_council_election(ctx, msg),
+        ExecuteMsg::ClaimDeposit(msg) => claim_deposit(ctx, msg),
+        ExecuteMsg::ExtendVoterUnlocking(msg) => extend_voter_unlocking(ctx, msg),
         ExecuteMsg::ExecuteProposalActions(msg) => execute_proposal_actions(ctx, msg),
+        ExecuteMsg::ExecuteProposalAction(msg) => execute_proposal_action(ctx, msg),
         ExecuteMsg::DeployInitialCrossChainTreasuries {} => {
             execute_deploy_initial_cross_chain_treasuries(ctx)
         }
@@ -180,11 +273,27 @@ fn determine_deposit_and_create_proposal(
     ctx: &mut Context,
     msg: CreateProposalMsg,
 ) -> GovernanceControllerResult<Response> {
-    let dao_type = query_dao_type(ctx.deps.as_ref())?;
-
     let proposer = ctx.info.sender.clone();
 
-    let deposit = match dao_type {
+    let deposit = deposit_coin_from_funds(ctx)?
+        .map(|coin| -> GovernanceControllerResult<ProposalDeposit> {
+            let depositor = deposit_owner_or_proposer(ctx, &msg, &proposer)?;
+            Ok(denom_deposit(depositor, coin))
+        })
+        .transpose()?;
+
+    create_proposal(ctx, msg, deposit, proposer)
+}
+
+/// Determines the coin deposited from the funds sent. Only native deposits can be made this way.
+fn deposit_coin_from_funds(ctx: &Context) -> GovernanceControllerResult<Option<Coin>> {
+    let accepted_deposit_assets = GOV_CONFIG.load(ctx.deps.storage)?.accepted_deposit_assets;
+
+    if let Some(accepted_deposit_assets) = accepted_deposit_assets {
+        return native_deposit_from_funds(&ctx.info.funds, &accepted_deposit_assets);
+    }
+
+    match query_dao_type(ctx.deps.as_ref())? {
         Denom => {
             let dao_denom_config = query_dao_denom_config(ctx.deps.as_ref())?;
 
@@ -193,27 +302,63 @@ fn determine_deposit_and_create_proposal(
                 .info
                 .funds
                 .iter()
-                .find(|coin| coin.denom == dao_denom_config.denom);
-
-            let depositor = msg
-                .deposit_owner
-                .as_ref()
-                .map(|it| ctx.deps.api.addr_validate(it))
-                .transpose()?
-                .unwrap_or_else(|| proposer.clone());
+                .find(|coin| coin.denom == dao_denom_config.denom)
+                .cloned();
 
-            dao_denom_from_funds.map(|coin| ProposalDeposit {
-                depositor,
-                asset: ProposalDepositAsset::Denom {
-                    denom: coin.denom.clone(),
-                    amount: coin.amount,
-                },
-            })
+            Ok(dao_denom_from_funds)
         }
-        Token | Nft | Multisig => None,
-    };
+        Token | Nft | Multisig => Ok(None),
+    }
+}
 
-    create_proposal(ctx, msg, deposit, proposer)
+fn deposit_owner_or_proposer(
+    ctx: &Context,
+    msg: &CreateProposalMsg,
+    proposer: &Addr,
+) -> GovernanceControllerResult<Addr> {
+    let depositor = msg
+        .deposit_owner
+        .as_ref()
+        .map(|it| ctx.deps.api.addr_validate(it))
+        .transpose()?
+        .unwrap_or_else(|| proposer.clone());
+
+    Ok(depositor)
+}
+
+fn denom_deposit(depositor: Addr, coin: Coin) -> ProposalDeposit {
+    ProposalDeposit {
+        depositor,
+        asset: ProposalDepositAsset::Denom {
+            denom: coin.denom,
+            amount: coin.amount,
+        },
+    }
+}
+
+/// Determines the deposited coin from the funds sent, when the DAO accepts deposits in a list
+/// of assets.
+fn native_deposit_from_funds(
+    funds: &[Coin],
+    accepted_deposit_assets: &[AcceptedDepositAsset],
+) -> GovernanceControllerResult<Option<Coin>> {
+    match funds {
+        [] => Ok(None),
+        [coin] => {
+            let is_accepted = accepted_deposit_assets
+                .iter()
+                .any(|accepted| accepted.asset == AssetInfo::native(coin.denom.clone()));
+
+            if !is_accepted {
+                return Err(UnacceptedDepositAsset);
+            }
+
+            Ok(Some(coin.clone()))
+        }
+        _ => Err(InvalidArgument {
+            msg: "Proposal deposit can only be made in a single asset".to_string(),
+        }),
+    }
 }
 
 fn create_proposal_with_nft_deposit(
@@ -228,6 +373,15 @@ fn create_proposal_with_nft_deposit(
         });
     }
 
+    // NFTs are never among the accepted deposit assets
+    if GOV_CONFIG
+        .load(ctx.deps.storage)?
+        .accepted_deposit_assets
+        .is_some()
+    {
+        return Err(UnacceptedDepositAsset);
+    }
+
     assert_no_duplicate_nft_deposits(&msg.deposit_tokens)?;
 
     let dao_nft_config = query_dao_nft_config(ctx.deps.as_ref())?;
@@ -337,6 +491,31 @@ fn create_proposal(
     deposit: Option<ProposalDeposit>,
     proposer: Addr,
 ) -> GovernanceControllerResult<Response> {
+    let (create_poll_submsg, whitelists_governance_token) =
+        create_proposal_poll(ctx, msg, deposit, proposer)?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    let mut response = execute_create_proposal_response(dao_address.to_string())
+        .add_submessage(create_poll_submsg);
+
+    // validation lets this through only if the DAO chose to be warned instead
+    if whitelists_governance_token {
+        response = response.add_attribute("warning", "governance_token_in_whitelist");
+    }
+
+    Ok(response)
+}
+
+/// Validates the proposal and prepares the submessage creating its poll.
+/// Also returns whether the proposal whitelists the governance token, which the DAO
+/// may have chosen to only be warned about.
+fn create_proposal_poll(
+    ctx: &mut Context,
+    msg: CreateProposalMsg,
+    deposit: Option<ProposalDeposit>,
+    proposer: Addr,
+) -> GovernanceControllerResult<(SubMsg, bool)> {
     unrestricted_users_only(ctx.deps.as_ref(), proposer.to_string())?;
 
     let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
@@ -349,20 +528,339 @@ fn create_proposal(
 
     assert_sufficient_deposit_or_member(&gov_config, &deposit, user_available_votes)?;
 
-    validate_proposal_actions(
+    let action_types = msg
+        .proposal_actions
+        .iter()
+        .map(to_proposal_action_type)
+        .collect::<Vec<ProposalActionType>>();
+
+    assert_can_create_general_proposal(
         ctx.deps.as_ref(),
-        query_dao_type(ctx.deps.as_ref())?,
-        &msg.proposal_actions,
+        &ctx.env,
+        &gov_config,
+        &proposer,
+        user_available_votes,
+        &action_types,
     )?;
+    record_action_type_proposals(ctx.deps.storage, &action_types, ctx.env.block.time)?;
+
+    let dao_type = query_dao_type(ctx.deps.as_ref())?;
+
+    validate_proposal_actions(ctx.deps.as_ref(), &msg.proposal_actions)?;
+    validate_spending_allowance_expiry(&ctx.env.block, &msg.proposal_actions)?;
+
+    let whitelists_governance_token =
+        whitelists_governance_token(ctx.deps.as_ref(), &dao_type, &msg.proposal_actions)?;
+
+    let gov_config = apply_action_type_thresholds(gov_config, &action_types);
 
     let create_poll_submsg = create_poll(ctx, gov_config, msg, deposit, General, proposer)?;
 
+    Ok((create_poll_submsg, whitelists_governance_token))
+}
+
+/// Creates several general proposals at once. Either all of them are created, or none are.
+///
+/// A native deposit sent along is split evenly between the proposals, with the remainder
+/// added to the first proposal's deposit, so it has to cover the required deposit of each.
+/// The IDs of the created proposals are set as the response's data.
+fn create_proposals(
+    ctx: &mut Context,
+    msg: CreateProposalsMsg,
+) -> GovernanceControllerResult<Response> {
+    let proposals_count = msg.proposals.len();
+
+    if proposals_count == 0 || proposals_count > MAX_BATCH_PROPOSALS as usize {
+        return Err(InvalidArgument {
+            msg: format!(
+                "A batch has to contain between 1 and {} proposals",
+                MAX_BATCH_PROPOSALS
+            ),
+        });
+    }
+
+    let proposer = ctx.info.sender.clone();
+
+    // proposals of the batch are only counted as open once their polls are created,
+    // so they have to be accounted for up front
+    if let Some(max) = GOV_CONFIG
+        .load(ctx.deps.storage)?
+        .max_open_proposals_per_proposer
+    {
+        let open_proposals =
+            count_open_proposals(ctx.deps.storage, proposer.clone(), ctx.env.block.time)?;
+        if open_proposals + proposals_count > max as usize {
+            return Err(TooManyOpenProposals { max });
+        }
+    }
+
+    let deposit_coin = deposit_coin_from_funds(ctx)?;
+
+    let mut create_poll_submsgs = vec![];
+    let mut whitelists_governance_token = false;
+
+    for (i, create_proposal_msg) in msg.proposals.into_iter().enumerate() {
+        let deposit = deposit_coin
+            .as_ref()
+            .map(|coin| -> GovernanceControllerResult<ProposalDeposit> {
+                let depositor = deposit_owner_or_proposer(ctx, &create_proposal_msg, &proposer)?;
+
+                let mut amount = coin.amount / Uint128::from(proposals_count as u128);
+                if i == 0 {
+                    amount += coin.amount % Uint128::from(proposals_count as u128);
+                }
+
+                Ok(denom_deposit(
+                    depositor,
+                    Coin::new(amount.u128(), &coin.denom),
+                ))
+            })
+            .transpose()?;
+
+        let (create_poll_submsg, whitelists_token) =
+            create_proposal_poll(ctx, create_proposal_msg, deposit, proposer.clone())?;
+
+        // only one proposal can be tracked as being created, so the batch's proposals are
+        // queued up in the order of their polls' creation
+        let proposal_info =
+            STATE
+                .load(ctx.deps.storage)?
+                .proposal_being_created
+                .ok_or(CustomError {
+                    val: "Invalid state - missing proposal info".to_string(),
+                })?;
+        BATCH_PROPOSALS_BEING_CREATED.push_back(ctx.deps.storage, &proposal_info)?;
+        STATE.update(ctx.deps.storage, |state| -> StdResult<State> {
+            Ok(State {
+                proposal_being_created: None,
+                ..state
+            })
+        })?;
+
+        create_poll_submsgs.push(create_poll_submsg);
+        whitelists_governance_token |= whitelists_token;
+    }
+
+    BATCH_CREATED_PROPOSAL_IDS.save(ctx.deps.storage, &vec![])?;
+
     let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
 
-    Ok(
-        execute_create_proposal_response(dao_address.to_string())
-            .add_submessage(create_poll_submsg),
+    let mut response = execute_create_proposals_response(dao_address.to_string(), proposals_count)
+        .add_submessages(create_poll_submsgs);
+
+    // validation lets this through only if the DAO chose to be warned instead
+    if whitelists_governance_token {
+        response = response.add_attribute("warning", "governance_token_in_whitelist");
+    }
+
+    Ok(response)
+}
+
+/// Nominates a candidate in the due council election, backing them with the nominator's weight.
+/// Only members of the DAO can nominate, and only members can be nominated.
+fn nominate_candidate(
+    ctx: &mut Context,
+    msg: NominateCouncilCandidateMsg,
+) -> GovernanceControllerResult<Response> {
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let nominator_weight = get_user_available_votes(qctx, ctx.info.sender.clone())?;
+
+    if nominator_weight.is_zero() {
+        return Err(NotDaoMember {
+            member: ctx.info.sender.to_string(),
+        });
+    }
+
+    let candidate = validate_council_candidate(ctx.deps.as_ref(), msg.candidate)?;
+
+    nominate_council_candidate(
+        ctx.deps.storage,
+        ctx.env.block.time,
+        ctx.info.sender.clone(),
+        nominator_weight,
+        candidate.clone(),
+    )?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    Ok(execute_nominate_council_candidate_response(
+        dao_address.to_string(),
+        ctx.info.sender.to_string(),
+        candidate.to_string(),
+    ))
+}
+
+/// Creates the council election proposal, once the due election's nominations are closed.
+/// Anyone can do this. If fewer candidates than there are seats were nominated, no proposal is
+/// created and the election is skipped.
+///
+/// The election is a general proposal replacing the council members with the best supported
+/// candidates, each with a voting weight of 1. The council's quorum, threshold and allowed
+/// proposal action types remain unchanged. The proposal is created by this contract, so no
+/// deposit is required.
+fn trigger_council_election(
+    ctx: &mut Context,
+    _msg: TriggerCouncilElectionMsg,
+) -> GovernanceControllerResult<Response> {
+    let (elected, next_election) = start_council_election(ctx.deps.storage, ctx.env.block.time)?;
+
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+
+    let Some(elected) = elected else {
+        return Ok(execute_trigger_council_election_response(
+            dao_address.to_string(),
+            ctx.info.sender.to_string(),
+            next_election,
+            false,
+        ));
+    };
+
+    let council_gov_config = COUNCIL_GOV_CONFIG
+        .load(ctx.deps.storage)?
+        .ok_or(NoDaoCouncil)?;
+
+    let election_action = UpdateCouncil(UpdateCouncilMsg {
+        dao_council: Some(DaoCouncilSpec {
+            members: elected.iter().map(|it| it.to_string()).collect(),
+            weights: None,
+            quorum: council_gov_config.quorum,
+            threshold: council_gov_config.threshold,
+            allowed_proposal_action_types: Some(council_gov_config.allowed_proposal_action_types),
+        }),
+    });
+
+    let gov_config = apply_action_type_thresholds(
+        GOV_CONFIG.load(ctx.deps.storage)?,
+        &[ProposalActionType::UpdateCouncil],
+    );
+
+    let create_proposal_msg = CreateProposalMsg {
+        title: "Council election".to_string(),
+        description: None,
+        proposal_actions: vec![election_action],
+        deposit_owner: None,
+        execution_atomicity: None,
+        expires_at: None,
+        execution_condition: None,
+        execution_budget: None,
+        metadata: None,
+    };
+
+    let proposer = ctx.env.contract.address.clone();
+    let create_poll_submsg = create_poll(
+        ctx,
+        gov_config,
+        create_proposal_msg,
+        None,
+        General,
+        proposer,
+    )?;
+
+    Ok(execute_trigger_council_election_response(
+        dao_address.to_string(),
+        ctx.info.sender.to_string(),
+        next_election,
+        true,
     )
+    .add_submessage(create_poll_submsg))
+}
+
+fn whitelists_governance_token(
+    deps: Deps,
+    dao_type: &DaoType,
+    proposal_actions: &[ProposalAction],
+) -> GovernanceControllerResult<bool> {
+    for proposal_action in proposal_actions {
+        let whitelist_msg = match proposal_action {
+            UpdateAssetWhitelist(msg) => msg.clone(),
+            UpdateAssetWhitelistWithBundle(msg) => expand_asset_bundle(deps.storage, msg)?,
+            _ => continue,
+        };
+
+        if adds_governance_token_to_whitelist(deps, dao_type, &whitelist_msg)? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Checks that the proposer is allowed to create a general proposal containing actions of
+/// the given types, according to the proposal creation policy, the limit on open proposals
+/// and the action type cooldowns.
+fn assert_can_create_general_proposal(
+    deps: Deps,
+    env: &Env,
+    gov_config: &GovConfig,
+    proposer: &Addr,
+    user_available_votes: Uint128,
+    action_types: &[ProposalActionType],
+) -> GovernanceControllerResult<()> {
+    assert_allowed_to_propose(gov_config, proposer, user_available_votes)?;
+
+    if let Some(max) = gov_config.max_open_proposals_per_proposer {
+        let open_proposals = count_open_proposals(deps.storage, proposer.clone(), env.block.time)?;
+        if open_proposals >= max as usize {
+            return Err(TooManyOpenProposals { max });
+        }
+    }
+
+    if let Some(action_type_cooldowns) = &gov_config.action_type_cooldowns {
+        assert_action_type_cooldowns(
+            deps.storage,
+            action_type_cooldowns,
+            action_types,
+            env.block.time,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the proposer is a member of the DAO's council, and that the council is allowed
+/// to propose actions of the given types. Returns the council's gov config.
+fn assert_can_create_council_proposal(
+    deps: Deps,
+    proposer: &Addr,
+    action_types: &[ProposalActionType],
+) -> GovernanceControllerResult<CouncilGovConfig> {
+    let dao_council = COUNCIL_GOV_CONFIG.load(deps.storage)?.ok_or(NoDaoCouncil)?;
+
+    if query_council_member_weight(deps, proposer.to_string())?.is_zero() {
+        return Err(Unauthorized);
+    }
+
+    // validate that proposal actions are allowed
+    for action_type in action_types {
+        if !dao_council
+            .allowed_proposal_action_types
+            .contains(action_type)
+        {
+            return Err(UnsupportedCouncilProposalAction {
+                action: action_type.clone(),
+            });
+        }
+    }
+
+    Ok(dao_council)
+}
+
+fn assert_allowed_to_propose(
+    gov_config: &GovConfig,
+    proposer: &Addr,
+    user_voting_weight: Uint128,
+) -> GovernanceControllerResult<()> {
+    let allowed = match &gov_config.proposal_creation_policy {
+        None | Some(ProposalCreationPolicy::AnyMember) => true,
+        Some(ProposalCreationPolicy::MinWeight(min_weight)) => user_voting_weight >= *min_weight,
+        Some(ProposalCreationPolicy::Allowlist(allowlist)) => allowlist.contains(proposer),
+    };
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(NotAuthorizedToPropose)
+    }
 }
 
 fn assert_sufficient_deposit_or_member(
@@ -370,6 +868,10 @@ fn assert_sufficient_deposit_or_member(
     deposit: &Option<ProposalDeposit>,
     user_voting_weight: Uint128,
 ) -> GovernanceControllerResult<()> {
+    if let Some(accepted_deposit_assets) = &gov_config.accepted_deposit_assets {
+        return assert_accepted_deposit(accepted_deposit_assets, deposit);
+    }
+
     match gov_config.minimum_deposit {
         None => {
             if user_voting_weight.is_zero() {
@@ -393,72 +895,94 @@ fn assert_sufficient_deposit_or_member(
     }
 }
 
+/// Checks that the deposit is in one of the accepted assets, and meets that asset's minimum.
+fn assert_accepted_deposit(
+    accepted_deposit_assets: &[AcceptedDepositAsset],
+    deposit: &Option<ProposalDeposit>,
+) -> GovernanceControllerResult<()> {
+    let deposit = match deposit {
+        Some(deposit) => deposit,
+        None => {
+            let required_amount = accepted_deposit_assets
+                .iter()
+                .map(|accepted| accepted.minimum_amount)
+                .min()
+                .unwrap_or_default();
+            return Err(InsufficientProposalDeposit { required_amount });
+        }
+    };
+
+    let deposit_asset = match &deposit.asset {
+        ProposalDepositAsset::Denom { denom, .. } => AssetInfo::native(denom.clone()),
+        ProposalDepositAsset::Cw20 { token_addr, .. } => AssetInfo::cw20(token_addr.clone()),
+        ProposalDepositAsset::Cw721 { .. } => return Err(UnacceptedDepositAsset),
+    };
+
+    let accepted_deposit_asset = accepted_deposit_assets
+        .iter()
+        .find(|accepted| accepted.asset == deposit_asset)
+        .ok_or(UnacceptedDepositAsset)?;
+
+    if deposit.amount() < accepted_deposit_asset.minimum_amount {
+        return Err(InsufficientProposalDeposit {
+            required_amount: accepted_deposit_asset.minimum_amount,
+        });
+    }
+
+    Ok(())
+}
+
 fn create_council_proposal(
     ctx: &mut Context,
     msg: CreateProposalMsg,
 ) -> GovernanceControllerResult<Response> {
     unrestricted_users_only(ctx.deps.as_ref(), ctx.info.sender.to_string())?;
 
-    let dao_council = COUNCIL_GOV_CONFIG.load(ctx.deps.storage)?;
-
-    match dao_council {
-        None => Err(NoDaoCouncil),
-        Some(dao_council) => {
-            validate_proposal_actions(
-                ctx.deps.as_ref(),
-                query_dao_type(ctx.deps.as_ref())?,
-                &msg.proposal_actions,
-            )?;
-
-            let member_weight = query_council_member_weight(
-                ctx.deps.as_ref(),
-                ctx.info.sender.clone().to_string(),
-            )?;
+    if COUNCIL_GOV_CONFIG.load(ctx.deps.storage)?.is_none() {
+        return Err(NoDaoCouncil);
+    }
 
-            if member_weight.is_zero() {
-                return Err(Unauthorized);
-            }
+    validate_proposal_actions(ctx.deps.as_ref(), &msg.proposal_actions)?;
 
-            let allowed_actions = dao_council.allowed_proposal_action_types;
+    let action_types = msg
+        .proposal_actions
+        .iter()
+        .map(to_proposal_action_type)
+        .collect::<Vec<ProposalActionType>>();
 
-            // validate that proposal actions are allowed
-            for proposal_action in &msg.proposal_actions {
-                let proposal_action_type = to_proposal_action_type(proposal_action);
-                if !allowed_actions.contains(&proposal_action_type) {
-                    return Err(UnsupportedCouncilProposalAction {
-                        action: proposal_action_type,
-                    });
-                }
-            }
+    let dao_council =
+        assert_can_create_council_proposal(ctx.deps.as_ref(), &ctx.info.sender, &action_types)?;
 
-            let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
+    let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
 
-            let council_gov_config = GovConfig {
-                quorum: dao_council.quorum,
-                threshold: dao_council.threshold,
-                ..gov_config
-            };
+    // council proposals are resolved using the council's quorum and threshold,
+    // which are stored in the poll itself. The minimum 'yes' votes is expressed in
+    // the DAO's voting weight, so it does not apply to them
+    let council_gov_config = GovConfig {
+        quorum: dao_council.quorum,
+        threshold: dao_council.threshold,
+        minimum_yes_votes: None,
+        ..gov_config
+    };
 
-            let create_poll_submsg = create_poll(
-                ctx,
-                council_gov_config,
-                msg,
-                None,
-                Council,
-                ctx.info.sender.clone(),
-            )?;
+    let create_poll_submsg = create_poll(
+        ctx,
+        council_gov_config,
+        msg,
+        None,
+        Council,
+        ctx.info.sender.clone(),
+    )?;
 
-            let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
+    let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
 
-            Ok(
-                execute_create_council_proposal_response(dao_address.to_string())
-                    .add_submessage(create_poll_submsg),
-            )
-        }
-    }
+    Ok(
+        execute_create_council_proposal_response(dao_address.to_string())
+            .add_submessage(create_poll_submsg),
+    )
 }
 
-fn to_proposal_action_type(proposal_action: &ProposalAction) -> ProposalActionType {
+pub(crate) fn to_proposal_action_type(proposal_action: &ProposalAction) -> ProposalActionType {
     match proposal_action {
         UpdateMetadata(_) => ProposalActionType::UpdateMetadata,
         UpdateGovConfig(_) => ProposalActionType::UpdateGovConfig,
@@ -473,7 +997,32 @@ fn to_proposal_action_type(proposal_action: &ProposalAction) -> ProposalActionTy
         ModifyMultisigMembership(_) => ProposalActionType::ModifyMultisigMembership,
         DistributeFunds(_) => ProposalActionType::DistributeFunds,
         UpdateMinimumWeightForRewards(_) => ProposalActionType::UpdateMinimumWeightForRewards,
+        UpdateDistributionFee(_) => ProposalActionType::UpdateDistributionFee,
+        SetDistributorClaimsPaused(_) => ProposalActionType::SetDistributorClaimsPaused,
+        ProposalAction::TransferNft(_) => ProposalActionType::TransferNft,
+        ProposalAction::SetDistributorEmissionSchedule(_) => {
+            ProposalActionType::SetDistributorEmissionSchedule
+        }
+        ProposalAction::DistributeToVoters(_) => ProposalActionType::DistributeToVoters,
+        ProposalAction::RegisterInterchainAccount(_) => {
+            ProposalActionType::RegisterInterchainAccount
+        }
         DeployCrossChainTreasury(_) => ProposalActionType::DeployCrossChainTreasury,
+        BurnTokens(_) => ProposalActionType::BurnTokens,
+        UpdateVetoCouncil(_) => ProposalActionType::UpdateVetoCouncil,
+        MintTokenfactoryDenom(_) => ProposalActionType::MintTokenfactoryDenom,
+        SetDenomAdmin(_) => ProposalActionType::SetDenomAdmin,
+        UpdateEnterpriseFactory(_) => ProposalActionType::UpdateEnterpriseFactory,
+        SetSelfAdmin(_) => ProposalActionType::SetSelfAdmin,
+        UpdateCouncilPermissions(_) => ProposalActionType::UpdateCouncilPermissions,
+        GrantSpendingAllowance(_) => ProposalActionType::GrantSpendingAllowance,
+        RevokeSpendingAllowance(_) => ProposalActionType::RevokeSpendingAllowance,
+        DistributeFromTreasury(_) => ProposalActionType::DistributeFromTreasury,
+        EditCouncilMembers(_) => ProposalActionType::EditCouncilMembers,
+        ExecuteInterchainAccountMsgs(_) => ProposalActionType::ExecuteInterchainAccountMsgs,
+        ManageAssetBundles(_) => ProposalActionType::ManageAssetBundles,
+        UpdateAssetWhitelistWithBundle(_) => ProposalActionType::UpdateAssetWhitelistWithBundle,
+        ScheduleCouncilElections(_) => ProposalActionType::ScheduleCouncilElections,
     }
 }
 
@@ -485,28 +1034,67 @@ fn create_poll(
     proposal_type: ProposalType,
     proposer: Addr,
 ) -> GovernanceControllerResult<SubMsg> {
-    let ends_at = ctx.env.block.time.plus_seconds(gov_config.vote_duration);
+    if msg.execution_atomicity == Some(ExecutionAtomicity::BestEffort)
+        && msg.proposal_actions.is_empty()
+    {
+        return Err(InvalidArgument {
+            msg: "Best-effort execution requires at least one proposal action".to_string(),
+        });
+    }
 
-    let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
-    let create_poll_submsg = SubMsg::reply_on_success(
-        wasm_execute(
-            governance_contract.to_string(),
-            &enterprise_governance_api::msg::ExecuteMsg::CreatePoll(CreatePollParams {
-                proposer: proposer.to_string(),
-                deposit_amount: Uint128::zero(),
-                label: msg.title,
-                description: msg.description.unwrap_or_default(),
+    if let (Some(description), Some(format)) = (&msg.description, &gov_config.description_format) {
+        validate_proposal_description(description, format)?;
+    }
+
+    if let Some(metadata) = &msg.metadata {
+        validate_proposal_metadata(metadata)?;
+    }
+
+    if let Some(condition) = &msg.execution_condition {
+        validate_execution_condition(ctx.deps.as_ref(), condition)?;
+    }
+
+    let execution_budget = validate_execution_budget(
+        ctx.deps.as_ref(),
+        &gov_config,
+        msg.execution_budget.as_ref(),
+        &msg.proposal_actions,
+    )?;
+
+    let ends_at = match msg.expires_at {
+        Some(expires_at) => validate_proposal_expiration(
+            &gov_config,
+            ctx.env.block.time,
+            expires_at,
+            query_dao_unlocking_period(ctx.deps.as_ref())?,
+        )?,
+        None => default_proposal_ends_at(&gov_config, ctx.env.block.time),
+    };
+
+    let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
+    let create_poll_submsg = SubMsg::reply_on_success(
+        wasm_execute(
+            governance_contract.to_string(),
+            &enterprise_governance_api::msg::ExecuteMsg::CreatePoll(CreatePollParams {
+                proposer: proposer.to_string(),
+                deposit_amount: Uint128::zero(),
+                label: msg.title,
+                description: msg.description.unwrap_or_default(),
                 scheme: VotingScheme::CoinVoting,
                 ends_at,
                 quorum: gov_config.quorum,
                 threshold: gov_config.threshold,
                 veto_threshold: gov_config.veto_threshold,
+                minimum_yes_votes: gov_config.minimum_yes_votes,
             }),
             vec![],
         )?,
         CREATE_POLL_REPLY_ID,
     );
 
+    // bundles are expanded once, so the proposal executes with the assets it was voted on with
+    let proposal_actions = resolve_asset_bundles(ctx.deps.storage, msg.proposal_actions)?;
+
     let state = STATE.load(ctx.deps.storage)?;
     STATE.save(
         ctx.deps.storage,
@@ -516,7 +1104,11 @@ fn create_poll(
                 executed_at: None,
                 earliest_execution: None,
                 proposal_deposit: deposit,
-                proposal_actions: msg.proposal_actions,
+                proposal_actions,
+                execution_atomicity: msg.execution_atomicity,
+                execution_condition: msg.execution_condition,
+                execution_budget,
+                metadata: msg.metadata,
             }),
             ..state
         },
@@ -543,6 +1135,23 @@ fn cast_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControllerResult<
         return Err(WrongProposalType);
     }
 
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let poll = query_poll(&qctx, msg.proposal_id)?.poll;
+
+    // DAOs can bar users that became members after the proposal started from voting on it
+    if restricts_late_joiner_votes(ctx.deps.storage)?
+        && joined_after(ctx.deps.storage, &ctx.info.sender, poll.started_at)?
+    {
+        return Err(Unauthorized);
+    }
+
+    assert_vote_change_allowed(
+        QueryContext::from(ctx.deps.as_ref(), ctx.env.clone()),
+        &ctx.info.sender,
+        msg.proposal_id,
+        msg.outcome,
+    )?;
+
     let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
 
     let cast_vote_submessage = SubMsg::reply_on_success(
@@ -588,6 +1197,39 @@ fn cast_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControllerResult<
     .add_submessage(cast_vote_submessage))
 }
 
+/// Checks that the voter is not changing their existing vote to another option, unless
+/// the DAO allows vote changes.
+pub fn assert_vote_change_allowed(
+    qctx: QueryContext,
+    voter: &Addr,
+    proposal_id: ProposalId,
+    outcome: VoteOutcome,
+) -> GovernanceControllerResult<()> {
+    let allow_vote_changes = GOV_CONFIG
+        .load(qctx.deps.storage)?
+        .allow_vote_changes
+        .unwrap_or(true);
+
+    if allow_vote_changes {
+        return Ok(());
+    }
+
+    let previous_vote = query_member_vote(
+        qctx,
+        MemberVoteParams {
+            member: voter.to_string(),
+            proposal_id,
+        },
+    )?
+    .vote;
+
+    if previous_vote.is_some_and(|vote| vote.outcome != outcome as u8) {
+        return Err(VoteChangesNotAllowed);
+    }
+
+    Ok(())
+}
+
 fn cast_council_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControllerResult<Response> {
     unrestricted_users_only(ctx.deps.as_ref(), ctx.info.sender.to_string())?;
 
@@ -615,16 +1257,52 @@ fn cast_council_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControlle
 
             let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
 
-            let cast_vote_submessage = SubMsg::new(wasm_execute(
-                governance_contract.to_string(),
-                &enterprise_governance_api::msg::ExecuteMsg::CastVote(CastVoteParams {
-                    poll_id: msg.proposal_id.into(),
+            // recorded here as well, so that the vote remains visible even if the member
+            // is removed from the council later
+            COUNCIL_VOTES.save(
+                ctx.deps.storage,
+                (msg.proposal_id, ctx.info.sender.clone()),
+                &CouncilVote {
+                    member: ctx.info.sender.clone(),
                     outcome: msg.outcome,
-                    voter: ctx.info.sender.to_string(),
-                    amount: member_weight,
-                }),
-                vec![],
-            )?);
+                    weight: member_weight,
+                    voted_at: ctx.env.block.time,
+                },
+            )?;
+
+            let cast_vote_submessage = SubMsg::reply_on_success(
+                wasm_execute(
+                    governance_contract.to_string(),
+                    &enterprise_governance_api::msg::ExecuteMsg::CastVote(CastVoteParams {
+                        poll_id: msg.proposal_id.into(),
+                        outcome: msg.outcome,
+                        voter: ctx.info.sender.to_string(),
+                        amount: member_weight,
+                    }),
+                    vec![],
+                )?,
+                CAST_VOTE_REPLY_ID,
+            );
+
+            let total_available_votes =
+                total_available_votes(ctx.deps.as_ref(), Never {}, proposal_info.proposal_type)?;
+
+            let end_proposal_status = simulate_end_proposal_status(
+                ctx.deps.as_ref(),
+                msg.proposal_id,
+                total_available_votes,
+            )?
+            .status;
+
+            STATE.update(ctx.deps.storage, |state| -> StdResult<State> {
+                Ok(State {
+                    proposal_being_voted_on: Some(ProposalBeingVotedOn {
+                        proposal_id: msg.proposal_id,
+                        executability_status: end_proposal_status.into(),
+                    }),
+                    ..state
+                })
+            })?;
 
             let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
 
@@ -633,7 +1311,7 @@ fn cast_council_vote(ctx: &mut Context, msg: CastVoteMsg) -> GovernanceControlle
                 msg.proposal_id,
                 ctx.info.sender.to_string(),
                 msg.outcome,
-                1u8.into(),
+                member_weight,
             )
             .add_submessage(cast_vote_submessage))
         }
@@ -660,6 +1338,25 @@ fn execute_proposal(
         }
     }
 
+    if VETOED_PROPOSALS.has(ctx.deps.storage, msg.proposal_id) {
+        return Err(ProposalVetoed);
+    }
+
+    if proposal_info.proposal_type == General {
+        if let Some(veto_council) = VETO_COUNCIL.may_load(ctx.deps.storage)?.flatten() {
+            let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+            let poll = query_poll(&qctx, msg.proposal_id)?.poll;
+
+            // only proposals that would pass can be vetoed, so others need not wait
+            let challenge_window_end = poll.ends_at.plus_seconds(veto_council.challenge_window);
+            if ctx.env.block.time < challenge_window_end
+                && would_pass_if_ended(ctx.deps.as_ref(), &ctx.env, &poll, General)?
+            {
+                return Err(ProposalInChallengeWindow);
+            }
+        }
+    }
+
     let submsgs = end_proposal(ctx, &msg, proposal_info.proposal_type.clone())?;
 
     let dao_address = query_main_dao_addr(ctx.deps.as_ref())?;
@@ -672,6 +1369,181 @@ fn execute_proposal(
     .add_submessages(submsgs))
 }
 
+/// Extends the voting period of a proposal that is still being voted on.
+/// Only council members and the configured extenders can extend voting, and the total extension
+/// of a single proposal cannot exceed the configured maximum.
+fn extend_proposal_voting(
+    ctx: &mut Context,
+    msg: ExtendProposalVotingMsg,
+) -> GovernanceControllerResult<Response> {
+    let voting_extension = GOV_CONFIG
+        .load(ctx.deps.storage)?
+        .voting_extension
+        .ok_or(VotingExtensionNotAllowed)?;
+
+    let is_extender = voting_extension.extenders.contains(&ctx.info.sender);
+    let is_council_member = COUNCIL_GOV_CONFIG.load(ctx.deps.storage)?.is_some()
+        && !query_council_member_weight(ctx.deps.as_ref(), ctx.info.sender.to_string())?.is_zero();
+
+    if !is_extender && !is_council_member {
+        return Err(Unauthorized);
+    }
+
+    let extra = match msg.extra {
+        Duration::Time(seconds) if seconds > 0 => seconds,
+        _ => {
+            return Err(InvalidArgument {
+                msg: "Voting can only be extended by a non-zero time duration".to_string(),
+            })
+        }
+    };
+
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    if proposal_info.executed_at.is_some() {
+        return Err(ProposalAlreadyExecuted);
+    }
+
+    if VETOED_PROPOSALS.has(ctx.deps.storage, msg.proposal_id) {
+        return Err(ProposalVetoed);
+    }
+
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let poll = query_poll(&qctx, msg.proposal_id)?.poll;
+
+    let in_progress = matches!(poll.status, PollStatus::InProgress { .. });
+    if !in_progress || ctx.env.block.time >= poll.ends_at {
+        return Err(ProposalVotingEnded);
+    }
+
+    let total_extension = PROPOSAL_VOTING_EXTENSIONS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .unwrap_or_default()
+        .saturating_add(extra);
+
+    if total_extension > voting_extension.max_total_extension {
+        return Err(VotingExtensionTooLong {
+            max_total_extension: voting_extension.max_total_extension,
+        });
+    }
+
+    PROPOSAL_VOTING_EXTENSIONS.save(ctx.deps.storage, msg.proposal_id, &total_extension)?;
+
+    let new_ends_at = poll.ends_at.plus_seconds(extra);
+
+    // keep the open proposals' end times in line with the polls
+    let open_proposal_key = (poll.proposer, msg.proposal_id);
+    if OPEN_PROPOSALS.has(ctx.deps.storage, open_proposal_key.clone()) {
+        OPEN_PROPOSALS.save(ctx.deps.storage, open_proposal_key, &new_ends_at)?;
+    }
+
+    let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
+
+    let extend_poll_submsg = SubMsg::new(wasm_execute(
+        governance_contract.to_string(),
+        &enterprise_governance_api::msg::ExecuteMsg::ExtendPoll(ExtendPollParams {
+            poll_id: msg.proposal_id.into(),
+            new_ends_at,
+        }),
+        vec![],
+    )?);
+
+    Ok(execute_extend_proposal_voting_response(
+        msg.proposal_id,
+        ctx.info.sender.to_string(),
+        extra,
+        total_extension,
+        new_ends_at,
+    )
+    .add_submessage(extend_poll_submsg))
+}
+
+/// Casts a veto by a veto council member on a passed general proposal within its
+/// challenge window. Once enough members veto the proposal, it is marked as vetoed and its
+/// deposit is returned.
+fn veto_proposal(ctx: &mut Context, msg: VetoProposalMsg) -> GovernanceControllerResult<Response> {
+    let veto_council = VETO_COUNCIL
+        .may_load(ctx.deps.storage)?
+        .flatten()
+        .ok_or(NoVetoCouncil)?;
+
+    if !veto_council.members.contains(&ctx.info.sender) {
+        return Err(Unauthorized);
+    }
+
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    if proposal_info.proposal_type != General {
+        return Err(WrongProposalType);
+    }
+
+    if VETOED_PROPOSALS.has(ctx.deps.storage, msg.proposal_id) {
+        return Err(ProposalVetoed);
+    }
+
+    let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
+    let poll = query_poll(&qctx, msg.proposal_id)?.poll;
+
+    let status = fix_poll_status(
+        ctx.deps.as_ref(),
+        msg.proposal_id,
+        poll.status.clone(),
+        ctx.env.block.time,
+        &proposal_info,
+    )?;
+
+    let challenge_window_end = poll.ends_at.plus_seconds(veto_council.challenge_window);
+
+    if status != ProposalStatus::Passed || ctx.env.block.time >= challenge_window_end {
+        return Err(ProposalNotChallengeable);
+    }
+
+    let veto_council_updates = VETO_COUNCIL_UPDATES
+        .may_load(ctx.deps.storage)?
+        .unwrap_or_default();
+
+    VETO_VOTES.save(
+        ctx.deps.storage,
+        (
+            msg.proposal_id,
+            veto_council_updates,
+            ctx.info.sender.clone(),
+        ),
+        &ctx.env.block.time,
+    )?;
+
+    let mut vetoes = 0u128;
+    for member in &veto_council.members {
+        if VETO_VOTES.has(
+            ctx.deps.storage,
+            (msg.proposal_id, veto_council_updates, member.clone()),
+        ) {
+            vetoes += 1;
+        }
+    }
+
+    let vetoed =
+        Decimal::from_ratio(vetoes, veto_council.members.len() as u128) >= veto_council.threshold;
+
+    let submsgs = if vetoed {
+        VETOED_PROPOSALS.save(ctx.deps.storage, msg.proposal_id, &ctx.env.block.time)?;
+        OPEN_PROPOSALS.remove(ctx.deps.storage, (poll.proposer, msg.proposal_id));
+
+        return_proposal_deposit_submsgs(ctx.deps.branch(), msg.proposal_id)?
+    } else {
+        vec![]
+    };
+
+    Ok(
+        execute_veto_proposal_response(msg.proposal_id, ctx.info.sender.to_string(), vetoed)
+            .add_submessages(submsgs),
+    )
+}
+
 fn return_proposal_deposit_submsgs(
     deps: DepsMut,
     proposal_id: ProposalId,
@@ -680,11 +1552,50 @@ fn return_proposal_deposit_submsgs(
         .may_load(deps.storage, proposal_id)?
         .ok_or(NoSuchProposal)?;
 
-    if let Some(deposit) = proposal_info.proposal_deposit {
-        send_proposal_deposit_to(deposit.asset, deposit.depositor)
-    } else {
-        Ok(vec![])
+    if proposal_info.proposal_deposit.is_none() {
+        return Ok(vec![]);
+    }
+
+    let manual_deposit_refunds = GOV_CONFIG
+        .load(deps.storage)?
+        .manual_deposit_refunds
+        .unwrap_or(false);
+
+    if manual_deposit_refunds {
+        // the depositor claims the deposit later, so its transfer cannot fail the resolution
+        REFUNDABLE_DEPOSITS.save(deps.storage, proposal_id, &())?;
+        return Ok(vec![]);
+    }
+
+    match proposal_info.proposal_deposit {
+        Some(deposit) => send_proposal_deposit_to(deposit.asset, deposit.depositor),
+        None => Ok(vec![]),
+    }
+}
+
+/// Sends a deposit marked refundable back to its depositor.
+fn claim_deposit(ctx: &mut Context, msg: ClaimDepositMsg) -> GovernanceControllerResult<Response> {
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    let deposit = match proposal_info.proposal_deposit {
+        Some(deposit) if REFUNDABLE_DEPOSITS.has(ctx.deps.storage, msg.proposal_id) => deposit,
+        _ => return Err(NoRefundableDeposit),
+    };
+
+    if ctx.info.sender != deposit.depositor {
+        return Err(Unauthorized);
     }
+
+    REFUNDABLE_DEPOSITS.remove(ctx.deps.storage, msg.proposal_id);
+
+    let submsgs = send_proposal_deposit_to(deposit.asset, deposit.depositor.clone())?;
+
+    Ok(
+        execute_claim_deposit_response(msg.proposal_id, deposit.depositor.to_string())
+            .add_submessages(submsgs),
+    )
 }
 
 fn send_proposal_deposit_to(
@@ -729,6 +1640,8 @@ fn end_proposal(
     let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
     let poll = query_poll(&qctx, msg.proposal_id)?.poll;
 
+    OPEN_PROPOSALS.remove(ctx.deps.storage, (poll.proposer.clone(), msg.proposal_id));
+
     let ends_at = poll.ends_at;
 
     let total_available_votes = if ends_at <= ctx.env.block.time {
@@ -769,6 +1682,24 @@ fn end_proposal(
     Ok(vec![end_poll_submsg])
 }
 
+/// Checks whether the proposal would pass if it were ended right now.
+fn would_pass_if_ended(
+    deps: Deps,
+    env: &Env,
+    poll: &Poll,
+    proposal_type: ProposalType,
+) -> GovernanceControllerResult<bool> {
+    let total_available_votes = if poll.ends_at <= env.block.time {
+        total_available_votes(deps, AtTime(poll.ends_at), proposal_type)?
+    } else {
+        total_available_votes(deps, Never {}, proposal_type)?
+    };
+
+    let status = simulate_end_proposal_status(deps, poll.id, total_available_votes)?.status;
+
+    Ok(matches!(status, PollStatus::Passed { .. }))
+}
+
 fn allows_early_ending(
     deps: Deps,
     proposal_type: &ProposalType,
@@ -786,7 +1717,7 @@ fn allows_early_ending(
 fn resolve_ended_proposal(
     ctx: &mut Context,
     proposal_id: ProposalId,
-) -> GovernanceControllerResult<Vec<SubMsg>> {
+) -> GovernanceControllerResult<Response> {
     let qctx = QueryContext::from(ctx.deps.as_ref(), ctx.env.clone());
     let poll_status = query_poll_status(&qctx, proposal_id)?.status;
 
@@ -799,17 +1730,74 @@ fn resolve_ended_proposal(
         }
         PollStatus::Passed { .. } => {
             set_proposal_executed(ctx.deps.storage, proposal_id, ctx.env.block.clone())?;
-            let execute_proposal_actions_msg = SubMsg::reply_always(
-                wasm_execute(
-                    ctx.env.contract.address.to_string(),
-                    &ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg { proposal_id }),
-                    vec![],
-                )?,
-                EXECUTE_PROPOSAL_ACTIONS_REPLY_ID,
-            );
-            let mut submsgs = return_proposal_deposit_submsgs(ctx.deps.branch(), proposal_id)?;
 
-            submsgs.insert(0, execute_proposal_actions_msg);
+            let proposal_info = PROPOSAL_INFOS
+                .may_load(ctx.deps.storage, proposal_id)?
+                .ok_or(NoSuchProposal)?;
+
+            if proposal_info.proposal_type == Council {
+                match assert_council_actions_still_allowed(
+                    ctx.deps.as_ref(),
+                    &proposal_info.proposal_actions,
+                ) {
+                    Err(e @ CouncilActionNoLongerAllowed { .. }) => {
+                        // the proposal is resolved without executing any of its actions,
+                        // instead of staying passed without ever being executable
+                        EXECUTION_FAILED_PROPOSALS.save(
+                            ctx.deps.storage,
+                            proposal_id,
+                            &ctx.env.block.time,
+                        )?;
+                        return Ok(Response::new().add_attribute("execution_error", e.to_string()));
+                    }
+                    result => result?,
+                }
+            }
+
+            let condition_met = match &proposal_info.execution_condition {
+                Some(condition) => is_execution_condition_met(ctx.deps.as_ref(), condition)?,
+                None => true,
+            };
+
+            let mut submsgs = if !condition_met {
+                // the proposal still counts as resolved, but none of its actions are executed
+                CONDITION_NOT_MET_PROPOSALS.save(
+                    ctx.deps.storage,
+                    proposal_id,
+                    &ctx.env.block.time,
+                )?;
+                vec![]
+            } else {
+                match proposal_info.execution_atomicity {
+                    None | Some(ExecutionAtomicity::Atomic) => vec![SubMsg::reply_always(
+                        wasm_execute(
+                            ctx.env.contract.address.to_string(),
+                            &ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg { proposal_id }),
+                            vec![],
+                        )?,
+                        EXECUTE_PROPOSAL_ACTIONS_REPLY_ID,
+                    )],
+                    Some(ExecutionAtomicity::BestEffort) => best_effort_proposal_actions_submsgs(
+                        ctx,
+                        proposal_id,
+                        proposal_info.proposal_actions.len(),
+                    )?,
+                }
+            };
+
+            submsgs.append(&mut return_proposal_deposit_submsgs(
+                ctx.deps.branch(),
+                proposal_id,
+            )?);
+
+            if proposal_info.proposal_type == General {
+                record_voter_unlocking_extension(
+                    ctx.deps.branch(),
+                    &ctx.env,
+                    proposal_id,
+                    &proposal_info.proposal_actions,
+                )?;
+            }
 
             submsgs
         }
@@ -824,10 +1812,9 @@ fn resolve_ended_proposal(
                 General => match reason {
                     QuorumNotReached | IsVetoOutcome => {
                         if let Some(deposit) = proposal_info.proposal_deposit {
-                            // confiscate the deposit by sending it to treasury
-                            let treasury_contract =
-                                query_enterprise_treasury_addr(ctx.deps.as_ref())?;
-                            send_proposal_deposit_to(deposit.asset, treasury_contract)?
+                            // confiscate the deposit by sending it to the forfeiture recipient
+                            let recipient = query_forfeiture_recipient(ctx.deps.as_ref())?;
+                            send_proposal_deposit_to(deposit.asset, recipient)?
                         } else {
                             vec![]
                         }
@@ -840,57 +1827,390 @@ fn resolve_ended_proposal(
         }
     };
 
-    Ok(submsgs)
+    Ok(Response::new().add_submessages(submsgs))
 }
 
-fn execute_proposal_actions(
-    ctx: &mut Context,
-    msg: ExecuteProposalMsg,
-) -> GovernanceControllerResult<Response> {
-    // only this contract itself can execute this
-    if ctx.info.sender != ctx.env.contract.address {
-        return Err(Unauthorized);
-    }
+/// Checks that a passed council proposal only contains actions the council is still allowed
+/// to propose. The council's permissions may have been narrowed (or the council removed) while
+/// the proposal was being voted on, in which case the proposal cannot be executed, since the
+/// council no longer has the authority to perform those actions. Such a proposal ends up with
+/// ExecutionFailed status.
+fn assert_council_actions_still_allowed(
+    deps: Deps,
+    proposal_actions: &[ProposalAction],
+) -> GovernanceControllerResult<()> {
+    let allowed_actions = COUNCIL_GOV_CONFIG
+        .load(deps.storage)?
+        .map(|council| council.allowed_proposal_action_types)
+        .unwrap_or_default();
 
-    let submsgs: Vec<SubMsg> = execute_proposal_actions_submsgs(ctx, msg.proposal_id)?;
+    for proposal_action in proposal_actions {
+        let proposal_action_type = to_proposal_action_type(proposal_action);
+        if !allowed_actions.contains(&proposal_action_type) {
+            return Err(CouncilActionNoLongerAllowed {
+                action: proposal_action_type,
+            });
+        }
+    }
 
-    Ok(Response::new()
-        .add_attribute("action", "execute_proposal_actions")
-        .add_attribute("proposal_id", msg.proposal_id.to_string())
-        .add_submessages(submsgs))
+    Ok(())
 }
 
-fn execute_proposal_actions_submsgs(
-    ctx: &mut Context,
+/// Records that the unlocking of everyone who voted on a passed proposal is to be extended,
+/// if the proposal contains actions whose types are configured to extend voters' unlocking.
+/// When several of the proposal's action types are configured, the longest extension is used.
+///
+/// The voters are not iterated here, since there can be arbitrarily many of them. Their
+/// unlocking is extended page by page through ExtendVoterUnlocking instead.
+fn record_voter_unlocking_extension(
+    deps: DepsMut,
+    env: &Env,
     proposal_id: ProposalId,
-) -> GovernanceControllerResult<Vec<SubMsg>> {
-    let proposal_actions =
-        get_proposal_actions(ctx.deps.storage, proposal_id)?.ok_or(NoSuchProposal)?;
-
-    let mut submsgs: Vec<SubMsg> = vec![];
+    proposal_actions: &[ProposalAction],
+) -> GovernanceControllerResult<()> {
+    let gov_config = GOV_CONFIG.load(deps.storage)?;
 
-    for proposal_action in proposal_actions {
-        let mut actions = match proposal_action {
-            UpdateMetadata(msg) => update_metadata(ctx.deps.branch(), msg)?,
-            UpdateGovConfig(msg) => update_gov_config(ctx, msg)?,
-            UpdateCouncil(msg) => update_council(ctx, msg)?,
-            RequestFundingFromDao(msg) => execute_funding_from_dao(ctx.deps.branch(), msg)?,
-            UpdateAssetWhitelist(msg) => update_asset_whitelist(ctx.deps.branch(), msg)?,
-            UpdateNftWhitelist(msg) => update_nft_whitelist(ctx.deps.branch(), msg)?,
-            UpgradeDao(msg) => upgrade_dao(ctx, msg)?,
-            ExecuteMsgs(msg) => execute_msgs(msg)?,
-            ExecuteTreasuryMsgs(msg) => execute_treasury_msgs(ctx, msg)?,
-            ExecuteEnterpriseMsgs(msg) => execute_enterprise_msgs(ctx, msg)?,
-            ModifyMultisigMembership(msg) => {
-                modify_multisig_membership(ctx.deps.branch(), ctx.env.clone(), msg)?
+    let extension = gov_config
+        .voter_unlocking_extensions
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|it| {
+            proposal_actions
+                .iter()
+                .any(|action| to_proposal_action_type(action) == it.action_type)
+        })
+        .map(|it| it.extension)
+        .max();
+
+    let extension = match extension {
+        Some(extension) => extension,
+        None => return Ok(()),
+    };
+
+    if query_dao_type(deps.as_ref())? == Multisig {
+        // no unlocking period to extend
+        return Ok(());
+    }
+
+    PENDING_VOTER_UNLOCKING_EXTENSIONS.save(
+        deps.storage,
+        proposal_id,
+        &PendingVoterUnlockingExtension {
+            until: env.block.time.plus_seconds(extension),
+            start_after: None,
+        },
+    )?;
+
+    Ok(())
+}
+
+fn extend_voter_unlocking(
+    ctx: &mut Context,
+    msg: ExtendVoterUnlockingMsg,
+) -> GovernanceControllerResult<Response> {
+    let pending_extension = PENDING_VOTER_UNLOCKING_EXTENSIONS
+        .may_load(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoPendingVoterUnlockingExtension {
+            proposal_id: msg.proposal_id,
+        })?;
+
+    let limit = msg
+        .limit
+        .unwrap_or(MAX_QUERY_LIMIT as u32)
+        .min(MAX_QUERY_LIMIT as u32);
+
+    let governance_contract = query_enterprise_governance_addr(ctx.deps.as_ref())?;
+
+    let voters = ctx
+        .deps
+        .querier
+        .query_wasm_smart::<PollVotersResponse>(
+            governance_contract.to_string(),
+            &enterprise_governance_api::msg::QueryMsg::PollVoters(PollVotersParams {
+                poll_id: msg.proposal_id,
+                pagination: Pagination {
+                    start_after: pending_extension.start_after.clone(),
+                    end_at: None,
+                    limit: Some(limit as u64),
+                    order_by: None,
+                },
+            }),
+        )?
+        .votes
+        .into_iter()
+        .map(|vote| vote.voter.to_string())
+        .collect::<Vec<String>>();
+
+    let done = voters.len() < limit as usize;
+
+    if done {
+        PENDING_VOTER_UNLOCKING_EXTENSIONS.remove(ctx.deps.storage, msg.proposal_id);
+    } else {
+        PENDING_VOTER_UNLOCKING_EXTENSIONS.save(
+            ctx.deps.storage,
+            msg.proposal_id,
+            &PendingVoterUnlockingExtension {
+                start_after: voters.last().cloned(),
+                ..pending_extension
+            },
+        )?;
+    }
+
+    let voters_count = voters.len();
+
+    let submsgs = if voters.is_empty() {
+        vec![]
+    } else {
+        extend_unlocking_submsgs(
+            ctx.deps.as_ref(),
+            ExtendUnlockingMsg {
+                users: voters,
+                until: pending_extension.until,
+            },
+        )?
+    };
+
+    Ok(
+        execute_extend_voter_unlocking_response(msg.proposal_id, voters_count, done)
+            .add_submessages(submsgs),
+    )
+}
+
+fn extend_unlocking_submsgs(
+    deps: Deps,
+    msg: ExtendUnlockingMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let membership_contract = query_membership_addr(deps)?.to_string();
+
+    let submsg = match query_dao_type(deps)? {
+        Denom => SubMsg::new(wasm_execute(
+            membership_contract,
+            &denom_staking_api::msg::ExecuteMsg::ExtendUnlocking(msg),
+            vec![],
+        )?),
+        Token => SubMsg::new(wasm_execute(
+            membership_contract,
+            &token_staking_api::msg::ExecuteMsg::ExtendUnlocking(msg),
+            vec![],
+        )?),
+        Nft => SubMsg::new(wasm_execute(
+            membership_contract,
+            &nft_staking_api::msg::ExecuteMsg::ExtendUnlocking(msg),
+            vec![],
+        )?),
+        Multisig => return Ok(vec![]), // no unlocking period to extend
+    };
+
+    Ok(vec![submsg])
+}
+
+fn execute_proposal_actions(
+    ctx: &mut Context,
+    msg: ExecuteProposalMsg,
+) -> GovernanceControllerResult<Response> {
+    // only this contract itself can execute this
+    if ctx.info.sender != ctx.env.contract.address {
+        return Err(Unauthorized);
+    }
+
+    let (submsgs, skipped_actions) = execute_proposal_actions_submsgs(ctx, msg.proposal_id)?;
+
+    let skipped_actions = skipped_actions
+        .into_iter()
+        .map(|skipped| format!("{}:{}", skipped.index, skipped.action_type))
+        .collect::<Vec<String>>()
+        .join(",");
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_proposal_actions")
+        .add_attribute("proposal_id", msg.proposal_id.to_string())
+        .add_attribute("skipped_actions", skipped_actions)
+        .add_submessages(submsgs))
+}
+
+/// Creates a separate submsg executing each of the proposal's actions, so that a failing action
+/// does not revert the others. Each action's reply ID identifies the action, so that its outcome
+/// can be recorded when the reply arrives.
+fn best_effort_proposal_actions_submsgs(
+    ctx: &mut Context,
+    proposal_id: ProposalId,
+    actions_count: usize,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let mut submsgs = vec![];
+
+    for action_index in 0..actions_count as u32 {
+        submsgs.push(SubMsg::reply_always(
+            wasm_execute(
+                ctx.env.contract.address.to_string(),
+                &ExecuteMsg::ExecuteProposalAction(ExecuteProposalActionMsg {
+                    proposal_id,
+                    action_index,
+                }),
+                vec![],
+            )?,
+            execute_proposal_action_reply_id(proposal_id, action_index)?,
+        ));
+    }
+
+    Ok(submsgs)
+}
+
+/// Reply ID of an individually executed proposal action, identifying the action.
+fn execute_proposal_action_reply_id(
+    proposal_id: ProposalId,
+    action_index: u32,
+) -> GovernanceControllerResult<u64> {
+    let max_proposal_id = EXECUTE_PROPOSAL_ACTION_REPLY_ID_FLAG >> ACTION_INDEX_REPLY_ID_BITS;
+    if proposal_id >= max_proposal_id || action_index >= 1 << ACTION_INDEX_REPLY_ID_BITS {
+        return Err(CustomError {
+            val: "Proposal action cannot be identified by a reply ID".to_string(),
+        });
+    }
+
+    Ok(EXECUTE_PROPOSAL_ACTION_REPLY_ID_FLAG
+        | proposal_id << ACTION_INDEX_REPLY_ID_BITS
+        | action_index as u64)
+}
+
+/// Proposal ID and action index identified by an individually executed proposal action's reply ID.
+fn parse_execute_proposal_action_reply_id(reply_id: u64) -> (ProposalId, u32) {
+    let id = reply_id & !EXECUTE_PROPOSAL_ACTION_REPLY_ID_FLAG;
+    let action_index = id & ((1 << ACTION_INDEX_REPLY_ID_BITS) - 1);
+
+    (id >> ACTION_INDEX_REPLY_ID_BITS, action_index as u32)
+}
+
+fn execute_proposal_action(
+    ctx: &mut Context,
+    msg: ExecuteProposalActionMsg,
+) -> GovernanceControllerResult<Response> {
+    // only this contract itself can execute this
+    if ctx.info.sender != ctx.env.contract.address {
+        return Err(Unauthorized);
+    }
+
+    let proposal_action = get_proposal_actions(ctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?
+        .into_iter()
+        .nth(msg.action_index as usize)
+        .ok_or(InvalidArgument {
+            msg: format!("Proposal has no action with index {}", msg.action_index),
+        })?;
+
+    // in best-effort execution, an invalid action simply fails on its own
+    validate_proposal_action(ctx.deps.as_ref(), &proposal_action)?;
+
+    record_execution_outflow(ctx.deps.branch(), msg.proposal_id, &proposal_action)?;
+
+    let submsgs = proposal_action_submsgs(ctx, proposal_action)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_proposal_action")
+        .add_attribute("proposal_id", msg.proposal_id.to_string())
+        .add_attribute("action_index", msg.action_index.to_string())
+        .add_submessages(submsgs))
+}
+
+struct SkippedProposalAction {
+    index: usize,
+    action_type: ProposalActionType,
+}
+
+/// Creates submsgs for executing the proposal's actions.
+///
+/// Each action is re-validated against the current state before being executed, since the state
+/// might have changed since the proposal was created (e.g. by another proposal).
+/// Invalid actions are handled according to the DAO's invalid action policy - they either fail
+/// the whole execution, or are skipped and returned alongside the submsgs.
+fn execute_proposal_actions_submsgs(
+    ctx: &mut Context,
+    proposal_id: ProposalId,
+) -> GovernanceControllerResult<(Vec<SubMsg>, Vec<SkippedProposalAction>)> {
+    let proposal_actions =
+        get_proposal_actions(ctx.deps.storage, proposal_id)?.ok_or(NoSuchProposal)?;
+
+    let invalid_action_policy = GOV_CONFIG
+        .load(ctx.deps.storage)?
+        .invalid_action_policy
+        .unwrap_or(InvalidProposalActionPolicy::FailAll);
+
+    let mut submsgs: Vec<SubMsg> = vec![];
+    let mut skipped_actions: Vec<SkippedProposalAction> = vec![];
+
+    for (index, proposal_action) in proposal_actions.into_iter().enumerate() {
+        if let Err(e) = validate_proposal_action(ctx.deps.as_ref(), &proposal_action) {
+            match invalid_action_policy {
+                InvalidProposalActionPolicy::FailAll => return Err(e),
+                InvalidProposalActionPolicy::SkipInvalid => {
+                    skipped_actions.push(SkippedProposalAction {
+                        index,
+                        action_type: to_proposal_action_type(&proposal_action),
+                    });
+                    continue;
+                }
             }
-            DistributeFunds(msg) => distribute_funds(ctx, msg)?,
-            UpdateMinimumWeightForRewards(msg) => update_minimum_weight_for_rewards(ctx, msg)?,
-            DeployCrossChainTreasury(msg) => deploy_cross_chain_treasury(ctx, msg)?,
-        };
+        }
+
+        record_execution_outflow(ctx.deps.branch(), proposal_id, &proposal_action)?;
+
+        let mut actions = proposal_action_submsgs(ctx, proposal_action)?;
         submsgs.append(&mut actions)
     }
 
+    Ok((submsgs, skipped_actions))
+}
+
+fn proposal_action_submsgs(
+    ctx: &mut Context,
+    proposal_action: ProposalAction,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let submsgs = match proposal_action {
+        UpdateMetadata(msg) => update_metadata(ctx.deps.branch(), msg)?,
+        UpdateGovConfig(msg) => update_gov_config(ctx, msg)?,
+        UpdateCouncil(msg) => update_council(ctx, msg)?,
+        EditCouncilMembers(msg) => edit_council_members(ctx, msg)?,
+        RequestFundingFromDao(msg) => execute_funding_from_dao(ctx.deps.branch(), msg)?,
+        UpdateAssetWhitelist(msg) => update_asset_whitelist(ctx.deps.branch(), msg)?,
+        UpdateAssetWhitelistWithBundle(msg) => {
+            let msg = expand_asset_bundle(ctx.deps.storage, &msg)?;
+            update_asset_whitelist(ctx.deps.branch(), msg)?
+        }
+        ManageAssetBundles(msg) => manage_asset_bundles(ctx.deps.storage, msg)?,
+        ScheduleCouncilElections(msg) => {
+            schedule_council_elections(ctx.deps.storage, ctx.env.block.time, msg)?
+        }
+        UpdateNftWhitelist(msg) => update_nft_whitelist(ctx.deps.branch(), msg)?,
+        UpgradeDao(msg) => upgrade_dao(ctx, msg)?,
+        ExecuteMsgs(msg) => execute_msgs(msg)?,
+        ExecuteTreasuryMsgs(msg) => execute_treasury_msgs(ctx, msg)?,
+        ExecuteEnterpriseMsgs(msg) => execute_enterprise_msgs(ctx, msg)?,
+        ExecuteInterchainAccountMsgs(msg) => execute_interchain_account_msgs(ctx, msg)?,
+        ModifyMultisigMembership(msg) => {
+            modify_multisig_membership(ctx.deps.branch(), ctx.env.clone(), msg)?
+        }
+        DistributeFunds(msg) => distribute_funds(ctx, msg)?,
+        DistributeFromTreasury(msg) => distribute_from_treasury(ctx, msg)?,
+        UpdateMinimumWeightForRewards(msg) => update_minimum_weight_for_rewards(ctx, msg)?,
+        UpdateDistributionFee(msg) => update_distribution_fee(ctx, msg)?,
+        SetDistributorClaimsPaused(msg) => set_distributor_claims_paused(ctx, msg)?,
+        ProposalAction::SetDistributorEmissionSchedule(msg) => {
+            set_distributor_emission_schedule(ctx, msg)?
+        }
+        ProposalAction::TransferNft(msg) => transfer_nft(ctx, msg)?,
+        ProposalAction::DistributeToVoters(msg) => distribute_to_voters(ctx, msg)?,
+        ProposalAction::RegisterInterchainAccount(msg) => register_interchain_account(ctx, msg)?,
+        DeployCrossChainTreasury(msg) => deploy_cross_chain_treasury(ctx, msg)?,
+        BurnTokens(msg) => burn_tokens(ctx, msg)?,
+        UpdateVetoCouncil(msg) => update_veto_council(ctx, msg)?,
+        MintTokenfactoryDenom(msg) => mint_tokenfactory_denom(ctx, msg)?,
+        SetDenomAdmin(msg) => set_denom_admin(ctx, msg)?,
+        UpdateEnterpriseFactory(msg) => update_enterprise_factory(ctx, msg)?,
+        SetSelfAdmin(msg) => set_self_admin(ctx, msg)?,
+        UpdateCouncilPermissions(msg) => update_council_permissions(ctx, msg)?,
+        GrantSpendingAllowance(msg) => grant_spending_allowance(ctx, msg)?,
+        RevokeSpendingAllowance(msg) => revoke_spending_allowance(ctx, msg)?,
+    };
+
     Ok(submsgs)
 }
 
@@ -931,9 +2251,16 @@ fn update_gov_config(
 ) -> GovernanceControllerResult<Vec<SubMsg>> {
     let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
 
-    let updated_gov_config = apply_gov_config_changes(gov_config, &msg);
+    assert_expected_gov_config(ctx.deps.as_ref(), &gov_config, &msg)?;
+
+    let updated_gov_config = apply_gov_config_changes(ctx.deps.as_ref(), gov_config, &msg)?;
 
-    validate_dao_gov_config(&query_dao_type(ctx.deps.as_ref())?, &updated_gov_config)?;
+    validate_dao_gov_config(
+        ctx.deps.as_ref(),
+        &query_dao_type(ctx.deps.as_ref())?,
+        &updated_gov_config,
+    )?;
+    validate_council_meets_changed_requirements(ctx.deps.as_ref(), &updated_gov_config, &msg)?;
 
     GOV_CONFIG.save(ctx.deps.storage, &updated_gov_config)?;
 
@@ -985,19 +2312,23 @@ fn update_council(
     ctx: &mut Context,
     msg: UpdateCouncilMsg,
 ) -> GovernanceControllerResult<Vec<SubMsg>> {
-    let dao_council = validate_dao_council(ctx.deps.as_ref(), msg.dao_council.clone())?;
+    let gov_config = GOV_CONFIG.load(ctx.deps.storage)?;
+    let dao_council =
+        validate_dao_council(ctx.deps.as_ref(), &gov_config, msg.dao_council.clone())?;
 
     let dao_council_membership_contract = query_council_membership_addr(ctx.deps.as_ref())?;
 
     let new_members = msg
         .dao_council
-        .map(|council| council.members)
+        .map(|council| council.member_weights())
         .unwrap_or_default()
         .into_iter()
-        .map(|member| multisig_membership_api::api::UserWeight {
-            user: member,
-            weight: Uint128::one(),
-        })
+        .map(
+            |(member, weight)| multisig_membership_api::api::UserWeight {
+                user: member,
+                weight,
+            },
+        )
         .collect();
 
     COUNCIL_GOV_CONFIG.save(ctx.deps.storage, &dao_council)?;
@@ -1011,6 +2342,76 @@ fn update_council(
     Ok(vec![submsg])
 }
 
+fn edit_council_members(
+    ctx: &mut Context,
+    msg: EditCouncilMembersMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    validate_edit_council_members(ctx.deps.as_ref(), &msg)?;
+
+    let dao_council_membership_contract = query_council_membership_addr(ctx.deps.as_ref())?;
+
+    // removed members are given a weight of 0, which takes them out of the council
+    let added_members = msg.add.into_iter().map(|member| (member, Uint128::one()));
+    let removed_members = msg
+        .remove
+        .into_iter()
+        .map(|member| (member, Uint128::zero()));
+
+    let update_members = added_members
+        .chain(removed_members)
+        .map(
+            |(member, weight)| multisig_membership_api::api::UserWeight {
+                user: member,
+                weight,
+            },
+        )
+        .collect();
+
+    let submsg = SubMsg::new(wasm_execute(
+        dao_council_membership_contract.to_string(),
+        &UpdateMembers(UpdateMembersMsg { update_members }),
+        vec![],
+    )?);
+
+    Ok(vec![submsg])
+}
+
+fn update_council_permissions(
+    ctx: &mut Context,
+    msg: UpdateCouncilPermissionsMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    validate_update_council_permissions(ctx.deps.as_ref(), &msg)?;
+
+    COUNCIL_GOV_CONFIG.update(
+        ctx.deps.storage,
+        |council_gov_config| -> GovernanceControllerResult<Option<CouncilGovConfig>> {
+            let council_gov_config = council_gov_config.ok_or(NoDaoCouncil)?;
+            Ok(Some(CouncilGovConfig {
+                allowed_proposal_action_types: msg.allowed_proposal_action_types,
+                ..council_gov_config
+            }))
+        },
+    )?;
+
+    Ok(vec![])
+}
+
+fn update_veto_council(
+    ctx: &mut Context,
+    msg: UpdateVetoCouncilMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let veto_council = validate_veto_council(ctx.deps.as_ref(), msg.veto_council)?;
+
+    VETO_COUNCIL.save(ctx.deps.storage, &veto_council)?;
+    // vetoes cast under the previous veto council no longer count
+    let veto_council_updates = VETO_COUNCIL_UPDATES
+        .may_load(ctx.deps.storage)?
+        .unwrap_or_default();
+    VETO_COUNCIL_UPDATES.save(ctx.deps.storage, &(veto_council_updates + 1))?;
+
+    Ok(vec![])
+}
+
 fn update_asset_whitelist(
     deps: DepsMut,
     msg: UpdateAssetWhitelistProposalActionMsg,
@@ -1088,6 +2489,50 @@ fn upgrade_dao(ctx: &mut Context, msg: UpgradeDaoMsg) -> GovernanceControllerRes
     Ok(vec![submsg])
 }
 
+fn update_enterprise_factory(
+    ctx: &mut Context,
+    msg: UpdateEnterpriseFactoryMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    validate_update_enterprise_factory(ctx.deps.as_ref(), &msg)?;
+
+    let enterprise_contract = ENTERPRISE_CONTRACT.load(ctx.deps.storage)?;
+
+    let submsg = SubMsg::new(wasm_execute(
+        enterprise_contract.to_string(),
+        &enterprise_protocol::msg::ExecuteMsg::UpdateConfig(
+            enterprise_protocol::api::UpdateConfigMsg {
+                new_versioning_contract: NoChange,
+                new_factory_contract: Change(msg.new_factory),
+            },
+        ),
+        vec![],
+    )?);
+
+    Ok(vec![submsg])
+}
+
+fn set_self_admin(
+    ctx: &mut Context,
+    msg: SetSelfAdminMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    validate_set_self_admin(ctx.deps.as_ref(), &msg)?;
+
+    let enterprise_contract = ENTERPRISE_CONTRACT.load(ctx.deps.storage)?;
+
+    // the enterprise contract is its own admin, so only it can change its admin
+    let submsg = SubMsg::new(wasm_execute(
+        enterprise_contract.to_string(),
+        &enterprise_protocol::msg::ExecuteMsg::SetSelfAdmin(
+            enterprise_protocol::api::SetSelfAdminMsg {
+                new_admin: msg.new_admin,
+            },
+        ),
+        vec![],
+    )?);
+
+    Ok(vec![submsg])
+}
+
 fn execute_msgs(msg: ExecuteMsgsMsg) -> GovernanceControllerResult<Vec<SubMsg>> {
     let mut submsgs: Vec<SubMsg> = vec![];
     for msg in msg.msgs {
@@ -1099,6 +2544,34 @@ fn execute_msgs(msg: ExecuteMsgsMsg) -> GovernanceControllerResult<Vec<SubMsg>>
     Ok(submsgs)
 }
 
+fn register_interchain_account(
+    ctx: &mut Context,
+    msg: RegisterInterchainAccountMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let register_msg = register_interchain_account_msg(
+        ctx.env.contract.address.to_string(),
+        msg.connection_id,
+        msg.version.unwrap_or_default(),
+    );
+
+    Ok(vec![SubMsg::new(register_msg)])
+}
+
+fn execute_interchain_account_msgs(
+    ctx: &mut Context,
+    msg: ExecuteInterchainAccountMsgsMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let send_tx_msg = send_tx_msg(
+        ctx.env.contract.address.to_string(),
+        msg.connection_id,
+        msg.msgs,
+        msg.memo.unwrap_or_default(),
+        msg.timeout_nanos.unwrap_or(DEFAULT_ICA_TIMEOUT_NANOS),
+    );
+
+    Ok(vec![SubMsg::new(send_tx_msg)])
+}
+
 fn execute_treasury_msgs(
     ctx: &mut Context,
     msg: ExecuteTreasuryMsgsMsg,
@@ -1112,6 +2585,143 @@ fn execute_treasury_msgs(
     Ok(vec![submsg])
 }
 
+fn burn_tokens(ctx: &mut Context, msg: BurnTokensMsg) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let token = match msg.asset.check(ctx.deps.api, None)? {
+        AssetInfo::Cw20(token) => token,
+        AssetInfo::Native(_) => return Err(CannotBurnNativeAsset),
+        _ => return Err(UnsupportedCw1155Asset),
+    };
+
+    let burn_msg: CosmosMsg = wasm_execute(
+        token.to_string(),
+        &cw20::Cw20ExecuteMsg::Burn { amount: msg.amount },
+        vec![],
+    )?
+    .into();
+
+    let submsg = execute_treasury_msg(
+        ctx.deps.branch(),
+        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: vec![to_json_string(&burn_msg)?],
+        }),
+        None,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn grant_spending_allowance(
+    ctx: &mut Context,
+    msg: GrantSpendingAllowanceMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    // the proposal might be executed long after it was created
+    validate_spending_allowance_expiry(&ctx.env.block, &[GrantSpendingAllowance(msg.clone())])?;
+
+    let token = match msg.asset.check(ctx.deps.api, None)? {
+        AssetInfo::Cw20(token) => token,
+        AssetInfo::Native(_) => return Err(NativeSpendingAllowanceNotSupported),
+        _ => return Err(UnsupportedCw1155Asset),
+    };
+
+    let grantee = ctx.deps.api.addr_validate(&msg.grantee)?;
+
+    let increase_allowance_msg: CosmosMsg = wasm_execute(
+        token.to_string(),
+        &cw20::Cw20ExecuteMsg::IncreaseAllowance {
+            spender: grantee.to_string(),
+            amount: msg.amount,
+            expires: Some(msg.expiry),
+        },
+        vec![],
+    )?
+    .into();
+
+    let submsg = execute_treasury_msg(
+        ctx.deps.branch(),
+        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: vec![to_json_string(&increase_allowance_msg)?],
+        }),
+        None,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn revoke_spending_allowance(
+    ctx: &mut Context,
+    msg: RevokeSpendingAllowanceMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let allowance = query_treasury_spending_allowance(ctx.deps.as_ref(), &msg)?;
+
+    let token = match msg.asset.check(ctx.deps.api, None)? {
+        AssetInfo::Cw20(token) => token,
+        AssetInfo::Native(_) => return Err(NativeSpendingAllowanceNotSupported),
+        _ => return Err(UnsupportedCw1155Asset),
+    };
+
+    // decreasing by the whole allowance removes it
+    let decrease_allowance_msg: CosmosMsg = wasm_execute(
+        token.to_string(),
+        &cw20::Cw20ExecuteMsg::DecreaseAllowance {
+            spender: msg.grantee,
+            amount: allowance,
+            expires: None,
+        },
+        vec![],
+    )?
+    .into();
+
+    let submsg = execute_treasury_msg(
+        ctx.deps.branch(),
+        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: vec![to_json_string(&decrease_allowance_msg)?],
+        }),
+        None,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn mint_tokenfactory_denom(
+    ctx: &mut Context,
+    msg: MintTokenfactoryDenomMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let treasury = query_enterprise_treasury_addr(ctx.deps.as_ref())?;
+
+    let recipient = msg.recipient.unwrap_or_else(|| treasury.to_string());
+
+    let mint_msg = mint_msg(treasury.to_string(), msg.denom, msg.amount, recipient);
+
+    let submsg = execute_treasury_msg(
+        ctx.deps.branch(),
+        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: vec![to_json_string(&mint_msg)?],
+        }),
+        None,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn set_denom_admin(
+    ctx: &mut Context,
+    msg: SetDenomAdminMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let treasury = query_enterprise_treasury_addr(ctx.deps.as_ref())?;
+
+    let change_admin_msg = change_admin_msg(treasury.to_string(), msg.denom, msg.new_admin);
+
+    let submsg = execute_treasury_msg(
+        ctx.deps.branch(),
+        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: vec![to_json_string(&change_admin_msg)?],
+        }),
+        None,
+    )?;
+
+    Ok(vec![submsg])
+}
+
 fn execute_enterprise_msgs(
     ctx: &mut Context,
     msg: ExecuteEnterpriseMsgsMsg,
@@ -1173,22 +2783,171 @@ fn distribute_funds(
     Ok(vec![submsg])
 }
 
-fn update_minimum_weight_for_rewards(
+/// Distributes the assets from the treasury the same way as DistributeFunds, and checks
+/// afterwards that the funds distributor actually received them.
+fn distribute_from_treasury(
+    ctx: &mut Context,
+    msg: DistributeFromTreasuryMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let funds_distributor =
+        query_enterprise_components(ctx.deps.as_ref())?.funds_distributor_contract;
+
+    let assets = msg
+        .assets
+        .iter()
+        .map(|asset| asset.check(ctx.deps.api, None))
+        .collect::<StdResult<Vec<Asset>>>()?;
+
+    record_pending_treasury_distribution(ctx.deps.branch(), funds_distributor, assets)?;
+
+    let submsgs = distribute_funds(ctx, DistributeFundsMsg { funds: msg.assets })?
+        .into_iter()
+        .map(|submsg| SubMsg::reply_on_success(submsg.msg, DISTRIBUTE_FROM_TREASURY_REPLY_ID))
+        .collect();
+
+    Ok(submsgs)
+}
+
+fn update_minimum_weight_for_rewards(
+    ctx: &mut Context,
+    msg: UpdateMinimumWeightForRewardsMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let funds_distributor =
+        query_enterprise_components(ctx.deps.as_ref())?.funds_distributor_contract;
+
+    let submsg = SubMsg::new(wasm_execute(
+        funds_distributor.to_string(),
+        &funds_distributor_api::msg::ExecuteMsg::UpdateMinimumEligibleWeight(
+            UpdateMinimumEligibleWeightMsg {
+                minimum_eligible_weight: msg.minimum_weight_for_rewards,
+            },
+        ),
+        vec![],
+    )?);
+
+    Ok(vec![submsg])
+}
+
+fn update_distribution_fee(
+    ctx: &mut Context,
+    msg: UpdateDistributionFeeMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let funds_distributor =
+        query_enterprise_components(ctx.deps.as_ref())?.funds_distributor_contract;
+
+    let submsg = SubMsg::new(wasm_execute(
+        funds_distributor.to_string(),
+        &funds_distributor_api::msg::ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            new_admin: None,
+            new_enterprise_contract: None,
+            new_claim_history_retention: None,
+            new_distribution_rate_limit: None,
+            new_distribution_mode: None,
+            new_distribution_fee: Some(DistributionFee {
+                fee_bps: msg.bps,
+                recipient: msg.recipient,
+            }),
+        }),
+        vec![],
+    )?);
+
+    Ok(vec![submsg])
+}
+
+fn set_distributor_claims_paused(
+    ctx: &mut Context,
+    msg: SetDistributorClaimsPausedMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let funds_distributor =
+        query_enterprise_components(ctx.deps.as_ref())?.funds_distributor_contract;
+
+    let submsg = SubMsg::new(wasm_execute(
+        funds_distributor.to_string(),
+        &funds_distributor_api::msg::ExecuteMsg::SetClaimsPaused { paused: msg.paused },
+        vec![],
+    )?);
+
+    Ok(vec![submsg])
+}
+
+fn set_distributor_emission_schedule(
+    ctx: &mut Context,
+    msg: SetDistributorEmissionScheduleMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let funds_distributor =
+        query_enterprise_components(ctx.deps.as_ref())?.funds_distributor_contract;
+
+    let schedule = msg
+        .schedule
+        .map(|schedule| funds_distributor_api::api::EmissionScheduleMsg {
+            amount_per_period: schedule.amount_per_period,
+            period: schedule.period,
+            periods: schedule.periods,
+            source: match schedule.source {
+                EmissionSource::Mint => funds_distributor_api::api::EmissionSource::Mint,
+                EmissionSource::Treasury => funds_distributor_api::api::EmissionSource::Treasury,
+            },
+        });
+
+    let submsg = SubMsg::new(wasm_execute(
+        funds_distributor.to_string(),
+        &funds_distributor_api::msg::ExecuteMsg::SetEmissionSchedule(
+            funds_distributor_api::api::SetEmissionScheduleMsg {
+                asset: msg.asset,
+                schedule,
+            },
+        ),
+        vec![],
+    )?);
+
+    Ok(vec![submsg])
+}
+
+fn transfer_nft(ctx: &mut Context, msg: TransferNftMsg) -> GovernanceControllerResult<Vec<SubMsg>> {
+    let nft_contract = ctx.deps.api.addr_validate(&msg.contract)?;
+
+    let transfer_msgs = msg
+        .token_ids
+        .into_iter()
+        .map(|token_id| -> GovernanceControllerResult<String> {
+            let transfer_msg: CosmosMsg = wasm_execute(
+                nft_contract.to_string(),
+                &TransferNft {
+                    recipient: msg.recipient.clone(),
+                    token_id,
+                },
+                vec![],
+            )?
+            .into();
+
+            Ok(to_json_string(&transfer_msg)?)
+        })
+        .collect::<GovernanceControllerResult<Vec<String>>>()?;
+
+    let submsg = execute_treasury_msg(
+        ctx.deps.branch(),
+        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: transfer_msgs,
+        }),
+        None,
+    )?;
+
+    Ok(vec![submsg])
+}
+
+fn distribute_to_voters(
     ctx: &mut Context,
-    msg: UpdateMinimumWeightForRewardsMsg,
+    msg: DistributeToVotersMsg,
 ) -> GovernanceControllerResult<Vec<SubMsg>> {
-    let funds_distributor =
-        query_enterprise_components(ctx.deps.as_ref())?.funds_distributor_contract;
+    let transfer_msgs = distribute_to_voters_msgs(ctx.deps.as_ref(), msg)?;
 
-    let submsg = SubMsg::new(wasm_execute(
-        funds_distributor.to_string(),
-        &funds_distributor_api::msg::ExecuteMsg::UpdateMinimumEligibleWeight(
-            UpdateMinimumEligibleWeightMsg {
-                minimum_eligible_weight: msg.minimum_weight_for_rewards,
-            },
-        ),
-        vec![],
-    )?);
+    let submsg = execute_treasury_msg(
+        ctx.deps.branch(),
+        ExecuteCosmosMsgs(ExecuteCosmosMsgsMsg {
+            msgs: transfer_msgs,
+        }),
+        None,
+    )?;
 
     Ok(vec![submsg])
 }
@@ -1255,14 +3014,35 @@ pub fn receive_cw20(
 ) -> GovernanceControllerResult<Response> {
     match from_json(&cw20_msg.msg) {
         Ok(Cw20HookMsg::CreateProposal(msg)) => {
-            // only membership CW20 contract can execute this message
-            let dao_type = query_dao_type(ctx.deps.as_ref())?;
+            let accepted_deposit_assets =
+                GOV_CONFIG.load(ctx.deps.storage)?.accepted_deposit_assets;
+
+            let token_contract = match accepted_deposit_assets {
+                // only accepted deposit assets can execute this message
+                Some(accepted_deposit_assets) => {
+                    let is_accepted = accepted_deposit_assets
+                        .iter()
+                        .any(|accepted| accepted.asset == AssetInfo::cw20(ctx.info.sender.clone()));
+
+                    if !is_accepted {
+                        return Err(UnacceptedDepositAsset);
+                    }
 
-            let token_contract = query_dao_token_config(ctx.deps.as_ref())?.token_contract;
+                    ctx.info.sender.clone()
+                }
+                // only membership CW20 contract can execute this message
+                None => {
+                    let dao_type = query_dao_type(ctx.deps.as_ref())?;
 
-            if dao_type != Token || ctx.info.sender != token_contract {
-                return Err(InvalidDepositType);
-            }
+                    let token_contract = query_dao_token_config(ctx.deps.as_ref())?.token_contract;
+
+                    if dao_type != Token || ctx.info.sender != token_contract {
+                        return Err(InvalidDepositType);
+                    }
+
+                    token_contract
+                }
+            };
 
             let sender = ctx.deps.api.addr_validate(&cw20_msg.sender)?;
 
@@ -1306,6 +3086,15 @@ pub fn weights_changed(
         return Err(Unauthorized);
     }
 
+    for user_weight_change in &msg.weight_changes {
+        if user_weight_change.old_weight.is_zero() && !user_weight_change.new_weight.is_zero() {
+            let user = ctx.deps.api.addr_validate(&user_weight_change.user)?;
+            MEMBER_JOINED_AT.save(ctx.deps.storage, user, &ctx.env.block.time)?;
+        }
+    }
+
+    update_council_nominations(ctx.deps.branch(), &msg.weight_changes)?;
+
     let update_votes_submsgs = update_user_votes(ctx.deps.as_ref(), &msg.weight_changes)?;
 
     let new_user_weights = msg
@@ -1357,16 +3146,23 @@ pub fn update_user_votes(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<Response> {
+pub fn reply(mut deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<Response> {
     match msg.id {
         CREATE_POLL_REPLY_ID => {
             let poll_id = parse_poll_id(msg)?;
 
             let state = STATE.load(deps.storage)?;
 
-            let proposal_info = state.proposal_being_created.ok_or(CustomError {
-                val: "Invalid state - missing proposal info".to_string(),
-            })?;
+            let is_batch = state.proposal_being_created.is_none();
+
+            let proposal_info = match state.proposal_being_created {
+                Some(proposal_info) => proposal_info,
+                None => BATCH_PROPOSALS_BEING_CREATED
+                    .pop_front(deps.storage)?
+                    .ok_or(CustomError {
+                        val: "Invalid state - missing proposal info".to_string(),
+                    })?,
+            };
 
             STATE.save(
                 deps.storage,
@@ -1378,7 +3174,61 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<
 
             PROPOSAL_INFOS.save(deps.storage, poll_id, &proposal_info)?;
 
-            Ok(reply_create_poll_response(poll_id))
+            index_proposal_assets(
+                deps.storage,
+                deps.api,
+                poll_id,
+                &proposal_info.proposal_actions,
+            )?;
+
+            let poll = query_poll(&QueryContext::from(deps.as_ref(), env), poll_id)?.poll;
+
+            if proposal_info.proposal_type == General {
+                OPEN_PROPOSALS.save(
+                    deps.storage,
+                    (poll.proposer.clone(), poll_id),
+                    &poll.ends_at,
+                )?;
+            }
+
+            if let Some(deposit) = &proposal_info.proposal_deposit {
+                DEPOSITOR_DEPOSITS.save(deps.storage, (deposit.depositor.clone(), poll_id), &())?;
+            }
+
+            let mut response = reply_create_poll_response(poll_id);
+
+            if is_batch {
+                let mut created_proposal_ids = BATCH_CREATED_PROPOSAL_IDS.load(deps.storage)?;
+                created_proposal_ids.push(poll_id);
+
+                if BATCH_PROPOSALS_BEING_CREATED.is_empty(deps.storage)? {
+                    BATCH_CREATED_PROPOSAL_IDS.remove(deps.storage);
+                    response = response.set_data(to_json_binary(&CreateProposalsResponse {
+                        proposal_ids: created_proposal_ids,
+                    })?);
+                } else {
+                    BATCH_CREATED_PROPOSAL_IDS.save(deps.storage, &created_proposal_ids)?;
+                }
+            }
+
+            if let Some(proposal_notifier) = GOV_CONFIG.load(deps.storage)?.proposal_notifier {
+                // the notifier's failure is caught in the reply, so it can't fail the creation
+                response = response.add_submessage(SubMsg::reply_on_error(
+                    wasm_execute(
+                        proposal_notifier.to_string(),
+                        &ProposalNotifierMsg::ProposalCreated(ProposalCreatedNotification {
+                            proposal_id: poll_id,
+                            proposal_type: proposal_info.proposal_type,
+                            proposer: poll.proposer,
+                            title: poll.label,
+                        }),
+                        vec![],
+                    )?,
+                    NOTIFY_PROPOSAL_CREATED_REPLY_ID,
+                ));
+            }
+
+            Ok(response)
         }
         END_POLL_REPLY_ID => {
             let info = MessageInfo {
@@ -1401,9 +3251,7 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<
                 },
             )?;
 
-            let execute_submsgs = resolve_ended_proposal(ctx, proposal_id)?;
-
-            Ok(Response::new().add_submessages(execute_submsgs))
+            resolve_ended_proposal(ctx, proposal_id)
         }
         CAST_VOTE_REPLY_ID => {
             let state = STATE.load(deps.storage)?;
@@ -1420,66 +3268,48 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<
                 },
             )?;
 
-            let gov_config = GOV_CONFIG.load(deps.storage)?;
-
-            if !gov_config.allow_early_proposal_execution {
-                // if no early execution is allowed, no need to store anything
-                Ok(Response::new())
-            } else {
-                // otherwise, let's see if we need to update earliest proposal execution time
-
-                let dao_type = query_dao_type(deps.as_ref())?;
-
-                if dao_type == Multisig {
-                    // nothing to modify in multisig DAOs - they don't need a delay
-                    return Ok(Response::new());
-                }
-
-                let proposal_info =
-                    PROPOSAL_INFOS.load(deps.storage, proposal_being_voted_on.proposal_id)?;
+            let proposal_id = proposal_being_voted_on.proposal_id;
 
-                if proposal_info.proposal_type == Council {
-                    // nothing to modify in council proposal types
-                    return Ok(Response::new());
-                }
-
-                let total_available_votes = total_available_votes(
-                    deps.as_ref(),
-                    Never {},
-                    proposal_info.proposal_type.clone(),
-                )?;
-
-                let end_proposal_status = simulate_end_proposal_status(
-                    deps.as_ref(),
-                    proposal_being_voted_on.proposal_id,
-                    total_available_votes,
-                )?;
-
-                let new_executability_status =
-                    ProposalExecutabilityStatus::from(end_proposal_status.status);
+            update_earliest_proposal_execution(deps.branch(), &env, proposal_being_voted_on)?;
 
-                // if status of the proposal has changed, we need to update its earliest execution time
-                if new_executability_status != proposal_being_voted_on.executability_status {
-                    // general-type proposals need a delay before the proposal can be executed
-                    // after its execution status changes (i.e. this vote changed the outcome)
+            let response = match auto_execute_proposal_submsg(deps.as_ref(), &env, proposal_id)? {
+                Some(submsg) => Response::new().add_submessage(submsg),
+                None => Response::new(),
+            };
 
-                    let execution_delay = gov_config.vote_duration / 10;
-                    let earliest_execution = env.block.time.plus_seconds(execution_delay);
+            Ok(response)
+        }
+        NOTIFY_PROPOSAL_CREATED_REPLY_ID => {
+            // only called on error - the proposal is created regardless of the notifier failing
+            let error = match msg.result {
+                SubMsgResult::Err(err) => err,
+                SubMsgResult::Ok(_) => String::new(),
+            };
 
-                    let proposal_ends_at = end_proposal_status.ends_at;
+            Ok(Response::new()
+                .add_attribute("action", "notify_proposal_created")
+                .add_attribute("notification_status", "failure")
+                .add_attribute("notification_error", error))
+        }
+        AUTO_EXECUTE_PROPOSAL_REPLY_ID => {
+            // the vote itself should stand even if the proposal could not be executed,
+            // the proposal can still be executed later as usual
+            let mut response = Response::new().add_attribute("action", "auto_execute_proposal");
+
+            if let SubMsgResult::Err(err) = msg.result {
+                response = response
+                    .add_attribute("auto_execution_status", "failure")
+                    .add_attribute("auto_execution_error", err);
+            }
 
-                    PROPOSAL_INFOS.save(
-                        deps.storage,
-                        proposal_being_voted_on.proposal_id,
-                        &ProposalInfo {
-                            earliest_execution: Some(min(earliest_execution, proposal_ends_at)),
-                            ..proposal_info
-                        },
-                    )?;
-                }
+            Ok(response)
+        }
+        DISTRIBUTE_FROM_TREASURY_REPLY_ID => {
+            // the treasury's transfer may have delivered less than it stated, e.g. for assets
+            // that take a fee on transfer
+            assert_treasury_distribution_received(deps.branch())?;
 
-                Ok(Response::new())
-            }
+            Ok(Response::new().add_attribute("action", "distribute_from_treasury"))
         }
         EXECUTE_PROPOSAL_ACTIONS_REPLY_ID => {
             // no actions, regardless of the result
@@ -1498,6 +3328,36 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> GovernanceControllerResult<
             }
             Ok(response)
         }
+        reply_id if reply_id & EXECUTE_PROPOSAL_ACTION_REPLY_ID_FLAG != 0 => {
+            let (proposal_id, action_index) = parse_execute_proposal_action_reply_id(reply_id);
+
+            let outcome = match msg.result {
+                SubMsgResult::Ok(_) => ProposalActionOutcome {
+                    action_index,
+                    success: true,
+                    error: None,
+                },
+                SubMsgResult::Err(err) => ProposalActionOutcome {
+                    action_index,
+                    success: false,
+                    error: Some(err),
+                },
+            };
+            PROPOSAL_ACTION_OUTCOMES.save(deps.storage, (proposal_id, action_index), &outcome)?;
+
+            Ok(Response::new()
+                .add_attribute("action", "execute_proposal_action")
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("action_index", action_index.to_string())
+                .add_attribute(
+                    PROPOSAL_ACTIONS_EXECUTION_STATUS,
+                    if outcome.success {
+                        "success"
+                    } else {
+                        "failure"
+                    },
+                ))
+        }
         _ => Err(Std(StdError::generic_err("No such reply ID found"))),
     }
 }
@@ -1547,8 +3407,46 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> GovernanceControllerResult<
         QueryMsg::Proposal(params) => to_json_binary(&query_proposal(qctx, params)?)?,
         QueryMsg::Proposals(params) => to_json_binary(&query_proposals(qctx, params)?)?,
         QueryMsg::ProposalStatus(params) => to_json_binary(&query_proposal_status(qctx, params)?)?,
+        QueryMsg::ProposalStatusDetail(params) => {
+            to_json_binary(&query_proposal_status_detail(qctx, params)?)?
+        }
         QueryMsg::MemberVote(params) => to_json_binary(&query_member_vote(qctx, params)?)?,
+        QueryMsg::ProposalActionOutcomes(params) => {
+            to_json_binary(&query_proposal_action_outcomes(qctx, params)?)?
+        }
+        QueryMsg::DecodedProposalActions(params) => {
+            to_json_binary(&query_decoded_proposal_actions(qctx, params)?)?
+        }
+        QueryMsg::CanExecuteAction(params) => {
+            to_json_binary(&query_can_execute_action(qctx, params)?)?
+        }
+        QueryMsg::VoterWeight(params) => to_json_binary(&query_voter_weight(qctx, params)?)?,
+        QueryMsg::IsMember(params) => to_json_binary(&query_is_member(qctx, params)?)?,
         QueryMsg::ProposalVotes(params) => to_json_binary(&query_proposal_votes(qctx, params)?)?,
+        QueryMsg::VetoCouncil {} => to_json_binary(&query_veto_council(qctx)?)?,
+        QueryMsg::SupportedProposalActions {} => {
+            to_json_binary(&query_supported_proposal_actions()?)?
+        }
+        QueryMsg::PendingCouncilProposals(params) => {
+            to_json_binary(&query_pending_council_proposals(qctx, params)?)?
+        }
+        QueryMsg::ExpiringProposals(params) => {
+            to_json_binary(&query_expiring_proposals(qctx, params)?)?
+        }
+        QueryMsg::CouncilVotes(params) => to_json_binary(&query_council_votes(qctx, params)?)?,
+        QueryMsg::ProposalsAffectingAsset(params) => {
+            to_json_binary(&query_proposals_affecting_asset(qctx, params)?)?
+        }
+        QueryMsg::AssetBundles(params) => to_json_binary(&query_asset_bundles(qctx, params)?)?,
+        QueryMsg::CouncilElectionSchedule {} => {
+            to_json_binary(&query_council_election_schedule(qctx)?)?
+        }
+        QueryMsg::ProposerDeposits(params) => {
+            to_json_binary(&query_proposer_deposits(qctx, params)?)?
+        }
+        QueryMsg::VerifyProposalHash(params) => {
+            to_json_binary(&query_verify_proposal_hash(qctx, params)?)?
+        }
     };
     Ok(response)
 }
@@ -1567,106 +3465,560 @@ pub fn query_gov_config(qctx: QueryContext) -> GovernanceControllerResult<GovCon
 
     let membership_contract = query_membership_addr(qctx.deps)?;
 
-    let council_gov_config = COUNCIL_GOV_CONFIG.load(qctx.deps.storage)?;
+    let council_gov_config = COUNCIL_GOV_CONFIG.load(qctx.deps.storage)?;
+
+    Ok(GovConfigResponse {
+        gov_config,
+        council_gov_config,
+        dao_membership_contract: membership_contract,
+        dao_council_membership_contract: dao_council_membership,
+    })
+}
+
+pub fn query_veto_council(qctx: QueryContext) -> GovernanceControllerResult<VetoCouncilResponse> {
+    let veto_council = VETO_COUNCIL.may_load(qctx.deps.storage)?.flatten();
+
+    Ok(VetoCouncilResponse { veto_council })
+}
+
+pub fn query_proposal(
+    qctx: QueryContext,
+    msg: ProposalParams,
+) -> GovernanceControllerResult<ProposalResponse> {
+    let poll = query_poll(&qctx, msg.proposal_id)?;
+
+    let mut proposal = poll_to_proposal_response(qctx.deps, &qctx.env, &poll.poll)?;
+
+    if hides_proposal_contents(&qctx, msg.viewer)? {
+        redact_proposal(&mut proposal);
+    }
+
+    Ok(proposal)
+}
+
+fn query_poll(qctx: &QueryContext, poll_id: PollId) -> GovernanceControllerResult<PollResponse> {
+    let governance_contract = query_enterprise_governance_addr(qctx.deps)?;
+
+    let poll: PollResponse = qctx.deps.querier.query_wasm_smart(
+        governance_contract.to_string(),
+        &enterprise_governance_api::msg::QueryMsg::Poll(PollParams { poll_id }),
+    )?;
+    Ok(poll)
+}
+
+pub fn query_proposals(
+    qctx: QueryContext,
+    msg: ProposalsParams,
+) -> GovernanceControllerResult<ProposalsResponse> {
+    let limit = msg
+        .limit
+        .map_or(DEFAULT_QUERY_LIMIT as usize, |limit| limit as usize)
+        .min(MAX_QUERY_LIMIT as usize);
+
+    let filter = msg.filter.map(|filter| match filter {
+        ProposalStatusFilter::InProgress => PollStatusFilter::InProgress,
+        ProposalStatusFilter::Passed => PollStatusFilter::Passed,
+        ProposalStatusFilter::Rejected => PollStatusFilter::Rejected,
+    });
+
+    let title_contains = msg.title_contains.map(|text| text.to_lowercase());
+
+    let governance_contract = query_enterprise_governance_addr(qctx.deps)?;
+
+    let mut proposals: Vec<ProposalResponse> = vec![];
+    let mut scanned_proposals: usize = 0;
+    let mut last_scanned: Option<ProposalId> = None;
+    let mut more_polls = true;
+
+    // polls are paged through until enough matching ones are found,
+    // or until the scan reaches its bound
+    while more_polls && proposals.len() < limit && scanned_proposals < MAX_SCANNED_PROPOSALS {
+        let polls: PollsResponse = qctx.deps.querier.query_wasm_smart(
+            governance_contract.to_string(),
+            &enterprise_governance_api::msg::QueryMsg::Polls(PollsParams {
+                filter: filter.clone(),
+                pagination: Pagination {
+                    start_after: last_scanned.or(msg.start_after).map(Uint64::from),
+                    end_at: None,
+                    limit: Some(MAX_QUERY_LIMIT as u64),
+                    order_by: None,
+                },
+            }),
+        )?;
+
+        // a full page means there may be more polls after it
+        more_polls = polls.polls.len() == MAX_QUERY_LIMIT as usize;
+
+        let mut polls = polls.polls.into_iter().peekable();
+
+        while proposals.len() < limit && scanned_proposals < MAX_SCANNED_PROPOSALS {
+            let Some(poll) = polls.next() else {
+                break;
+            };
+            scanned_proposals += 1;
+            last_scanned = Some(poll.id);
+
+            if let Some(text) = &title_contains {
+                if !poll.label.to_lowercase().contains(text) {
+                    continue;
+                }
+            }
+
+            // filthy hack: we do not store whether a poll is of type General or Council
+            // we listed all polls in poll-engine, but only when we try to add remaining data
+            // contained in this contract can we know what their type is and exclude them from
+            // the results if they're not of the requested type
+            match poll_to_proposal_response(qctx.deps, &qctx.env, &poll) {
+                Ok(proposal) => proposals.push(proposal),
+                Err(NoSuchProposal) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        if polls.peek().is_some() {
+            more_polls = true;
+        }
+    }
+
+    if hides_proposal_contents(&qctx, msg.viewer)? {
+        proposals.iter_mut().for_each(redact_proposal);
+    }
+
+    Ok(ProposalsResponse {
+        proposals,
+        next_start_after: last_scanned.filter(|_| more_polls),
+    })
+}
+
+/// Whether proposals' contents have to be hidden from the given viewer, i.e. the DAO's proposals
+/// are private and the viewer is not a member of the DAO.
+fn hides_proposal_contents(
+    qctx: &QueryContext,
+    viewer: Option<String>,
+) -> GovernanceControllerResult<bool> {
+    let private_proposals = GOV_CONFIG
+        .load(qctx.deps.storage)?
+        .private_proposals
+        .unwrap_or(false);
+
+    Ok(private_proposals && !is_dao_member(qctx.clone(), viewer)?)
+}
+
+fn redact_proposal(proposal_response: &mut ProposalResponse) {
+    proposal_response.proposal.description = String::new();
+    proposal_response.proposal.proposal_actions = vec![];
+}
+
+fn is_dao_member(qctx: QueryContext, user: Option<String>) -> GovernanceControllerResult<bool> {
+    match user {
+        None => Ok(false),
+        Some(user) => {
+            let user = qctx.deps.api.addr_validate(&user)?;
+            Ok(!get_user_available_votes(qctx, user)?.is_zero())
+        }
+    }
+}
+
+pub fn query_proposal_status(
+    qctx: QueryContext,
+    msg: ProposalStatusParams,
+) -> GovernanceControllerResult<ProposalStatusResponse> {
+    let poll_status = query_poll_status(&qctx, msg.proposal_id)?;
+
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(qctx.deps.storage, msg.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    let status = fix_poll_status(
+        qctx.deps,
+        msg.proposal_id,
+        poll_status.status,
+        qctx.env.block.time,
+        &proposal_info,
+    )?;
+
+    Ok(ProposalStatusResponse {
+        status,
+        expires: AtTime(poll_status.ends_at),
+        results: poll_status.results,
+    })
+}
+
+pub fn query_proposal_status_detail(
+    qctx: QueryContext,
+    msg: ProposalStatusParams,
+) -> GovernanceControllerResult<ProposalStatusDetailResponse> {
+    let poll = query_poll(&qctx, msg.proposal_id)?.poll;
+
+    let proposal = poll_to_proposal_response(qctx.deps, &qctx.env, &poll)?;
+
+    let projected_outcome = match proposal.proposal_status {
+        ProposalStatus::Passed
+        | ProposalStatus::Executed
+        | ProposalStatus::ConditionNotMet
+        | ProposalStatus::ExecutionFailed => ProjectedProposalOutcome::WouldPass,
+        ProposalStatus::Rejected | ProposalStatus::Vetoed => ProjectedProposalOutcome::WouldFail,
+        ProposalStatus::InProgress | ProposalStatus::InProgressCanExecuteEarly => {
+            // resolve the proposal the same way the poll engine would if it ended now
+            let simulated_status = simulate_end_proposal_status(
+                qctx.deps,
+                msg.proposal_id,
+                proposal.total_votes_available,
+            )?;
+            match simulated_status.status {
+                PollStatus::Passed { .. } => ProjectedProposalOutcome::WouldPass,
+                PollStatus::InProgress { .. } | PollStatus::Rejected { .. } => {
+                    ProjectedProposalOutcome::WouldFail
+                }
+            }
+        }
+    };
+
+    let (current_quorum, current_threshold) =
+        current_vote_ratios(&poll.results, proposal.total_votes_available);
+
+    let seconds_remaining = poll
+        .ends_at
+        .seconds()
+        .saturating_sub(qctx.env.block.time.seconds());
+
+    Ok(ProposalStatusDetailResponse {
+        status: proposal.proposal_status,
+        expires: AtTime(poll.ends_at),
+        seconds_remaining,
+        results: poll.results,
+        total_votes_available: proposal.total_votes_available,
+        quorum: poll.quorum,
+        threshold: poll.threshold,
+        current_quorum,
+        current_threshold,
+        projected_outcome,
+    })
+}
+
+/// Calculates the current portion of available votes cast, and the current portion of
+/// non-abstaining votes that are 'yes' votes.
+fn current_vote_ratios(
+    results: &BTreeMap<u8, u128>,
+    total_votes_available: Uint128,
+) -> (Decimal, Decimal) {
+    let total_votes: u128 = results.values().sum();
+    let abstain_votes = results
+        .get(&(VoteOutcome::Abstain as u8))
+        .copied()
+        .unwrap_or_default();
+    let yes_votes = results
+        .get(&(VoteOutcome::Yes as u8))
+        .copied()
+        .unwrap_or_default();
+
+    let current_quorum =
+        Decimal::checked_from_ratio(total_votes, total_votes_available).unwrap_or_default();
+    let current_threshold =
+        Decimal::checked_from_ratio(yes_votes, total_votes - abstain_votes).unwrap_or_default();
+
+    (current_quorum, current_threshold)
+}
+
+pub fn query_council_votes(
+    qctx: QueryContext,
+    params: CouncilVotesParams,
+) -> GovernanceControllerResult<CouncilVotesResponse> {
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(qctx.deps.storage, params.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    if proposal_info.proposal_type != Council {
+        return Err(WrongProposalType);
+    }
+
+    let start_after = params
+        .start_after
+        .map(|member| qctx.deps.api.addr_validate(&member))
+        .transpose()?;
+
+    let limit = params
+        .limit
+        .map_or(DEFAULT_QUERY_LIMIT as usize, |limit| limit as usize)
+        .min(MAX_QUERY_LIMIT as usize);
+
+    let votes = COUNCIL_VOTES
+        .prefix(params.proposal_id)
+        .range(
+            qctx.deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(_, vote)| vote))
+        .collect::<StdResult<Vec<CouncilVote>>>()?;
+
+    Ok(CouncilVotesResponse { votes })
+}
+
+pub fn query_pending_council_proposals(
+    qctx: QueryContext,
+    params: PendingCouncilProposalsParams,
+) -> GovernanceControllerResult<PendingCouncilProposalsResponse> {
+    let limit = params
+        .limit
+        .map_or(DEFAULT_QUERY_LIMIT as usize, |limit| limit as usize)
+        .min(MAX_QUERY_LIMIT as usize);
+
+    let mut scanned_proposals = PROPOSAL_INFOS
+        .range(
+            qctx.deps.storage,
+            params.start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(MAX_SCANNED_PROPOSALS);
+
+    let mut proposals: Vec<CouncilProposalTally> = vec![];
+    let mut last_scanned: Option<ProposalId> = None;
+
+    while proposals.len() < limit {
+        let Some(res) = scanned_proposals.next() else {
+            break;
+        };
+        let (proposal_id, info) = res?;
+        last_scanned = Some(proposal_id);
+
+        // only council proposals that were not yet executed can still be pending
+        if info.proposal_type != Council || info.executed_at.is_some() {
+            continue;
+        }
+
+        let poll = query_poll(&qctx, proposal_id)?.poll;
+
+        let proposal = poll_to_proposal_response(qctx.deps, &qctx.env, &poll)?;
+
+        match proposal.proposal_status {
+            ProposalStatus::InProgress | ProposalStatus::InProgressCanExecuteEarly => {}
+            _ => continue,
+        }
+
+        let (current_quorum, current_threshold) =
+            current_vote_ratios(&poll.results, proposal.total_votes_available);
+
+        let quorum_reached = current_quorum >= poll.quorum;
+        let threshold_reached = current_threshold >= poll.threshold;
+
+        proposals.push(CouncilProposalTally {
+            proposal,
+            quorum: poll.quorum,
+            threshold: poll.threshold,
+            quorum_reached,
+            threshold_reached,
+        });
+    }
+
+    // the scan may have stopped at its bound or at the limit, with more proposals left to look at
+    let next_start_after = last_scanned.filter(|last_scanned| {
+        PROPOSAL_INFOS
+            .keys(
+                qctx.deps.storage,
+                Some(Bound::exclusive(*last_scanned)),
+                None,
+                Ascending,
+            )
+            .next()
+            .is_some()
+    });
+
+    if hides_proposal_contents(&qctx, params.viewer)? {
+        proposals
+            .iter_mut()
+            .for_each(|tally| redact_proposal(&mut tally.proposal));
+    }
 
-    Ok(GovConfigResponse {
-        gov_config,
-        council_gov_config,
-        dao_membership_contract: membership_contract,
-        dao_council_membership_contract: dao_council_membership,
+    Ok(PendingCouncilProposalsResponse {
+        proposals,
+        next_start_after,
     })
 }
 
-pub fn query_proposal(
+pub fn query_proposals_affecting_asset(
     qctx: QueryContext,
-    msg: ProposalParams,
-) -> GovernanceControllerResult<ProposalResponse> {
-    let poll = query_poll(&qctx, msg.proposal_id)?;
-
-    let proposal = poll_to_proposal_response(qctx.deps, &qctx.env, &poll.poll)?;
+    params: ProposalsAffectingAssetParams,
+) -> GovernanceControllerResult<ProposalsAffectingAssetResponse> {
+    let asset_key =
+        asset_key(&params.asset.check(qctx.deps.api, None)?).ok_or(UnsupportedCw1155Asset)?;
+
+    let limit = params
+        .limit
+        .map_or(DEFAULT_QUERY_LIMIT as usize, |limit| limit as usize)
+        .min(MAX_QUERY_LIMIT as usize);
+
+    let proposal_ids = PROPOSALS_BY_ASSET
+        .prefix(asset_key)
+        .keys(
+            qctx.deps.storage,
+            params.start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<ProposalId>>>()?;
 
-    Ok(proposal)
+    Ok(ProposalsAffectingAssetResponse { proposal_ids })
 }
 
-fn query_poll(qctx: &QueryContext, poll_id: PollId) -> GovernanceControllerResult<PollResponse> {
-    let governance_contract = query_enterprise_governance_addr(qctx.deps)?;
+pub fn query_expiring_proposals(
+    qctx: QueryContext,
+    params: ExpiringProposalsParams,
+) -> GovernanceControllerResult<ExpiringProposalsResponse> {
+    let within = match params.within {
+        Duration::Time(seconds) => seconds,
+        Duration::Height(_) => {
+            return Err(InvalidArgument {
+                msg: "Expiry window has to be a time duration".to_string(),
+            })
+        }
+    };
 
-    let poll: PollResponse = qctx.deps.querier.query_wasm_smart(
-        governance_contract.to_string(),
-        &enterprise_governance_api::msg::QueryMsg::Poll(PollParams { poll_id }),
-    )?;
-    Ok(poll)
-}
+    let limit = params
+        .limit
+        .map_or(DEFAULT_QUERY_LIMIT as usize, |limit| limit as usize)
+        .min(MAX_QUERY_LIMIT as usize);
+
+    let now = qctx.env.block.time;
+    let expiring_until = now.plus_seconds(within);
 
-pub fn query_proposals(
-    qctx: QueryContext,
-    msg: ProposalsParams,
-) -> GovernanceControllerResult<ProposalsResponse> {
     let governance_contract = query_enterprise_governance_addr(qctx.deps)?;
 
-    let polls: PollsResponse = qctx.deps.querier.query_wasm_smart(
-        governance_contract.to_string(),
-        &enterprise_governance_api::msg::QueryMsg::Polls(PollsParams {
-            filter: msg.filter.map(|filter| match filter {
-                ProposalStatusFilter::InProgress => PollStatusFilter::InProgress,
-                ProposalStatusFilter::Passed => PollStatusFilter::Passed,
-                ProposalStatusFilter::Rejected => PollStatusFilter::Rejected,
+    let mut proposals: Vec<ExpiringProposal> = vec![];
+    let mut scanned_proposals: usize = 0;
+    let mut last_scanned: Option<ProposalId> = None;
+    let mut more_polls = true;
+
+    // in-progress polls are paged through until enough expiring ones are found,
+    // or until the scan reaches its bound
+    while more_polls && proposals.len() < limit && scanned_proposals < MAX_SCANNED_PROPOSALS {
+        let polls: PollsResponse = qctx.deps.querier.query_wasm_smart(
+            governance_contract.to_string(),
+            &enterprise_governance_api::msg::QueryMsg::Polls(PollsParams {
+                filter: Some(PollStatusFilter::InProgress),
+                pagination: Pagination {
+                    start_after: last_scanned.or(params.start_after).map(Uint64::from),
+                    end_at: None,
+                    limit: Some(MAX_QUERY_LIMIT as u64),
+                    order_by: None,
+                },
             }),
-            pagination: Pagination {
-                start_after: msg.start_after.map(Uint64::from),
-                end_at: None,
-                limit: Some(
-                    msg.limit
-                        .map_or(DEFAULT_QUERY_LIMIT as u64, |limit| limit as u64)
-                        .min(MAX_QUERY_LIMIT as u64),
-                ),
-                order_by: None,
-            },
-        }),
-    )?;
+        )?;
 
-    let proposals = polls
-        .polls
-        .into_iter()
-        .filter_map(|poll| {
-            let proposal_response = poll_to_proposal_response(qctx.deps, &qctx.env, &poll);
-            // filthy hack: we do not store whether a poll is of type General or Council
-            // we listed all polls in poll-engine, but only when we try to add remaining data
-            // contained in this contract can we know what their type is and exclude them from
-            // the results if they're not of the requested type
-            if let Err(NoSuchProposal) = proposal_response {
-                None
-            } else {
-                Some(proposal_response)
+        // a full page means there may be more polls after it
+        more_polls = polls.polls.len() == MAX_QUERY_LIMIT as usize;
+
+        let mut polls = polls.polls.into_iter().peekable();
+
+        while proposals.len() < limit && scanned_proposals < MAX_SCANNED_PROPOSALS {
+            let Some(poll) = polls.next() else {
+                break;
+            };
+            scanned_proposals += 1;
+            last_scanned = Some(poll.id);
+
+            let ends_at = poll.ends_at;
+            if ends_at <= now || ends_at > expiring_until {
+                continue;
             }
-        })
-        .collect::<GovernanceControllerResult<Vec<ProposalResponse>>>()?;
 
-    Ok(ProposalsResponse { proposals })
+            let proposal = match poll_to_proposal_response(qctx.deps, &qctx.env, &poll) {
+                Ok(proposal) => proposal,
+                Err(NoSuchProposal) => continue,
+                Err(e) => return Err(e),
+            };
+
+            proposals.push(ExpiringProposal {
+                proposal,
+                time_remaining: ends_at.seconds() - now.seconds(),
+            });
+        }
+
+        if polls.peek().is_some() {
+            more_polls = true;
+        }
+    }
+
+    if hides_proposal_contents(&qctx, params.viewer)? {
+        proposals
+            .iter_mut()
+            .for_each(|expiring| redact_proposal(&mut expiring.proposal));
+    }
+
+    Ok(ExpiringProposalsResponse {
+        proposals,
+        next_start_after: last_scanned.filter(|_| more_polls),
+    })
 }
 
-pub fn query_proposal_status(
+pub fn query_proposer_deposits(
     qctx: QueryContext,
-    msg: ProposalStatusParams,
-) -> GovernanceControllerResult<ProposalStatusResponse> {
-    let poll_status = query_poll_status(&qctx, msg.proposal_id)?;
+    params: ProposerDepositsParams,
+) -> GovernanceControllerResult<ProposerDepositsResponse> {
+    let depositor = qctx.deps.api.addr_validate(&params.depositor)?;
+
+    let limit = params
+        .limit
+        .map_or(DEFAULT_QUERY_LIMIT as usize, |limit| limit as usize)
+        .min(MAX_QUERY_LIMIT as usize);
+
+    let proposal_ids = DEPOSITOR_DEPOSITS
+        .prefix(depositor)
+        .keys(
+            qctx.deps.storage,
+            params.start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<ProposalId>>>()?;
 
-    let proposal_info = PROPOSAL_INFOS
-        .may_load(qctx.deps.storage, msg.proposal_id)?
-        .ok_or(NoSuchProposal)?;
+    let mut deposits = vec![];
 
-    let status = fix_poll_status(
-        qctx.deps,
-        msg.proposal_id,
-        poll_status.status,
-        qctx.env.block.time,
-        &proposal_info,
-    )?;
+    for proposal_id in proposal_ids {
+        let proposal_info = PROPOSAL_INFOS
+            .may_load(qctx.deps.storage, proposal_id)?
+            .ok_or(NoSuchProposal)?;
 
-    Ok(ProposalStatusResponse {
-        status,
-        expires: AtTime(poll_status.ends_at),
-        results: poll_status.results,
+        let Some(deposit) = proposal_info.proposal_deposit.clone() else {
+            continue;
+        };
+
+        let poll = query_poll(&qctx, proposal_id)?.poll;
+
+        let status = proposal_deposit_status(
+            &proposal_info,
+            &poll,
+            VETOED_PROPOSALS.has(qctx.deps.storage, proposal_id),
+            REFUNDABLE_DEPOSITS.has(qctx.deps.storage, proposal_id),
+            qctx.env.block.time,
+        );
+
+        deposits.push(ProposerDeposit {
+            proposal_id,
+            deposit,
+            status,
+        });
+    }
+
+    Ok(ProposerDepositsResponse { deposits })
+}
+
+pub fn query_verify_proposal_hash(
+    qctx: QueryContext,
+    params: VerifyProposalHashParams,
+) -> GovernanceControllerResult<VerifyProposalHashResponse> {
+    let proposal_actions =
+        get_proposal_actions(qctx.deps.storage, params.proposal_id)?.ok_or(NoSuchProposal)?;
+
+    let hash = proposal_actions_hash(&proposal_actions)?;
+
+    Ok(VerifyProposalHashResponse {
+        matches: hash == params.expected_hash,
     })
 }
 
@@ -1715,6 +4067,8 @@ fn poll_to_proposal_response(
         proposal_actions: proposal_info.proposal_actions,
     };
 
+    let metadata = proposal_info.metadata;
+
     let expiration = match proposal_info.executed_at {
         Some(executed_block) => match proposal.expires {
             AtHeight(height) => AtHeight(min(height, executed_block.height)),
@@ -1748,6 +4102,7 @@ fn poll_to_proposal_response(
         proposal_status: status,
         results: poll.results.clone(),
         total_votes_available,
+        metadata,
     })
 }
 
@@ -1761,8 +4116,14 @@ fn fix_poll_status(
     now: Timestamp,
     proposal_info: &ProposalInfo,
 ) -> GovernanceControllerResult<ProposalStatus> {
-    let status = if proposal_info.executed_at.is_some() {
+    let status = if CONDITION_NOT_MET_PROPOSALS.has(deps.storage, poll_id) {
+        ProposalStatus::ConditionNotMet
+    } else if EXECUTION_FAILED_PROPOSALS.has(deps.storage, poll_id) {
+        ProposalStatus::ExecutionFailed
+    } else if proposal_info.executed_at.is_some() {
         ProposalStatus::Executed
+    } else if VETOED_PROPOSALS.has(deps.storage, poll_id) {
+        ProposalStatus::Vetoed
     } else {
         match poll_status {
             PollStatus::InProgress { ends_at } => {
@@ -1845,6 +4206,127 @@ fn total_available_votes(
     }
 }
 
+/// After a vote, updates the earliest execution time of a general proposal if the vote
+/// changed the proposal's outcome and early execution is allowed.
+fn update_earliest_proposal_execution(
+    deps: DepsMut,
+    env: &Env,
+    proposal_being_voted_on: ProposalBeingVotedOn,
+) -> GovernanceControllerResult<()> {
+    let gov_config = GOV_CONFIG.load(deps.storage)?;
+
+    if !gov_config.allow_early_proposal_execution {
+        // if no early execution is allowed, no need to store anything
+        return Ok(());
+    }
+
+    // otherwise, let's see if we need to update earliest proposal execution time
+
+    let dao_type = query_dao_type(deps.as_ref())?;
+
+    if dao_type == Multisig {
+        // nothing to modify in multisig DAOs - they don't need a delay
+        return Ok(());
+    }
+
+    let proposal_info = PROPOSAL_INFOS.load(deps.storage, proposal_being_voted_on.proposal_id)?;
+
+    if proposal_info.proposal_type == Council {
+        // nothing to modify in council proposal types
+        return Ok(());
+    }
+
+    let total_available_votes =
+        total_available_votes(deps.as_ref(), Never {}, proposal_info.proposal_type.clone())?;
+
+    let end_proposal_status = simulate_end_proposal_status(
+        deps.as_ref(),
+        proposal_being_voted_on.proposal_id,
+        total_available_votes,
+    )?;
+
+    let new_executability_status = ProposalExecutabilityStatus::from(end_proposal_status.status);
+
+    // if status of the proposal has changed, we need to update its earliest execution time
+    if new_executability_status != proposal_being_voted_on.executability_status {
+        // general-type proposals need a delay before the proposal can be executed
+        // after its execution status changes (i.e. this vote changed the outcome)
+
+        let execution_delay = gov_config.vote_duration / 10;
+        let earliest_execution = env.block.time.plus_seconds(execution_delay);
+
+        let proposal_ends_at = end_proposal_status.ends_at;
+
+        PROPOSAL_INFOS.save(
+            deps.storage,
+            proposal_being_voted_on.proposal_id,
+            &ProposalInfo {
+                earliest_execution: Some(min(earliest_execution, proposal_ends_at)),
+                ..proposal_info
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+/// If auto-execution is enabled and the proposal passes after a vote, creates a submessage
+/// executing the proposal.
+/// Proposals that cannot be executed yet, e.g. due to their earliest execution time not being
+/// reached, are skipped and will be executed by a later vote or a regular execution.
+fn auto_execute_proposal_submsg(
+    deps: Deps,
+    env: &Env,
+    proposal_id: ProposalId,
+) -> GovernanceControllerResult<Option<SubMsg>> {
+    let gov_config = GOV_CONFIG.load(deps.storage)?;
+
+    if gov_config.auto_execute_on_pass != Some(true) {
+        return Ok(None);
+    }
+
+    let proposal_info = PROPOSAL_INFOS.load(deps.storage, proposal_id)?;
+
+    if proposal_info.executed_at.is_some() {
+        return Ok(None);
+    }
+
+    if let Some(earliest_execution) = proposal_info.earliest_execution {
+        if env.block.time < earliest_execution {
+            return Ok(None);
+        }
+    }
+
+    if !allows_early_ending(deps, &proposal_info.proposal_type)? {
+        return Ok(None);
+    }
+
+    let total_available_votes = total_available_votes(deps, Never {}, proposal_info.proposal_type)?;
+
+    let end_proposal_status =
+        simulate_end_proposal_status(deps, proposal_id, total_available_votes)?.status;
+
+    if !matches!(
+        ProposalExecutabilityStatus::from(end_proposal_status),
+        ProposalExecutabilityStatus::Passed { .. }
+    ) {
+        return Ok(None);
+    }
+
+    // executed through a self-call, so that the usual execution checks and atomicity apply,
+    // and any failure to execute does not revert the vote
+    let submsg = SubMsg::reply_on_error(
+        wasm_execute(
+            env.contract.address.to_string(),
+            &ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+            vec![],
+        )?,
+        AUTO_EXECUTE_PROPOSAL_REPLY_ID,
+    );
+
+    Ok(Some(submsg))
+}
+
 /// Checks what the status of a proposal would be if we tried to execute it right now.
 fn simulate_end_proposal_status(
     deps: Deps,
@@ -1892,6 +4374,256 @@ pub fn query_member_vote(
     Ok(MemberVoteResponse { vote: vote.vote })
 }
 
+pub fn query_proposal_action_outcomes(
+    qctx: QueryContext,
+    params: ProposalParams,
+) -> GovernanceControllerResult<ProposalActionOutcomesResponse> {
+    let outcomes = PROPOSAL_ACTION_OUTCOMES
+        .prefix(params.proposal_id)
+        .range(qctx.deps.storage, None, None, Ascending)
+        .map(|res| res.map(|(_, outcome)| outcome))
+        .collect::<StdResult<Vec<ProposalActionOutcome>>>()?;
+
+    Ok(ProposalActionOutcomesResponse { outcomes })
+}
+
+pub fn query_decoded_proposal_actions(
+    qctx: QueryContext,
+    params: ProposalParams,
+) -> GovernanceControllerResult<DecodedProposalActionsResponse> {
+    let proposal_actions =
+        get_proposal_actions(qctx.deps.storage, params.proposal_id)?.ok_or(NoSuchProposal)?;
+
+    if hides_proposal_contents(&qctx, params.viewer)? {
+        return Ok(DecodedProposalActionsResponse {
+            proposal_actions: vec![],
+        });
+    }
+
+    let proposal_actions = proposal_actions
+        .into_iter()
+        .map(|proposal_action| {
+            let raw_msgs = match &proposal_action {
+                ExecuteMsgs(msg) => Some(&msg.msgs),
+                ExecuteTreasuryMsgs(msg) => Some(&msg.msgs),
+                ExecuteEnterpriseMsgs(msg) => Some(&msg.msgs),
+                _ => None,
+            };
+
+            let decoded_msgs = raw_msgs.map(|msgs| {
+                msgs.iter()
+                    .map(
+                        |msg| match serde_json_wasm::from_str::<CosmosMsg>(msg.as_str()) {
+                            Ok(msg) => DecodedMsg {
+                                msg: Some(msg),
+                                error: None,
+                            },
+                            Err(e) => DecodedMsg {
+                                msg: None,
+                                error: Some(e.to_string()),
+                            },
+                        },
+                    )
+                    .collect()
+            });
+
+            DecodedProposalAction {
+                proposal_action,
+                decoded_msgs,
+            }
+        })
+        .collect();
+
+    Ok(DecodedProposalActionsResponse { proposal_actions })
+}
+
+/// Lists every proposal action type, along with whether councils can be allowed to propose it.
+pub fn query_supported_proposal_actions(
+) -> GovernanceControllerResult<SupportedProposalActionsResponse> {
+    let proposal_actions = ProposalActionType::iter()
+        .map(|action_type| {
+            let council_allowed =
+                validate_allowed_council_proposal_types(Some(vec![action_type.clone()])).is_ok();
+
+            SupportedProposalAction {
+                action_type,
+                council_allowed,
+            }
+        })
+        .collect();
+
+    Ok(SupportedProposalActionsResponse { proposal_actions })
+}
+
+/// Runs the same checks that creating a general or a council proposal with the given action type
+/// would run for the caller, reporting the first one that fails.
+pub fn query_can_execute_action(
+    qctx: QueryContext,
+    params: CanExecuteActionParams,
+) -> GovernanceControllerResult<CanExecuteActionResponse> {
+    let caller = qctx.deps.api.addr_validate(&params.caller)?;
+
+    if let Err(e) = unrestricted_users_only(qctx.deps, caller.to_string()) {
+        return Ok(CanExecuteActionResponse {
+            can_execute: false,
+            reason: e.to_string(),
+        });
+    }
+
+    let general_proposal_error =
+        match check_can_create_general_proposal(qctx.clone(), &caller, &params.action_type) {
+            Ok(reason) => {
+                return Ok(CanExecuteActionResponse {
+                    can_execute: true,
+                    reason,
+                })
+            }
+            Err(e) => e,
+        };
+
+    let council_proposal_error = match assert_can_create_council_proposal(
+        qctx.deps,
+        &caller,
+        std::slice::from_ref(&params.action_type),
+    ) {
+        Ok(_) => {
+            return Ok(CanExecuteActionResponse {
+                can_execute: true,
+                reason: "Can be proposed through a council proposal".to_string(),
+            })
+        }
+        Err(e) => e,
+    };
+
+    Ok(CanExecuteActionResponse {
+        can_execute: false,
+        reason: format!("{}; {}", general_proposal_error, council_proposal_error),
+    })
+}
+
+fn check_can_create_general_proposal(
+    qctx: QueryContext,
+    caller: &Addr,
+    action_type: &ProposalActionType,
+) -> GovernanceControllerResult<String> {
+    let gov_config = GOV_CONFIG.load(qctx.deps.storage)?;
+
+    let user_available_votes = get_user_available_votes(qctx.clone(), caller.clone())?;
+
+    assert_can_create_general_proposal(
+        qctx.deps,
+        &qctx.env,
+        &gov_config,
+        caller,
+        user_available_votes,
+        std::slice::from_ref(action_type),
+    )?;
+
+    if gov_config.accepted_deposit_assets.is_some() {
+        return Ok(
+            "Can be proposed through a general proposal with a deposit in an accepted asset"
+                .to_string(),
+        );
+    }
+
+    match gov_config.minimum_deposit {
+        None => {
+            assert_sufficient_deposit_or_member(&gov_config, &None, user_available_votes)?;
+            Ok("Can be proposed through a general proposal".to_string())
+        }
+        Some(minimum_deposit) => Ok(format!(
+            "Can be proposed through a general proposal with a deposit of at least {}",
+            minimum_deposit
+        )),
+    }
+}
+
+pub fn query_voter_weight(
+    qctx: QueryContext,
+    params: VoterWeightParams,
+) -> GovernanceControllerResult<VoterWeightResponse> {
+    let voter = qctx.deps.api.addr_validate(&params.voter)?;
+
+    let proposal_info = PROPOSAL_INFOS
+        .may_load(qctx.deps.storage, params.proposal_id)?
+        .ok_or(NoSuchProposal)?;
+
+    let poll = query_poll(&qctx, params.proposal_id)?.poll;
+
+    let vote = query_member_vote(
+        qctx.clone(),
+        MemberVoteParams {
+            member: voter.to_string(),
+            proposal_id: params.proposal_id,
+        },
+    )?
+    .vote;
+
+    // votes already cast are kept up to date with the voter's weight, so they show what is counted
+    if let Some(vote) = vote {
+        return Ok(VoterWeightResponse {
+            weight: vote.amount.into(),
+        });
+    }
+
+    // no more votes can be cast on a proposal whose voting period is over
+    let voting_open =
+        matches!(poll.status, PollStatus::InProgress { .. }) && qctx.env.block.time < poll.ends_at;
+    if !voting_open {
+        return Ok(VoterWeightResponse {
+            weight: Uint128::zero(),
+        });
+    }
+
+    let weight = match proposal_info.proposal_type {
+        General => {
+            if restricts_late_joiner_votes(qctx.deps.storage)?
+                && joined_after(qctx.deps.storage, &voter, poll.started_at)?
+            {
+                Uint128::zero()
+            } else {
+                get_user_available_votes(qctx, voter)?
+            }
+        }
+        Council => query_council_member_weight(qctx.deps, voter.to_string())?,
+    };
+
+    Ok(VoterWeightResponse { weight })
+}
+
+/// Uses the same membership weight that is checked when creating proposals.
+pub fn query_is_member(
+    qctx: QueryContext,
+    params: IsMemberParams,
+) -> GovernanceControllerResult<IsMemberResponse> {
+    let address = qctx.deps.api.addr_validate(&params.address)?;
+
+    let weight = get_user_available_votes(qctx, address)?;
+
+    Ok(IsMemberResponse {
+        is_member: !weight.is_zero(),
+        weight,
+    })
+}
+
+fn restricts_late_joiner_votes(storage: &dyn Storage) -> GovernanceControllerResult<bool> {
+    Ok(GOV_CONFIG
+        .load(storage)?
+        .restrict_late_joiner_votes
+        .unwrap_or(false))
+}
+
+/// Whether the user became a member after the given moment.
+fn joined_after(
+    storage: &dyn Storage,
+    user: &Addr,
+    moment: Timestamp,
+) -> GovernanceControllerResult<bool> {
+    Ok(MEMBER_JOINED_AT
+        .may_load(storage, user.clone())?
+        .is_some_and(|joined_at| joined_at > moment))
+}
+
 pub fn query_proposal_votes(
     qctx: QueryContext,
     params: ProposalVotesParams,
@@ -1962,7 +4694,7 @@ fn query_enterprise_components(
     Ok(response)
 }
 
-fn query_enterprise_governance_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
+pub(crate) fn query_enterprise_governance_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
     Ok(query_enterprise_components(deps)?.enterprise_governance_contract)
 }
 
@@ -1970,6 +4702,14 @@ fn query_enterprise_treasury_addr(deps: Deps) -> GovernanceControllerResult<Addr
     Ok(query_enterprise_components(deps)?.enterprise_treasury_contract)
 }
 
+/// Address that all forfeited funds are sent to.
+fn query_forfeiture_recipient(deps: Deps) -> GovernanceControllerResult<Addr> {
+    match GOV_CONFIG.load(deps.storage)?.treasury_address {
+        Some(treasury_address) => Ok(treasury_address),
+        None => query_enterprise_treasury_addr(deps),
+    }
+}
+
 fn query_main_dao_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
     query_enterprise_treasury_addr(deps)
 }
@@ -2018,6 +4758,21 @@ fn query_dao_denom_config(deps: Deps) -> GovernanceControllerResult<DenomConfigR
     Ok(denom_config)
 }
 
+/// Query the membership contract for its unlocking period.
+/// Multisig DAOs have no unlocking period, so None is returned for them.
+pub(crate) fn query_dao_unlocking_period(
+    deps: Deps,
+) -> GovernanceControllerResult<Option<Duration>> {
+    let unlocking_period = match query_dao_type(deps)? {
+        Token => Some(query_dao_token_config(deps)?.unlocking_period),
+        Nft => Some(query_dao_nft_config(deps)?.unlocking_period),
+        Denom => Some(query_dao_denom_config(deps)?.unlocking_period),
+        Multisig => None,
+    };
+
+    Ok(unlocking_period)
+}
+
 fn query_council_membership_addr(deps: Deps) -> GovernanceControllerResult<Addr> {
     Ok(query_enterprise_components(deps)?.council_membership_contract)
 }
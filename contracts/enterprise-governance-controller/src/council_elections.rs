@@ -0,0 +1,338 @@
+use common::cw::QueryContext;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, Order, StdResult, Storage, SubMsg, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use enterprise_governance_controller_api::api::{
+    CouncilCandidate, CouncilElectionSchedule, CouncilElectionScheduleResponse,
+    ScheduleCouncilElectionsMsg,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    CouncilElectionNotDue, CouncilNominationsClosed, CouncilNominationsOpen,
+    NoCouncilElectionSchedule, TooManyCouncilCandidates,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use membership_common_api::api::UserWeightChange;
+use std::cmp::Reverse;
+
+/// Maximum number of candidates that can be nominated in a single council election.
+const MAXIMUM_COUNCIL_CANDIDATES: u32 = 200;
+
+pub const COUNCIL_ELECTION_SCHEDULE: Item<CouncilElectionSchedule> =
+    Item::new("council_election_schedule");
+
+/// Time at which the next council election is due, opening its nominations.
+const NEXT_COUNCIL_ELECTION: Item<Timestamp> = Item::new("next_council_election");
+
+/// Candidate nominated by each member, keyed by the election's due time (in nanos)
+/// and the nominating member.
+const COUNCIL_NOMINATIONS: Map<(u64, Addr), CouncilNomination> = Map::new("council_nominations");
+
+/// Number of times each candidate was dropped from an election, keyed by the election's due time
+/// (in nanos) and the candidate. Nominations made before a candidate was dropped no longer count.
+const COUNCIL_CANDIDATE_REMOVALS: Map<(u64, Addr), u32> = Map::new("council_candidate_removals");
+
+/// Combined weight of the members nominating each candidate, keyed by the election's due time
+/// (in nanos) and the candidate.
+const COUNCIL_CANDIDATE_SUPPORT: Map<(u64, Addr), Uint128> = Map::new("council_candidate_support");
+
+/// Number of candidates nominated in each election, keyed by the election's due time (in nanos).
+const COUNCIL_CANDIDATE_COUNT: Map<u64, u32> = Map::new("council_candidate_count");
+
+#[cw_serde]
+struct CouncilNomination {
+    candidate: Addr,
+    /// Number of times the candidate had been dropped from the election when nominated
+    candidate_removals: u32,
+}
+
+/// Sets the council election schedule. The first election is due an interval from now.
+pub fn schedule_council_elections(
+    storage: &mut dyn Storage,
+    now: Timestamp,
+    msg: ScheduleCouncilElectionsMsg,
+) -> GovernanceControllerResult<Vec<SubMsg>> {
+    match msg.schedule {
+        Some(schedule) => {
+            NEXT_COUNCIL_ELECTION.save(storage, &now.plus_seconds(schedule.interval))?;
+            COUNCIL_ELECTION_SCHEDULE.save(storage, &schedule)?;
+        }
+        None => {
+            NEXT_COUNCIL_ELECTION.remove(storage);
+            COUNCIL_ELECTION_SCHEDULE.remove(storage);
+        }
+    }
+
+    Ok(vec![])
+}
+
+/// Records the nominator's nomination of the candidate in the due council election, moving
+/// the nominator's weight from the candidate they nominated before, if any.
+/// Both the nominator and the candidate are expected to be members of the DAO.
+pub fn nominate_council_candidate(
+    storage: &mut dyn Storage,
+    now: Timestamp,
+    nominator: Addr,
+    nominator_weight: Uint128,
+    candidate: Addr,
+) -> GovernanceControllerResult<()> {
+    let schedule = COUNCIL_ELECTION_SCHEDULE
+        .may_load(storage)?
+        .ok_or(NoCouncilElectionSchedule)?;
+
+    let next_election = NEXT_COUNCIL_ELECTION.load(storage)?;
+
+    if now < next_election {
+        return Err(CouncilElectionNotDue { next_election });
+    }
+
+    let nominations_close = next_election.plus_seconds(schedule.nomination_period);
+    if now >= nominations_close {
+        return Err(CouncilNominationsClosed {
+            closed_at: nominations_close,
+        });
+    }
+
+    let election = next_election.nanos();
+
+    if let Some(previous_candidate) = load_nominated_candidate(storage, election, &nominator)? {
+        if previous_candidate == candidate {
+            return Ok(());
+        }
+
+        change_candidate_support(
+            storage,
+            election,
+            previous_candidate,
+            nominator_weight,
+            Uint128::zero(),
+        )?;
+    }
+
+    let support =
+        match COUNCIL_CANDIDATE_SUPPORT.may_load(storage, (election, candidate.clone()))? {
+            Some(support) => support,
+            None => {
+                let candidate_count = COUNCIL_CANDIDATE_COUNT
+                    .may_load(storage, election)?
+                    .unwrap_or_default();
+
+                if candidate_count >= MAXIMUM_COUNCIL_CANDIDATES {
+                    return Err(TooManyCouncilCandidates {
+                        max: MAXIMUM_COUNCIL_CANDIDATES,
+                    });
+                }
+
+                COUNCIL_CANDIDATE_COUNT.save(storage, election, &(candidate_count + 1))?;
+
+                Uint128::zero()
+            }
+        };
+
+    COUNCIL_CANDIDATE_SUPPORT.save(
+        storage,
+        (election, candidate.clone()),
+        &support.checked_add(nominator_weight)?,
+    )?;
+
+    let candidate_removals = COUNCIL_CANDIDATE_REMOVALS
+        .may_load(storage, (election, candidate.clone()))?
+        .unwrap_or_default();
+    COUNCIL_NOMINATIONS.save(
+        storage,
+        (election, nominator),
+        &CouncilNomination {
+            candidate,
+            candidate_removals,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Keeps the support of the next election's candidates in line with their nominators' weights,
+/// and drops candidates who are no longer members of the DAO or are left without support.
+pub fn update_council_nominations(
+    deps: DepsMut,
+    weight_changes: &[UserWeightChange],
+) -> GovernanceControllerResult<()> {
+    let Some(next_election) = NEXT_COUNCIL_ELECTION.may_load(deps.storage)? else {
+        return Ok(());
+    };
+    let election = next_election.nanos();
+
+    for weight_change in weight_changes {
+        let user = deps.api.addr_validate(&weight_change.user)?;
+
+        if let Some(candidate) = load_nominated_candidate(deps.storage, election, &user)? {
+            change_candidate_support(
+                deps.storage,
+                election,
+                candidate,
+                weight_change.old_weight,
+                weight_change.new_weight,
+            )?;
+        }
+
+        if weight_change.new_weight.is_zero()
+            && COUNCIL_CANDIDATE_SUPPORT.has(deps.storage, (election, user.clone()))
+        {
+            remove_candidate(deps.storage, election, user)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads the candidate the nominator nominated in the election, unless the candidate was dropped
+/// from the election since.
+fn load_nominated_candidate(
+    storage: &dyn Storage,
+    election: u64,
+    nominator: &Addr,
+) -> GovernanceControllerResult<Option<Addr>> {
+    let Some(nomination) = COUNCIL_NOMINATIONS.may_load(storage, (election, nominator.clone()))?
+    else {
+        return Ok(None);
+    };
+
+    let candidate_removals = COUNCIL_CANDIDATE_REMOVALS
+        .may_load(storage, (election, nomination.candidate.clone()))?
+        .unwrap_or_default();
+
+    if nomination.candidate_removals == candidate_removals {
+        Ok(Some(nomination.candidate))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Replaces a nominator's old weight in the candidate's support with their new weight,
+/// dropping the candidate if they are left without support.
+fn change_candidate_support(
+    storage: &mut dyn Storage,
+    election: u64,
+    candidate: Addr,
+    old_weight: Uint128,
+    new_weight: Uint128,
+) -> GovernanceControllerResult<()> {
+    let support = COUNCIL_CANDIDATE_SUPPORT
+        .load(storage, (election, candidate.clone()))?
+        .checked_sub(old_weight)?
+        .checked_add(new_weight)?;
+
+    if support.is_zero() {
+        remove_candidate(storage, election, candidate)?;
+    } else {
+        COUNCIL_CANDIDATE_SUPPORT.save(storage, (election, candidate), &support)?;
+    }
+
+    Ok(())
+}
+
+/// Drops the candidate from the election, along with all of their nominations.
+fn remove_candidate(
+    storage: &mut dyn Storage,
+    election: u64,
+    candidate: Addr,
+) -> GovernanceControllerResult<()> {
+    COUNCIL_CANDIDATE_SUPPORT.remove(storage, (election, candidate.clone()));
+
+    // nominations are not indexed by candidate, so they are invalidated instead of removed
+    COUNCIL_CANDIDATE_REMOVALS.update(storage, (election, candidate), |removals| {
+        StdResult::Ok(removals.unwrap_or_default() + 1)
+    })?;
+
+    let candidate_count = COUNCIL_CANDIDATE_COUNT
+        .may_load(storage, election)?
+        .unwrap_or_default();
+    COUNCIL_CANDIDATE_COUNT.save(storage, election, &candidate_count.saturating_sub(1))?;
+
+    Ok(())
+}
+
+/// Checks that the due council election's nominations are closed, and schedules the next
+/// election an interval from now.
+/// Returns the elected candidates, i.e. the best supported ones filling the schedule's seats,
+/// or None if fewer candidates than there are seats were nominated.
+pub fn start_council_election(
+    storage: &mut dyn Storage,
+    now: Timestamp,
+) -> GovernanceControllerResult<(Option<Vec<Addr>>, Timestamp)> {
+    let schedule = COUNCIL_ELECTION_SCHEDULE
+        .may_load(storage)?
+        .ok_or(NoCouncilElectionSchedule)?;
+
+    let next_election = NEXT_COUNCIL_ELECTION.load(storage)?;
+
+    if now < next_election {
+        return Err(CouncilElectionNotDue { next_election });
+    }
+
+    let nominations_close = next_election.plus_seconds(schedule.nomination_period);
+    if now < nominations_close {
+        return Err(CouncilNominationsOpen {
+            closes_at: nominations_close,
+        });
+    }
+
+    let candidates = load_candidates(storage, next_election)?;
+
+    let elected = if candidates.len() < schedule.seats as usize {
+        None
+    } else {
+        Some(
+            candidates
+                .into_iter()
+                .take(schedule.seats as usize)
+                .map(|it| it.candidate)
+                .collect(),
+        )
+    };
+
+    let next_election = now.plus_seconds(schedule.interval);
+    NEXT_COUNCIL_ELECTION.save(storage, &next_election)?;
+
+    Ok((elected, next_election))
+}
+
+/// Loads the candidates of the election due at the given time, best supported first.
+/// Candidates with equal support are ordered by address.
+fn load_candidates(
+    storage: &dyn Storage,
+    election: Timestamp,
+) -> GovernanceControllerResult<Vec<CouncilCandidate>> {
+    let mut candidates = COUNCIL_CANDIDATE_SUPPORT
+        .prefix(election.nanos())
+        .range(storage, None, None, Order::Ascending)
+        .take(MAXIMUM_COUNCIL_CANDIDATES as usize)
+        .map(|res| res.map(|(candidate, support)| CouncilCandidate { candidate, support }))
+        .collect::<StdResult<Vec<CouncilCandidate>>>()?;
+
+    candidates.sort_by_key(|it| Reverse(it.support));
+
+    Ok(candidates)
+}
+
+pub fn query_council_election_schedule(
+    qctx: QueryContext,
+) -> GovernanceControllerResult<CouncilElectionScheduleResponse> {
+    let schedule = COUNCIL_ELECTION_SCHEDULE.may_load(qctx.deps.storage)?;
+    let next_election = NEXT_COUNCIL_ELECTION.may_load(qctx.deps.storage)?;
+
+    let nominations_close = schedule
+        .as_ref()
+        .zip(next_election)
+        .map(|(schedule, next_election)| next_election.plus_seconds(schedule.nomination_period));
+
+    let candidates = match next_election {
+        Some(next_election) => load_candidates(qctx.deps.storage, next_election)?,
+        None => vec![],
+    };
+
+    Ok(CouncilElectionScheduleResponse {
+        schedule,
+        next_election,
+        nominations_close,
+        candidates,
+    })
+}
@@ -0,0 +1,222 @@
+use crate::contract::to_proposal_action_type;
+use crate::proposals::{PROPOSAL_EXECUTION_OUTFLOWS, PROPOSAL_INFOS};
+use cosmwasm_std::{Deps, DepsMut, Uint128};
+use cw_asset::{Asset, AssetInfo, AssetUnchecked};
+use enterprise_governance_controller_api::api::{
+    EmissionSource, GovConfig, ProposalAction, ProposalId,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    ExecutionBudgetAboveMaximum, ExecutionBudgetExceeded, ExecutionBudgetRequired, InvalidArgument,
+    NoSuchProposal, ProposalActionNotBudgetable,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+
+/// What the given action moves out of the treasury, as far as execution budgets are concerned.
+enum ActionOutflow {
+    /// The action moves (or allows others to move) at most these assets out of the treasury
+    Assets(Vec<Asset>),
+    /// The action's effect on the treasury cannot be determined from its contents, e.g. because
+    /// it executes arbitrary messages, or cannot be expressed in assets, as with NFT transfers.
+    /// Such actions cannot be limited by an execution budget.
+    Unbudgetable,
+}
+
+/// Assets that the given action moves out of the treasury.
+fn proposal_action_outflow(
+    deps: Deps,
+    proposal_action: &ProposalAction,
+) -> GovernanceControllerResult<ActionOutflow> {
+    let assets: Vec<AssetUnchecked> = match proposal_action {
+        ProposalAction::RequestFundingFromDao(msg) => msg.assets.clone(),
+        ProposalAction::DistributeFunds(msg) => msg.funds.clone(),
+        ProposalAction::DistributeFromTreasury(msg) => msg.assets.clone(),
+        ProposalAction::DistributeToVoters(msg) => vec![msg.asset.clone()],
+        ProposalAction::BurnTokens(msg) => vec![AssetUnchecked::new(msg.asset.clone(), msg.amount)],
+        ProposalAction::GrantSpendingAllowance(msg) => {
+            vec![AssetUnchecked::new(msg.asset.clone(), msg.amount)]
+        }
+        ProposalAction::SetDistributorEmissionSchedule(msg) => match &msg.schedule {
+            Some(schedule) if schedule.source == EmissionSource::Treasury => {
+                let total_emission = schedule
+                    .amount_per_period
+                    .checked_mul(Uint128::from(schedule.periods))?;
+                vec![AssetUnchecked::cw20(&msg.asset, total_emission)]
+            }
+            Some(_) | None => vec![],
+        },
+        ProposalAction::ExecuteMsgs(_)
+        | ProposalAction::ExecuteTreasuryMsgs(_)
+        | ProposalAction::ExecuteEnterpriseMsgs(_)
+        | ProposalAction::ExecuteInterchainAccountMsgs(_)
+        | ProposalAction::UpgradeDao(_)
+        | ProposalAction::TransferNft(_) => return Ok(ActionOutflow::Unbudgetable),
+        ProposalAction::UpdateMetadata(_)
+        | ProposalAction::UpdateGovConfig(_)
+        | ProposalAction::UpdateCouncil(_)
+        | ProposalAction::UpdateAssetWhitelist(_)
+        | ProposalAction::UpdateNftWhitelist(_)
+        | ProposalAction::ModifyMultisigMembership(_)
+        | ProposalAction::UpdateMinimumWeightForRewards(_)
+        | ProposalAction::DeployCrossChainTreasury(_)
+        | ProposalAction::UpdateVetoCouncil(_)
+        | ProposalAction::MintTokenfactoryDenom(_)
+        | ProposalAction::SetDenomAdmin(_)
+        | ProposalAction::UpdateEnterpriseFactory(_)
+        | ProposalAction::SetSelfAdmin(_)
+        | ProposalAction::UpdateCouncilPermissions(_)
+        | ProposalAction::RevokeSpendingAllowance(_)
+        | ProposalAction::EditCouncilMembers(_)
+        | ProposalAction::ManageAssetBundles(_)
+        | ProposalAction::UpdateAssetWhitelistWithBundle(_)
+        | ProposalAction::ScheduleCouncilElections(_)
+        | ProposalAction::UpdateDistributionFee(_)
+        | ProposalAction::SetDistributorClaimsPaused(_)
+        | ProposalAction::RegisterInterchainAccount(_) => vec![],
+    };
+
+    let outflow = assets
+        .iter()
+        .map(|asset| asset.check(deps.api, None))
+        .collect::<Result<Vec<Asset>, _>>()?;
+
+    Ok(ActionOutflow::Assets(outflow))
+}
+
+/// Assets that the given action moves out of a budgeted treasury, failing for actions that
+/// cannot be limited by an execution budget.
+fn budgeted_action_outflow(
+    deps: Deps,
+    proposal_action: &ProposalAction,
+) -> GovernanceControllerResult<Vec<Asset>> {
+    match proposal_action_outflow(deps, proposal_action)? {
+        ActionOutflow::Assets(assets) => Ok(assets),
+        ActionOutflow::Unbudgetable => Err(ProposalActionNotBudgetable {
+            action: to_proposal_action_type(proposal_action),
+        }),
+    }
+}
+
+fn budgeted_amount(budget: &[Asset], asset_info: &AssetInfo) -> Uint128 {
+    budget
+        .iter()
+        .find(|asset| &asset.info == asset_info)
+        .map(|asset| asset.amount)
+        .unwrap_or_default()
+}
+
+/// Validates a proposal's execution budget against the DAO's maximum, and estimates whether the
+/// proposal's actions would fit into it, based on the amounts they state.
+/// Actions that cannot be limited by a budget are rejected if the proposal has a budget, or the
+/// DAO sets a maximum budget.
+/// Returns the validated budget.
+pub fn validate_execution_budget(
+    deps: Deps,
+    gov_config: &GovConfig,
+    execution_budget: Option<&Vec<AssetUnchecked>>,
+    proposal_actions: &[ProposalAction],
+) -> GovernanceControllerResult<Option<Vec<Asset>>> {
+    let is_budgeted = execution_budget.is_some() || gov_config.max_execution_budget.is_some();
+
+    let mut estimated_outflow: Vec<Asset> = vec![];
+
+    for proposal_action in proposal_actions {
+        let outflow = if is_budgeted {
+            budgeted_action_outflow(deps, proposal_action)?
+        } else {
+            // unbudgeted proposals are not limited, so their outflow is only used to
+            // determine whether a budget would be required
+            match proposal_action_outflow(deps, proposal_action)? {
+                ActionOutflow::Assets(assets) => assets,
+                ActionOutflow::Unbudgetable => vec![],
+            }
+        };
+
+        for asset in outflow {
+            match estimated_outflow
+                .iter_mut()
+                .find(|outflow| outflow.info == asset.info)
+            {
+                Some(outflow) => outflow.amount = outflow.amount.checked_add(asset.amount)?,
+                None => estimated_outflow.push(asset),
+            }
+        }
+    }
+
+    let execution_budget = match execution_budget {
+        Some(execution_budget) => execution_budget
+            .iter()
+            .map(|asset| asset.check(deps.api, None))
+            .collect::<Result<Vec<Asset>, _>>()?,
+        None => {
+            if gov_config.max_execution_budget.is_some() && !estimated_outflow.is_empty() {
+                return Err(ExecutionBudgetRequired);
+            }
+            return Ok(None);
+        }
+    };
+
+    for (i, asset) in execution_budget.iter().enumerate() {
+        if execution_budget[..i]
+            .iter()
+            .any(|other| other.info == asset.info)
+        {
+            return Err(InvalidArgument {
+                msg: format!("Duplicate asset in execution budget: {}", asset.info),
+            });
+        }
+
+        if let Some(max_execution_budget) = &gov_config.max_execution_budget {
+            if asset.amount > budgeted_amount(max_execution_budget, &asset.info) {
+                return Err(ExecutionBudgetAboveMaximum {
+                    asset: asset.info.to_string(),
+                });
+            }
+        }
+    }
+
+    for outflow in estimated_outflow {
+        if outflow.amount > budgeted_amount(&execution_budget, &outflow.info) {
+            return Err(ExecutionBudgetExceeded {
+                asset: outflow.info.to_string(),
+            });
+        }
+    }
+
+    Ok(Some(execution_budget))
+}
+
+/// Adds the assets moved by the given action to the proposal's cumulative outflow, failing if
+/// that would exceed the proposal's execution budget. Does nothing for unbudgeted proposals.
+pub fn record_execution_outflow(
+    deps: DepsMut,
+    proposal_id: ProposalId,
+    proposal_action: &ProposalAction,
+) -> GovernanceControllerResult<()> {
+    let execution_budget = PROPOSAL_INFOS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(NoSuchProposal)?
+        .execution_budget;
+
+    let Some(execution_budget) = execution_budget else {
+        return Ok(());
+    };
+
+    for asset in budgeted_action_outflow(deps.as_ref(), proposal_action)? {
+        let key = (proposal_id, asset.info.to_string());
+
+        let outflow = PROPOSAL_EXECUTION_OUTFLOWS
+            .may_load(deps.storage, key.clone())?
+            .unwrap_or_default()
+            .checked_add(asset.amount)?;
+
+        if outflow > budgeted_amount(&execution_budget, &asset.info) {
+            return Err(ExecutionBudgetExceeded {
+                asset: asset.info.to_string(),
+            });
+        }
+
+        PROPOSAL_EXECUTION_OUTFLOWS.save(deps.storage, key, &outflow)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::json::JsonValue;
+use cosmwasm_std::{
+    from_json, to_json_vec, Binary, ContractResult, Deps, Empty, QueryRequest, StdResult,
+    SystemResult, WasmQuery,
+};
+use enterprise_governance_controller_api::api::ExecutionCondition;
+
+/// Queries the condition's contract and checks whether its response matches the expected result.
+/// Both are compared as parsed JSON values, so formatting and the order of object keys
+/// do not matter. A failing query is treated as the condition not being met.
+pub fn is_execution_condition_met(deps: Deps, condition: &ExecutionCondition) -> StdResult<bool> {
+    let request: QueryRequest<Empty> = QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: condition.contract.clone(),
+        msg: Binary::from(condition.query_msg.as_bytes()),
+    });
+
+    // we need the raw response to compare against, so we can't use the regular JSON query
+    let is_met = match deps.querier.raw_query(&to_json_vec(&request)?) {
+        SystemResult::Ok(ContractResult::Ok(response)) => {
+            is_same_json(response.as_slice(), condition.expected_result.as_bytes())
+        }
+        _ => false,
+    };
+
+    Ok(is_met)
+}
+
+/// Checks whether both are well-formed JSON holding the same value.
+fn is_same_json(json: &[u8], other_json: &[u8]) -> bool {
+    match (
+        from_json::<JsonValue>(json),
+        from_json::<JsonValue>(other_json),
+    ) {
+        (Ok(value), Ok(other_value)) => value == other_value,
+        _ => false,
+    }
+}
+
+/// Checks whether the given string is a single, well-formed JSON value.
+pub fn is_valid_json(json: &str) -> bool {
+    from_json::<JsonValue>(json).is_ok()
+}
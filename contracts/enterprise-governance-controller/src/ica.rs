@@ -0,0 +1,166 @@
+use cosmwasm_std::CosmosMsg::Stargate;
+use cosmwasm_std::{
+    to_json_vec, ContractResult, CosmosMsg, Deps, Empty, QueryRequest, StdError, StdResult,
+    SystemResult,
+};
+use enterprise_governance_controller_api::api::InterchainAccountMsg;
+use prost::Message;
+
+pub const DEFAULT_ICA_TIMEOUT_NANOS: u64 = 15 * 60 * 1_000_000_000;
+
+/// Packet type for executing a transaction on the host chain.
+const TYPE_EXECUTE_TX: i32 = 1;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Any {
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+
+    #[prost(bytes, tag = "2")]
+    pub value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<Any>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct InterchainAccountPacketData {
+    #[prost(int32, tag = "1")]
+    pub r#type: i32,
+
+    #[prost(bytes, tag = "2")]
+    pub data: Vec<u8>,
+
+    #[prost(string, tag = "3")]
+    pub memo: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSendTx {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+
+    #[prost(message, tag = "3")]
+    pub packet_data: Option<InterchainAccountPacketData>,
+
+    #[prost(uint64, tag = "4")]
+    pub relative_timeout: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgRegisterInterchainAccount {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+
+    #[prost(string, tag = "3")]
+    pub version: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryInterchainAccountRequest {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryInterchainAccountResponse {
+    #[prost(string, tag = "1")]
+    pub address: String,
+}
+
+/// Queries the interchain-accounts controller module for the address of the interchain account
+/// registered by the owner over the given connection.
+pub fn query_interchain_account(
+    deps: Deps,
+    owner: String,
+    connection_id: String,
+) -> StdResult<String> {
+    let request: QueryRequest<Empty> = QueryRequest::Stargate {
+        path: "/ibc.applications.interchain_accounts.controller.v1.Query/InterchainAccount"
+            .to_string(),
+        data: QueryInterchainAccountRequest {
+            owner,
+            connection_id,
+        }
+        .encode_to_vec()
+        .into(),
+    };
+
+    // stargate queries respond with protobuf-encoded data, so we can't use the regular JSON query
+    let response = match deps.querier.raw_query(&to_json_vec(&request)?) {
+        SystemResult::Err(e) => return Err(StdError::generic_err(e.to_string())),
+        SystemResult::Ok(ContractResult::Err(e)) => return Err(StdError::generic_err(e)),
+        SystemResult::Ok(ContractResult::Ok(response)) => response,
+    };
+
+    let address = QueryInterchainAccountResponse::decode(response.as_slice())
+        .map_err(|e| StdError::generic_err(e.to_string()))?
+        .address;
+
+    Ok(address)
+}
+
+pub fn register_interchain_account_msg(
+    owner: String,
+    connection_id: String,
+    version: String,
+) -> CosmosMsg {
+    Stargate {
+        type_url:
+            "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount"
+                .to_string(),
+        value: MsgRegisterInterchainAccount {
+            owner,
+            connection_id,
+            version,
+        }
+        .encode_to_vec()
+        .into(),
+    }
+}
+
+pub fn send_tx_msg(
+    owner: String,
+    connection_id: String,
+    msgs: Vec<InterchainAccountMsg>,
+    memo: String,
+    relative_timeout_nanos: u64,
+) -> CosmosMsg {
+    let cosmos_tx = CosmosTx {
+        messages: msgs
+            .into_iter()
+            .map(|msg| Any {
+                type_url: msg.type_url,
+                value: msg.value.to_vec(),
+            })
+            .collect(),
+    };
+
+    Stargate {
+        type_url: "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx".to_string(),
+        value: MsgSendTx {
+            owner,
+            connection_id,
+            packet_data: Some(InterchainAccountPacketData {
+                r#type: TYPE_EXECUTE_TX,
+                data: cosmos_tx.encode_to_vec(),
+                memo,
+            }),
+            relative_timeout: relative_timeout_nanos,
+        }
+        .encode_to_vec()
+        .into(),
+    }
+}
@@ -1,9 +1,18 @@
 extern crate core;
 
+mod asset_bundles;
 pub mod contract;
+mod council_elections;
+mod execution_budget;
+mod execution_condition;
+mod ica;
+mod json;
 pub mod proposals;
 pub mod state;
+mod token_factory;
+mod treasury_distributions;
 pub mod validate;
+mod voter_rewards;
 
 #[cfg(test)]
 mod tests;
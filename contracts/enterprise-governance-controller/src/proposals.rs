@@ -1,11 +1,238 @@
-use cosmwasm_std::{BlockInfo, StdResult, Storage};
-use cw_storage_plus::Map;
-use enterprise_governance_controller_api::api::{ProposalAction, ProposalId, ProposalInfo};
-use enterprise_governance_controller_api::error::GovernanceControllerError::NoSuchProposal;
+use crate::asset_bundles::expand_asset_bundle;
+use crate::json::JsonValue;
+use cosmwasm_std::{
+    from_json, to_json_string, to_json_vec, Addr, Api, Binary, BlockInfo, Order, StdResult,
+    Storage, Timestamp, Uint128,
+};
+use cw_asset::{AssetInfo, AssetInfoUnchecked};
+use cw_storage_plus::{Deque, Item, Map};
+use enterprise_governance_controller_api::api::{
+    ActionTypeCooldown, ActionTypeThreshold, CouncilVote, GovConfig, ProposalAction,
+    ProposalActionOutcome, ProposalActionType, ProposalDepositStatus, ProposalId, ProposalInfo,
+    ProposalType,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    ActionTypeCooldownActive, NoSuchProposal,
+};
 use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use poll_engine_api::api::PollRejectionReason::{IsVetoOutcome, QuorumNotReached};
+use poll_engine_api::api::{Poll, PollStatus};
+use sha2::{Digest, Sha256};
 
 pub const PROPOSAL_INFOS: Map<ProposalId, ProposalInfo> = Map::new("proposal_infos");
 
+/// Veto council members' vetoes cast on proposals, keyed by the proposal, the number of
+/// veto council updates at the time of the veto, and the member.
+pub const VETO_VOTES: Map<(ProposalId, u64, Addr), Timestamp> = Map::new("veto_votes");
+
+/// Votes cast by council members on council proposals.
+pub const COUNCIL_VOTES: Map<(ProposalId, Addr), CouncilVote> = Map::new("council_votes");
+
+/// Proposals vetoed by the veto council, with the time at which they were vetoed.
+pub const VETOED_PROPOSALS: Map<ProposalId, Timestamp> = Map::new("vetoed_proposals");
+
+/// Passed proposals whose execution condition did not hold when they were executed,
+/// with the time at which that happened.
+pub const CONDITION_NOT_MET_PROPOSALS: Map<ProposalId, Timestamp> =
+    Map::new("condition_not_met_proposals");
+
+/// Passed proposals that could not be executed when they were resolved, with the time at which
+/// that happened.
+pub const EXECUTION_FAILED_PROPOSALS: Map<ProposalId, Timestamp> =
+    Map::new("execution_failed_proposals");
+
+/// Amounts of assets moved out of the treasury by executed actions of budgeted proposals,
+/// keyed by the asset's string representation.
+pub const PROPOSAL_EXECUTION_OUTFLOWS: Map<(ProposalId, String), Uint128> =
+    Map::new("proposal_execution_outflows");
+
+/// General proposals that were not yet executed, keyed by their proposer.
+/// Values are the times at which the proposals' voting periods end.
+pub const OPEN_PROPOSALS: Map<(Addr, ProposalId), Timestamp> = Map::new("open_proposals");
+
+/// Proposals whose deposit is waiting to be claimed by its depositor, when the DAO refunds
+/// deposits manually.
+pub const REFUNDABLE_DEPOSITS: Map<ProposalId, ()> = Map::new("refundable_deposits");
+
+/// Proposals created with a deposit, keyed by their depositor.
+pub const DEPOSITOR_DEPOSITS: Map<(Addr, ProposalId), ()> = Map::new("depositor_deposits");
+
+/// Proposals of a batch whose polls are being created, in the order the polls are created in.
+pub const BATCH_PROPOSALS_BEING_CREATED: Deque<ProposalInfo> =
+    Deque::new("batch_proposals_being_created");
+
+/// IDs of the proposals of the batch being created whose polls were already created.
+pub const BATCH_CREATED_PROPOSAL_IDS: Item<Vec<ProposalId>> =
+    Item::new("batch_created_proposal_ids");
+
+/// End of the voting period of a proposal created at the given time without an explicit
+/// expiration. It is stored in the proposal's poll, so later changes to the vote duration only
+/// apply to proposals created after them.
+pub fn default_proposal_ends_at(gov_config: &GovConfig, created_at: Timestamp) -> Timestamp {
+    created_at.plus_seconds(gov_config.vote_duration)
+}
+
+/// Applies the quorum and threshold required by the proposal's most sensitive action type,
+/// i.e. the one with the highest threshold, with ties going to the higher quorum.
+/// Action types without an override require the DAO's quorum and threshold.
+/// The veto threshold is left as it was, even when it fell back to the DAO's threshold.
+pub fn apply_action_type_thresholds(
+    gov_config: GovConfig,
+    action_types: &[ProposalActionType],
+) -> GovConfig {
+    let Some(action_type_thresholds) = &gov_config.action_type_thresholds else {
+        return gov_config;
+    };
+
+    let (quorum, threshold) = action_types
+        .iter()
+        .map(|action_type| {
+            action_type_thresholds
+                .iter()
+                .find(|it| &it.action_type == action_type)
+                .map(
+                    |ActionTypeThreshold {
+                         quorum, threshold, ..
+                     }| (*quorum, *threshold),
+                )
+                .unwrap_or((gov_config.quorum, gov_config.threshold))
+        })
+        .max_by(|(quorum1, threshold1), (quorum2, threshold2)| {
+            (threshold1, quorum1).cmp(&(threshold2, quorum2))
+        })
+        .unwrap_or((gov_config.quorum, gov_config.threshold));
+
+    GovConfig {
+        quorum,
+        threshold,
+        veto_threshold: Some(gov_config.veto_threshold.unwrap_or(gov_config.threshold)),
+        ..gov_config
+    }
+}
+
+/// Outcomes of individually executed actions of best-effort proposals.
+pub const PROPOSAL_ACTION_OUTCOMES: Map<(ProposalId, u32), ProposalActionOutcome> =
+    Map::new("proposal_action_outcomes");
+
+/// Total time by which proposals' voting periods were extended, in seconds.
+pub const PROPOSAL_VOTING_EXTENSIONS: Map<ProposalId, u64> = Map::new("proposal_voting_extensions");
+
+/// Times at which general proposals containing actions of a given type were last created,
+/// keyed by [action_type_key].
+pub const LAST_ACTION_TYPE_PROPOSALS: Map<String, Timestamp> =
+    Map::new("last_action_type_proposals");
+
+/// Index of proposals whose actions reference an asset, keyed by [asset_key].
+/// Only native and CW20 assets are indexed.
+pub const PROPOSALS_BY_ASSET: Map<(String, ProposalId), ()> = Map::new("proposals_by_asset");
+
+/// Key of an asset in [PROPOSALS_BY_ASSET], formatted as 'native:{denom}' or 'cw20:{address}'.
+/// Returns None for assets that are not indexed.
+pub fn asset_key(asset: &AssetInfo) -> Option<String> {
+    match asset {
+        AssetInfo::Native(denom) => Some(format!("native:{denom}")),
+        AssetInfo::Cw20(address) => Some(format!("cw20:{address}")),
+        _ => None,
+    }
+}
+
+/// Indexes the proposal under every native and CW20 asset that its actions reference.
+/// An asset referenced several times within the proposal is indexed only once.
+///
+/// Assets moved by arbitrary messages are not known to the controller, and NFTs are not
+/// assets in this sense, so actions executing messages or transferring NFTs are not indexed.
+pub fn index_proposal_assets(
+    store: &mut dyn Storage,
+    api: &dyn Api,
+    proposal_id: ProposalId,
+    proposal_actions: &[ProposalAction],
+) -> GovernanceControllerResult<()> {
+    let mut assets: Vec<AssetInfoUnchecked> = vec![];
+
+    for proposal_action in proposal_actions {
+        match proposal_action {
+            ProposalAction::UpdateAssetWhitelist(msg) => {
+                assets.extend(msg.add.iter().chain(msg.remove.iter()).cloned())
+            }
+            ProposalAction::UpdateAssetWhitelistWithBundle(msg) => {
+                let msg = expand_asset_bundle(store, msg)?;
+                assets.extend(msg.add.into_iter().chain(msg.remove))
+            }
+            ProposalAction::ManageAssetBundles(msg) => assets.extend(
+                msg.register
+                    .iter()
+                    .flat_map(|bundle| bundle.assets.iter().cloned()),
+            ),
+            ProposalAction::RequestFundingFromDao(msg) => {
+                assets.extend(msg.assets.iter().map(|asset| asset.info.clone()))
+            }
+            ProposalAction::DistributeFunds(msg) => {
+                assets.extend(msg.funds.iter().map(|asset| asset.info.clone()))
+            }
+            ProposalAction::DistributeFromTreasury(msg) => {
+                assets.extend(msg.assets.iter().map(|asset| asset.info.clone()))
+            }
+            ProposalAction::BurnTokens(msg) => assets.push(msg.asset.clone()),
+            ProposalAction::DistributeToVoters(msg) => assets.push(msg.asset.info.clone()),
+            ProposalAction::GrantSpendingAllowance(msg) => assets.push(msg.asset.clone()),
+            ProposalAction::RevokeSpendingAllowance(msg) => assets.push(msg.asset.clone()),
+            ProposalAction::SetDistributorEmissionSchedule(msg) => {
+                assets.push(AssetInfoUnchecked::cw20(&msg.asset))
+            }
+            ProposalAction::MintTokenfactoryDenom(msg) => {
+                assets.push(AssetInfoUnchecked::native(&msg.denom))
+            }
+            ProposalAction::SetDenomAdmin(msg) => {
+                assets.push(AssetInfoUnchecked::native(&msg.denom))
+            }
+            ProposalAction::ExecuteMsgs(_)
+            | ProposalAction::ExecuteTreasuryMsgs(_)
+            | ProposalAction::ExecuteEnterpriseMsgs(_)
+            | ProposalAction::ExecuteInterchainAccountMsgs(_)
+            | ProposalAction::TransferNft(_)
+            | ProposalAction::UpdateNftWhitelist(_)
+            | ProposalAction::UpdateMetadata(_)
+            | ProposalAction::UpdateGovConfig(_)
+            | ProposalAction::UpdateCouncil(_)
+            | ProposalAction::UpgradeDao(_)
+            | ProposalAction::ModifyMultisigMembership(_)
+            | ProposalAction::UpdateMinimumWeightForRewards(_)
+            | ProposalAction::DeployCrossChainTreasury(_)
+            | ProposalAction::UpdateVetoCouncil(_)
+            | ProposalAction::UpdateEnterpriseFactory(_)
+            | ProposalAction::SetSelfAdmin(_)
+            | ProposalAction::UpdateCouncilPermissions(_)
+            | ProposalAction::EditCouncilMembers(_)
+            | ProposalAction::ScheduleCouncilElections(_)
+            | ProposalAction::UpdateDistributionFee(_)
+            | ProposalAction::SetDistributorClaimsPaused(_)
+            | ProposalAction::RegisterInterchainAccount(_) => {}
+        }
+    }
+
+    for asset in assets {
+        if let Some(key) = asset_key(&asset.check(api, None)?) {
+            PROPOSALS_BY_ASSET.save(store, (key, proposal_id), &())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Counts proposer's proposals that were not yet executed and whose voting period has not ended.
+pub fn count_open_proposals(
+    store: &dyn Storage,
+    proposer: Addr,
+    now: Timestamp,
+) -> StdResult<usize> {
+    OPEN_PROPOSALS
+        .prefix(proposer)
+        .range(store, None, None, Order::Ascending)
+        .try_fold(0, |count, res| {
+            res.map(|(_, ends_at)| if ends_at > now { count + 1 } else { count })
+        })
+}
+
 pub fn set_proposal_executed(
     store: &mut dyn Storage,
     proposal_id: ProposalId,
@@ -26,6 +253,76 @@ pub fn set_proposal_executed(
     Ok(())
 }
 
+/// Hashes the proposal actions, using SHA-256 over their canonical JSON serialization as
+/// specified by the JSON Canonicalization Scheme (RFC 8785): compact, with object keys sorted
+/// and strings minimally escaped.
+///
+/// The actions only contain strings, booleans, nulls and integers (large amounts are encoded
+/// as strings), for which the encoding below matches the scheme.
+pub fn proposal_actions_hash(proposal_actions: &[ProposalAction]) -> StdResult<Binary> {
+    let value: JsonValue = from_json(to_json_vec(proposal_actions)?)?;
+
+    let mut canonical_json = String::new();
+    write_canonical_json(&value, &mut canonical_json);
+
+    let hash = Sha256::digest(canonical_json);
+
+    Ok(Binary::from(hash.as_slice()))
+}
+
+fn write_canonical_json(value: &JsonValue, out: &mut String) {
+    match value {
+        JsonValue::Null => out.push_str("null"),
+        JsonValue::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+        JsonValue::Integer(value) => out.push_str(&value.to_string()),
+        JsonValue::String(value) => write_canonical_json_string(value, out),
+        JsonValue::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(']');
+        }
+        JsonValue::Object(fields) => {
+            let mut fields: Vec<(&String, &JsonValue)> = fields.iter().collect();
+            fields.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+            out.push('{');
+            for (i, (key, field)) in fields.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_json_string(key, out);
+                out.push(':');
+                write_canonical_json(field, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Writes the string quoted, escaping only quotes, backslashes and control characters.
+fn write_canonical_json_string(value: &str, out: &mut String) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
 pub fn get_proposal_actions(
     store: &dyn Storage,
     proposal_id: ProposalId,
@@ -34,3 +331,86 @@ pub fn get_proposal_actions(
         .may_load(store, proposal_id)
         .map(|info_opt| info_opt.map(|info| info.proposal_actions))
 }
+
+/// Checks that none of the given action types is in its cooldown, i.e. that enough time
+/// has passed since a proposal containing an action of that type was last created.
+pub fn assert_action_type_cooldowns(
+    storage: &dyn Storage,
+    action_type_cooldowns: &[ActionTypeCooldown],
+    action_types: &[ProposalActionType],
+    now: Timestamp,
+) -> GovernanceControllerResult<()> {
+    for action_type_cooldown in action_type_cooldowns {
+        if !action_types.contains(&action_type_cooldown.action_type) {
+            continue;
+        }
+
+        let last_created = LAST_ACTION_TYPE_PROPOSALS
+            .may_load(storage, action_type_key(&action_type_cooldown.action_type)?)?;
+
+        if let Some(last_created) = last_created {
+            let next_allowed_at = last_created.plus_seconds(action_type_cooldown.cooldown);
+            if now < next_allowed_at {
+                return Err(ActionTypeCooldownActive {
+                    action_type: action_type_cooldown.action_type.clone(),
+                    next_allowed_at,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the given time as the last time a proposal containing the given action types
+/// was created.
+pub fn record_action_type_proposals(
+    storage: &mut dyn Storage,
+    action_types: &[ProposalActionType],
+    now: Timestamp,
+) -> StdResult<()> {
+    for action_type in action_types {
+        LAST_ACTION_TYPE_PROPOSALS.save(storage, action_type_key(action_type)?, &now)?;
+    }
+
+    Ok(())
+}
+
+/// Storage key of an action type - its name as serialized in messages, which is part of
+/// the API and, unlike its display name, does not change along with the Rust code.
+fn action_type_key(action_type: &ProposalActionType) -> StdResult<String> {
+    Ok(to_json_string(action_type)?.trim_matches('"').to_string())
+}
+
+/// Determines what happened to a proposal's deposit, given the proposal's poll, whether
+/// the veto council vetoed it, and whether the deposit is waiting to be claimed.
+pub fn proposal_deposit_status(
+    proposal_info: &ProposalInfo,
+    poll: &Poll,
+    vetoed: bool,
+    refundable: bool,
+    now: Timestamp,
+) -> ProposalDepositStatus {
+    if refundable {
+        return ProposalDepositStatus::Refundable;
+    }
+
+    // deposits of proposals vetoed by the veto council are refunded right away
+    if vetoed {
+        return ProposalDepositStatus::Refunded;
+    }
+
+    match (&proposal_info.executed_at, &poll.status) {
+        (None, PollStatus::InProgress { .. }) if now < poll.ends_at => {
+            ProposalDepositStatus::HeldInProgress
+        }
+        (None, _) => ProposalDepositStatus::Pending,
+        (Some(_), PollStatus::Rejected { reason })
+            if proposal_info.proposal_type == ProposalType::General
+                && (*reason == QuorumNotReached || *reason == IsVetoOutcome) =>
+        {
+            ProposalDepositStatus::Slashed
+        }
+        (Some(_), _) => ProposalDepositStatus::Refunded,
+    }
+}
@@ -1,14 +1,16 @@
 use crate::state::ProposalExecutabilityStatus::{Draw, NotExecutable, Passed, Rejected};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Timestamp, Uint128};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 use enterprise_governance_controller_api::api::ProposalInfo;
-use enterprise_governance_controller_api::api::{CouncilGovConfig, GovConfig, ProposalId};
+use enterprise_governance_controller_api::api::{
+    CouncilGovConfig, GovConfig, ProposalId, VetoCouncilConfig,
+};
 use enterprise_outposts_api::api::DeployCrossChainTreasuryMsg;
 use poll_engine_api::api::{PollRejectionReason, PollStatus};
 use PollRejectionReason::{
-    IsRejectingOutcome, IsVetoOutcome, OutcomeDraw, QuorumAndThresholdNotReached, QuorumNotReached,
-    ThresholdNotReached,
+    IsRejectingOutcome, IsVetoOutcome, MinimumYesVotesNotReached, OutcomeDraw,
+    QuorumAndThresholdNotReached, QuorumNotReached, ThresholdNotReached,
 };
 
 #[cw_serde]
@@ -48,9 +50,10 @@ impl From<PollStatus> for ProposalExecutabilityStatus {
             PollStatus::InProgress { .. } => NotExecutable,
             PollStatus::Passed { outcome, .. } => Passed { outcome },
             PollStatus::Rejected { reason } => match reason {
-                QuorumNotReached | ThresholdNotReached | QuorumAndThresholdNotReached => {
-                    NotExecutable
-                }
+                QuorumNotReached
+                | ThresholdNotReached
+                | QuorumAndThresholdNotReached
+                | MinimumYesVotesNotReached => NotExecutable,
                 IsRejectingOutcome => Rejected { veto: false },
                 IsVetoOutcome => Rejected { veto: true },
                 OutcomeDraw(outcome1, outcome2, votes_for_each) => Draw {
@@ -71,7 +74,30 @@ pub const GOV_CONFIG: Item<GovConfig> = Item::new("gov_config");
 
 pub const COUNCIL_GOV_CONFIG: Item<Option<CouncilGovConfig>> = Item::new("council_gov_config");
 
+pub const VETO_COUNCIL: Item<Option<VetoCouncilConfig>> = Item::new("veto_council");
+
+/// Number of times the veto council was updated. Vetoes are kept per veto council update,
+/// so that vetoes cast under a previous veto council are not counted.
+pub const VETO_COUNCIL_UPDATES: Item<u64> = Item::new("veto_council_updates");
+
 pub const CREATION_DATE: Item<Timestamp> = Item::new("creation_date");
 
+/// Unlocking extensions of passed proposals' voters that still have to be applied to some of
+/// the voters, through ExtendVoterUnlocking.
+pub const PENDING_VOTER_UNLOCKING_EXTENSIONS: Map<ProposalId, PendingVoterUnlockingExtension> =
+    Map::new("pending_voter_unlocking_extensions");
+
+#[cw_serde]
+pub struct PendingVoterUnlockingExtension {
+    /// Moment until which the voters' unlocking is extended
+    pub until: Timestamp,
+    /// Last voter whose unlocking was already extended, if any
+    pub start_after: Option<String>,
+}
+
+/// Last moment at which each user went from having no weight to having some weight.
+/// Users that were members before this started being tracked have no entry.
+pub const MEMBER_JOINED_AT: Map<Addr, Timestamp> = Map::new("member_joined_at");
+
 pub const INITIAL_CROSS_CHAIN_TREASURIES: Item<Vec<DeployCrossChainTreasuryMsg>> =
     Item::new("initial_cross_chain_treasuries");
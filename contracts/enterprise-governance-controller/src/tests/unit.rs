@@ -1,4 +1,131 @@
+use crate::contract::{
+    assert_vote_change_allowed, execute, instantiate, query, reply, AUTO_EXECUTE_PROPOSAL_REPLY_ID,
+    CAST_VOTE_REPLY_ID, CREATE_POLL_REPLY_ID, DISTRIBUTE_FROM_TREASURY_REPLY_ID, END_POLL_REPLY_ID,
+};
+use crate::council_elections::{
+    nominate_council_candidate, query_council_election_schedule, schedule_council_elections,
+    update_council_nominations,
+};
+use crate::ica::{
+    Any, CosmosTx, InterchainAccountPacketData, MsgRegisterInterchainAccount, MsgSendTx,
+    QueryInterchainAccountRequest, QueryInterchainAccountResponse, DEFAULT_ICA_TIMEOUT_NANOS,
+};
+use crate::proposals::{
+    apply_action_type_thresholds, proposal_deposit_status, PROPOSAL_INFOS, REFUNDABLE_DEPOSITS,
+};
+use crate::state::{State, ENTERPRISE_CONTRACT, GOV_CONFIG, MEMBER_JOINED_AT, STATE};
+use crate::validate::{
+    normalize_asset_whitelist, validate_dao_council, validate_execution_condition,
+    validate_proposal_action, validate_proposal_description, validate_proposal_metadata,
+};
+use crate::voter_rewards::{distribute_to_voters_msgs, validate_distribute_to_voters};
+use common::commons::ModifyValue::{Change, NoChange};
+use common::cw::QueryContext;
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, to_json_string, Addr, Attribute, BankMsg, Binary,
+    ContractResult, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, Event, OwnedDeps, Querier,
+    QuerierResult, QueryRequest, Reply, ReplyOn, Response, SubMsgResponse, SubMsgResult,
+    SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
+};
+use cw20::{AllowanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw721::{Cw721QueryMsg, OwnerOfResponse};
+use cw_asset::{Asset, AssetInfo, AssetInfoUnchecked, AssetUnchecked};
+use cw_utils::{Duration, Expiration};
+use enterprise_factory_api::api::{
+    Config as FactoryConfig, ConfigResponse as FactoryConfigResponse,
+};
+use enterprise_factory_api::msg::QueryMsg as FactoryQueryMsg;
+use enterprise_governance_api::msg::ExecuteMsg as GovernanceExecuteMsg;
+use enterprise_governance_api::msg::QueryMsg as GovernanceQueryMsg;
+use enterprise_governance_controller_api::api::DescriptionFormat::{Markdown, Plaintext};
+use enterprise_governance_controller_api::api::{
+    AcceptedDepositAsset, AcceptedDepositAssetUnchecked, ActionTypeCooldown, ActionTypeThreshold,
+    AssetBundle, AssetBundleChange, BurnTokensMsg, CanExecuteActionParams,
+    CanExecuteActionResponse, CastVoteMsg, ClaimDepositMsg, CouncilActionRequirement,
+    CouncilCandidate, CouncilElectionSchedule, CouncilElectionScheduleResponse, CouncilGovConfig,
+    CreateProposalMsg, CreateProposalsMsg, CreateProposalsResponse, DaoCouncilSpec,
+    DecodedProposalActionsResponse, DistributeFromTreasuryMsg, DistributeToVotersMsg,
+    DistributorEmissionSchedule, EditCouncilMembersMsg, EmissionSource,
+    ExecuteInterchainAccountMsgsMsg, ExecuteMsgsMsg, ExecuteProposalMsg, ExecutionAtomicity,
+    ExecutionCondition, ExpiringProposalsParams, ExpiringProposalsResponse,
+    ExtendVoterUnlockingMsg, GovConfig, GovConfigResponse, GrantSpendingAllowanceMsg,
+    InterchainAccountMsg, IsMemberParams, IsMemberResponse, ManageAssetBundlesMsg,
+    ModifyMultisigMembershipMsg, NominateCouncilCandidateMsg, PendingCouncilProposalsParams,
+    PendingCouncilProposalsResponse, ProposalAction, ProposalActionOutcome,
+    ProposalActionOutcomesResponse, ProposalActionType, ProposalCreationPolicy, ProposalDeposit,
+    ProposalDepositAsset, ProposalDepositStatus, ProposalExpirationBounds, ProposalId,
+    ProposalInfo, ProposalMetadata, ProposalParams, ProposalResponse, ProposalStatus,
+    ProposalStatusParams, ProposalStatusResponse, ProposalType, ProposalVotesParams,
+    ProposalVotesResponse, ProposalsAffectingAssetParams, ProposalsAffectingAssetResponse,
+    ProposalsParams, ProposalsResponse, ProposerDepositsParams, ProposerDepositsResponse,
+    RegisterInterchainAccountMsg, RevokeSpendingAllowanceMsg, ScheduleCouncilElectionsMsg,
+    SetDistributorClaimsPausedMsg, SetDistributorEmissionScheduleMsg, SetSelfAdminMsg,
+    SupportedProposalActionsResponse, TransferNftMsg, TriggerCouncilElectionMsg,
+    UpdateAssetWhitelistProposalActionMsg, UpdateAssetWhitelistWithBundleMsg, UpdateCouncilMsg,
+    UpdateCouncilPermissionsMsg, UpdateDistributionFeeMsg, UpdateEnterpriseFactoryMsg,
+    UpdateGovConfigMsg, UpdateVetoCouncilMsg, VerifyProposalHashParams, VerifyProposalHashResponse,
+    VetoCouncilSpec, VetoProposalMsg, VetoThresholdBounds, VoterUnlockingExtension,
+    VotingExtensionConfig, VotingExtensionConfigUnchecked,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    ActionTypeCooldownActive, CannotBurnNativeAsset, CouncilActionNoLongerAllowed,
+    CouncilElectionNotDue, CouncilNominationsClosed, CouncilNominationsOpen,
+    CouncilTooPowerfulForAction, CouncilWouldBeEmpty, Dao, DistributionNotReceived,
+    DuplicateCouncilMember, ExecutionBudgetAboveMaximum, ExecutionBudgetExceeded,
+    ExecutionBudgetRequired, GovConfigValueChanged, GovernanceTokenInWhitelist,
+    InsufficientProposalDeposit, InterchainAccountAlreadyRegistered, InvalidArgument,
+    MinimumDepositNotAllowed, NativeSpendingAllowanceNotSupported, NftNotOwnedByDao,
+    NoAllowanceToRevoke, NoCouncilElectionSchedule, NoDaoCouncil, NoInterchainAccount,
+    NoPendingVoterUnlockingExtension, NoRefundableDeposit, NoSuchAssetBundle, NoSuchProposal,
+    NotAnEnterpriseFactory, NotAuthorizedToPropose, NotCouncilMember, NotDaoMember,
+    ProposalActionNotBudgetable, ProposalAlreadyExecuted, ProposalInChallengeWindow,
+    ProposalNotChallengeable, ProposalVetoed, SelfAdminWouldPreventUpgrades, Std,
+    TooManyOpenProposals, UnacceptedDepositAsset, Unauthorized, UnsupportedCouncilProposalAction,
+    UnsupportedCw1155Asset, VetoThresholdBelowFloor, VoteChangesNotAllowed,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use enterprise_governance_controller_api::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use enterprise_protocol::api::{
+    ComponentContractsResponse, DaoType, IsRestrictedUserResponse,
+    SetSelfAdminMsg as EnterpriseSetSelfAdminMsg, UpdateConfigMsg, UpdateMetadataMsg,
+};
+use enterprise_protocol::error::DaoError::VoteDurationLongerThanUnstaking;
 use enterprise_protocol::error::DaoResult;
+use enterprise_protocol::msg::ExecuteMsg as EnterpriseExecuteMsg;
+use enterprise_protocol::msg::QueryMsg as EnterpriseQueryMsg;
+use enterprise_treasury_api::api::{
+    DistributeFundsMsg as TreasuryDistributeFundsMsg, ExecuteCosmosMsgsMsg, UpdateAssetWhitelistMsg,
+};
+use enterprise_treasury_api::msg::ExecuteMsg as TreasuryExecuteMsg;
+use funds_distributor_api::api::{DistributionFee, DistributionFeeResponse};
+use membership_common_api::api::{
+    ExtendUnlockingMsg, MembersResponse, TotalWeightResponse, UserWeightChange, UserWeightResponse,
+    WeightsChangedMsg,
+};
+use membership_common_api::msg::QueryMsg as MembershipQueryMsg;
+use multisig_membership_api::api::{UpdateMembersMsg, UserWeight as MultisigUserWeight};
+use multisig_membership_api::msg::ExecuteMsg as MultisigMembershipExecuteMsg;
+use multisig_membership_api::msg::QueryMsg as MultisigMembershipQueryMsg;
+use poll_engine_api::api::PollRejectionReason::{
+    IsRejectingOutcome, IsVetoOutcome, QuorumNotReached,
+};
+use poll_engine_api::api::{
+    CastVoteParams, Poll, PollResponse, PollStatus, PollStatusResponse, PollVoterResponse,
+    PollVotersResponse, PollsResponse, Vote, VoteOutcome, VotingScheme,
+};
+use prost::Message;
+use sha2::{Digest, Sha256};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use strum::IntoEnumIterator;
+use token_staking_api::api::TokenConfigResponse;
+use token_staking_api::msg::ExecuteMsg as TokenStakingExecuteMsg;
+use token_staking_api::msg::QueryMsg as TokenStakingQueryMsg;
 
 #[test]
 fn initial_test() -> DaoResult<()> {
@@ -6,3 +133,5163 @@ fn initial_test() -> DaoResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn description_validation_rejects_denied_constructs() {
+    let markdown = "# Title\n\nSee [the forum](https://forum.example) for a < b comparison";
+    assert!(validate_proposal_description(markdown, &Markdown).is_ok());
+    assert!(validate_proposal_description(markdown, &Plaintext).is_err());
+
+    assert!(validate_proposal_description("hi <script>alert(1)</script>", &Markdown).is_err());
+    assert!(validate_proposal_description("<IMG src=x>", &Markdown).is_err());
+    assert!(validate_proposal_description("[x](JavaScript:alert(1))", &Markdown).is_err());
+    assert!(validate_proposal_description("token metadata: updated", &Plaintext).is_ok());
+}
+
+#[test]
+fn normalized_asset_whitelist_is_sorted_regardless_of_input_order() {
+    let deps = mock_dependencies();
+
+    let whitelist = vec![
+        AssetInfoUnchecked::cw1155("cw1155_token", "2"),
+        AssetInfoUnchecked::cw20("cw20_b"),
+        AssetInfoUnchecked::native("uluna"),
+        AssetInfoUnchecked::cw1155("cw1155_token", "1"),
+        AssetInfoUnchecked::cw20("cw20_a"),
+        AssetInfoUnchecked::native("ibc/denom"),
+    ];
+
+    let mut reversed_whitelist = whitelist.clone();
+    reversed_whitelist.reverse();
+
+    let expected = vec![
+        AssetInfo::native("ibc/denom"),
+        AssetInfo::native("uluna"),
+        AssetInfo::cw20(Addr::unchecked("cw20_a")),
+        AssetInfo::cw20(Addr::unchecked("cw20_b")),
+        AssetInfo::cw1155(Addr::unchecked("cw1155_token"), "1"),
+        AssetInfo::cw1155(Addr::unchecked("cw1155_token"), "2"),
+    ];
+
+    assert_eq!(
+        normalize_asset_whitelist(deps.as_ref(), &whitelist).unwrap(),
+        expected
+    );
+    assert_eq!(
+        normalize_asset_whitelist(deps.as_ref(), &reversed_whitelist).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn execution_condition_requires_valid_json() {
+    let deps = mock_dependencies();
+
+    let condition = |query_msg: &str, expected_result: &str| ExecutionCondition {
+        contract: "price_oracle".to_string(),
+        query_msg: query_msg.to_string(),
+        expected_result: expected_result.to_string(),
+    };
+
+    let valid = condition(r#"{"price_below":{"threshold":"100"}}"#, "true");
+    assert!(validate_execution_condition(deps.as_ref(), &valid).is_ok());
+
+    let invalid_query = condition(r#"{"price_below":"#, "true");
+    assert!(validate_execution_condition(deps.as_ref(), &invalid_query).is_err());
+
+    let invalid_result = condition(r#"{"price_below":{}}"#, "yes");
+    assert!(validate_execution_condition(deps.as_ref(), &invalid_result).is_err());
+
+    for expected_result in ["-25", "null", r#"{"price":null,"ratio":[5,-1]}"#] {
+        let valid = condition(r#"{"price":{}}"#, expected_result);
+        assert!(validate_execution_condition(deps.as_ref(), &valid).is_ok());
+    }
+
+    // as everywhere in CosmWasm JSON, numbers can only be integers
+    for expected_result in ["1.5", "-2.5e3", r#"{"ratio":[0.5,-1]}"#] {
+        let float_result = condition(r#"{"price":{}}"#, expected_result);
+        assert!(validate_execution_condition(deps.as_ref(), &float_result).is_err());
+    }
+
+    let trailing_content = condition(r#"{"price":{}}"#, "true false");
+    assert!(validate_execution_condition(deps.as_ref(), &trailing_content).is_err());
+}
+
+#[test]
+fn normalized_cw1155_whitelist_follows_treasury_storage_order() {
+    let deps = mock_dependencies();
+
+    let whitelist = vec![
+        AssetInfoUnchecked::cw1155("a_long_cw1155", "1"),
+        AssetInfoUnchecked::cw1155("b_cw1155", "2"),
+        AssetInfoUnchecked::cw1155("b_cw1155", "10"),
+    ];
+
+    // shorter addresses come first, as in the treasury's length-prefixed storage keys
+    let expected = vec![
+        AssetInfo::cw1155(Addr::unchecked("b_cw1155"), "10"),
+        AssetInfo::cw1155(Addr::unchecked("b_cw1155"), "2"),
+        AssetInfo::cw1155(Addr::unchecked("a_long_cw1155"), "1"),
+    ];
+
+    assert_eq!(
+        normalize_asset_whitelist(deps.as_ref(), &whitelist).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn vote_duration_change_does_not_move_existing_proposal_expiry() {
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        None,
+        vec![ProposalAction::UpdateGovConfig(UpdateGovConfigMsg {
+            voting_duration: Change(400u64.into()),
+            ..no_gov_config_changes()
+        })],
+    );
+    mock_token_dao(&mut deps.querier);
+
+    let now = mock_env().block.time;
+    let create_poll_ends_at = |response: Response| {
+        let CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) = &response.messages[0].msg else {
+            panic!("expected poll creation");
+        };
+        let GovernanceExecuteMsg::CreatePoll(params) = from_json(msg).unwrap() else {
+            panic!("expected poll creation");
+        };
+        params.ends_at
+    };
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer", &[]),
+        ExecuteMsg::CreateProposal(create_proposal_msg(vec![])),
+    )
+    .unwrap();
+    assert_eq!(create_poll_ends_at(response), now.plus_seconds(100));
+    reply(deps.as_mut(), mock_env(), create_poll_reply(2)).unwrap();
+
+    // the duration change leaves the poll already in progress alone
+    let response = execute_proposal_actions(deps.as_mut()).unwrap();
+    assert!(response.messages.iter().all(|submsg| !matches!(
+        &submsg.msg,
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) if contract_addr == "governance"
+    )));
+
+    let response: GovConfigResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::GovConfig {}).unwrap()).unwrap();
+    assert_eq!(response.gov_config.vote_duration, 400);
+
+    // while proposals created from now on use the new duration
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer", &[]),
+        ExecuteMsg::CreateProposal(create_proposal_msg(vec![])),
+    )
+    .unwrap();
+    assert_eq!(create_poll_ends_at(response), now.plus_seconds(400));
+}
+
+#[test]
+fn gov_config_changes_are_rejected_if_changed_fields_no_longer_match() {
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(deps.as_mut(), None, vec![]);
+    mock_token_dao(&mut deps.querier);
+
+    let mut create_proposal = |msg: UpdateGovConfigMsg| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("proposer", &[]),
+            ExecuteMsg::CreateProposal(create_proposal_msg(vec![ProposalAction::UpdateGovConfig(
+                msg,
+            )])),
+        )
+    };
+
+    let msg = UpdateGovConfigMsg {
+        quorum: Change(Decimal::percent(40)),
+        voting_duration: Change(200u64.into()),
+        ..no_gov_config_changes()
+    };
+
+    // only the fields being changed have to match
+    create_proposal(UpdateGovConfigMsg {
+        expected_current_config: Some(Box::new(GovConfig {
+            threshold: Decimal::percent(60),
+            ..gov_config()
+        })),
+        ..msg.clone()
+    })
+    .unwrap();
+
+    assert_eq!(
+        create_proposal(UpdateGovConfigMsg {
+            expected_current_config: Some(Box::new(GovConfig {
+                vote_duration: 50,
+                ..gov_config()
+            })),
+            ..msg.clone()
+        })
+        .unwrap_err(),
+        GovConfigValueChanged {
+            field: "voting_duration".to_string()
+        }
+    );
+
+    create_proposal(UpdateGovConfigMsg {
+        expected_current_config: None,
+        ..msg
+    })
+    .unwrap();
+
+    // the unlocking period is checked against the membership contract's
+    let change_unlocking_period =
+        |expected_unlocking_period: Option<Duration>| UpdateGovConfigMsg {
+            unlocking_period: Change(Duration::Time(800)),
+            expected_unlocking_period,
+            ..no_gov_config_changes()
+        };
+
+    assert_eq!(
+        create_proposal(change_unlocking_period(Some(Duration::Time(500)))).unwrap_err(),
+        GovConfigValueChanged {
+            field: "unlocking_period".to_string()
+        }
+    );
+    create_proposal(change_unlocking_period(Some(Duration::Time(1000)))).unwrap();
+    create_proposal(change_unlocking_period(None)).unwrap();
+}
+
+#[test]
+fn most_sensitive_action_type_determines_proposal_thresholds() {
+    let gov_config = GovConfig {
+        action_type_thresholds: Some(vec![
+            ActionTypeThreshold {
+                action_type: ProposalActionType::UpgradeDao,
+                quorum: Decimal::percent(20),
+                threshold: Decimal::percent(66),
+            },
+            ActionTypeThreshold {
+                action_type: ProposalActionType::UpdateMetadata,
+                quorum: Decimal::percent(10),
+                threshold: Decimal::percent(40),
+            },
+        ]),
+        ..gov_config()
+    };
+
+    let metadata_only =
+        apply_action_type_thresholds(gov_config.clone(), &[ProposalActionType::UpdateMetadata]);
+    assert_eq!(metadata_only.quorum, Decimal::percent(10));
+    assert_eq!(metadata_only.threshold, Decimal::percent(40));
+    // veto threshold stays at the DAO's threshold
+    assert_eq!(metadata_only.veto_threshold, Some(Decimal::percent(50)));
+
+    // actions without an override require the DAO's thresholds
+    let with_default = apply_action_type_thresholds(
+        gov_config.clone(),
+        &[
+            ProposalActionType::UpdateMetadata,
+            ProposalActionType::UpdateCouncil,
+        ],
+    );
+    assert_eq!(with_default.quorum, Decimal::percent(30));
+    assert_eq!(with_default.threshold, Decimal::percent(50));
+
+    let with_upgrade = apply_action_type_thresholds(
+        gov_config,
+        &[
+            ProposalActionType::UpdateMetadata,
+            ProposalActionType::UpgradeDao,
+            ProposalActionType::UpdateCouncil,
+        ],
+    );
+    assert_eq!(with_upgrade.quorum, Decimal::percent(20));
+    assert_eq!(with_upgrade.threshold, Decimal::percent(66));
+}
+
+#[test]
+fn asset_bundle_is_expanded_to_its_assets_when_the_proposal_is_created() {
+    let assets = vec![
+        AssetInfoUnchecked::native("uluna"),
+        AssetInfoUnchecked::cw20("cw20_token"),
+    ];
+
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        None,
+        vec![ProposalAction::ManageAssetBundles(ManageAssetBundlesMsg {
+            register: vec![AssetBundle {
+                name: "stables".to_string(),
+                assets: assets.clone(),
+            }],
+            remove: vec![],
+        })],
+    );
+    mock_token_dao(&mut deps.querier);
+    execute_proposal_actions(deps.as_mut()).unwrap();
+
+    let whitelist_bundle = |assets: Option<Vec<AssetInfoUnchecked>>| {
+        ProposalAction::UpdateAssetWhitelistWithBundle(UpdateAssetWhitelistWithBundleMsg {
+            remote_treasury_target: None,
+            bundle: "stables".to_string(),
+            change: AssetBundleChange::Add,
+            assets,
+        })
+    };
+    let create_proposal = |deps: DepsMut, proposal_action: ProposalAction| {
+        execute(
+            deps,
+            mock_env(),
+            mock_info("proposer", &[]),
+            ExecuteMsg::CreateProposal(create_proposal_msg(vec![proposal_action])),
+        )
+    };
+
+    create_proposal(deps.as_mut(), whitelist_bundle(None)).unwrap();
+    reply(deps.as_mut(), mock_env(), create_poll_reply(2)).unwrap();
+
+    assert_eq!(
+        create_proposal(
+            deps.as_mut(),
+            whitelist_bundle(Some(vec![AssetInfoUnchecked::native("uluna")]))
+        )
+        .unwrap_err(),
+        InvalidArgument {
+            msg: "Given assets do not match the registered assets of bundle stables".to_string()
+        }
+    );
+
+    create_proposal(
+        deps.as_mut(),
+        ProposalAction::ManageAssetBundles(ManageAssetBundlesMsg {
+            register: vec![],
+            remove: vec!["stables".to_string()],
+        }),
+    )
+    .unwrap();
+    reply(deps.as_mut(), mock_env(), create_poll_reply(3)).unwrap();
+
+    let response: ProposalResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Proposal(ProposalParams {
+                proposal_id: 2,
+                viewer: None,
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        response.proposal.proposal_actions,
+        vec![whitelist_bundle(Some(assets.clone()))]
+    );
+
+    let execute_proposal_actions = |deps: DepsMut, proposal_id: ProposalId| {
+        execute(
+            deps,
+            mock_env(),
+            mock_info(mock_env().contract.address.as_str(), &[]),
+            ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg { proposal_id }),
+        )
+    };
+
+    // the bundle is removed before the proposal whitelisting it executes
+    execute_proposal_actions(deps.as_mut(), 3).unwrap();
+
+    let response = execute_proposal_actions(deps.as_mut(), 2).unwrap();
+    let CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr, msg, ..
+    }) = &response.messages[0].msg
+    else {
+        panic!("expected treasury message");
+    };
+    assert_eq!(contract_addr, "treasury");
+    assert_eq!(
+        from_json::<TreasuryExecuteMsg>(msg).unwrap(),
+        TreasuryExecuteMsg::UpdateAssetWhitelist(UpdateAssetWhitelistMsg {
+            add: assets,
+            remove: vec![],
+        })
+    );
+
+    // while new proposals can no longer use it
+    assert_eq!(
+        create_proposal(deps.as_mut(), whitelist_bundle(None)).unwrap_err(),
+        NoSuchAssetBundle {
+            name: "stables".to_string()
+        }
+    );
+}
+
+#[test]
+fn council_elections_elect_the_best_supported_nominated_candidates() {
+    let mut deps = mock_dependencies();
+    mock_token_dao(&mut deps.querier);
+
+    let council = DaoCouncilSpec {
+        members: vec!["member1".to_string()],
+        weights: None,
+        quorum: Decimal::percent(50),
+        threshold: Decimal::percent(60),
+        allowed_proposal_action_types: Some(vec![ProposalActionType::UpdateMetadata]),
+    };
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        Some(council),
+        vec![ProposalAction::ScheduleCouncilElections(
+            ScheduleCouncilElectionsMsg {
+                schedule: Some(CouncilElectionSchedule {
+                    interval: 1000,
+                    seats: 2,
+                    nomination_period: 100,
+                }),
+            },
+        )],
+    );
+
+    let now = mock_env().block.time;
+    let env_at = |seconds: u64| {
+        let mut env = mock_env();
+        env.block.time = now.plus_seconds(seconds);
+        env
+    };
+    let nominate = |deps: DepsMut, seconds: u64, nominator: &str, candidate: &str| {
+        execute(
+            deps,
+            env_at(seconds),
+            mock_info(nominator, &[]),
+            ExecuteMsg::NominateCouncilCandidate(NominateCouncilCandidateMsg {
+                candidate: candidate.to_string(),
+            }),
+        )
+    };
+    let trigger = |deps: DepsMut, seconds: u64| {
+        execute(
+            deps,
+            env_at(seconds),
+            mock_info("anyone", &[]),
+            ExecuteMsg::TriggerCouncilElection(TriggerCouncilElectionMsg {}),
+        )
+    };
+    let change_weights = |deps: DepsMut, weight_changes: Vec<(&str, u128, u128)>| {
+        execute(
+            deps,
+            mock_env(),
+            mock_info("membership", &[]),
+            ExecuteMsg::WeightsChanged(WeightsChangedMsg {
+                weight_changes: weight_changes
+                    .into_iter()
+                    .map(|(user, old_weight, new_weight)| UserWeightChange {
+                        user: user.to_string(),
+                        old_weight: Uint128::new(old_weight),
+                        new_weight: Uint128::new(new_weight),
+                    })
+                    .collect(),
+            }),
+        )
+        .unwrap()
+    };
+    let election_schedule = |deps: Deps| -> CouncilElectionScheduleResponse {
+        from_json(query(deps, mock_env(), QueryMsg::CouncilElectionSchedule {}).unwrap()).unwrap()
+    };
+
+    assert_eq!(
+        trigger(deps.as_mut(), 1000).unwrap_err(),
+        NoCouncilElectionSchedule
+    );
+
+    execute_proposal_actions(deps.as_mut()).unwrap();
+
+    assert_eq!(
+        nominate(deps.as_mut(), 999, "member1", "alice").unwrap_err(),
+        CouncilElectionNotDue {
+            next_election: now.plus_seconds(1000)
+        }
+    );
+
+    // only members can nominate, and only members can be nominated
+    assert_eq!(
+        nominate(deps.as_mut(), 1000, "non_member", "alice").unwrap_err(),
+        NotDaoMember {
+            member: "non_member".to_string()
+        }
+    );
+    assert_eq!(
+        nominate(deps.as_mut(), 1000, "member1", "non_member").unwrap_err(),
+        NotDaoMember {
+            member: "non_member".to_string()
+        }
+    );
+
+    nominate(deps.as_mut(), 1000, "member1", "alice").unwrap();
+    nominate(deps.as_mut(), 1000, "member2", "bob").unwrap();
+    nominate(deps.as_mut(), 1000, "member3", "carol").unwrap();
+    nominate(deps.as_mut(), 1000, "member4", "carol").unwrap();
+    nominate(deps.as_mut(), 1000, "member5", "dave").unwrap();
+
+    // nominating another candidate moves the support, leaving alice without any
+    nominate(deps.as_mut(), 1050, "member1", "bob").unwrap();
+
+    // support follows the nominators' weights, and candidates leaving the DAO drop out
+    change_weights(deps.as_mut(), vec![("member2", 10, 30), ("member3", 10, 0)]);
+    change_weights(deps.as_mut(), vec![("carol", 5, 0)]);
+
+    let schedule = election_schedule(deps.as_ref());
+    assert_eq!(schedule.next_election, Some(now.plus_seconds(1000)));
+    assert_eq!(schedule.nominations_close, Some(now.plus_seconds(1100)));
+    assert_eq!(
+        schedule.candidates,
+        vec![
+            CouncilCandidate {
+                candidate: Addr::unchecked("bob"),
+                support: Uint128::new(40),
+            },
+            CouncilCandidate {
+                candidate: Addr::unchecked("dave"),
+                support: Uint128::new(10),
+            },
+        ]
+    );
+
+    assert_eq!(
+        trigger(deps.as_mut(), 1099).unwrap_err(),
+        CouncilNominationsOpen {
+            closes_at: now.plus_seconds(1100)
+        }
+    );
+    assert_eq!(
+        nominate(deps.as_mut(), 1100, "member6", "erin").unwrap_err(),
+        CouncilNominationsClosed {
+            closed_at: now.plus_seconds(1100)
+        }
+    );
+
+    let response = trigger(deps.as_mut(), 1100).unwrap();
+    assert_eq!(response.messages.len(), 1);
+    reply(deps.as_mut(), mock_env(), create_poll_reply(2)).unwrap();
+
+    let proposal: ProposalResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Proposal(ProposalParams {
+                proposal_id: 2,
+                viewer: None,
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        proposal.proposal.proposal_actions,
+        vec![ProposalAction::UpdateCouncil(UpdateCouncilMsg {
+            dao_council: Some(DaoCouncilSpec {
+                members: vec!["bob".to_string(), "dave".to_string()],
+                weights: None,
+                quorum: Decimal::percent(50),
+                threshold: Decimal::percent(60),
+                allowed_proposal_action_types: Some(vec![ProposalActionType::UpdateMetadata]),
+            }),
+        })]
+    );
+
+    // the next election is due an interval after this one was triggered
+    assert_eq!(
+        trigger(deps.as_mut(), 1200).unwrap_err(),
+        CouncilElectionNotDue {
+            next_election: now.plus_seconds(2100)
+        }
+    );
+    assert!(election_schedule(deps.as_ref()).candidates.is_empty());
+
+    // an election without enough candidates for its seats is skipped
+    nominate(deps.as_mut(), 2100, "member1", "bob").unwrap();
+
+    let response = trigger(deps.as_mut(), 2200).unwrap();
+    assert!(response.messages.is_empty());
+    assert!(response
+        .attributes
+        .contains(&Attribute::new("election_held", "false")));
+    assert_eq!(
+        election_schedule(deps.as_ref()).next_election,
+        Some(now.plus_seconds(3200))
+    );
+}
+
+#[test]
+fn dropped_council_candidates_lose_their_nominations() {
+    let mut deps = mock_dependencies();
+
+    let now = mock_env().block.time;
+    schedule_council_elections(
+        deps.as_mut().storage,
+        now,
+        ScheduleCouncilElectionsMsg {
+            schedule: Some(CouncilElectionSchedule {
+                interval: 1000,
+                seats: 2,
+                nomination_period: 100,
+            }),
+        },
+    )
+    .unwrap();
+
+    let nominations_open = now.plus_seconds(1000);
+    let nominate = |deps: DepsMut, nominator: &str, weight: u128, candidate: &str| {
+        nominate_council_candidate(
+            deps.storage,
+            nominations_open,
+            Addr::unchecked(nominator),
+            Uint128::new(weight),
+            Addr::unchecked(candidate),
+        )
+    };
+    let change_weight = |deps: DepsMut, user: &str, old_weight: u128, new_weight: u128| {
+        update_council_nominations(
+            deps,
+            &[UserWeightChange {
+                user: user.to_string(),
+                old_weight: Uint128::new(old_weight),
+                new_weight: Uint128::new(new_weight),
+            }],
+        )
+    };
+    let candidates = |deps: Deps| {
+        query_council_election_schedule(QueryContext::from(deps, mock_env()))
+            .unwrap()
+            .candidates
+            .into_iter()
+            .map(|it| (it.candidate.to_string(), it.support.u128()))
+            .collect::<Vec<(String, u128)>>()
+    };
+
+    nominate(deps.as_mut(), "member1", 20, "carol").unwrap();
+
+    // carol leaves the DAO and rejoins, to be nominated again by a lighter member
+    change_weight(deps.as_mut(), "carol", 5, 0).unwrap();
+    assert!(candidates(deps.as_ref()).is_empty());
+    nominate(deps.as_mut(), "member2", 10, "carol").unwrap();
+
+    // member1's nomination from before carol was dropped no longer counts
+    change_weight(deps.as_mut(), "member1", 20, 30).unwrap();
+    assert_eq!(candidates(deps.as_ref()), vec![("carol".to_string(), 10)]);
+
+    // so member1 can nominate carol again
+    nominate(deps.as_mut(), "member1", 30, "carol").unwrap();
+    assert_eq!(candidates(deps.as_ref()), vec![("carol".to_string(), 40)]);
+
+    // candidates left without support are dropped
+    nominate(deps.as_mut(), "member3", 10, "dave").unwrap();
+    assert_eq!(
+        candidates(deps.as_ref()),
+        vec![("carol".to_string(), 40), ("dave".to_string(), 10)]
+    );
+    change_weight(deps.as_mut(), "member3", 10, 0).unwrap();
+    assert_eq!(candidates(deps.as_ref()), vec![("carol".to_string(), 40)]);
+
+    // and their nominators' later weight changes are ignored
+    change_weight(deps.as_mut(), "member3", 0, 10).unwrap();
+    nominate(deps.as_mut(), "member4", 5, "dave").unwrap();
+    assert_eq!(
+        candidates(deps.as_ref()),
+        vec![("carol".to_string(), 40), ("dave".to_string(), 5)]
+    );
+}
+
+#[test]
+fn veto_council_vetoes_passed_proposals_within_their_challenge_window() {
+    let mut deps = mock_dependencies();
+
+    // proposal 1 updates the veto council, 2 and 4 pass and 3 is rejected
+    let update_veto_council = |members: Vec<&str>| {
+        ProposalAction::UpdateVetoCouncil(UpdateVetoCouncilMsg {
+            veto_council: Some(VetoCouncilSpec {
+                members: members.into_iter().map(|it| it.to_string()).collect(),
+                threshold: Decimal::percent(60),
+                challenge_window: 100,
+            }),
+        })
+    };
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        None,
+        vec![update_veto_council(vec!["veto1", "veto2", "veto3"])],
+    );
+    for proposal_id in 2..=4 {
+        PROPOSAL_INFOS
+            .save(
+                deps.as_mut().storage,
+                proposal_id,
+                &proposal_info(ProposalType::General),
+            )
+            .unwrap();
+    }
+
+    let ends_at = mock_env().block.time;
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "enterprise" => to_json_binary(&IsRestrictedUserResponse {
+            is_restricted: false,
+        })
+        .unwrap(),
+        "membership" => match from_json(msg).unwrap() {
+            MembershipQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(100),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                poll: poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at),
+            })
+            .unwrap(),
+            GovernanceQueryMsg::SimulateEndPollStatus { poll_id, .. } => {
+                let status = if poll_id == 3 {
+                    PollStatus::Rejected {
+                        reason: QuorumNotReached,
+                    }
+                } else {
+                    PollStatus::Passed {
+                        outcome: VoteOutcome::Yes as u8,
+                        count: Uint128::new(50),
+                    }
+                };
+                to_json_binary(&PollStatusResponse {
+                    status,
+                    ends_at,
+                    results: BTreeMap::new(),
+                })
+                .unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let env_at = |seconds: u64| {
+        let mut env = mock_env();
+        env.block.time = ends_at.plus_seconds(seconds);
+        env
+    };
+    let veto = |deps: DepsMut, seconds: u64, member: &str, proposal_id: ProposalId| {
+        execute(
+            deps,
+            env_at(seconds),
+            mock_info(member, &[]),
+            ExecuteMsg::VetoProposal(VetoProposalMsg { proposal_id }),
+        )
+    };
+    let execute_proposal = |deps: DepsMut, seconds: u64, proposal_id: ProposalId| {
+        execute(
+            deps,
+            env_at(seconds),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+        )
+    };
+    let vetoed = |response: Response| {
+        response
+            .attributes
+            .contains(&Attribute::new("vetoed", "true"))
+    };
+
+    execute_proposal_actions(deps.as_mut()).unwrap();
+
+    // passing proposals wait for their challenge window, rejected ones can be executed right away
+    assert_eq!(
+        execute_proposal(deps.as_mut(), 10, 2).unwrap_err(),
+        ProposalInChallengeWindow
+    );
+    assert!(execute_proposal(deps.as_mut(), 10, 3).is_ok());
+
+    assert_eq!(
+        veto(deps.as_mut(), 10, "outsider", 2).unwrap_err(),
+        Unauthorized
+    );
+    assert!(!vetoed(veto(deps.as_mut(), 10, "veto1", 2).unwrap()));
+
+    // vetoes cast before the veto council changes no longer count
+    PROPOSAL_INFOS
+        .save(
+            deps.as_mut().storage,
+            1,
+            &ProposalInfo {
+                proposal_actions: vec![update_veto_council(vec!["veto2", "veto3"])],
+                ..proposal_info(ProposalType::General)
+            },
+        )
+        .unwrap();
+    execute_proposal_actions(deps.as_mut()).unwrap();
+    PROPOSAL_INFOS
+        .save(
+            deps.as_mut().storage,
+            1,
+            &ProposalInfo {
+                proposal_actions: vec![update_veto_council(vec!["veto1", "veto2", "veto3"])],
+                ..proposal_info(ProposalType::General)
+            },
+        )
+        .unwrap();
+    execute_proposal_actions(deps.as_mut()).unwrap();
+
+    assert!(!vetoed(veto(deps.as_mut(), 20, "veto2", 2).unwrap()));
+
+    // reaching the threshold vetoes the proposal, which then can never be executed
+    assert!(vetoed(veto(deps.as_mut(), 20, "veto3", 2).unwrap()));
+    assert_eq!(
+        execute_proposal(deps.as_mut(), 100, 2).unwrap_err(),
+        ProposalVetoed
+    );
+
+    // once the challenge window ends, proposals can no longer be vetoed and can be executed
+    assert_eq!(
+        veto(deps.as_mut(), 100, "veto1", 4).unwrap_err(),
+        ProposalNotChallengeable
+    );
+    assert!(execute_proposal(deps.as_mut(), 100, 4).is_ok());
+}
+
+#[test]
+fn distribution_fee_update_is_bounded_and_needs_valid_recipient() {
+    let deps = mock_dependencies();
+
+    let update_fee = |bps: u16, recipient: &str| {
+        validate_proposal_action(
+            deps.as_ref(),
+            &ProposalAction::UpdateDistributionFee(UpdateDistributionFeeMsg {
+                bps,
+                recipient: recipient.to_string(),
+            }),
+        )
+    };
+
+    assert!(update_fee(1000, "fee_recipient").is_ok());
+    assert!(update_fee(0, "fee_recipient").is_ok());
+    assert!(matches!(
+        update_fee(1001, "fee_recipient"),
+        Err(InvalidArgument { .. })
+    ));
+    assert!(update_fee(100, "").is_err());
+}
+
+#[test]
+fn nft_transfer_requires_treasury_to_own_each_token() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "enterprise" => {
+            let _: EnterpriseQueryMsg = from_json(msg).unwrap();
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&component_contracts()).unwrap(),
+            ))
+        }
+        WasmQuery::Smart { msg, .. } => match from_json(msg).unwrap() {
+            Cw721QueryMsg::OwnerOf { token_id, .. } => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&OwnerOfResponse {
+                    owner: if token_id == "stranger_token" {
+                        "stranger".to_string()
+                    } else {
+                        "treasury".to_string()
+                    },
+                    approvals: vec![],
+                })
+                .unwrap(),
+            )),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let transfer_nft = |token_ids: &[&str], recipient: &str| {
+        validate_proposal_action(
+            deps.as_ref(),
+            &ProposalAction::TransferNft(TransferNftMsg {
+                contract: "nft_contract".to_string(),
+                token_ids: token_ids.iter().map(|id| id.to_string()).collect(),
+                recipient: recipient.to_string(),
+            }),
+        )
+    };
+
+    assert!(transfer_nft(&["token1", "token2"], "recipient").is_ok());
+    assert_eq!(
+        transfer_nft(&["token1", "stranger_token"], "recipient").unwrap_err(),
+        NftNotOwnedByDao {
+            token_id: "stranger_token".to_string()
+        }
+    );
+    assert!(matches!(
+        transfer_nft(&["token1", "token1"], "recipient"),
+        Err(InvalidArgument { .. })
+    ));
+    assert!(matches!(
+        transfer_nft(&[], "recipient"),
+        Err(InvalidArgument { .. })
+    ));
+    assert!(transfer_nft(&["token1"], "").is_err());
+}
+
+#[test]
+fn deposit_status_follows_proposal_outcome() {
+    let now = Timestamp::from_seconds(1000);
+
+    let proposal_info = |executed: bool| ProposalInfo {
+        proposal_type: ProposalType::General,
+        executed_at: executed.then(|| mock_env().block),
+        earliest_execution: None,
+        proposal_deposit: None,
+        proposal_actions: vec![],
+        execution_atomicity: None,
+        execution_condition: None,
+        execution_budget: None,
+        metadata: None,
+    };
+    let poll = |status: PollStatus, ends_at: u64| Poll {
+        id: 1,
+        proposer: Addr::unchecked("proposer"),
+        deposit_amount: 0,
+        label: "proposal".to_string(),
+        description: String::new(),
+        scheme: VotingScheme::CoinVoting,
+        status,
+        started_at: Timestamp::from_seconds(0),
+        ends_at: Timestamp::from_seconds(ends_at),
+        quorum: Decimal::percent(30),
+        threshold: Decimal::percent(50),
+        veto_threshold: None,
+        minimum_yes_votes: None,
+        results: BTreeMap::new(),
+    };
+    let in_progress = PollStatus::InProgress {
+        ends_at: Timestamp::from_seconds(2000),
+    };
+    let passed = PollStatus::Passed {
+        outcome: 0,
+        count: Uint128::new(10),
+    };
+    let rejected = |reason| PollStatus::Rejected { reason };
+
+    let status = |executed: bool, poll_status: PollStatus, ends_at: u64, vetoed: bool| {
+        proposal_deposit_status(
+            &proposal_info(executed),
+            &poll(poll_status, ends_at),
+            vetoed,
+            false,
+            now,
+        )
+    };
+
+    assert_eq!(
+        status(false, in_progress.clone(), 2000, false),
+        ProposalDepositStatus::HeldInProgress
+    );
+    // voting ended, but the proposal was not executed or ended yet
+    assert_eq!(
+        status(false, in_progress, 500, false),
+        ProposalDepositStatus::Pending
+    );
+    assert_eq!(
+        status(false, passed.clone(), 500, false),
+        ProposalDepositStatus::Pending
+    );
+    assert_eq!(
+        status(false, passed.clone(), 500, true),
+        ProposalDepositStatus::Refunded
+    );
+    assert_eq!(
+        status(true, passed.clone(), 500, false),
+        ProposalDepositStatus::Refunded
+    );
+    assert_eq!(
+        status(true, rejected(IsRejectingOutcome), 500, false),
+        ProposalDepositStatus::Refunded
+    );
+    assert_eq!(
+        status(true, rejected(QuorumNotReached), 500, false),
+        ProposalDepositStatus::Slashed
+    );
+    assert_eq!(
+        status(true, rejected(IsVetoOutcome), 500, false),
+        ProposalDepositStatus::Slashed
+    );
+    assert_eq!(
+        proposal_deposit_status(&proposal_info(true), &poll(passed, 500), false, true, now),
+        ProposalDepositStatus::Refundable
+    );
+}
+
+#[test]
+fn refundable_deposit_can_only_be_claimed_once_by_its_depositor() {
+    let mut deps = mock_dependencies();
+
+    PROPOSAL_INFOS
+        .save(
+            deps.as_mut().storage,
+            1,
+            &ProposalInfo {
+                proposal_type: ProposalType::General,
+                executed_at: Some(mock_env().block),
+                earliest_execution: None,
+                proposal_deposit: Some(ProposalDeposit {
+                    depositor: Addr::unchecked("depositor"),
+                    asset: ProposalDepositAsset::Denom {
+                        denom: "uluna".to_string(),
+                        amount: Uint128::new(100),
+                    },
+                }),
+                proposal_actions: vec![],
+                execution_atomicity: None,
+                execution_condition: None,
+                execution_budget: None,
+                metadata: None,
+            },
+        )
+        .unwrap();
+    REFUNDABLE_DEPOSITS
+        .save(deps.as_mut().storage, 1, &())
+        .unwrap();
+
+    let mut claim_deposit = |sender: &str| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::ClaimDeposit(ClaimDepositMsg { proposal_id: 1 }),
+        )
+    };
+
+    assert_eq!(claim_deposit("proposer").unwrap_err(), Unauthorized);
+
+    let response = claim_deposit("depositor").unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "depositor".to_string(),
+            amount: coins(100, "uluna"),
+        })
+    );
+
+    assert_eq!(claim_deposit("depositor").unwrap_err(), NoRefundableDeposit);
+}
+
+#[test]
+fn council_allowed_high_risk_action_has_to_meet_dao_requirements() {
+    let deps = mock_dependencies();
+
+    let gov_config_with_requirements = GovConfig {
+        council_action_requirements: Some(vec![CouncilActionRequirement {
+            action_type: ProposalActionType::UpgradeDao,
+            minimum_members: 3,
+            minimum_threshold: Decimal::percent(60),
+        }]),
+        ..gov_config()
+    };
+
+    let council =
+        |members: &[&str], threshold: u64, action_types: Option<Vec<ProposalActionType>>| {
+            Some(DaoCouncilSpec {
+                members: members.iter().map(|member| member.to_string()).collect(),
+                weights: None,
+                quorum: Decimal::percent(50),
+                threshold: Decimal::percent(threshold),
+                allowed_proposal_action_types: action_types,
+            })
+        };
+
+    // councils are allowed UpgradeDao by default
+    assert_eq!(
+        validate_dao_council(
+            deps.as_ref(),
+            &gov_config_with_requirements,
+            council(&["member1", "member2"], 60, None)
+        )
+        .unwrap_err(),
+        CouncilTooPowerfulForAction {
+            action: ProposalActionType::UpgradeDao
+        }
+    );
+    assert_eq!(
+        validate_dao_council(
+            deps.as_ref(),
+            &gov_config_with_requirements,
+            council(&["member1", "member2", "member3"], 51, None)
+        )
+        .unwrap_err(),
+        CouncilTooPowerfulForAction {
+            action: ProposalActionType::UpgradeDao
+        }
+    );
+    assert!(validate_dao_council(
+        deps.as_ref(),
+        &gov_config_with_requirements,
+        council(&["member1", "member2", "member3"], 60, None)
+    )
+    .is_ok());
+
+    // a small council is fine as long as it is not allowed the action
+    assert!(validate_dao_council(
+        deps.as_ref(),
+        &gov_config_with_requirements,
+        council(
+            &["member1"],
+            51,
+            Some(vec![ProposalActionType::UpdateMetadata])
+        )
+    )
+    .is_ok());
+
+    // without requirements, any council can be allowed the action
+    assert!(validate_dao_council(
+        deps.as_ref(),
+        &gov_config(),
+        council(&["member1"], 51, None)
+    )
+    .is_ok());
+}
+
+#[test]
+fn proposal_votes_report_outcome_and_counted_weight() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, .. } if contract_addr == "enterprise" => {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&component_contracts()).unwrap(),
+            ))
+        }
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "governance" => {
+            match from_json(msg).unwrap() {
+                GovernanceQueryMsg::PollVoters(params) => {
+                    assert_eq!(params.poll_id, 4);
+                    assert_eq!(params.pagination.start_after, Some("voter1".to_string()));
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&PollVotersResponse {
+                            votes: vec![
+                                Vote::new(4, Addr::unchecked("voter2"), VoteOutcome::Veto, 30),
+                                Vote::new(4, Addr::unchecked("voter3"), VoteOutcome::Yes, 20),
+                            ],
+                        })
+                        .unwrap(),
+                    ))
+                }
+                _ => panic!("unexpected query"),
+            }
+        }
+        _ => panic!("unexpected query"),
+    });
+
+    let response: ProposalVotesResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProposalVotes(ProposalVotesParams {
+                proposal_id: 4,
+                start_after: Some("voter1".to_string()),
+                limit: None,
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.votes,
+        vec![
+            Vote::new(4, Addr::unchecked("voter2"), VoteOutcome::Veto, 30),
+            Vote::new(4, Addr::unchecked("voter3"), VoteOutcome::Yes, 20),
+        ]
+    );
+}
+
+#[test]
+fn votes_can_only_be_changed_to_another_option_if_allowed() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, .. } if contract_addr == "enterprise" => {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&component_contracts()).unwrap(),
+            ))
+        }
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "governance" => {
+            match from_json(msg).unwrap() {
+                GovernanceQueryMsg::PollVoter(params) => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&PollVoterResponse {
+                        vote: (params.voter_addr == "voter")
+                            .then(|| Vote::new(1, Addr::unchecked("voter"), VoteOutcome::Yes, 10)),
+                    })
+                    .unwrap(),
+                )),
+                _ => panic!("unexpected query"),
+            }
+        }
+        _ => panic!("unexpected query"),
+    });
+
+    let mut assert_vote = |allow_vote_changes: Option<bool>, voter: &str, outcome: VoteOutcome| {
+        GOV_CONFIG
+            .save(
+                deps.as_mut().storage,
+                &GovConfig {
+                    allow_vote_changes,
+                    ..gov_config()
+                },
+            )
+            .unwrap();
+
+        assert_vote_change_allowed(
+            QueryContext::from(deps.as_ref(), mock_env()),
+            &Addr::unchecked(voter),
+            1,
+            outcome,
+        )
+    };
+
+    assert!(assert_vote(None, "voter", VoteOutcome::No).is_ok());
+    assert!(assert_vote(Some(true), "voter", VoteOutcome::No).is_ok());
+    assert_eq!(
+        assert_vote(Some(false), "voter", VoteOutcome::No).unwrap_err(),
+        VoteChangesNotAllowed
+    );
+    assert!(assert_vote(Some(false), "voter", VoteOutcome::Yes).is_ok());
+    assert!(assert_vote(Some(false), "new_voter", VoteOutcome::No).is_ok());
+}
+
+#[test]
+fn batch_proposals_are_created_together_with_the_deposit_split_between_them() {
+    let setup = || {
+        let mut deps = mock_dependencies();
+        mock_token_dao(&mut deps.querier);
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("enterprise", &[]),
+            InstantiateMsg {
+                enterprise_contract: "enterprise".to_string(),
+                dao_type: DaoType::Token,
+                gov_config: GovConfig {
+                    accepted_deposit_assets: Some(vec![AcceptedDepositAsset {
+                        asset: AssetInfo::native("uluna"),
+                        minimum_amount: Uint128::new(10),
+                    }]),
+                    max_open_proposals_per_proposer: Some(3),
+                    ..gov_config()
+                },
+                council_gov_config: None,
+                proposal_infos: None,
+                initial_cross_chain_treasuries: None,
+            },
+        )
+        .unwrap();
+
+        deps
+    };
+    let create_proposals = |deps: DepsMut, proposals: Vec<CreateProposalMsg>, deposit: u128| {
+        execute(
+            deps,
+            mock_env(),
+            mock_info("proposer", &coins(deposit, "uluna")),
+            ExecuteMsg::CreateProposals(CreateProposalsMsg { proposals }),
+        )
+    };
+    let proposal = |title: &str| CreateProposalMsg {
+        title: title.to_string(),
+        ..create_proposal_msg(vec![])
+    };
+    let batch_size_error = InvalidArgument {
+        msg: "A batch has to contain between 1 and 10 proposals".to_string(),
+    };
+
+    let mut deps = setup();
+
+    assert_eq!(
+        create_proposals(deps.as_mut(), vec![], 10).unwrap_err(),
+        batch_size_error
+    );
+    assert_eq!(
+        create_proposals(deps.as_mut(), vec![proposal("proposal"); 11], 110).unwrap_err(),
+        batch_size_error
+    );
+    assert_eq!(
+        create_proposals(deps.as_mut(), vec![proposal("proposal"); 4], 40).unwrap_err(),
+        TooManyOpenProposals { max: 3 }
+    );
+
+    // failing batches are reverted as a whole, so they are tried out on separate storage
+
+    // each proposal's share of the deposit has to meet the minimum on its own
+    assert_eq!(
+        create_proposals(setup().as_mut(), vec![proposal("proposal"); 2], 19).unwrap_err(),
+        InsufficientProposalDeposit {
+            required_amount: Uint128::new(10)
+        }
+    );
+
+    // a single invalid proposal fails the whole batch, before any poll is created
+    assert!(matches!(
+        create_proposals(
+            setup().as_mut(),
+            vec![
+                proposal("first"),
+                create_proposal_msg(vec![ProposalAction::UpdateDistributionFee(
+                    UpdateDistributionFeeMsg {
+                        bps: 1001,
+                        recipient: "fee_recipient".to_string(),
+                    }
+                )]),
+            ],
+            20
+        ),
+        Err(InvalidArgument { .. })
+    ));
+
+    let response = create_proposals(
+        deps.as_mut(),
+        vec![proposal("first"), proposal("second")],
+        25,
+    )
+    .unwrap();
+    assert_eq!(response.messages.len(), 2);
+
+    let response = reply(deps.as_mut(), mock_env(), create_poll_reply(7)).unwrap();
+    assert_eq!(response.data, None);
+
+    let response = reply(deps.as_mut(), mock_env(), create_poll_reply(8)).unwrap();
+    let created: CreateProposalsResponse = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(created.proposal_ids, vec![7, 8]);
+
+    // the first proposal gets the remainder of the split deposit
+    let deposits: ProposerDepositsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProposerDeposits(ProposerDepositsParams {
+                depositor: "proposer".to_string(),
+                start_after: None,
+                limit: None,
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        deposits
+            .deposits
+            .iter()
+            .map(|deposit| (deposit.proposal_id, deposit.deposit.amount()))
+            .collect::<Vec<(u64, Uint128)>>(),
+        vec![(7, Uint128::new(13)), (8, Uint128::new(12))]
+    );
+
+    // the batch's proposals count towards the proposer's open proposals
+    assert_eq!(
+        create_proposals(deps.as_mut(), vec![proposal("proposal"); 2], 20).unwrap_err(),
+        TooManyOpenProposals { max: 3 }
+    );
+    create_proposals(deps.as_mut(), vec![proposal("third")], 10).unwrap();
+}
+
+#[test]
+fn proposal_hash_is_verified_against_canonical_actions_encoding() {
+    let mut deps = mock_dependencies();
+
+    let proposals = [
+        (
+            3,
+            ProposalAction::SetDistributorClaimsPaused(SetDistributorClaimsPausedMsg {
+                paused: true,
+            }),
+        ),
+        (
+            5,
+            ProposalAction::ScheduleCouncilElections(ScheduleCouncilElectionsMsg {
+                schedule: Some(CouncilElectionSchedule {
+                    interval: 1000,
+                    seats: 2,
+                    nomination_period: 100,
+                }),
+            }),
+        ),
+        (
+            6,
+            ProposalAction::ExecuteMsgs(ExecuteMsgsMsg {
+                action_type: "custom".to_string(),
+                msgs: vec!["line\n\"quoted\" \u{1f} é\u{2028}".to_string()],
+            }),
+        ),
+    ];
+    for (proposal_id, proposal_action) in proposals {
+        PROPOSAL_INFOS
+            .save(
+                deps.as_mut().storage,
+                proposal_id,
+                &ProposalInfo {
+                    proposal_type: ProposalType::General,
+                    executed_at: None,
+                    earliest_execution: None,
+                    proposal_deposit: None,
+                    proposal_actions: vec![proposal_action],
+                    execution_atomicity: None,
+                    execution_condition: None,
+                    execution_budget: None,
+                    metadata: None,
+                },
+            )
+            .unwrap();
+    }
+
+    let verify = |proposal_id: u64, expected_hash: &[u8]| {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::VerifyProposalHash(VerifyProposalHashParams {
+                proposal_id,
+                expected_hash: Binary::from(expected_hash),
+            }),
+        )
+        .map(|response| {
+            from_json::<VerifyProposalHashResponse>(response)
+                .unwrap()
+                .matches
+        })
+    };
+
+    let drafted_hash = Sha256::digest(r#"[{"set_distributor_claims_paused":{"paused":true}}]"#);
+    let modified_hash = Sha256::digest(r#"[{"set_distributor_claims_paused":{"paused":false}}]"#);
+
+    assert_eq!(verify(3, &drafted_hash), Ok(true));
+    assert_eq!(verify(3, &modified_hash), Ok(false));
+    assert_eq!(verify(4, &drafted_hash), Err(NoSuchProposal));
+
+    // object keys are hashed in sorted order, rather than the order the fields are declared in
+    let sorted_keys_hash = Sha256::digest(
+        r#"[{"schedule_council_elections":{"schedule":{"interval":1000,"nomination_period":100,"seats":2}}}]"#,
+    );
+    let declared_order_hash = Sha256::digest(
+        r#"[{"schedule_council_elections":{"schedule":{"interval":1000,"seats":2,"nomination_period":100}}}]"#,
+    );
+
+    assert_eq!(verify(5, &sorted_keys_hash), Ok(true));
+    assert_eq!(verify(5, &declared_order_hash), Ok(false));
+
+    // only quotes, backslashes and control characters are escaped, with lowercase hex digits
+    let minimally_escaped_hash = Sha256::digest(
+        "[{\"execute_msgs\":{\"action_type\":\"custom\",\"msgs\":[\"line\\n\\\"quoted\\\" \\u001f é\u{2028}\"]}}]",
+    );
+    let fully_escaped_hash = Sha256::digest(
+        r#"[{"execute_msgs":{"action_type":"custom","msgs":["line\n\"quoted\" \u001F \u00e9\u2028"]}}]"#,
+    );
+
+    assert_eq!(verify(6, &minimally_escaped_hash), Ok(true));
+    assert_eq!(verify(6, &fully_escaped_hash), Ok(false));
+}
+
+#[test]
+fn proposal_metadata_requires_well_formed_uris_within_limits() {
+    let metadata = |discussion_url: &str, attachments: Vec<&str>| ProposalMetadata {
+        discussion_url: Some(discussion_url.to_string()),
+        attachments: attachments.into_iter().map(|a| a.to_string()).collect(),
+    };
+
+    assert_eq!(
+        validate_proposal_metadata(&metadata(
+            "https://forum.dao.org/t/proposal-12",
+            vec![
+                "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+                "ar://tx-id"
+            ],
+        )),
+        Ok(())
+    );
+
+    for (discussion_url, attachments) in [
+        ("forum.dao.org/t/proposal-12", vec![]),
+        ("javascript://alert(1)", vec![]),
+        ("https://", vec![]),
+        ("https://forum.dao.org/t/proposal 12", vec![]),
+        ("https://forum.dao.org", vec!["ftp://files.dao.org/doc.pdf"]),
+        (
+            "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            vec![],
+        ),
+    ] {
+        assert!(matches!(
+            validate_proposal_metadata(&metadata(discussion_url, attachments)),
+            Err(InvalidArgument { .. })
+        ));
+    }
+
+    let too_many_attachments = vec!["https://dao.org/doc"; 11];
+    assert!(matches!(
+        validate_proposal_metadata(&metadata("https://dao.org", too_many_attachments)),
+        Err(InvalidArgument { .. })
+    ));
+
+    let too_long_url = format!("https://dao.org/{}", "a".repeat(512));
+    assert!(matches!(
+        validate_proposal_metadata(&metadata(&too_long_url, vec![])),
+        Err(InvalidArgument { .. })
+    ));
+}
+
+#[test]
+fn gov_config_update_checks_minimum_deposit_against_current_dao_type() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+    GOV_CONFIG
+        .save(deps.as_mut().storage, &gov_config())
+        .unwrap();
+
+    let mut msg = no_gov_config_changes();
+    msg.minimum_deposit = Change(Some(Uint128::new(2)));
+
+    // NFT DAOs take deposits in NFTs, with the minimum deposit being the number of NFTs
+    for (dao_type, expected) in [
+        (DaoType::Multisig, Err(MinimumDepositNotAllowed {})),
+        (DaoType::Nft, Ok(())),
+    ] {
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == "enterprise" => {
+                let _: EnterpriseQueryMsg = from_json(msg).unwrap();
+                SystemResult::Ok(ContractResult::Ok(dao_info(dao_type.clone())))
+            }
+            _ => panic!("unexpected query"),
+        });
+
+        assert_eq!(
+            validate_proposal_action(deps.as_ref(), &ProposalAction::UpdateGovConfig(msg.clone())),
+            expected
+        );
+    }
+}
+
+fn dao_info(dao_type: DaoType) -> Binary {
+    Binary::from(
+        format!(
+            r#"{{
+                "creation_date": "0",
+                "metadata": {{
+                    "name": "DAO",
+                    "description": null,
+                    "logo": "none",
+                    "socials": {{
+                        "github_username": null,
+                        "discord_username": null,
+                        "twitter_username": null,
+                        "telegram_username": null
+                    }}
+                }},
+                "dao_type": {},
+                "dao_version": {{ "major": 1, "minor": 2, "patch": 0 }}
+            }}"#,
+            to_json_string(&dao_type).unwrap()
+        )
+        .into_bytes(),
+    )
+}
+
+#[test]
+fn resolved_proposal_voters_are_rewarded_by_vote_weight() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+    PROPOSAL_INFOS
+        .save(
+            deps.as_mut().storage,
+            3,
+            &ProposalInfo {
+                proposal_type: ProposalType::General,
+                executed_at: None,
+                earliest_execution: None,
+                proposal_deposit: None,
+                proposal_actions: vec![],
+                execution_atomicity: None,
+                execution_condition: None,
+                execution_budget: None,
+                metadata: None,
+            },
+        )
+        .unwrap();
+
+    let mock_governance = |querier: &mut MockQuerier, status: PollStatus, votes: Vec<Vote>| {
+        querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == "enterprise" => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&component_contracts()).unwrap(),
+                ))
+            }
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == "governance" => {
+                let response = match from_json(msg).unwrap() {
+                    GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                        poll: Poll {
+                            id: params.poll_id,
+                            proposer: Addr::unchecked("proposer"),
+                            deposit_amount: 0,
+                            label: "proposal".to_string(),
+                            description: String::new(),
+                            scheme: VotingScheme::CoinVoting,
+                            status: status.clone(),
+                            started_at: Timestamp::from_seconds(0),
+                            ends_at: Timestamp::from_seconds(100),
+                            quorum: Decimal::percent(30),
+                            threshold: Decimal::percent(50),
+                            veto_threshold: None,
+                            minimum_yes_votes: None,
+                            results: BTreeMap::new(),
+                        },
+                    }),
+                    GovernanceQueryMsg::PollVoters(params) => {
+                        assert_eq!(params.poll_id, 3);
+                        to_json_binary(&PollVotersResponse {
+                            votes: votes.clone(),
+                        })
+                    }
+                    _ => panic!("unexpected query"),
+                };
+                SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+            }
+            _ => panic!("unexpected query"),
+        });
+    };
+
+    let reward = |proposal_id, amount: u128| DistributeToVotersMsg {
+        proposal_id,
+        asset: AssetUnchecked::native("uluna", amount),
+    };
+    let votes = vec![
+        Vote::new(3, Addr::unchecked("voter1"), VoteOutcome::Yes, 30),
+        Vote::new(3, Addr::unchecked("voter2"), VoteOutcome::No, 70),
+        Vote::new(3, Addr::unchecked("voter3"), VoteOutcome::Abstain, 0),
+    ];
+    let passed = PollStatus::Passed {
+        outcome: 0,
+        count: Uint128::new(30),
+    };
+
+    mock_governance(&mut deps.querier, passed.clone(), votes.clone());
+
+    assert!(validate_distribute_to_voters(deps.as_ref(), &reward(3, 101)).is_ok());
+    assert_eq!(
+        validate_distribute_to_voters(deps.as_ref(), &reward(4, 101)),
+        Err(NoSuchProposal)
+    );
+    assert!(validate_distribute_to_voters(deps.as_ref(), &reward(3, 0)).is_err());
+
+    // shares are rounded down, and voters with no weight get nothing
+    let msgs = distribute_to_voters_msgs(deps.as_ref(), reward(3, 101))
+        .unwrap()
+        .into_iter()
+        .map(|msg| from_json(msg).unwrap())
+        .collect::<Vec<CosmosMsg>>();
+    assert_eq!(
+        msgs,
+        vec![
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "voter1".to_string(),
+                amount: coins(30, "uluna"),
+            }),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "voter2".to_string(),
+                amount: coins(70, "uluna"),
+            }),
+        ]
+    );
+
+    mock_governance(
+        &mut deps.querier,
+        PollStatus::InProgress {
+            ends_at: Timestamp::from_seconds(100),
+        },
+        votes,
+    );
+    assert!(validate_distribute_to_voters(deps.as_ref(), &reward(3, 101)).is_err());
+
+    mock_governance(&mut deps.querier, passed, vec![]);
+    assert!(validate_distribute_to_voters(deps.as_ref(), &reward(3, 101)).is_err());
+}
+
+#[test]
+fn members_are_addresses_with_nonzero_membership_weight() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, .. } if contract_addr == "enterprise" => {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&component_contracts()).unwrap(),
+            ))
+        }
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "membership" => {
+            match from_json(msg).unwrap() {
+                MembershipQueryMsg::UserWeight(params) => {
+                    let weight = if params.user == "member" { 12 } else { 0 };
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&UserWeightResponse {
+                            user: Addr::unchecked(params.user),
+                            weight: Uint128::new(weight),
+                        })
+                        .unwrap(),
+                    ))
+                }
+                _ => panic!("unexpected query"),
+            }
+        }
+        _ => panic!("unexpected query"),
+    });
+
+    let is_member = |address: &str| -> IsMemberResponse {
+        from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::IsMember(IsMemberParams {
+                    address: address.to_string(),
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    assert_eq!(
+        is_member("member"),
+        IsMemberResponse {
+            is_member: true,
+            weight: Uint128::new(12),
+        }
+    );
+    assert_eq!(
+        is_member("outsider"),
+        IsMemberResponse {
+            is_member: false,
+            weight: Uint128::zero(),
+        }
+    );
+}
+
+#[test]
+fn pending_council_proposals_are_paged_with_a_bounded_scan() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+    GOV_CONFIG
+        .save(deps.as_mut().storage, &gov_config())
+        .unwrap();
+
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    let mut executed_council_proposal = proposal_info(ProposalType::Council);
+    executed_council_proposal.executed_at = Some(mock_env().block);
+
+    for (id, info) in [
+        (1, proposal_info(ProposalType::General)),
+        (2, executed_council_proposal),
+        (3, proposal_info(ProposalType::Council)),
+        (4, proposal_info(ProposalType::Council)),
+    ] {
+        PROPOSAL_INFOS
+            .save(deps.as_mut().storage, id, &info)
+            .unwrap();
+    }
+
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => {
+                let status = if params.poll_id == 4 {
+                    PollStatus::Rejected {
+                        reason: QuorumNotReached,
+                    }
+                } else {
+                    PollStatus::InProgress { ends_at }
+                };
+                to_json_binary(&PollResponse {
+                    poll: poll(params.poll_id, status, ends_at),
+                })
+                .unwrap()
+            }
+            GovernanceQueryMsg::SimulateEndPollStatus { .. } => {
+                to_json_binary(&PollStatusResponse {
+                    status: PollStatus::InProgress { ends_at },
+                    ends_at,
+                    results: BTreeMap::new(),
+                })
+                .unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        "council_membership" => to_json_binary(&TotalWeightResponse {
+            total_weight: Uint128::new(10),
+        })
+        .unwrap(),
+        _ => panic!("unexpected query"),
+    });
+
+    let pending_council_proposals = |start_after: Option<u64>| {
+        from_json::<PendingCouncilProposalsResponse>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PendingCouncilProposals(PendingCouncilProposalsParams {
+                    start_after,
+                    limit: Some(1),
+                    viewer: None,
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    let page = pending_council_proposals(None);
+    assert_eq!(
+        page.proposals
+            .iter()
+            .map(|tally| tally.proposal.proposal.id)
+            .collect::<Vec<u64>>(),
+        vec![3]
+    );
+    assert_eq!(page.next_start_after, Some(3));
+
+    // the remaining proposal is no longer in progress
+    let page = pending_council_proposals(page.next_start_after);
+    assert!(page.proposals.is_empty());
+    assert_eq!(page.next_start_after, None);
+}
+
+#[test]
+fn multisig_membership_edits_fail_instead_of_overflowing_total_weight() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+
+    mock_wasm(&mut deps.querier, |contract, msg| match contract {
+        "enterprise" => dao_info(DaoType::Multisig),
+        "membership" => match from_json(msg).unwrap() {
+            MultisigMembershipQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::MAX - Uint128::new(5),
+            })
+            .unwrap(),
+            MultisigMembershipQueryMsg::UserWeight(params) => {
+                let weight = if params.user == "member" { 10 } else { 0 };
+                to_json_binary(&UserWeightResponse {
+                    user: Addr::unchecked(params.user),
+                    weight: Uint128::new(weight),
+                })
+                .unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let modify_membership = |user: &str, weight: u128| {
+        ProposalAction::ModifyMultisigMembership(ModifyMultisigMembershipMsg {
+            edit_members: vec![MultisigUserWeight {
+                user: user.to_string(),
+                weight: Uint128::new(weight),
+            }],
+        })
+    };
+
+    assert!(validate_proposal_action(deps.as_ref(), &modify_membership("member", 15)).is_ok());
+    // adding more weight than fits into the total fails instead of panicking
+    assert!(validate_proposal_action(deps.as_ref(), &modify_membership("newcomer", 10)).is_err());
+}
+
+#[test]
+fn late_joiners_can_vote_unless_the_dao_restricts_them() {
+    let mut deps = mock_dependencies();
+    ENTERPRISE_CONTRACT
+        .save(deps.as_mut().storage, &Addr::unchecked("enterprise"))
+        .unwrap();
+    STATE
+        .save(
+            deps.as_mut().storage,
+            &State {
+                proposal_being_created: None,
+                proposal_being_executed: None,
+                proposal_being_voted_on: None,
+            },
+        )
+        .unwrap();
+    PROPOSAL_INFOS
+        .save(
+            deps.as_mut().storage,
+            1,
+            &proposal_info(ProposalType::General),
+        )
+        .unwrap();
+    // the proposal started at 0, long before the voter joined
+    MEMBER_JOINED_AT
+        .save(
+            deps.as_mut().storage,
+            Addr::unchecked("late_joiner"),
+            &mock_env().block.time,
+        )
+        .unwrap();
+
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "enterprise" => to_json_binary(&IsRestrictedUserResponse {
+            is_restricted: false,
+        })
+        .unwrap(),
+        "membership" => match from_json(msg).unwrap() {
+            MembershipQueryMsg::UserWeight(params) => to_json_binary(&UserWeightResponse {
+                user: Addr::unchecked(params.user),
+                weight: Uint128::new(10),
+            })
+            .unwrap(),
+            MembershipQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(100),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                poll: poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at),
+            })
+            .unwrap(),
+            GovernanceQueryMsg::PollVoter(_) => {
+                to_json_binary(&PollVoterResponse { vote: None }).unwrap()
+            }
+            GovernanceQueryMsg::SimulateEndPollStatus { .. } => {
+                to_json_binary(&PollStatusResponse {
+                    status: PollStatus::InProgress { ends_at },
+                    ends_at,
+                    results: BTreeMap::new(),
+                })
+                .unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let mut cast_vote = |restrict_late_joiner_votes: Option<bool>| {
+        GOV_CONFIG
+            .save(
+                deps.as_mut().storage,
+                &GovConfig {
+                    restrict_late_joiner_votes,
+                    ..gov_config()
+                },
+            )
+            .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("late_joiner", &[]),
+            ExecuteMsg::CastVote(CastVoteMsg {
+                proposal_id: 1,
+                outcome: VoteOutcome::Yes,
+            }),
+        )
+    };
+
+    assert!(cast_vote(None).is_ok());
+    assert_eq!(cast_vote(Some(true)), Err(Unauthorized));
+}
+
+#[test]
+fn council_members_vote_with_their_configured_weights() {
+    let mut deps = mock_dependencies();
+
+    let council = |weights: Vec<u128>| DaoCouncilSpec {
+        members: vec!["member1".to_string(), "member2".to_string()],
+        weights: Some(weights.into_iter().map(Uint128::new).collect()),
+        quorum: Decimal::percent(50),
+        threshold: Decimal::percent(50),
+        allowed_proposal_action_types: None,
+    };
+    let instantiate_msg = |council_gov_config| InstantiateMsg {
+        enterprise_contract: "enterprise".to_string(),
+        dao_type: DaoType::Token,
+        gov_config: gov_config(),
+        council_gov_config: Some(council_gov_config),
+        proposal_infos: Some(vec![(1, proposal_info(ProposalType::Council))]),
+        initial_cross_chain_treasuries: None,
+    };
+
+    // every member needs exactly one non-zero weight
+    for weights in [vec![1], vec![1, 0]] {
+        assert!(instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("enterprise", &[]),
+            instantiate_msg(council(weights)),
+        )
+        .is_err());
+    }
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        instantiate_msg(council(vec![1, 3])),
+    )
+    .unwrap();
+
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "enterprise" => to_json_binary(&IsRestrictedUserResponse {
+            is_restricted: false,
+        })
+        .unwrap(),
+        "council_membership" => match from_json(msg).unwrap() {
+            MultisigMembershipQueryMsg::UserWeight(params) => {
+                let weight = if params.user == "member2" { 3 } else { 1 };
+                to_json_binary(&UserWeightResponse {
+                    user: Addr::unchecked(params.user),
+                    weight: Uint128::new(weight),
+                })
+                .unwrap()
+            }
+            MultisigMembershipQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(4),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "governance" => to_json_binary(&PollStatusResponse {
+            status: PollStatus::InProgress { ends_at },
+            ends_at,
+            results: BTreeMap::new(),
+        })
+        .unwrap(),
+        _ => panic!("unexpected query"),
+    });
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("member2", &[]),
+        ExecuteMsg::CastCouncilVote(CastVoteMsg {
+            proposal_id: 1,
+            outcome: VoteOutcome::Yes,
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "governance".to_string(),
+            msg: to_json_binary(&GovernanceExecuteMsg::CastVote(CastVoteParams {
+                poll_id: 1u64.into(),
+                outcome: VoteOutcome::Yes,
+                voter: "member2".to_string(),
+                amount: Uint128::new(3),
+            }))
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
+#[test]
+fn enterprise_factory_is_only_replaced_by_a_factory() {
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        None,
+        vec![ProposalAction::UpdateEnterpriseFactory(
+            UpdateEnterpriseFactoryMsg {
+                new_factory: "new_factory".to_string(),
+            },
+        )],
+    );
+
+    mock_wasm(&mut deps.querier, |contract, _| match contract {
+        "new_factory" => to_json_binary(&"not a factory").unwrap(),
+        _ => panic!("unexpected query"),
+    });
+
+    assert_eq!(
+        execute_proposal_actions(deps.as_mut()).unwrap_err(),
+        NotAnEnterpriseFactory {
+            address: "new_factory".to_string()
+        }
+    );
+
+    mock_wasm(&mut deps.querier, |contract, msg| match contract {
+        "new_factory" => match from_json(msg).unwrap() {
+            FactoryQueryMsg::Config {} => to_json_binary(&FactoryConfigResponse {
+                config: FactoryConfig {
+                    admin: Addr::unchecked("admin"),
+                    enterprise_versioning: Addr::unchecked("versioning"),
+                    cw20_code_id: 1,
+                    cw721_code_id: 2,
+                },
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let response = execute_proposal_actions(deps.as_mut()).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "enterprise".to_string(),
+            msg: to_json_binary(&EnterpriseExecuteMsg::UpdateConfig(UpdateConfigMsg {
+                new_versioning_contract: NoChange,
+                new_factory_contract: Change("new_factory".to_string()),
+            }))
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
+#[test]
+fn gov_config_update_validates_addresses_before_storing_them() {
+    let update = |treasury_address: &str| {
+        ProposalAction::UpdateGovConfig(UpdateGovConfigMsg {
+            treasury_address: Change(Some(treasury_address.to_string())),
+            accepted_deposit_assets: Change(Some(vec![AcceptedDepositAssetUnchecked {
+                asset: AssetInfoUnchecked::cw20("deposit_token"),
+                minimum_amount: Uint128::new(10),
+            }])),
+            voting_extension: Change(Some(VotingExtensionConfigUnchecked {
+                max_total_extension: 100,
+                extenders: vec!["extender".to_string()],
+            })),
+            proposal_notifier: Change(Some("notifier".to_string())),
+            ..no_gov_config_changes()
+        })
+    };
+
+    for (treasury_address, is_valid) in [("Forfeits", false), ("forfeits", true)] {
+        let mut deps = mock_dependencies();
+        instantiate_with_proposal_actions(deps.as_mut(), None, vec![update(treasury_address)]);
+        mock_wasm(&mut deps.querier, |contract, _| match contract {
+            "enterprise" => dao_info(DaoType::Token),
+            _ => panic!("unexpected query"),
+        });
+
+        let result = execute_proposal_actions(deps.as_mut());
+        if !is_valid {
+            assert!(matches!(result, Err(Std(_))));
+            continue;
+        }
+        result.unwrap();
+
+        let response: GovConfigResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::GovConfig {}).unwrap()).unwrap();
+        let gov_config = response.gov_config;
+        assert_eq!(
+            gov_config.treasury_address,
+            Some(Addr::unchecked("forfeits"))
+        );
+        assert_eq!(
+            gov_config.accepted_deposit_assets,
+            Some(vec![AcceptedDepositAsset {
+                asset: AssetInfo::cw20(Addr::unchecked("deposit_token")),
+                minimum_amount: Uint128::new(10),
+            }])
+        );
+        assert_eq!(
+            gov_config.voting_extension,
+            Some(VotingExtensionConfig {
+                max_total_extension: 100,
+                extenders: vec![Addr::unchecked("extender")],
+            })
+        );
+        assert_eq!(
+            gov_config.proposal_notifier,
+            Some(Addr::unchecked("notifier"))
+        );
+    }
+}
+
+#[test]
+fn best_effort_action_outcomes_are_recorded_for_the_action_each_reply_identifies() {
+    let mut deps = mock_dependencies();
+
+    let best_effort_proposal = ProposalInfo {
+        proposal_actions: vec![
+            ProposalAction::SetDistributorClaimsPaused(SetDistributorClaimsPausedMsg {
+                paused: true,
+            }),
+            ProposalAction::SetDistributorClaimsPaused(SetDistributorClaimsPausedMsg {
+                paused: false,
+            }),
+        ],
+        execution_atomicity: Some(ExecutionAtomicity::BestEffort),
+        ..proposal_info(ProposalType::General)
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: gov_config(),
+            council_gov_config: None,
+            proposal_infos: Some(vec![
+                (1, best_effort_proposal.clone()),
+                (2, best_effort_proposal),
+            ]),
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    let ends_at = mock_env().block.time.minus_seconds(10);
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "enterprise" => to_json_binary(&IsRestrictedUserResponse {
+            is_restricted: false,
+        })
+        .unwrap(),
+        "membership" => to_json_binary(&TotalWeightResponse {
+            total_weight: Uint128::new(100),
+        })
+        .unwrap(),
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                poll: poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at),
+            })
+            .unwrap(),
+            GovernanceQueryMsg::PollStatus { .. } => to_json_binary(&PollStatusResponse {
+                status: PollStatus::Passed {
+                    outcome: VoteOutcome::Yes as u8,
+                    count: Uint128::new(100),
+                },
+                ends_at,
+                results: BTreeMap::new(),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    // resolves the proposal, returning the reply IDs of its individually executed actions
+    let mut resolve_proposal = |proposal_id: u64| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+        )
+        .unwrap();
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: END_POLL_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap()
+        .messages
+        .into_iter()
+        .map(|submsg| submsg.id)
+        .collect::<Vec<u64>>()
+    };
+    let proposal1_reply_ids = resolve_proposal(1);
+    let proposal2_reply_ids = resolve_proposal(2);
+
+    // replies of the two proposals' actions arrive interleaved and out of order
+    for (reply_id, error) in [
+        (proposal2_reply_ids[1], Some("proposal 2 action 1 failed")),
+        (proposal1_reply_ids[0], None),
+        (proposal2_reply_ids[0], None),
+        (proposal1_reply_ids[1], Some("proposal 1 action 1 failed")),
+    ] {
+        let result = match error {
+            Some(error) => SubMsgResult::Err(error.to_string()),
+            None => SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        };
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: reply_id,
+                result,
+            },
+        )
+        .unwrap();
+    }
+
+    let outcomes = |proposal_id: u64| {
+        from_json::<ProposalActionOutcomesResponse>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ProposalActionOutcomes(ProposalParams {
+                    proposal_id,
+                    viewer: None,
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .outcomes
+    };
+    for proposal_id in [1, 2] {
+        assert_eq!(
+            outcomes(proposal_id),
+            vec![
+                ProposalActionOutcome {
+                    action_index: 0,
+                    success: true,
+                    error: None,
+                },
+                ProposalActionOutcome {
+                    action_index: 1,
+                    success: false,
+                    error: Some(format!("proposal {} action 1 failed", proposal_id)),
+                },
+            ]
+        );
+    }
+}
+
+#[test]
+fn council_permissions_are_replaced_for_an_existing_council() {
+    let update_permissions = |allowed_proposal_action_types: Vec<ProposalActionType>| {
+        vec![ProposalAction::UpdateCouncilPermissions(
+            UpdateCouncilPermissionsMsg {
+                allowed_proposal_action_types,
+            },
+        )]
+    };
+    let council = DaoCouncilSpec {
+        members: vec!["member1".to_string(), "member2".to_string()],
+        weights: None,
+        quorum: Decimal::percent(50),
+        threshold: Decimal::percent(60),
+        allowed_proposal_action_types: Some(vec![ProposalActionType::UpdateMetadata]),
+    };
+
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        None,
+        update_permissions(vec![ProposalActionType::UpdateMetadata]),
+    );
+    assert_eq!(
+        execute_proposal_actions(deps.as_mut()).unwrap_err(),
+        NoDaoCouncil
+    );
+
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        Some(council.clone()),
+        update_permissions(vec![ProposalActionType::UpdateEnterpriseFactory]),
+    );
+    assert_eq!(
+        execute_proposal_actions(deps.as_mut()).unwrap_err(),
+        UnsupportedCouncilProposalAction {
+            action: ProposalActionType::UpdateEnterpriseFactory
+        }
+    );
+
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        Some(council),
+        update_permissions(vec![
+            ProposalActionType::UpdateMetadata,
+            ProposalActionType::UpgradeDao,
+        ]),
+    );
+    mock_wasm(&mut deps.querier, |_, _| panic!("unexpected query"));
+    execute_proposal_actions(deps.as_mut()).unwrap();
+
+    let response: GovConfigResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::GovConfig {}).unwrap()).unwrap();
+    assert_eq!(
+        response.council_gov_config,
+        Some(CouncilGovConfig {
+            allowed_proposal_action_types: vec![
+                ProposalActionType::UpdateMetadata,
+                ProposalActionType::UpgradeDao,
+            ],
+            quorum: Decimal::percent(50),
+            threshold: Decimal::percent(60),
+        })
+    );
+}
+
+#[test]
+fn can_execute_action_applies_the_proposal_creation_checks() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                proposal_creation_policy: Some(ProposalCreationPolicy::Allowlist(vec![
+                    Addr::unchecked("proposer"),
+                ])),
+                ..gov_config()
+            },
+            council_gov_config: Some(DaoCouncilSpec {
+                members: vec!["member1".to_string()],
+                weights: None,
+                quorum: Decimal::percent(50),
+                threshold: Decimal::percent(50),
+                allowed_proposal_action_types: Some(vec![ProposalActionType::UpdateMetadata]),
+            }),
+            proposal_infos: None,
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    mock_wasm(&mut deps.querier, |contract, msg| match contract {
+        "enterprise" => to_json_binary(&IsRestrictedUserResponse {
+            is_restricted: false,
+        })
+        .unwrap(),
+        "membership" | "council_membership" => match from_json(msg).unwrap() {
+            MembershipQueryMsg::UserWeight(params) => {
+                let weight = match (contract, params.user.as_str()) {
+                    ("membership", "proposer") => 5,
+                    ("council_membership", "member1") => 1,
+                    _ => 0,
+                };
+                to_json_binary(&UserWeightResponse {
+                    user: Addr::unchecked(params.user),
+                    weight: Uint128::new(weight),
+                })
+                .unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let can_execute = |caller: &str, action_type: ProposalActionType| {
+        from_json::<CanExecuteActionResponse>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::CanExecuteAction(CanExecuteActionParams {
+                    caller: caller.to_string(),
+                    action_type,
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    assert_eq!(
+        can_execute("proposer", ProposalActionType::UpgradeDao),
+        CanExecuteActionResponse {
+            can_execute: true,
+            reason: "Can be proposed through a general proposal".to_string(),
+        }
+    );
+    assert_eq!(
+        can_execute("member1", ProposalActionType::UpdateMetadata),
+        CanExecuteActionResponse {
+            can_execute: true,
+            reason: "Can be proposed through a council proposal".to_string(),
+        }
+    );
+    assert_eq!(
+        can_execute("member1", ProposalActionType::UpgradeDao),
+        CanExecuteActionResponse {
+            can_execute: false,
+            reason: format!(
+                "{}; {}",
+                NotAuthorizedToPropose,
+                UnsupportedCouncilProposalAction {
+                    action: ProposalActionType::UpgradeDao
+                }
+            ),
+        }
+    );
+    assert_eq!(
+        can_execute("stranger", ProposalActionType::UpdateMetadata),
+        CanExecuteActionResponse {
+            can_execute: false,
+            reason: format!("{}; {}", NotAuthorizedToPropose, Unauthorized),
+        }
+    );
+}
+
+#[test]
+fn spending_allowance_is_granted_by_the_treasury_until_its_expiry() {
+    let grant = |asset: AssetInfoUnchecked, expiry: Expiration| {
+        ProposalAction::GrantSpendingAllowance(GrantSpendingAllowanceMsg {
+            grantee: "grantee".to_string(),
+            asset,
+            amount: Uint128::new(100),
+            expiry,
+        })
+    };
+    let expiry = Expiration::AtHeight(mock_env().block.height + 10);
+
+    for (action, error) in [
+        (
+            grant(AssetInfoUnchecked::native("uluna"), expiry),
+            NativeSpendingAllowanceNotSupported,
+        ),
+        (
+            grant(
+                AssetInfoUnchecked::cw20("token"),
+                Expiration::AtHeight(mock_env().block.height),
+            ),
+            InvalidArgument {
+                msg: "Spending allowance expiry must be in the future".to_string(),
+            },
+        ),
+    ] {
+        let mut deps = mock_dependencies();
+        instantiate_with_proposal_actions(deps.as_mut(), None, vec![action]);
+        assert_eq!(execute_proposal_actions(deps.as_mut()).unwrap_err(), error);
+    }
+
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        None,
+        vec![grant(AssetInfoUnchecked::cw20("token"), expiry)],
+    );
+    mock_wasm(&mut deps.querier, |_, _| panic!("unexpected query"));
+
+    let response = execute_proposal_actions(deps.as_mut()).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        treasury_cosmos_msg(
+            WasmMsg::Execute {
+                contract_addr: "token".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: "grantee".to_string(),
+                    amount: Uint128::new(100),
+                    expires: Some(expiry),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()
+        )
+    );
+}
+
+#[test]
+fn spending_allowance_is_revoked_by_decreasing_it_by_its_whole_amount() {
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        None,
+        vec![ProposalAction::RevokeSpendingAllowance(
+            RevokeSpendingAllowanceMsg {
+                grantee: "grantee".to_string(),
+                asset: AssetInfoUnchecked::cw20("token"),
+            },
+        )],
+    );
+
+    let mock_allowance = |querier: &mut MockQuerier, allowance: u128| {
+        mock_wasm(querier, move |contract, msg| match contract {
+            "token" => match from_json(msg).unwrap() {
+                Cw20QueryMsg::Allowance { owner, spender } => {
+                    assert_eq!(owner, "treasury");
+                    assert_eq!(spender, "grantee");
+                    to_json_binary(&AllowanceResponse {
+                        allowance: Uint128::new(allowance),
+                        expires: Expiration::Never {},
+                    })
+                    .unwrap()
+                }
+                _ => panic!("unexpected query"),
+            },
+            _ => panic!("unexpected query"),
+        })
+    };
+
+    mock_allowance(&mut deps.querier, 0);
+    assert_eq!(
+        execute_proposal_actions(deps.as_mut()).unwrap_err(),
+        NoAllowanceToRevoke
+    );
+
+    mock_allowance(&mut deps.querier, 70);
+    let response = execute_proposal_actions(deps.as_mut()).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        treasury_cosmos_msg(
+            WasmMsg::Execute {
+                contract_addr: "token".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::DecreaseAllowance {
+                    spender: "grantee".to_string(),
+                    amount: Uint128::new(70),
+                    expires: None,
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()
+        )
+    );
+}
+
+#[test]
+fn passing_council_vote_auto_executes_the_proposal_if_enabled() {
+    // returns the submessages added when a council vote makes proposal 1 pass
+    let vote_to_pass = |auto_execute_on_pass: Option<bool>| {
+        let mut deps = mock_dependencies();
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("enterprise", &[]),
+            InstantiateMsg {
+                enterprise_contract: "enterprise".to_string(),
+                dao_type: DaoType::Token,
+                gov_config: GovConfig {
+                    auto_execute_on_pass,
+                    ..gov_config()
+                },
+                council_gov_config: Some(DaoCouncilSpec {
+                    members: vec!["member".to_string()],
+                    weights: None,
+                    quorum: Decimal::percent(50),
+                    threshold: Decimal::percent(50),
+                    allowed_proposal_action_types: None,
+                }),
+                proposal_infos: Some(vec![(1, proposal_info(ProposalType::Council))]),
+                initial_cross_chain_treasuries: None,
+            },
+        )
+        .unwrap();
+
+        let ends_at = mock_env().block.time.plus_seconds(1000);
+        mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+            "enterprise" => to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+            "council_membership" => match from_json(msg).unwrap() {
+                MultisigMembershipQueryMsg::UserWeight(params) => {
+                    to_json_binary(&UserWeightResponse {
+                        user: Addr::unchecked(params.user),
+                        weight: Uint128::one(),
+                    })
+                    .unwrap()
+                }
+                MultisigMembershipQueryMsg::TotalWeight(_) => {
+                    to_json_binary(&TotalWeightResponse {
+                        total_weight: Uint128::one(),
+                    })
+                    .unwrap()
+                }
+                _ => panic!("unexpected query"),
+            },
+            "governance" => to_json_binary(&PollStatusResponse {
+                status: PollStatus::Passed {
+                    outcome: VoteOutcome::Yes as u8,
+                    count: Uint128::one(),
+                },
+                ends_at,
+                results: BTreeMap::new(),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        });
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("member", &[]),
+            ExecuteMsg::CastCouncilVote(CastVoteMsg {
+                proposal_id: 1,
+                outcome: VoteOutcome::Yes,
+            }),
+        )
+        .unwrap();
+
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: CAST_VOTE_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap()
+        .messages
+    };
+
+    assert!(vote_to_pass(None).is_empty());
+    assert!(vote_to_pass(Some(false)).is_empty());
+
+    let submsgs = vote_to_pass(Some(true));
+    assert_eq!(submsgs.len(), 1);
+    assert_eq!(submsgs[0].id, AUTO_EXECUTE_PROPOSAL_REPLY_ID);
+    assert_eq!(submsgs[0].reply_on, ReplyOn::Error);
+    assert_eq!(
+        submsgs[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: mock_env().contract.address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::ExecuteProposal(ExecuteProposalMsg {
+                proposal_id: 1
+            }))
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    // a failed auto-execution is only reported, so that the vote itself is kept
+    let response = reply(
+        mock_dependencies().as_mut(),
+        mock_env(),
+        Reply {
+            id: AUTO_EXECUTE_PROPOSAL_REPLY_ID,
+            result: SubMsgResult::Err("execution failed".to_string()),
+        },
+    )
+    .unwrap();
+    assert!(response
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "auto_execution_error" && attr.value == "execution failed"));
+}
+
+#[test]
+fn action_type_cooldown_applies_only_to_proposals_with_that_action_type() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                action_type_cooldowns: Some(vec![ActionTypeCooldown {
+                    action_type: ProposalActionType::SetDistributorClaimsPaused,
+                    cooldown: 100,
+                }]),
+                ..gov_config()
+            },
+            council_gov_config: None,
+            proposal_infos: None,
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+    mock_token_dao(&mut deps.querier);
+
+    let pause_claims =
+        ProposalAction::SetDistributorClaimsPaused(SetDistributorClaimsPausedMsg { paused: true });
+    let update_metadata = ProposalAction::UpdateMetadata(UpdateMetadataMsg {
+        name: NoChange,
+        description: NoChange,
+        logo: NoChange,
+        github_username: NoChange,
+        discord_username: NoChange,
+        twitter_username: NoChange,
+        telegram_username: NoChange,
+    });
+    let mut create_proposal = |env: Env, proposal_action: &ProposalAction| {
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("proposer", &[]),
+            ExecuteMsg::CreateProposal(create_proposal_msg(vec![proposal_action.clone()])),
+        )
+    };
+
+    create_proposal(mock_env(), &pause_claims).unwrap();
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(99);
+    assert_eq!(
+        create_proposal(env.clone(), &pause_claims).unwrap_err(),
+        ActionTypeCooldownActive {
+            action_type: ProposalActionType::SetDistributorClaimsPaused,
+            next_allowed_at: mock_env().block.time.plus_seconds(100),
+        }
+    );
+
+    // other action types are not affected by the cooldown
+    create_proposal(env.clone(), &update_metadata).unwrap();
+
+    env.block.time = env.block.time.plus_seconds(1);
+    create_proposal(env, &pause_claims).unwrap();
+}
+
+#[test]
+fn supported_proposal_actions_list_every_action_type_with_its_council_eligibility() {
+    let deps = mock_dependencies();
+
+    let response: SupportedProposalActionsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::SupportedProposalActions {},
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response
+            .proposal_actions
+            .iter()
+            .map(|action| action.action_type.clone())
+            .collect::<Vec<ProposalActionType>>(),
+        ProposalActionType::iter().collect::<Vec<ProposalActionType>>()
+    );
+
+    let council_allowed = |action_type: ProposalActionType| {
+        response
+            .proposal_actions
+            .iter()
+            .find(|action| action.action_type == action_type)
+            .unwrap()
+            .council_allowed
+    };
+    assert!(council_allowed(ProposalActionType::UpdateMetadata));
+    assert!(council_allowed(ProposalActionType::UpgradeDao));
+    assert!(!council_allowed(ProposalActionType::UpdateGovConfig));
+    assert!(!council_allowed(ProposalActionType::ExecuteTreasuryMsgs));
+}
+
+#[test]
+fn treasury_distribution_fails_unless_the_distributor_receives_the_assets() {
+    // distributor holds 50 uluna before the distribution and takes a 10% fee
+    for (distributor_balance_after, is_received) in [(140, true), (139, false)] {
+        let mut deps = mock_dependencies();
+        instantiate_with_proposal_actions(
+            deps.as_mut(),
+            None,
+            vec![ProposalAction::DistributeFromTreasury(
+                DistributeFromTreasuryMsg {
+                    assets: vec![AssetUnchecked::native("uluna", 100u128)],
+                },
+            )],
+        );
+        deps.querier.update_balance("treasury", coins(100, "uluna"));
+        deps.querier
+            .update_balance("funds_distributor", coins(50, "uluna"));
+        mock_wasm(&mut deps.querier, |contract, _| match contract {
+            "funds_distributor" => to_json_binary(&DistributionFeeResponse {
+                distribution_fee: Some(DistributionFee {
+                    fee_bps: 1000,
+                    recipient: "fee_recipient".to_string(),
+                }),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        });
+
+        let response = execute_proposal_actions(deps.as_mut()).unwrap();
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(response.messages[0].id, DISTRIBUTE_FROM_TREASURY_REPLY_ID);
+        assert_eq!(response.messages[0].reply_on, ReplyOn::Success);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "treasury".to_string(),
+                msg: to_json_binary(&TreasuryExecuteMsg::DistributeFunds(
+                    TreasuryDistributeFundsMsg {
+                        funds: vec![AssetUnchecked::native("uluna", 100u128)],
+                        funds_distributor_contract: "funds_distributor".to_string(),
+                    }
+                ))
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+
+        deps.querier.update_balance("treasury", vec![]);
+        deps.querier.update_balance(
+            "funds_distributor",
+            coins(distributor_balance_after, "uluna"),
+        );
+
+        let result = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: DISTRIBUTE_FROM_TREASURY_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        );
+
+        if is_received {
+            result.unwrap();
+        } else {
+            assert_eq!(
+                result.unwrap_err(),
+                DistributionNotReceived {
+                    asset: "native:uluna".to_string(),
+                    balance: Uint128::new(139),
+                    expected: Uint128::new(140),
+                }
+            );
+        }
+    }
+}
+
+#[test]
+fn expiring_proposals_are_filtered_before_limiting_and_limit_is_clamped() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: gov_config(),
+            council_gov_config: None,
+            proposal_infos: Some(
+                (1..=200)
+                    .map(|id| (id, proposal_info(ProposalType::General)))
+                    .collect(),
+            ),
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    // every third proposal only ends after the queried window
+    let now = mock_env().block.time;
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "membership" => to_json_binary(&TotalWeightResponse {
+            total_weight: Uint128::new(100),
+        })
+        .unwrap(),
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Polls(params) => {
+                let start_after = params.pagination.start_after.map_or(0, |id| id.u64());
+                let limit = params.pagination.limit.unwrap() as usize;
+                let polls = (start_after + 1..=200)
+                    .take(limit)
+                    .map(|id| {
+                        let ends_at = now.plus_seconds(if id % 3 == 0 { 1000 } else { 50 });
+                        poll(id, PollStatus::InProgress { ends_at }, ends_at)
+                    })
+                    .collect();
+                to_json_binary(&PollsResponse { polls }).unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let expiring_proposals = |start_after: Option<u64>, limit: u32| {
+        let response: ExpiringProposalsResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ExpiringProposals(ExpiringProposalsParams {
+                    within: Duration::Time(100),
+                    start_after,
+                    limit: Some(limit),
+                    viewer: None,
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        (
+            response
+                .proposals
+                .into_iter()
+                .map(|proposal| proposal.proposal.proposal.id)
+                .collect::<Vec<u64>>(),
+            response.next_start_after,
+        )
+    };
+
+    assert_eq!(expiring_proposals(None, 2), (vec![1, 2], Some(2)));
+    assert_eq!(expiring_proposals(Some(2), 2), (vec![4, 5], Some(5)));
+
+    let (ids, next_start_after) = expiring_proposals(None, 1000);
+    assert_eq!(ids.len(), 100);
+    assert_eq!(next_start_after, Some(149));
+
+    let (ids, next_start_after) = expiring_proposals(next_start_after, 1000);
+    assert_eq!(ids.len(), 34);
+    assert_eq!(next_start_after, None);
+}
+
+#[test]
+fn council_members_are_edited_incrementally_without_emptying_the_council() {
+    let edit = |add: Vec<&str>, remove: Vec<&str>| {
+        ProposalAction::EditCouncilMembers(EditCouncilMembersMsg {
+            add: add.into_iter().map(|member| member.to_string()).collect(),
+            remove: remove
+                .into_iter()
+                .map(|member| member.to_string())
+                .collect(),
+        })
+    };
+    let council = DaoCouncilSpec {
+        members: vec!["member1".to_string()],
+        weights: None,
+        quorum: Decimal::percent(50),
+        threshold: Decimal::percent(50),
+        allowed_proposal_action_types: None,
+    };
+
+    let execute_edit = |proposal_action: ProposalAction| {
+        let mut deps = mock_dependencies();
+        instantiate_with_proposal_actions(
+            deps.as_mut(),
+            Some(council.clone()),
+            vec![proposal_action],
+        );
+        mock_wasm(&mut deps.querier, |contract, msg| {
+            let user_weight = |user: String, weight: u128| {
+                to_json_binary(&UserWeightResponse {
+                    user: Addr::unchecked(user),
+                    weight: Uint128::new(weight),
+                })
+                .unwrap()
+            };
+            match (contract, from_json(msg).unwrap()) {
+                ("council_membership", MembershipQueryMsg::UserWeight(params)) => {
+                    let weight = if params.user == "member1" { 1 } else { 0 };
+                    user_weight(params.user, weight)
+                }
+                ("council_membership", MembershipQueryMsg::TotalWeight(_)) => {
+                    to_json_binary(&TotalWeightResponse {
+                        total_weight: Uint128::one(),
+                    })
+                    .unwrap()
+                }
+                ("membership", MembershipQueryMsg::UserWeight(params)) => {
+                    let weight = if params.user == "outsider" { 0 } else { 5 };
+                    user_weight(params.user, weight)
+                }
+                _ => panic!("unexpected query"),
+            }
+        });
+        execute_proposal_actions(deps.as_mut())
+    };
+
+    let response = execute_edit(edit(vec!["member2"], vec!["member1"])).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "council_membership".to_string(),
+            msg: to_json_binary(&MultisigMembershipExecuteMsg::UpdateMembers(
+                UpdateMembersMsg {
+                    update_members: vec![
+                        MultisigUserWeight {
+                            user: "member2".to_string(),
+                            weight: Uint128::one(),
+                        },
+                        MultisigUserWeight {
+                            user: "member1".to_string(),
+                            weight: Uint128::zero(),
+                        },
+                    ],
+                }
+            ))
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    assert_eq!(
+        execute_edit(edit(vec![], vec!["member1"])).unwrap_err(),
+        CouncilWouldBeEmpty
+    );
+    assert_eq!(
+        execute_edit(edit(vec!["member1"], vec![])).unwrap_err(),
+        DuplicateCouncilMember {
+            member: "member1".to_string()
+        }
+    );
+    assert_eq!(
+        execute_edit(edit(vec!["outsider"], vec![])).unwrap_err(),
+        NotDaoMember {
+            member: "outsider".to_string()
+        }
+    );
+    assert_eq!(
+        execute_edit(edit(vec![], vec!["member2"])).unwrap_err(),
+        NotCouncilMember {
+            member: "member2".to_string()
+        }
+    );
+}
+
+#[test]
+fn interchain_accounts_are_registered_once_and_execute_well_formed_msgs() {
+    let mut deps = mock_dependencies_with_interchain_accounts(&["connection-0"]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: gov_config(),
+            council_gov_config: None,
+            proposal_infos: None,
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+    mock_token_dao(&mut deps.querier.base);
+
+    let register = |connection_id: &str| {
+        ProposalAction::RegisterInterchainAccount(RegisterInterchainAccountMsg {
+            connection_id: connection_id.to_string(),
+            version: None,
+        })
+    };
+    let execute_ica_msg = |connection_id: &str, type_url: &str| {
+        ProposalAction::ExecuteInterchainAccountMsgs(ExecuteInterchainAccountMsgsMsg {
+            connection_id: connection_id.to_string(),
+            msgs: vec![InterchainAccountMsg {
+                type_url: type_url.to_string(),
+                value: Binary::from(vec![1, 2, 3]),
+            }],
+            memo: None,
+            timeout_nanos: None,
+        })
+    };
+    let mut create_proposal = |proposal_action: ProposalAction| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("proposer", &[]),
+            ExecuteMsg::CreateProposal(create_proposal_msg(vec![proposal_action])),
+        )
+    };
+
+    create_proposal(register("connection-1")).unwrap();
+    assert_eq!(
+        create_proposal(register("connection-0")).unwrap_err(),
+        InterchainAccountAlreadyRegistered {
+            connection_id: "connection-0".to_string()
+        }
+    );
+    assert!(matches!(
+        create_proposal(register("channel-0")).unwrap_err(),
+        InvalidArgument { .. }
+    ));
+
+    create_proposal(execute_ica_msg(
+        "connection-0",
+        "/cosmos.bank.v1beta1.MsgSend",
+    ))
+    .unwrap();
+    assert_eq!(
+        create_proposal(execute_ica_msg(
+            "connection-1",
+            "/cosmos.bank.v1beta1.MsgSend"
+        ))
+        .unwrap_err(),
+        NoInterchainAccount {
+            connection_id: "connection-1".to_string()
+        }
+    );
+    for invalid_type_url in [
+        "cosmos.bank.v1beta1.MsgSend",
+        "/MsgSend",
+        "/cosmos..MsgSend",
+        "/cosmos.bank.v1beta1.MsgSend/",
+        "/cosmos.bank v1beta1.MsgSend",
+    ] {
+        assert!(matches!(
+            create_proposal(execute_ica_msg("connection-0", invalid_type_url)).unwrap_err(),
+            InvalidArgument { .. }
+        ));
+    }
+    assert!(matches!(
+        create_proposal(execute_ica_msg(
+            "connection-x",
+            "/cosmos.bank.v1beta1.MsgSend"
+        ))
+        .unwrap_err(),
+        InvalidArgument { .. }
+    ));
+
+    let mut deps = mock_dependencies_with_interchain_accounts(&["connection-0"]);
+    instantiate_with_proposal_actions(
+        deps.as_mut(),
+        None,
+        vec![
+            register("connection-1"),
+            execute_ica_msg("connection-0", "/cosmos.bank.v1beta1.MsgSend"),
+        ],
+    );
+    mock_wasm(&mut deps.querier.base, |_, _| panic!("unexpected query"));
+
+    let response = execute_proposal_actions(deps.as_mut()).unwrap();
+    let controller = mock_env().contract.address.to_string();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Stargate {
+            type_url:
+                "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount"
+                    .to_string(),
+            value: MsgRegisterInterchainAccount {
+                owner: controller.clone(),
+                connection_id: "connection-1".to_string(),
+                version: String::new(),
+            }
+            .encode_to_vec()
+            .into(),
+        }
+    );
+    assert_eq!(
+        response.messages[1].msg,
+        CosmosMsg::Stargate {
+            type_url: "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx".to_string(),
+            value: MsgSendTx {
+                owner: controller,
+                connection_id: "connection-0".to_string(),
+                packet_data: Some(InterchainAccountPacketData {
+                    r#type: 1,
+                    data: CosmosTx {
+                        messages: vec![Any {
+                            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                            value: vec![1, 2, 3],
+                        }],
+                    }
+                    .encode_to_vec(),
+                    memo: String::new(),
+                }),
+                relative_timeout: DEFAULT_ICA_TIMEOUT_NANOS,
+            }
+            .encode_to_vec()
+            .into(),
+        }
+    );
+}
+
+#[test]
+fn passed_proposal_voters_unlocking_is_extended_page_by_page() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                voter_unlocking_extensions: Some(vec![VoterUnlockingExtension {
+                    action_type: ProposalActionType::SetDistributorClaimsPaused,
+                    extension: 1000,
+                }]),
+                ..gov_config()
+            },
+            council_gov_config: None,
+            proposal_infos: Some(vec![(
+                1,
+                ProposalInfo {
+                    proposal_actions: vec![ProposalAction::SetDistributorClaimsPaused(
+                        SetDistributorClaimsPausedMsg { paused: true },
+                    )],
+                    ..proposal_info(ProposalType::General)
+                },
+            )]),
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    let ends_at = mock_env().block.time.minus_seconds(1);
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "enterprise" => match from_json(msg).unwrap() {
+            EnterpriseQueryMsg::DaoInfo {} => dao_info(DaoType::Token),
+            EnterpriseQueryMsg::IsRestrictedUser(_) => to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                poll: poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at),
+            })
+            .unwrap(),
+            GovernanceQueryMsg::PollStatus { .. } => to_json_binary(&PollStatusResponse {
+                status: PollStatus::Passed {
+                    outcome: VoteOutcome::Yes as u8,
+                    count: Uint128::new(30),
+                },
+                ends_at,
+                results: BTreeMap::new(),
+            })
+            .unwrap(),
+            GovernanceQueryMsg::PollVoters(params) => {
+                let voters = ["voter1", "voter2", "voter3"];
+                let votes = voters
+                    .iter()
+                    .filter(|voter| {
+                        params
+                            .pagination
+                            .start_after
+                            .as_ref()
+                            .is_none_or(|start_after| **voter > start_after.as_str())
+                    })
+                    .take(params.pagination.limit.unwrap() as usize)
+                    .map(|voter| Vote::new(1, Addr::unchecked(*voter), VoteOutcome::Yes, 10))
+                    .collect();
+                to_json_binary(&PollVotersResponse { votes }).unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        "membership" => to_json_binary(&TotalWeightResponse {
+            total_weight: Uint128::new(100),
+        })
+        .unwrap(),
+        _ => panic!("unexpected query"),
+    });
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("executor", &[]),
+        ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id: 1 }),
+    )
+    .unwrap();
+
+    // resolving the proposal does not go through the voters
+    let response = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: END_POLL_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    assert!(response.messages.iter().all(|submsg| !matches!(
+        &submsg.msg,
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) if contract_addr == "membership"
+    )));
+
+    let mut extend_voter_unlocking = |limit: Option<u32>| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExtendVoterUnlocking(ExtendVoterUnlockingMsg {
+                proposal_id: 1,
+                limit,
+            }),
+        )
+    };
+    let extend_unlocking_msg = |users: Vec<&str>| {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "membership".to_string(),
+            msg: to_json_binary(&TokenStakingExecuteMsg::ExtendUnlocking(
+                ExtendUnlockingMsg {
+                    users: users.into_iter().map(|user| user.to_string()).collect(),
+                    until: mock_env().block.time.plus_seconds(1000),
+                },
+            ))
+            .unwrap(),
+            funds: vec![],
+        })
+    };
+
+    let response = extend_voter_unlocking(Some(2)).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        extend_unlocking_msg(vec!["voter1", "voter2"])
+    );
+    assert!(response
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "done" && attr.value == "false"));
+
+    let response = extend_voter_unlocking(Some(2)).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        extend_unlocking_msg(vec!["voter3"])
+    );
+    assert!(response
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "done" && attr.value == "true"));
+
+    assert_eq!(
+        extend_voter_unlocking(None).unwrap_err(),
+        NoPendingVoterUnlockingExtension { proposal_id: 1 }
+    );
+}
+
+#[test]
+fn council_proposal_with_revoked_action_types_is_resolved_as_failed() {
+    let mut deps = mock_dependencies();
+    let council_proposal = |proposal_action: ProposalAction| ProposalInfo {
+        proposal_actions: vec![proposal_action],
+        ..proposal_info(ProposalType::Council)
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: gov_config(),
+            council_gov_config: Some(DaoCouncilSpec {
+                members: vec!["member".to_string()],
+                weights: None,
+                quorum: Decimal::percent(50),
+                threshold: Decimal::percent(50),
+                allowed_proposal_action_types: Some(vec![ProposalActionType::UpdateMetadata]),
+            }),
+            proposal_infos: Some(vec![
+                (
+                    1,
+                    council_proposal(ProposalAction::SetDistributorClaimsPaused(
+                        SetDistributorClaimsPausedMsg { paused: true },
+                    )),
+                ),
+                (
+                    2,
+                    council_proposal(ProposalAction::UpdateMetadata(UpdateMetadataMsg {
+                        name: Change("new name".to_string()),
+                        description: NoChange,
+                        logo: NoChange,
+                        github_username: NoChange,
+                        discord_username: NoChange,
+                        twitter_username: NoChange,
+                        telegram_username: NoChange,
+                    })),
+                ),
+            ]),
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    let ends_at = mock_env().block.time.minus_seconds(1);
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "enterprise" => to_json_binary(&IsRestrictedUserResponse {
+            is_restricted: false,
+        })
+        .unwrap(),
+        "council_membership" => to_json_binary(&TotalWeightResponse {
+            total_weight: Uint128::one(),
+        })
+        .unwrap(),
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                poll: poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at),
+            })
+            .unwrap(),
+            GovernanceQueryMsg::PollStatus { .. } => to_json_binary(&PollStatusResponse {
+                status: PollStatus::Passed {
+                    outcome: VoteOutcome::Yes as u8,
+                    count: Uint128::one(),
+                },
+                ends_at,
+                results: BTreeMap::new(),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let mut resolve_proposal = |proposal_id: ProposalId| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id }),
+        )?;
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: END_POLL_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+    };
+
+    let response = resolve_proposal(1).unwrap();
+    assert!(response.messages.is_empty());
+    assert!(response
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "execution_error"
+            && attr.value
+                == CouncilActionNoLongerAllowed {
+                    action: ProposalActionType::SetDistributorClaimsPaused
+                }
+                .to_string()));
+
+    // the failed proposal counts as resolved, and cannot be executed again
+    assert_eq!(resolve_proposal(1).unwrap_err(), ProposalAlreadyExecuted);
+
+    let response = resolve_proposal(2).unwrap();
+    assert_eq!(response.messages.len(), 1);
+
+    let status = |proposal_id: ProposalId| {
+        from_json::<ProposalStatusResponse>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ProposalStatus(ProposalStatusParams { proposal_id }),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .status
+    };
+    assert_eq!(status(1), ProposalStatus::ExecutionFailed);
+    assert_eq!(status(2), ProposalStatus::Executed);
+}
+
+#[test]
+fn proposals_expire_at_an_absolute_time_within_the_expiration_bounds() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                proposal_expiration_bounds: Some(ProposalExpirationBounds {
+                    min_duration: 100,
+                    max_duration: 1000,
+                }),
+                ..gov_config()
+            },
+            council_gov_config: None,
+            proposal_infos: None,
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+    mock_wasm(&mut deps.querier, |contract, msg| match contract {
+        "enterprise" => match from_json(msg).unwrap() {
+            EnterpriseQueryMsg::DaoInfo {} => dao_info(DaoType::Token),
+            EnterpriseQueryMsg::IsRestrictedUser(_) => to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "membership" => match from_json(msg).unwrap() {
+            TokenStakingQueryMsg::TokenConfig {} => to_json_binary(&TokenConfigResponse {
+                enterprise_contract: Addr::unchecked("enterprise"),
+                token_contract: Addr::unchecked("token"),
+                unlocking_period: Duration::Time(500),
+            })
+            .unwrap(),
+            TokenStakingQueryMsg::UserWeight(params) => to_json_binary(&UserWeightResponse {
+                user: Addr::unchecked(params.user),
+                weight: Uint128::new(10),
+            })
+            .unwrap(),
+            TokenStakingQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(100),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let now = mock_env().block.time;
+    let mut create_proposal = |expires_at: Expiration| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("proposer", &[]),
+            ExecuteMsg::CreateProposal(CreateProposalMsg {
+                expires_at: Some(expires_at),
+                ..create_proposal_msg(vec![])
+            }),
+        )
+    };
+
+    let response = create_proposal(Expiration::AtTime(now.plus_seconds(300))).unwrap();
+    let CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) = &response.messages[0].msg else {
+        panic!("expected poll creation");
+    };
+    let GovernanceExecuteMsg::CreatePoll(params) = from_json(msg).unwrap() else {
+        panic!("expected poll creation");
+    };
+    assert_eq!(params.ends_at, now.plus_seconds(300));
+
+    for invalid_expiration in [
+        // outside of the bounds
+        Expiration::AtTime(now.plus_seconds(99)),
+        Expiration::AtTime(now.plus_seconds(1001)),
+        // not in the future
+        Expiration::AtTime(now),
+        // not a time
+        Expiration::AtHeight(mock_env().block.height + 100),
+        Expiration::Never {},
+    ] {
+        assert!(matches!(
+            create_proposal(invalid_expiration).unwrap_err(),
+            InvalidArgument { .. }
+        ));
+    }
+
+    // within the bounds, but voting would outlast the unlocking period
+    assert_eq!(
+        create_proposal(Expiration::AtTime(now.plus_seconds(501))).unwrap_err(),
+        Dao(VoteDurationLongerThanUnstaking)
+    );
+}
+
+#[test]
+fn title_filter_scans_proposals_until_limit_matches() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: gov_config(),
+            council_gov_config: None,
+            proposal_infos: Some(
+                (1..=4)
+                    .map(|id| (id, proposal_info(ProposalType::General)))
+                    .collect(),
+            ),
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    mock_wasm(&mut deps.querier, |contract, msg| match contract {
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Polls(params) => {
+                let ends_at = mock_env().block.time.plus_seconds(1000);
+                let start_after = params.pagination.start_after.map_or(0, |id| id.u64());
+                let limit = params.pagination.limit.unwrap() as usize;
+                let polls = [
+                    "Fund the treasury",
+                    "Update metadata",
+                    "TREASURY diversification",
+                    "Council elections",
+                ]
+                .into_iter()
+                .zip(1u64..)
+                .filter(|(_, id)| *id > start_after)
+                .take(limit)
+                .map(|(label, id)| Poll {
+                    label: label.to_string(),
+                    ..poll(id, PollStatus::InProgress { ends_at }, ends_at)
+                })
+                .collect();
+                to_json_binary(&PollsResponse { polls }).unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        "membership" => match from_json(msg).unwrap() {
+            MembershipQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(100),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let search_proposals = |start_after: Option<ProposalId>, limit: u32| {
+        let response: ProposalsResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Proposals(ProposalsParams {
+                    filter: None,
+                    start_after,
+                    limit: Some(limit),
+                    viewer: None,
+                    title_contains: Some("Treasury".to_string()),
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        (
+            response
+                .proposals
+                .into_iter()
+                .map(|proposal| proposal.proposal.id)
+                .collect::<Vec<ProposalId>>(),
+            response.next_start_after,
+        )
+    };
+
+    // the match is case-insensitive, and the scan reaching the last proposal leaves no cursor
+    assert_eq!(search_proposals(None, 10), (vec![1, 3], None));
+
+    // pages are filled past proposals that do not match
+    assert_eq!(search_proposals(None, 1), (vec![1], Some(1)));
+    assert_eq!(search_proposals(Some(1), 1), (vec![3], Some(3)));
+    assert_eq!(search_proposals(Some(3), 1), (vec![], None));
+}
+
+#[test]
+fn private_proposals_are_redacted_for_viewers_that_are_not_members() {
+    let pause_claims =
+        ProposalAction::SetDistributorClaimsPaused(SetDistributorClaimsPausedMsg { paused: true });
+
+    let list_proposals = |private_proposals: Option<bool>, viewer: Option<&str>| {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("enterprise", &[]),
+            InstantiateMsg {
+                enterprise_contract: "enterprise".to_string(),
+                dao_type: DaoType::Token,
+                gov_config: GovConfig {
+                    private_proposals,
+                    ..gov_config()
+                },
+                council_gov_config: None,
+                proposal_infos: Some(vec![(
+                    1,
+                    ProposalInfo {
+                        proposal_actions: vec![pause_claims.clone()],
+                        ..proposal_info(ProposalType::General)
+                    },
+                )]),
+                initial_cross_chain_treasuries: None,
+            },
+        )
+        .unwrap();
+
+        let ends_at = mock_env().block.time.plus_seconds(1000);
+        mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+            "governance" => to_json_binary(&PollsResponse {
+                polls: vec![Poll {
+                    description: "secret plans".to_string(),
+                    ..poll(1, PollStatus::InProgress { ends_at }, ends_at)
+                }],
+            })
+            .unwrap(),
+            "membership" => match from_json(msg).unwrap() {
+                MembershipQueryMsg::UserWeight(params) => {
+                    let weight = if params.user == "member" { 10 } else { 0 };
+                    to_json_binary(&UserWeightResponse {
+                        user: Addr::unchecked(params.user),
+                        weight: Uint128::new(weight),
+                    })
+                    .unwrap()
+                }
+                MembershipQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                    total_weight: Uint128::new(100),
+                })
+                .unwrap(),
+                _ => panic!("unexpected query"),
+            },
+            _ => panic!("unexpected query"),
+        });
+
+        let response: ProposalsResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Proposals(ProposalsParams {
+                    filter: None,
+                    start_after: None,
+                    limit: None,
+                    viewer: viewer.map(|viewer| viewer.to_string()),
+                    title_contains: None,
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let proposal = response.proposals[0].proposal.clone();
+        (proposal.description, proposal.proposal_actions)
+    };
+
+    let full_details = ("secret plans".to_string(), vec![pause_claims.clone()]);
+    let redacted = (String::new(), vec![]);
+
+    assert_eq!(list_proposals(Some(true), Some("member")), full_details);
+    assert_eq!(list_proposals(Some(true), Some("outsider")), redacted);
+    assert_eq!(list_proposals(Some(true), None), redacted);
+
+    assert_eq!(list_proposals(Some(false), None), full_details);
+    assert_eq!(list_proposals(None, Some("outsider")), full_details);
+}
+
+#[test]
+fn private_proposal_is_redacted_when_queried_by_a_non_member() {
+    let pause_claims =
+        ProposalAction::SetDistributorClaimsPaused(SetDistributorClaimsPausedMsg { paused: true });
+
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                private_proposals: Some(true),
+                ..gov_config()
+            },
+            council_gov_config: None,
+            proposal_infos: Some(vec![(
+                1,
+                ProposalInfo {
+                    proposal_actions: vec![pause_claims.clone()],
+                    ..proposal_info(ProposalType::General)
+                },
+            )]),
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                poll: Poll {
+                    description: "secret plans".to_string(),
+                    ..poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at)
+                },
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "membership" => match from_json(msg).unwrap() {
+            MembershipQueryMsg::UserWeight(params) => {
+                let weight = if params.user == "member" { 10 } else { 0 };
+                to_json_binary(&UserWeightResponse {
+                    user: Addr::unchecked(params.user),
+                    weight: Uint128::new(weight),
+                })
+                .unwrap()
+            }
+            MembershipQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(100),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    let query_proposal = |viewer: Option<&str>| {
+        let params = ProposalParams {
+            proposal_id: 1,
+            viewer: viewer.map(|viewer| viewer.to_string()),
+        };
+        let proposal: ProposalResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Proposal(params.clone()),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let decoded_actions: DecodedProposalActionsResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::DecodedProposalActions(params),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        (
+            proposal.proposal.description,
+            proposal.proposal.proposal_actions,
+            decoded_actions.proposal_actions.len(),
+        )
+    };
+
+    assert_eq!(
+        query_proposal(Some("member")),
+        ("secret plans".to_string(), vec![pause_claims], 1)
+    );
+    assert_eq!(query_proposal(Some("outsider")), (String::new(), vec![], 0));
+    assert_eq!(query_proposal(None), (String::new(), vec![], 0));
+}
+
+#[test]
+fn self_admin_other_than_the_enterprise_contract_has_to_be_explicitly_allowed() {
+    let set_self_admin = |new_admin: Option<&str>, allow_losing_upgradability: bool| {
+        let mut deps = mock_dependencies();
+        instantiate_with_proposal_actions(
+            deps.as_mut(),
+            None,
+            vec![ProposalAction::SetSelfAdmin(SetSelfAdminMsg {
+                new_admin: new_admin.map(|admin| admin.to_string()),
+                allow_losing_upgradability,
+            })],
+        );
+        execute_proposal_actions(deps.as_mut())
+    };
+    let enterprise_set_self_admin_msg = |new_admin: Option<&str>| {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "enterprise".to_string(),
+            msg: to_json_binary(&EnterpriseExecuteMsg::SetSelfAdmin(
+                EnterpriseSetSelfAdminMsg {
+                    new_admin: new_admin.map(|admin| admin.to_string()),
+                },
+            ))
+            .unwrap(),
+            funds: vec![],
+        })
+    };
+
+    assert_eq!(
+        set_self_admin(Some("enterprise"), false).unwrap().messages[0].msg,
+        enterprise_set_self_admin_msg(Some("enterprise"))
+    );
+
+    assert_eq!(
+        set_self_admin(Some("new_admin"), false).unwrap_err(),
+        SelfAdminWouldPreventUpgrades
+    );
+    assert_eq!(
+        set_self_admin(None, false).unwrap_err(),
+        SelfAdminWouldPreventUpgrades
+    );
+
+    assert_eq!(
+        set_self_admin(Some("new_admin"), true).unwrap().messages[0].msg,
+        enterprise_set_self_admin_msg(Some("new_admin"))
+    );
+    assert_eq!(
+        set_self_admin(None, true).unwrap().messages[0].msg,
+        enterprise_set_self_admin_msg(None)
+    );
+}
+
+#[test]
+fn execution_condition_compares_query_response_as_json_value() {
+    let resolve_proposal = |expected_result: &str, response: &'static str| {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("enterprise", &[]),
+            InstantiateMsg {
+                enterprise_contract: "enterprise".to_string(),
+                dao_type: DaoType::Token,
+                gov_config: gov_config(),
+                council_gov_config: None,
+                proposal_infos: Some(vec![(
+                    1,
+                    ProposalInfo {
+                        execution_condition: Some(ExecutionCondition {
+                            contract: "price_oracle".to_string(),
+                            query_msg: r#"{"price":{}}"#.to_string(),
+                            expected_result: expected_result.to_string(),
+                        }),
+                        ..proposal_info(ProposalType::General)
+                    },
+                )]),
+                initial_cross_chain_treasuries: None,
+            },
+        )
+        .unwrap();
+
+        let ends_at = mock_env().block.time.minus_seconds(1);
+        mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+            "enterprise" => to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+            "membership" => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(100),
+            })
+            .unwrap(),
+            "governance" => match from_json(msg).unwrap() {
+                GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                    poll: poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at),
+                })
+                .unwrap(),
+                GovernanceQueryMsg::PollStatus { .. } => to_json_binary(&PollStatusResponse {
+                    status: PollStatus::Passed {
+                        outcome: VoteOutcome::Yes as u8,
+                        count: Uint128::new(30),
+                    },
+                    ends_at,
+                    results: BTreeMap::new(),
+                })
+                .unwrap(),
+                _ => panic!("unexpected query"),
+            },
+            "price_oracle" => Binary::from(response.as_bytes()),
+            _ => panic!("unexpected query"),
+        });
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecuteProposal(ExecuteProposalMsg { proposal_id: 1 }),
+        )
+        .unwrap();
+        let response = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: END_POLL_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let status: ProposalStatusResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ProposalStatus(ProposalStatusParams { proposal_id: 1 }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        (response.messages.len(), status.status)
+    };
+
+    let executed = (1, ProposalStatus::Executed);
+    let condition_not_met = (0, ProposalStatus::ConditionNotMet);
+
+    // formatting and order of object keys do not matter
+    assert_eq!(
+        resolve_proposal(
+            r#"{"price":"1.5","ratio":25}"#,
+            r#"{ "ratio": 25, "price": "1.5" }"#
+        ),
+        executed
+    );
+    assert_eq!(resolve_proposal("null", " null "), executed);
+
+    assert_eq!(
+        resolve_proposal(
+            r#"{"price":"1.5","ratio":25}"#,
+            r#"{"price":"1.5","ratio":50}"#
+        ),
+        condition_not_met
+    );
+    assert_eq!(resolve_proposal("true", "not json"), condition_not_met);
+}
+
+#[test]
+fn execution_budgets_limit_treasury_outflows_and_reject_unbudgetable_actions() {
+    let instantiate_with_max_execution_budget =
+        |deps: DepsMut, max_execution_budget: Option<Vec<Asset>>| {
+            instantiate(
+                deps,
+                mock_env(),
+                mock_info("enterprise", &[]),
+                InstantiateMsg {
+                    enterprise_contract: "enterprise".to_string(),
+                    dao_type: DaoType::Token,
+                    gov_config: GovConfig {
+                        max_execution_budget,
+                        ..gov_config()
+                    },
+                    council_gov_config: None,
+                    proposal_infos: None,
+                    initial_cross_chain_treasuries: None,
+                },
+            )
+            .unwrap();
+        };
+
+    let grant_allowance = ProposalAction::GrantSpendingAllowance(GrantSpendingAllowanceMsg {
+        grantee: "grantee".to_string(),
+        asset: AssetInfoUnchecked::cw20("token"),
+        amount: Uint128::new(500),
+        expiry: Expiration::Never {},
+    });
+    let set_emission_schedule = |source: EmissionSource| {
+        ProposalAction::SetDistributorEmissionSchedule(SetDistributorEmissionScheduleMsg {
+            asset: "token".to_string(),
+            schedule: Some(DistributorEmissionSchedule {
+                amount_per_period: Uint128::new(100),
+                period: 10,
+                periods: 6,
+                source,
+            }),
+        })
+    };
+    let emit_from_treasury = set_emission_schedule(EmissionSource::Treasury);
+    let emit_by_minting = set_emission_schedule(EmissionSource::Mint);
+    let execute_msgs = ProposalAction::ExecuteMsgs(ExecuteMsgsMsg {
+        action_type: "custom".to_string(),
+        msgs: vec![to_json_string(&CosmosMsg::<Empty>::Bank(BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: coins(1000, "uluna"),
+        }))
+        .unwrap()],
+    });
+    let budget = |amount: u128| Some(vec![AssetUnchecked::cw20("token", amount)]);
+
+    // the DAO limits execution budgets
+    let mut deps = mock_dependencies();
+    instantiate_with_max_execution_budget(
+        deps.as_mut(),
+        Some(vec![Asset::cw20(Addr::unchecked("token"), 1000u128)]),
+    );
+    mock_token_dao(&mut deps.querier);
+
+    let mut create_proposal =
+        |proposal_action: &ProposalAction, execution_budget: Option<Vec<AssetUnchecked>>| {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("proposer", &[]),
+                ExecuteMsg::CreateProposal(CreateProposalMsg {
+                    execution_budget,
+                    ..create_proposal_msg(vec![proposal_action.clone()])
+                }),
+            )
+        };
+
+    assert_eq!(
+        create_proposal(&grant_allowance, None).unwrap_err(),
+        ExecutionBudgetRequired
+    );
+    assert_eq!(
+        create_proposal(&grant_allowance, budget(400)).unwrap_err(),
+        ExecutionBudgetExceeded {
+            asset: "cw20:token".to_string()
+        }
+    );
+    assert_eq!(
+        create_proposal(&grant_allowance, budget(1001)).unwrap_err(),
+        ExecutionBudgetAboveMaximum {
+            asset: "cw20:token".to_string()
+        }
+    );
+    create_proposal(&grant_allowance, budget(500)).unwrap();
+
+    // emissions from the treasury are budgeted for the whole schedule
+    assert_eq!(
+        create_proposal(&emit_from_treasury, budget(500)).unwrap_err(),
+        ExecutionBudgetExceeded {
+            asset: "cw20:token".to_string()
+        }
+    );
+    create_proposal(&emit_from_treasury, budget(600)).unwrap();
+    create_proposal(&emit_by_minting, None).unwrap();
+
+    // arbitrary messages cannot be budgeted, so they cannot bypass the DAO's limits
+    for execution_budget in [None, budget(1000)] {
+        assert_eq!(
+            create_proposal(&execute_msgs, execution_budget).unwrap_err(),
+            ProposalActionNotBudgetable {
+                action: ProposalActionType::ExecuteMsgs
+            }
+        );
+    }
+
+    // the DAO does not limit execution budgets
+    let mut deps = mock_dependencies();
+    instantiate_with_max_execution_budget(deps.as_mut(), None);
+    mock_token_dao(&mut deps.querier);
+
+    let mut create_proposal =
+        |proposal_action: &ProposalAction, execution_budget: Option<Vec<AssetUnchecked>>| {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("proposer", &[]),
+                ExecuteMsg::CreateProposal(CreateProposalMsg {
+                    execution_budget,
+                    ..create_proposal_msg(vec![proposal_action.clone()])
+                }),
+            )
+        };
+
+    create_proposal(&grant_allowance, None).unwrap();
+    create_proposal(&execute_msgs, None).unwrap();
+
+    // a proposal opting into a budget still cannot contain actions the budget cannot limit
+    assert_eq!(
+        create_proposal(&execute_msgs, budget(1000)).unwrap_err(),
+        ProposalActionNotBudgetable {
+            action: ProposalActionType::ExecuteMsgs
+        }
+    );
+}
+
+#[test]
+fn max_execution_budget_updates_are_validated() {
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(deps.as_mut(), None, vec![]);
+    mock_token_dao(&mut deps.querier);
+
+    let mut create_proposal = |max_execution_budget: Vec<AssetUnchecked>| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("proposer", &[]),
+            ExecuteMsg::CreateProposal(create_proposal_msg(vec![ProposalAction::UpdateGovConfig(
+                UpdateGovConfigMsg {
+                    max_execution_budget: Change(Some(max_execution_budget)),
+                    ..no_gov_config_changes()
+                },
+            )])),
+        )
+    };
+
+    let error = create_proposal(vec![AssetUnchecked::cw20("Token", 1000u128)]).unwrap_err();
+    assert!(matches!(error, Std(_)), "unexpected error: {error}");
+
+    create_proposal(vec![AssetUnchecked::cw20("token", 1000u128)]).unwrap();
+}
+
+#[test]
+fn proposals_are_indexed_under_every_asset_their_actions_reference() {
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(deps.as_mut(), None, vec![]);
+    mock_token_dao(&mut deps.querier);
+
+    let mut next_poll_id = 2;
+    let mut create_proposal = |proposal_actions: Vec<ProposalAction>| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("proposer", &[]),
+            ExecuteMsg::CreateProposal(create_proposal_msg(proposal_actions)),
+        )
+        .unwrap();
+        reply(deps.as_mut(), mock_env(), create_poll_reply(next_poll_id)).unwrap();
+        next_poll_id += 1;
+    };
+
+    // proposal 2 references the token twice
+    create_proposal(vec![
+        ProposalAction::SetDistributorEmissionSchedule(SetDistributorEmissionScheduleMsg {
+            asset: "token".to_string(),
+            schedule: None,
+        }),
+        ProposalAction::GrantSpendingAllowance(GrantSpendingAllowanceMsg {
+            grantee: "grantee".to_string(),
+            asset: AssetInfoUnchecked::cw20("token"),
+            amount: Uint128::new(500),
+            expiry: Expiration::Never {},
+        }),
+    ]);
+    // proposal 3 executes arbitrary messages, whose assets are not known
+    create_proposal(vec![ProposalAction::ExecuteMsgs(ExecuteMsgsMsg {
+        action_type: "custom".to_string(),
+        msgs: vec![to_json_string(&CosmosMsg::<Empty>::Bank(BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: coins(1000, "uluna"),
+        }))
+        .unwrap()],
+    })]);
+    // proposal 4 registers a bundle of assets
+    create_proposal(vec![ProposalAction::ManageAssetBundles(
+        ManageAssetBundlesMsg {
+            register: vec![AssetBundle {
+                name: "bundle".to_string(),
+                assets: vec![
+                    AssetInfoUnchecked::native("uluna"),
+                    AssetInfoUnchecked::cw20("token"),
+                ],
+            }],
+            remove: vec![],
+        },
+    )]);
+
+    let query_proposals_affecting = |asset: AssetInfoUnchecked, start_after: Option<ProposalId>| {
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ProposalsAffectingAsset(ProposalsAffectingAssetParams {
+                asset,
+                start_after,
+                limit: None,
+            }),
+        )
+        .map(|response| {
+            from_json::<ProposalsAffectingAssetResponse>(&response)
+                .unwrap()
+                .proposal_ids
+        })
+    };
+
+    assert_eq!(
+        query_proposals_affecting(AssetInfoUnchecked::cw20("token"), None).unwrap(),
+        vec![2, 4]
+    );
+    assert_eq!(
+        query_proposals_affecting(AssetInfoUnchecked::cw20("token"), Some(2)).unwrap(),
+        vec![4]
+    );
+    assert_eq!(
+        query_proposals_affecting(AssetInfoUnchecked::native("uluna"), None).unwrap(),
+        vec![4]
+    );
+    assert_eq!(
+        query_proposals_affecting(AssetInfoUnchecked::cw1155("token", "1"), None).unwrap_err(),
+        UnsupportedCw1155Asset
+    );
+}
+
+#[test]
+fn council_changes_have_to_keep_meeting_dao_requirements() {
+    let requirements = vec![CouncilActionRequirement {
+        action_type: ProposalActionType::UpgradeDao,
+        minimum_members: 3,
+        minimum_threshold: Decimal::percent(60),
+    }];
+    let council = |weights: Vec<u128>, action_types: Vec<ProposalActionType>| DaoCouncilSpec {
+        members: (1..=weights.len())
+            .map(|i| format!("member{}", i))
+            .collect(),
+        weights: Some(weights.into_iter().map(Uint128::new).collect()),
+        quorum: Decimal::percent(50),
+        threshold: Decimal::percent(60),
+        allowed_proposal_action_types: Some(action_types),
+    };
+
+    // executes the action in a DAO with the given council and council action requirements
+    let execute_action = |requirements: Option<Vec<CouncilActionRequirement>>,
+                          council: DaoCouncilSpec,
+                          proposal_action: ProposalAction| {
+        let mut deps = mock_dependencies();
+
+        let members: Vec<(String, Uint128)> = council
+            .members
+            .iter()
+            .cloned()
+            .zip(council.weights.clone().unwrap())
+            .collect();
+        mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+            "enterprise" => dao_info(DaoType::Token),
+            "membership" => match from_json(msg).unwrap() {
+                TokenStakingQueryMsg::TokenConfig {} => to_json_binary(&TokenConfigResponse {
+                    enterprise_contract: Addr::unchecked("enterprise"),
+                    token_contract: Addr::unchecked("token"),
+                    unlocking_period: Duration::Time(1000),
+                })
+                .unwrap(),
+                TokenStakingQueryMsg::UserWeight(params) => to_json_binary(&UserWeightResponse {
+                    user: Addr::unchecked(params.user),
+                    weight: Uint128::new(10),
+                })
+                .unwrap(),
+                _ => panic!("unexpected query"),
+            },
+            "council_membership" => match from_json(msg).unwrap() {
+                MultisigMembershipQueryMsg::Members(params) => {
+                    assert_eq!(params.start_after, None);
+                    to_json_binary(&MembersResponse {
+                        members: members
+                            .iter()
+                            .map(|(member, weight)| UserWeightResponse {
+                                user: Addr::unchecked(member),
+                                weight: *weight,
+                            })
+                            .collect(),
+                    })
+                    .unwrap()
+                }
+                MultisigMembershipQueryMsg::UserWeight(params) => {
+                    to_json_binary(&UserWeightResponse {
+                        weight: members
+                            .iter()
+                            .find(|(member, _)| *member == params.user)
+                            .map_or(Uint128::zero(), |(_, weight)| *weight),
+                        user: Addr::unchecked(params.user),
+                    })
+                    .unwrap()
+                }
+                MultisigMembershipQueryMsg::TotalWeight(_) => {
+                    to_json_binary(&TotalWeightResponse {
+                        total_weight: members.iter().map(|(_, weight)| *weight).sum(),
+                    })
+                    .unwrap()
+                }
+                _ => panic!("unexpected query"),
+            },
+            _ => panic!("unexpected query"),
+        });
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("enterprise", &[]),
+            InstantiateMsg {
+                enterprise_contract: "enterprise".to_string(),
+                dao_type: DaoType::Token,
+                gov_config: GovConfig {
+                    council_action_requirements: requirements,
+                    ..gov_config()
+                },
+                council_gov_config: Some(council),
+                proposal_infos: Some(vec![(
+                    1,
+                    ProposalInfo {
+                        proposal_actions: vec![proposal_action],
+                        ..proposal_info(ProposalType::General)
+                    },
+                )]),
+                initial_cross_chain_treasuries: None,
+            },
+        )
+        .unwrap();
+
+        execute_proposal_actions(deps.as_mut())
+    };
+    let too_powerful = Err(CouncilTooPowerfulForAction {
+        action: ProposalActionType::UpgradeDao,
+    });
+    let upgrade_dao = || vec![ProposalActionType::UpgradeDao];
+
+    // removing a member takes the council below the required number of members
+    let remove_member = ProposalAction::EditCouncilMembers(EditCouncilMembersMsg {
+        add: vec![],
+        remove: vec!["member3".to_string()],
+    });
+    assert_eq!(
+        execute_action(
+            Some(requirements.clone()),
+            council(vec![1, 1, 1], upgrade_dao()),
+            remove_member.clone()
+        ),
+        too_powerful
+    );
+    assert!(execute_action(
+        Some(requirements.clone()),
+        council(vec![1, 1, 1, 1], upgrade_dao()),
+        remove_member
+    )
+    .is_ok());
+
+    // granting the action to a council too small for it
+    let grant_upgrade_dao = ProposalAction::UpdateCouncilPermissions(UpdateCouncilPermissionsMsg {
+        allowed_proposal_action_types: upgrade_dao(),
+    });
+    assert_eq!(
+        execute_action(
+            Some(requirements.clone()),
+            council(vec![1, 1], vec![ProposalActionType::UpdateMetadata]),
+            grant_upgrade_dao.clone()
+        ),
+        too_powerful
+    );
+    assert!(execute_action(
+        Some(requirements.clone()),
+        council(vec![1, 1, 1], vec![ProposalActionType::UpdateMetadata]),
+        grant_upgrade_dao
+    )
+    .is_ok());
+
+    // introducing requirements the current council does not meet
+    let require = ProposalAction::UpdateGovConfig(UpdateGovConfigMsg {
+        council_action_requirements: Change(Some(requirements.clone())),
+        ..no_gov_config_changes()
+    });
+    assert_eq!(
+        execute_action(None, council(vec![1, 1], upgrade_dao()), require.clone()),
+        too_powerful
+    );
+    assert!(execute_action(None, council(vec![1, 1, 1], upgrade_dao()), require.clone()).is_ok());
+
+    // a weighted council in which a single member reaches the threshold is as powerful
+    // as a single member, however many members it has
+    assert_eq!(
+        execute_action(None, council(vec![8, 1, 1], upgrade_dao()), require.clone()),
+        too_powerful
+    );
+    assert!(execute_action(None, council(vec![2, 1, 1], upgrade_dao()), require).is_ok());
+    assert_eq!(
+        execute_action(
+            Some(requirements),
+            council(vec![1, 1, 1], vec![]),
+            ProposalAction::UpdateCouncil(UpdateCouncilMsg {
+                dao_council: Some(council(vec![8, 1, 1], upgrade_dao())),
+            })
+        ),
+        too_powerful
+    );
+}
+
+#[test]
+fn deposits_are_listed_under_their_depositor_rather_than_the_proposer() {
+    let mut deps = mock_dependencies();
+    mock_token_dao(&mut deps.querier);
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                accepted_deposit_assets: Some(vec![AcceptedDepositAsset {
+                    asset: AssetInfo::native("uluna"),
+                    minimum_amount: Uint128::new(10),
+                }]),
+                ..gov_config()
+            },
+            council_gov_config: None,
+            proposal_infos: None,
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer", &coins(10, "uluna")),
+        ExecuteMsg::CreateProposal(CreateProposalMsg {
+            deposit_owner: Some("sponsor".to_string()),
+            ..create_proposal_msg(vec![])
+        }),
+    )
+    .unwrap();
+    reply(deps.as_mut(), mock_env(), create_poll_reply(1)).unwrap();
+
+    let deposits_of = |depositor: &str| -> ProposerDepositsResponse {
+        from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ProposerDeposits(ProposerDepositsParams {
+                    depositor: depositor.to_string(),
+                    start_after: None,
+                    limit: None,
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    let deposits = deposits_of("sponsor").deposits;
+    assert_eq!(deposits.len(), 1);
+    assert_eq!(deposits[0].proposal_id, 1);
+    assert_eq!(
+        deposits[0].deposit,
+        ProposalDeposit {
+            depositor: Addr::unchecked("sponsor"),
+            asset: ProposalDepositAsset::Denom {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(10),
+            },
+        }
+    );
+
+    assert!(deposits_of("proposer").deposits.is_empty());
+}
+
+#[test]
+fn deciding_general_vote_only_auto_executes_after_the_execution_delay() {
+    let mut deps = mock_dependencies();
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                allow_early_proposal_execution: true,
+                auto_execute_on_pass: Some(true),
+                ..gov_config()
+            },
+            council_gov_config: None,
+            proposal_infos: Some(vec![(1, proposal_info(ProposalType::General))]),
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    // the proposal passes once the first vote has been cast
+    let passed = Rc::new(Cell::new(false));
+    let ends_at = mock_env().block.time.plus_seconds(1000);
+    let poll_passed = passed.clone();
+    mock_wasm(&mut deps.querier, move |contract, msg| match contract {
+        "enterprise" => match from_json(msg).unwrap() {
+            EnterpriseQueryMsg::DaoInfo {} => dao_info(DaoType::Token),
+            EnterpriseQueryMsg::IsRestrictedUser(_) => to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "membership" => match from_json(msg).unwrap() {
+            MembershipQueryMsg::UserWeight(params) => to_json_binary(&UserWeightResponse {
+                user: Addr::unchecked(params.user),
+                weight: Uint128::new(60),
+            })
+            .unwrap(),
+            MembershipQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(100),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => to_json_binary(&PollResponse {
+                poll: poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at),
+            })
+            .unwrap(),
+            GovernanceQueryMsg::SimulateEndPollStatus { .. } => {
+                let status = if poll_passed.get() {
+                    PollStatus::Passed {
+                        outcome: VoteOutcome::Yes as u8,
+                        count: Uint128::new(60),
+                    }
+                } else {
+                    PollStatus::InProgress { ends_at }
+                };
+                to_json_binary(&PollStatusResponse {
+                    status,
+                    ends_at,
+                    results: BTreeMap::new(),
+                })
+                .unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+
+    // returns the submessages added once the vote has been recorded
+    let vote = |mut deps: DepsMut, voter: &str, env: Env| {
+        execute(
+            deps.branch(),
+            env.clone(),
+            mock_info(voter, &[]),
+            ExecuteMsg::CastVote(CastVoteMsg {
+                proposal_id: 1,
+                outcome: VoteOutcome::Yes,
+            }),
+        )
+        .unwrap();
+
+        passed.set(true);
+
+        reply(
+            deps,
+            env,
+            Reply {
+                id: CAST_VOTE_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap()
+        .messages
+    };
+
+    // the deciding vote starts the execution delay, so it cannot execute the proposal
+    assert!(vote(deps.as_mut(), "voter1", mock_env()).is_empty());
+
+    let earliest_execution = PROPOSAL_INFOS
+        .load(deps.as_ref().storage, 1)
+        .unwrap()
+        .earliest_execution
+        .unwrap();
+    assert_eq!(
+        earliest_execution,
+        mock_env()
+            .block
+            .time
+            .plus_seconds(gov_config().vote_duration / 10)
+    );
+
+    // a later vote after the delay executes it
+    let mut env = mock_env();
+    env.block.time = earliest_execution;
+    let submsgs = vote(deps.as_mut(), "voter2", env);
+    assert_eq!(submsgs.len(), 1);
+    assert_eq!(submsgs[0].id, AUTO_EXECUTE_PROPOSAL_REPLY_ID);
+}
+
+#[test]
+fn proposal_burning_native_asset_is_rejected() {
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(deps.as_mut(), None, vec![]);
+    mock_token_dao(&mut deps.querier);
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer", &[]),
+        ExecuteMsg::CreateProposal(create_proposal_msg(vec![ProposalAction::BurnTokens(
+            BurnTokensMsg {
+                asset: AssetInfoUnchecked::native("uluna"),
+                amount: Uint128::new(10),
+            },
+        )])),
+    );
+
+    assert_eq!(result.unwrap_err(), CannotBurnNativeAsset);
+}
+
+#[test]
+fn gov_config_update_lowering_veto_threshold_below_its_floor_is_rejected() {
+    let mut deps = mock_dependencies();
+    mock_token_dao(&mut deps.querier);
+
+    let floor = Decimal::percent(40);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                veto_threshold_bounds: Some(VetoThresholdBounds {
+                    floor: Some(floor),
+                    ceiling: None,
+                }),
+                ..gov_config()
+            },
+            council_gov_config: None,
+            proposal_infos: None,
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer", &[]),
+        ExecuteMsg::CreateProposal(create_proposal_msg(vec![ProposalAction::UpdateGovConfig(
+            UpdateGovConfigMsg {
+                veto_threshold: Change(Some(Decimal::percent(30))),
+                ..no_gov_config_changes()
+            },
+        )])),
+    );
+
+    assert_eq!(result.unwrap_err(), VetoThresholdBelowFloor { floor });
+}
+
+#[test]
+fn proposal_whitelisting_governance_token_is_rejected_by_default() {
+    let mut deps = mock_dependencies();
+    instantiate_with_proposal_actions(deps.as_mut(), None, vec![]);
+    mock_token_dao(&mut deps.querier);
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer", &[]),
+        ExecuteMsg::CreateProposal(create_proposal_msg(vec![
+            ProposalAction::UpdateAssetWhitelist(UpdateAssetWhitelistProposalActionMsg {
+                remote_treasury_target: None,
+                add: vec![AssetInfoUnchecked::cw20("token")],
+                remove: vec![],
+            }),
+        ])),
+    );
+
+    assert_eq!(result.unwrap_err(), GovernanceTokenInWhitelist);
+}
+
+#[test]
+fn proposal_deposit_in_an_asset_not_accepted_is_rejected() {
+    let mut deps = mock_dependencies();
+    mock_token_dao(&mut deps.querier);
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: GovConfig {
+                accepted_deposit_assets: Some(vec![AcceptedDepositAsset {
+                    asset: AssetInfo::native("uluna"),
+                    minimum_amount: Uint128::new(10),
+                }]),
+                ..gov_config()
+            },
+            council_gov_config: None,
+            proposal_infos: None,
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer", &coins(10, "uusd")),
+        ExecuteMsg::CreateProposal(create_proposal_msg(vec![])),
+    );
+
+    assert_eq!(result.unwrap_err(), UnacceptedDepositAsset);
+}
+
+fn component_contracts() -> ComponentContractsResponse {
+    ComponentContractsResponse {
+        enterprise_factory_contract: Addr::unchecked("factory"),
+        enterprise_versioning_contract: Addr::unchecked("versioning"),
+        enterprise_governance_contract: Addr::unchecked("governance"),
+        enterprise_governance_controller_contract: Addr::unchecked("controller"),
+        enterprise_outposts_contract: Addr::unchecked("outposts"),
+        enterprise_treasury_contract: Addr::unchecked("treasury"),
+        funds_distributor_contract: Addr::unchecked("funds_distributor"),
+        membership_contract: Addr::unchecked("membership"),
+        council_membership_contract: Addr::unchecked("council_membership"),
+        attestation_contract: None,
+    }
+}
+
+fn gov_config() -> GovConfig {
+    GovConfig {
+        quorum: Decimal::percent(30),
+        threshold: Decimal::percent(50),
+        veto_threshold: None,
+        vote_duration: 100,
+        minimum_deposit: None,
+        allow_early_proposal_execution: false,
+        invalid_action_policy: None,
+        proposal_creation_policy: None,
+        max_open_proposals_per_proposer: None,
+        veto_threshold_bounds: None,
+        governance_token_whitelist_policy: None,
+        treasury_address: None,
+        description_format: None,
+        accepted_deposit_assets: None,
+        auto_execute_on_pass: None,
+        action_type_cooldowns: None,
+        voting_extension: None,
+        minimum_yes_votes: None,
+        voter_unlocking_extensions: None,
+        proposal_expiration_bounds: None,
+        private_proposals: None,
+        max_distribution_assets: None,
+        max_execution_budget: None,
+        proposal_notifier: None,
+        action_type_thresholds: None,
+        manual_deposit_refunds: None,
+        council_action_requirements: None,
+        allow_vote_changes: None,
+        restrict_late_joiner_votes: None,
+    }
+}
+
+fn no_gov_config_changes() -> UpdateGovConfigMsg {
+    from_json(
+        r#"{
+            "quorum": "no_change",
+            "threshold": "no_change",
+            "veto_threshold": "no_change",
+            "voting_duration": "no_change",
+            "unlocking_period": "no_change",
+            "minimum_deposit": "no_change",
+            "allow_early_proposal_execution": "no_change"
+        }"#,
+    )
+    .unwrap()
+}
+
+/// Answers the enterprise contract's component contracts query, and passes all other
+/// smart queries to the handler along with the queried contract's address.
+fn mock_wasm(querier: &mut MockQuerier, handler: impl Fn(&str, &Binary) -> Binary + 'static) {
+    querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } => {
+            let response = match from_json(msg) {
+                Ok(EnterpriseQueryMsg::ComponentContracts {}) if contract_addr == "enterprise" => {
+                    to_json_binary(&component_contracts()).unwrap()
+                }
+                _ => handler(contract_addr, msg),
+            };
+            SystemResult::Ok(ContractResult::Ok(response))
+        }
+        _ => panic!("unexpected query"),
+    });
+}
+
+fn proposal_info(proposal_type: ProposalType) -> ProposalInfo {
+    ProposalInfo {
+        proposal_type,
+        executed_at: None,
+        earliest_execution: None,
+        proposal_deposit: None,
+        proposal_actions: vec![],
+        execution_atomicity: None,
+        execution_condition: None,
+        execution_budget: None,
+        metadata: None,
+    }
+}
+
+fn poll(id: u64, status: PollStatus, ends_at: Timestamp) -> Poll {
+    Poll {
+        id,
+        proposer: Addr::unchecked("proposer"),
+        deposit_amount: 0,
+        label: "proposal".to_string(),
+        description: String::new(),
+        scheme: VotingScheme::CoinVoting,
+        status,
+        started_at: Timestamp::from_seconds(0),
+        ends_at,
+        quorum: Decimal::percent(30),
+        threshold: Decimal::percent(50),
+        veto_threshold: None,
+        minimum_yes_votes: None,
+        results: BTreeMap::new(),
+    }
+}
+
+/// Instantiates the controller with a single general proposal holding the given actions.
+fn instantiate_with_proposal_actions(
+    deps: DepsMut,
+    council_gov_config: Option<DaoCouncilSpec>,
+    proposal_actions: Vec<ProposalAction>,
+) {
+    instantiate(
+        deps,
+        mock_env(),
+        mock_info("enterprise", &[]),
+        InstantiateMsg {
+            enterprise_contract: "enterprise".to_string(),
+            dao_type: DaoType::Token,
+            gov_config: gov_config(),
+            council_gov_config,
+            proposal_infos: Some(vec![(
+                1,
+                ProposalInfo {
+                    proposal_actions,
+                    ..proposal_info(ProposalType::General)
+                },
+            )]),
+            initial_cross_chain_treasuries: None,
+        },
+    )
+    .unwrap();
+}
+
+/// Executes the actions of proposal 1 the way the contract does it for itself.
+fn execute_proposal_actions(deps: DepsMut) -> GovernanceControllerResult<Response> {
+    execute(
+        deps,
+        mock_env(),
+        mock_info(mock_env().contract.address.as_str(), &[]),
+        ExecuteMsg::ExecuteProposalActions(ExecuteProposalMsg { proposal_id: 1 }),
+    )
+}
+
+/// Message making the treasury execute the given message.
+fn treasury_cosmos_msg(msg: CosmosMsg) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: "treasury".to_string(),
+        msg: to_json_binary(&TreasuryExecuteMsg::ExecuteCosmosMsgs(
+            ExecuteCosmosMsgsMsg {
+                msgs: vec![to_json_string(&msg).unwrap()],
+            },
+        ))
+        .unwrap(),
+        funds: vec![],
+    })
+}
+
+fn create_proposal_msg(proposal_actions: Vec<ProposalAction>) -> CreateProposalMsg {
+    CreateProposalMsg {
+        title: "proposal".to_string(),
+        description: None,
+        proposal_actions,
+        deposit_owner: None,
+        execution_atomicity: None,
+        expires_at: None,
+        execution_condition: None,
+        execution_budget: None,
+        metadata: None,
+    }
+}
+
+/// Mocks the queries made when creating a proposal in a token DAO, in which everyone but
+/// `non_member` holds 10 of the total of 100 votes, stakes unlock after 1000 seconds, and polls are in
+/// progress for the default vote duration.
+fn mock_token_dao(querier: &mut MockQuerier) {
+    mock_wasm(querier, |contract, msg| match contract {
+        "governance" => match from_json(msg).unwrap() {
+            GovernanceQueryMsg::Poll(params) => {
+                let ends_at = mock_env()
+                    .block
+                    .time
+                    .plus_seconds(gov_config().vote_duration);
+                to_json_binary(&PollResponse {
+                    poll: poll(params.poll_id, PollStatus::InProgress { ends_at }, ends_at),
+                })
+                .unwrap()
+            }
+            _ => panic!("unexpected query"),
+        },
+        "enterprise" => match from_json(msg).unwrap() {
+            EnterpriseQueryMsg::DaoInfo {} => dao_info(DaoType::Token),
+            EnterpriseQueryMsg::IsRestrictedUser(_) => to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        "membership" => match from_json(msg).unwrap() {
+            TokenStakingQueryMsg::TokenConfig {} => to_json_binary(&TokenConfigResponse {
+                enterprise_contract: Addr::unchecked("enterprise"),
+                token_contract: Addr::unchecked("token"),
+                unlocking_period: Duration::Time(1000),
+            })
+            .unwrap(),
+            TokenStakingQueryMsg::UserWeight(params) => to_json_binary(&UserWeightResponse {
+                weight: if params.user == "non_member" {
+                    Uint128::zero()
+                } else {
+                    Uint128::new(10)
+                },
+                user: Addr::unchecked(params.user),
+            })
+            .unwrap(),
+            TokenStakingQueryMsg::TotalWeight(_) => to_json_binary(&TotalWeightResponse {
+                total_weight: Uint128::new(100),
+            })
+            .unwrap(),
+            _ => panic!("unexpected query"),
+        },
+        _ => panic!("unexpected query"),
+    });
+}
+
+/// Mock querier that also answers the interchain-accounts controller module's queries,
+/// for which the governance controller has accounts registered over the given connections.
+struct InterchainAccountsQuerier {
+    base: MockQuerier,
+    registered_connections: Vec<String>,
+}
+
+impl Querier for InterchainAccountsQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        match from_json::<QueryRequest<Empty>>(bin_request) {
+            Ok(QueryRequest::Stargate { path, data }) => {
+                assert_eq!(
+                    path,
+                    "/ibc.applications.interchain_accounts.controller.v1.Query/InterchainAccount"
+                );
+                let request = QueryInterchainAccountRequest::decode(data.as_slice()).unwrap();
+
+                if request.owner == "controller"
+                    && self.registered_connections.contains(&request.connection_id)
+                {
+                    let response = QueryInterchainAccountResponse {
+                        address: format!("ica_{}", request.connection_id),
+                    };
+                    SystemResult::Ok(ContractResult::Ok(response.encode_to_vec().into()))
+                } else {
+                    SystemResult::Ok(ContractResult::Err(
+                        "interchain account not found".to_string(),
+                    ))
+                }
+            }
+            _ => self.base.raw_query(bin_request),
+        }
+    }
+}
+
+fn mock_dependencies_with_interchain_accounts(
+    registered_connections: &[&str],
+) -> OwnedDeps<MockStorage, MockApi, InterchainAccountsQuerier> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: InterchainAccountsQuerier {
+            base: MockQuerier::default(),
+            registered_connections: registered_connections
+                .iter()
+                .map(|connection_id| connection_id.to_string())
+                .collect(),
+        },
+        custom_query_type: PhantomData,
+    }
+}
+
+/// Reply of the governance contract to the creation of the given poll.
+fn create_poll_reply(poll_id: u64) -> Reply {
+    Reply {
+        id: CREATE_POLL_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![Event::new("wasm")
+                .add_attribute("action", "create_poll")
+                .add_attribute("poll_id", poll_id.to_string())],
+            data: None,
+        }),
+    }
+}
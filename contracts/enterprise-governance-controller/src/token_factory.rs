@@ -0,0 +1,115 @@
+use cosmwasm_std::CosmosMsg::Stargate;
+use cosmwasm_std::{
+    to_json_vec, ContractResult, CosmosMsg, Deps, Empty, QueryRequest, StdError, StdResult,
+    SystemResult, Uint128,
+};
+use prost::Message;
+
+const TOKEN_FACTORY_DENOM_PREFIX: &str = "factory/";
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Coin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgMint {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+
+    #[prost(message, tag = "2")]
+    pub amount: Option<Coin>,
+
+    #[prost(string, tag = "3")]
+    pub mint_to_address: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgChangeAdmin {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+
+    #[prost(string, tag = "2")]
+    pub denom: String,
+
+    #[prost(string, tag = "3")]
+    pub new_admin: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryDenomAuthorityMetadataRequest {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryDenomAuthorityMetadataResponse {
+    #[prost(message, tag = "1")]
+    pub authority_metadata: Option<DenomAuthorityMetadata>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DenomAuthorityMetadata {
+    #[prost(string, tag = "1")]
+    pub admin: String,
+}
+
+pub fn is_token_factory_denom(denom: &str) -> bool {
+    denom.starts_with(TOKEN_FACTORY_DENOM_PREFIX)
+}
+
+/// Queries the token-factory module for the current admin of the given denom.
+pub fn query_denom_admin(deps: Deps, denom: String) -> StdResult<String> {
+    let request: QueryRequest<Empty> = QueryRequest::Stargate {
+        path: "/osmosis.tokenfactory.v1beta1.Query/DenomAuthorityMetadata".to_string(),
+        data: QueryDenomAuthorityMetadataRequest { denom }
+            .encode_to_vec()
+            .into(),
+    };
+
+    // stargate queries respond with protobuf-encoded data, so we can't use the regular JSON query
+    let response = match deps.querier.raw_query(&to_json_vec(&request)?) {
+        SystemResult::Err(e) => return Err(StdError::generic_err(e.to_string())),
+        SystemResult::Ok(ContractResult::Err(e)) => return Err(StdError::generic_err(e)),
+        SystemResult::Ok(ContractResult::Ok(response)) => response,
+    };
+
+    QueryDenomAuthorityMetadataResponse::decode(response.as_slice())
+        .map_err(|e| StdError::generic_err(e.to_string()))?
+        .authority_metadata
+        .map(|metadata| metadata.admin)
+        .ok_or_else(|| StdError::generic_err("denom has no authority metadata"))
+}
+
+pub fn mint_msg(sender: String, denom: String, amount: Uint128, recipient: String) -> CosmosMsg {
+    Stargate {
+        type_url: "/osmosis.tokenfactory.v1beta1.MsgMint".to_string(),
+        value: MsgMint {
+            sender,
+            amount: Some(Coin {
+                denom,
+                amount: amount.to_string(),
+            }),
+            mint_to_address: recipient,
+        }
+        .encode_to_vec()
+        .into(),
+    }
+}
+
+pub fn change_admin_msg(sender: String, denom: String, new_admin: String) -> CosmosMsg {
+    Stargate {
+        type_url: "/osmosis.tokenfactory.v1beta1.MsgChangeAdmin".to_string(),
+        value: MsgChangeAdmin {
+            sender,
+            denom,
+            new_admin,
+        }
+        .encode_to_vec()
+        .into(),
+    }
+}
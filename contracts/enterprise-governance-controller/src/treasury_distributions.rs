@@ -0,0 +1,114 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, Uint128};
+use cw_asset::{Asset, AssetInfo};
+use cw_storage_plus::Deque;
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    CustomError, DistributionNotReceived,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use funds_distributor_api::api::DistributionFeeResponse;
+use funds_distributor_api::msg::QueryMsg::DistributionFee;
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Balances the funds distributor is expected to hold once the treasury's distributions are done.
+/// Each entry belongs to a DistributeFromTreasury action whose distribution was sent but
+/// not yet checked, in the order in which the distributions will be executed.
+pub const PENDING_TREASURY_DISTRIBUTIONS: Deque<Vec<ExpectedDistributorBalance>> =
+    Deque::new("pending_treasury_distributions");
+
+#[cw_serde]
+pub struct ExpectedDistributorBalance {
+    pub funds_distributor: Addr,
+    pub asset: AssetInfo,
+    pub minimum_balance: Uint128,
+}
+
+/// Records the balances the funds distributor has to hold once the given assets are distributed,
+/// i.e. its current balances increased by the assets, less the distributor's fee.
+///
+/// Distributions of the same proposal are all sent before any of them executes, so the
+/// expectations of earlier pending distributions are built upon instead of current balances.
+pub fn record_pending_treasury_distribution(
+    deps: DepsMut,
+    funds_distributor: Addr,
+    assets: Vec<Asset>,
+) -> GovernanceControllerResult<()> {
+    let pending_distributions = PENDING_TREASURY_DISTRIBUTIONS
+        .iter(deps.storage)?
+        .collect::<Result<Vec<Vec<ExpectedDistributorBalance>>, _>>()?;
+
+    let fee_bps = deps
+        .querier
+        .query_wasm_smart::<DistributionFeeResponse>(
+            funds_distributor.to_string(),
+            &DistributionFee {},
+        )?
+        .distribution_fee
+        .map_or(0, |fee| fee.fee_bps);
+
+    let mut expected_balances: Vec<ExpectedDistributorBalance> = vec![];
+
+    for asset in assets {
+        let fee = asset.amount.multiply_ratio(fee_bps, BPS_DENOMINATOR);
+        let received = asset.amount.checked_sub(fee)?;
+
+        let previously_expected = expected_balances
+            .iter()
+            .chain(pending_distributions.iter().flatten().rev())
+            .find(|expected| {
+                expected.funds_distributor == funds_distributor && expected.asset == asset.info
+            })
+            .map(|expected| expected.minimum_balance);
+
+        let balance = match previously_expected {
+            Some(balance) => balance,
+            None => asset
+                .info
+                .query_balance(&deps.querier, funds_distributor.to_string())?,
+        };
+
+        let minimum_balance = balance.checked_add(received)?;
+
+        match expected_balances
+            .iter_mut()
+            .find(|expected| expected.asset == asset.info)
+        {
+            Some(expected) => expected.minimum_balance = minimum_balance,
+            None => expected_balances.push(ExpectedDistributorBalance {
+                funds_distributor: funds_distributor.clone(),
+                asset: asset.info,
+                minimum_balance,
+            }),
+        }
+    }
+
+    PENDING_TREASURY_DISTRIBUTIONS.push_back(deps.storage, &expected_balances)?;
+
+    Ok(())
+}
+
+/// Checks that the funds distributor received the assets of the oldest pending distribution.
+pub fn assert_treasury_distribution_received(deps: DepsMut) -> GovernanceControllerResult<()> {
+    let expected_balances = PENDING_TREASURY_DISTRIBUTIONS
+        .pop_front(deps.storage)?
+        .ok_or(CustomError {
+            val: "Invalid state - no pending treasury distribution".to_string(),
+        })?;
+
+    for expected in expected_balances {
+        let balance = expected
+            .asset
+            .query_balance(&deps.querier, expected.funds_distributor.to_string())?;
+
+        if balance < expected.minimum_balance {
+            return Err(DistributionNotReceived {
+                asset: expected.asset.to_string(),
+                balance,
+                expected: expected.minimum_balance,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -1,40 +1,117 @@
-use crate::state::{ENTERPRISE_CONTRACT, GOV_CONFIG};
+use crate::asset_bundles::expand_asset_bundle;
+use crate::contract::query_dao_unlocking_period;
+use crate::execution_condition::is_valid_json;
+use crate::ica::query_interchain_account;
+use crate::state::{COUNCIL_GOV_CONFIG, ENTERPRISE_CONTRACT, GOV_CONFIG};
+use crate::token_factory::{is_token_factory_denom, query_denom_admin};
+use crate::voter_rewards::validate_distribute_to_voters;
+use common::commons::ModifyValue;
 use common::commons::ModifyValue::Change;
-use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps, StdError, Uint128};
-use cw_asset::{AssetInfo, AssetInfoBase, AssetInfoUnchecked};
+use cosmwasm_std::{
+    Addr, BlockInfo, CosmosMsg, Decimal, Deps, StdError, StdResult, Timestamp, Uint128,
+};
+use cw20::{AllowanceResponse, BalanceResponse, Cw20QueryMsg};
+use cw721::Cw721QueryMsg::OwnerOf;
+use cw721::OwnerOfResponse;
+use cw_asset::{Asset, AssetInfo, AssetInfoBase, AssetInfoUnchecked};
 use cw_utils::Duration;
+use cw_utils::Expiration;
+use cw_utils::Expiration::Never;
+use enterprise_factory_api::api::ConfigResponse;
 use enterprise_governance_controller_api::api::ProposalAction::{
     DistributeFunds, ExecuteMsgs, ModifyMultisigMembership, RequestFundingFromDao,
     UpdateAssetWhitelist, UpdateCouncil, UpdateGovConfig, UpdateMetadata,
     UpdateMinimumWeightForRewards, UpdateNftWhitelist, UpgradeDao,
 };
 use enterprise_governance_controller_api::api::{
-    CouncilGovConfig, DaoCouncilSpec, DistributeFundsMsg, ExecuteEnterpriseMsgsMsg, ExecuteMsgsMsg,
-    ExecuteTreasuryMsgsMsg, GovConfig, ModifyMultisigMembershipMsg, ProposalAction,
-    ProposalActionType, RequestFundingFromDaoMsg, UpdateGovConfigMsg,
+    AcceptedDepositAsset, BurnTokensMsg, CouncilGovConfig, DaoCouncilSpec, DescriptionFormat,
+    DistributeFromTreasuryMsg, DistributeFundsMsg, EditCouncilMembersMsg, ExecuteEnterpriseMsgsMsg,
+    ExecuteInterchainAccountMsgsMsg, ExecuteMsgsMsg, ExecuteTreasuryMsgsMsg, ExecutionCondition,
+    GovConfig, GovernanceTokenWhitelistPolicy, GrantSpendingAllowanceMsg, ManageAssetBundlesMsg,
+    MintTokenfactoryDenomMsg, ModifyMultisigMembershipMsg, ProposalAction, ProposalActionType,
+    ProposalCreationPolicy, ProposalMetadata, RegisterInterchainAccountMsg,
+    RequestFundingFromDaoMsg, RevokeSpendingAllowanceMsg, ScheduleCouncilElectionsMsg,
+    SetDenomAdminMsg, SetDistributorEmissionScheduleMsg, SetSelfAdminMsg, TransferNftMsg,
+    UpdateAssetWhitelistProposalActionMsg, UpdateCouncilPermissionsMsg, UpdateDistributionFeeMsg,
+    UpdateEnterpriseFactoryMsg, UpdateGovConfigMsg, UpdateMinimumWeightForRewardsMsg,
+    VetoCouncilConfig, VetoCouncilSpec, VetoThresholdBounds, VotingExtensionConfig,
 };
 use enterprise_governance_controller_api::error::GovernanceControllerError::{
-    Dao, DuplicateCouncilMember, InvalidArgument, InvalidCosmosMessage,
-    MaximumProposalActionsExceeded, Std, UnsupportedCouncilProposalAction, UnsupportedCw1155Asset,
-    ZeroVoteDuration,
+    CannotBurnNativeAsset, CouncilMemberPresentInBothAddAndRemove, CouncilTooPowerfulForAction,
+    CouncilWouldBeEmpty, Dao, DenomNotOwnedByDao, DuplicateCouncilMember, GovConfigValueChanged,
+    GovernanceTokenInWhitelist, InsufficientTreasuryBalance, InterchainAccountAlreadyRegistered,
+    InvalidArgument, InvalidCosmosMessage, InvalidDescriptionContent,
+    MaximumProposalActionsExceeded, MultisigWouldBeEmpty, NativeSpendingAllowanceNotSupported,
+    NftNotOwnedByDao, NoAllowanceToRevoke, NoDaoCouncil, NoInterchainAccount,
+    NotAnEnterpriseFactory, NotCouncilMember, NotDaoMember, NotTokenFactoryDenom,
+    SelfAdminWouldPreventUpgrades, Std, TooManyDistributionAssets,
+    UnsupportedCouncilProposalAction, UnsupportedCw1155Asset, VetoThresholdAboveCeiling,
+    VetoThresholdBelowFloor, ZeroVoteDuration,
 };
 use enterprise_governance_controller_api::error::{
     GovernanceControllerError, GovernanceControllerResult,
 };
 use enterprise_outposts_api::api::RemoteTreasuryTarget;
-use enterprise_protocol::api::DaoType::Multisig;
-use enterprise_protocol::api::{DaoInfoResponse, DaoType, UpgradeDaoMsg};
+use enterprise_protocol::api::DaoType::{Multisig, Token};
+use enterprise_protocol::api::{
+    ComponentContractsResponse, DaoInfoResponse, DaoType, UpgradeDaoMsg,
+};
 use enterprise_protocol::error::DaoError::{
     MigratingToLowerVersion, VoteDurationLongerThanUnstaking,
 };
-use enterprise_protocol::msg::QueryMsg::DaoInfo;
+use enterprise_protocol::msg::QueryMsg::{ComponentContracts, DaoInfo};
+use funds_distributor_api::api::MAX_DISTRIBUTION_FEE_BPS;
+use membership_common_api::api::{
+    MembersParams, MembersResponse, TotalWeightParams, TotalWeightResponse, UserWeightParams,
+    UserWeightResponse,
+};
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
+use token_staking_api::api::TokenConfigResponse;
+use token_staking_api::msg::QueryMsg::TokenConfig;
 use GovernanceControllerError::{MinimumDepositNotAllowed, UnsupportedOperationForDaoType};
 use ProposalAction::ExecuteTreasuryMsgs;
 
 const MAXIMUM_PROPOSAL_ACTIONS: u8 = 10;
 
+/// Maximum number of council seats filled in a single council election.
+const MAXIMUM_COUNCIL_ELECTION_SEATS: u32 = 100;
+
+/// Number of council members loaded per query when reading the whole council.
+const COUNCIL_MEMBERS_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of documents that can be attached to a proposal.
+const MAXIMUM_PROPOSAL_ATTACHMENTS: usize = 10;
+
+/// Maximum length of a proposal's discussion URL and of each of its attachment URIs.
+const MAXIMUM_PROPOSAL_URI_LENGTH: usize = 512;
+
+/// Schemes that proposal discussion URLs can use.
+const PROPOSAL_DISCUSSION_URL_SCHEMES: &[&str] = &["https", "http"];
+
+/// Schemes that proposal attachment URIs can use, covering web links and content-addressed
+/// storage.
+const PROPOSAL_ATTACHMENT_URI_SCHEMES: &[&str] = &["https", "http", "ipfs", "ar"];
+
+/// Constructs not allowed in markdown proposal descriptions, matched case-insensitively.
+/// Covers the opening of any HTML tag, comment or declaration, and URL schemes that
+/// can execute scripts when used as link targets.
+pub const DENIED_MARKDOWN_DESCRIPTION_CONSTRUCTS: &[&str] = &[
+    "<[a-z]",
+    "</",
+    "<!",
+    "<?",
+    "javascript:",
+    "vbscript:",
+    "data:text/html",
+];
+
+/// Constructs not allowed in plain text proposal descriptions, in addition to those
+/// not allowed in markdown descriptions - markdown links and images.
+pub const DENIED_PLAINTEXT_DESCRIPTION_CONSTRUCTS: &[&str] = &["](", "]["];
+
 pub fn validate_dao_gov_config(
+    deps: Deps,
     dao_type: &DaoType,
     dao_gov_config: &GovConfig,
 ) -> GovernanceControllerResult<()> {
@@ -54,11 +131,275 @@ pub fn validate_dao_gov_config(
         }
     }
 
+    if let Some(bounds) = &dao_gov_config.veto_threshold_bounds {
+        validate_veto_threshold_bounds(dao_gov_config, bounds)?;
+    }
+
     // no minimum deposits allowed for multisig DAOs
     if dao_gov_config.minimum_deposit.is_some() && dao_type == &Multisig {
         return Err(MinimumDepositNotAllowed {});
     }
 
+    if let Some(accepted_deposit_assets) = &dao_gov_config.accepted_deposit_assets {
+        validate_accepted_deposit_assets(deps, dao_type, accepted_deposit_assets)?;
+    }
+
+    match &dao_gov_config.proposal_creation_policy {
+        Some(ProposalCreationPolicy::MinWeight(min_weight)) if min_weight.is_zero() => {
+            return Err(InvalidArgument {
+                msg: "Minimum weight for creating proposals must be greater than 0".to_string(),
+            });
+        }
+        Some(ProposalCreationPolicy::Allowlist(allowlist)) if allowlist.is_empty() => {
+            return Err(InvalidArgument {
+                msg: "Allowlist for creating proposals cannot be empty".to_string(),
+            });
+        }
+        _ => {}
+    }
+
+    if let Some(treasury_address) = &dao_gov_config.treasury_address {
+        deps.api.addr_validate(treasury_address.as_str())?;
+    }
+
+    if let Some(proposal_notifier) = &dao_gov_config.proposal_notifier {
+        deps.api.addr_validate(proposal_notifier.as_str())?;
+    }
+
+    if dao_gov_config.max_open_proposals_per_proposer == Some(0) {
+        return Err(InvalidArgument {
+            msg: "Maximum open proposals per proposer must be greater than 0".to_string(),
+        });
+    }
+
+    if let Some(max_execution_budget) = &dao_gov_config.max_execution_budget {
+        validate_max_execution_budget(deps, max_execution_budget)?;
+    }
+
+    if dao_gov_config.max_distribution_assets == Some(0) {
+        return Err(InvalidArgument {
+            msg: "Maximum distribution assets must be greater than 0".to_string(),
+        });
+    }
+
+    if dao_gov_config.minimum_yes_votes == Some(Uint128::zero()) {
+        return Err(InvalidArgument {
+            msg: "Minimum 'yes' votes must be greater than 0".to_string(),
+        });
+    }
+
+    if let Some(voting_extension) = &dao_gov_config.voting_extension {
+        if voting_extension.max_total_extension == 0 {
+            return Err(InvalidArgument {
+                msg: "Maximum total voting extension must be greater than 0".to_string(),
+            });
+        }
+
+        for extender in &voting_extension.extenders {
+            deps.api.addr_validate(extender.as_str())?;
+        }
+    }
+
+    if let Some(bounds) = &dao_gov_config.proposal_expiration_bounds {
+        if bounds.min_duration == 0 || bounds.min_duration > bounds.max_duration {
+            return Err(InvalidArgument {
+                msg: "Proposal expiration bounds must have a minimum duration greater than 0 and not greater than the maximum duration".to_string(),
+            });
+        }
+    }
+
+    if let Some(voter_unlocking_extensions) = &dao_gov_config.voter_unlocking_extensions {
+        // multisig DAOs have no unlocking period to extend
+        if dao_type == &Multisig {
+            return Err(InvalidArgument {
+                msg: "Voter unlocking extensions are not available for multisig DAOs".to_string(),
+            });
+        }
+
+        for (i, voter_unlocking_extension) in voter_unlocking_extensions.iter().enumerate() {
+            if voter_unlocking_extension.extension == 0 {
+                return Err(InvalidArgument {
+                    msg: "Voter unlocking extension must be greater than 0".to_string(),
+                });
+            }
+
+            if voter_unlocking_extensions[..i]
+                .iter()
+                .any(|it| it.action_type == voter_unlocking_extension.action_type)
+            {
+                return Err(InvalidArgument {
+                    msg: format!(
+                        "Duplicate voter unlocking extension for action type {}",
+                        voter_unlocking_extension.action_type
+                    ),
+                });
+            }
+        }
+    }
+
+    if let Some(action_type_cooldowns) = &dao_gov_config.action_type_cooldowns {
+        for (i, action_type_cooldown) in action_type_cooldowns.iter().enumerate() {
+            if action_type_cooldowns[..i]
+                .iter()
+                .any(|it| it.action_type == action_type_cooldown.action_type)
+            {
+                return Err(InvalidArgument {
+                    msg: format!(
+                        "Duplicate cooldown for action type {}",
+                        action_type_cooldown.action_type
+                    ),
+                });
+            }
+        }
+    }
+
+    if let Some(action_type_thresholds) = &dao_gov_config.action_type_thresholds {
+        for (i, action_type_threshold) in action_type_thresholds.iter().enumerate() {
+            validate_quorum_value(action_type_threshold.quorum)?;
+            validate_threshold_value(action_type_threshold.threshold)?;
+
+            if action_type_thresholds[..i]
+                .iter()
+                .any(|it| it.action_type == action_type_threshold.action_type)
+            {
+                return Err(InvalidArgument {
+                    msg: format!(
+                        "Duplicate threshold for action type {}",
+                        action_type_threshold.action_type
+                    ),
+                });
+            }
+        }
+    }
+
+    if let Some(council_action_requirements) = &dao_gov_config.council_action_requirements {
+        for (i, requirement) in council_action_requirements.iter().enumerate() {
+            validate_threshold_value(requirement.minimum_threshold)?;
+
+            if council_action_requirements[..i]
+                .iter()
+                .any(|it| it.action_type == requirement.action_type)
+            {
+                return Err(InvalidArgument {
+                    msg: format!(
+                        "Duplicate council requirement for action type {}",
+                        requirement.action_type
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_max_execution_budget(
+    deps: Deps,
+    max_execution_budget: &[Asset],
+) -> GovernanceControllerResult<()> {
+    if max_execution_budget.is_empty() {
+        return Err(InvalidArgument {
+            msg: "Maximum execution budget must contain at least one asset".to_string(),
+        });
+    }
+
+    let mut assets = HashSet::new();
+
+    for asset in max_execution_budget {
+        if let AssetInfo::Cw20(token_addr) | AssetInfo::Cw1155(token_addr, _) = &asset.info {
+            deps.api.addr_validate(token_addr.as_str())?;
+        }
+
+        if !assets.insert(asset.info.to_string()) {
+            return Err(InvalidArgument {
+                msg: format!(
+                    "Duplicate asset in maximum execution budget: {}",
+                    asset.info
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_accepted_deposit_assets(
+    deps: Deps,
+    dao_type: &DaoType,
+    accepted_deposit_assets: &[AcceptedDepositAsset],
+) -> GovernanceControllerResult<()> {
+    // deposits are not used by multisig DAOs
+    if dao_type == &Multisig {
+        return Err(MinimumDepositNotAllowed {});
+    }
+
+    if accepted_deposit_assets.is_empty() {
+        return Err(InvalidArgument {
+            msg: "At least one accepted deposit asset must be given".to_string(),
+        });
+    }
+
+    let mut assets = HashSet::new();
+
+    for accepted_deposit_asset in accepted_deposit_assets {
+        match &accepted_deposit_asset.asset {
+            AssetInfo::Native(_) => {}
+            AssetInfo::Cw20(token_addr) => {
+                deps.api.addr_validate(token_addr.as_str())?;
+            }
+            _ => return Err(UnsupportedCw1155Asset),
+        }
+
+        if !assets.insert(accepted_deposit_asset.asset.to_string()) {
+            return Err(InvalidArgument {
+                msg: format!(
+                    "Deposit asset {} is listed multiple times",
+                    accepted_deposit_asset.asset
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_veto_threshold_bounds(
+    dao_gov_config: &GovConfig,
+    bounds: &VetoThresholdBounds,
+) -> GovernanceControllerResult<()> {
+    if let Some(floor) = bounds.floor {
+        validate_gt_zero_lte_one(floor, "veto threshold floor".to_string())?;
+    }
+
+    if let Some(ceiling) = bounds.ceiling {
+        validate_gt_zero_lte_one(ceiling, "veto threshold ceiling".to_string())?;
+    }
+
+    if let (Some(floor), Some(ceiling)) = (bounds.floor, bounds.ceiling) {
+        if floor > ceiling {
+            return Err(InvalidArgument {
+                msg: "Veto threshold floor cannot be higher than its ceiling".to_string(),
+            });
+        }
+    }
+
+    // veto threshold falls back to the threshold when not set
+    let veto_threshold = dao_gov_config
+        .veto_threshold
+        .unwrap_or(dao_gov_config.threshold);
+
+    if let Some(floor) = bounds.floor {
+        if veto_threshold < floor {
+            return Err(VetoThresholdBelowFloor { floor });
+        }
+    }
+
+    if let Some(ceiling) = bounds.ceiling {
+        if veto_threshold > ceiling {
+            return Err(VetoThresholdAboveCeiling { ceiling });
+        }
+    }
+
     Ok(())
 }
 
@@ -74,6 +415,75 @@ pub fn validate_unlocking_period(
     Ok(())
 }
 
+/// Validates a proposal's execution condition, ensuring the contract address is valid
+/// and that both the query message and the expected result are valid JSON.
+pub fn validate_execution_condition(
+    deps: Deps,
+    condition: &ExecutionCondition,
+) -> GovernanceControllerResult<()> {
+    deps.api.addr_validate(&condition.contract)?;
+
+    if !is_valid_json(&condition.query_msg) || !is_valid_json(&condition.expected_result) {
+        return Err(InvalidArgument {
+            msg: "Execution condition's query message and expected result have to be valid JSON"
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates an absolute expiration given for a proposal, returning the moment it expires at.
+/// The resulting voting duration has to be within the DAO's proposal expiration bounds,
+/// and cannot be longer than a time-based unlocking period.
+pub fn validate_proposal_expiration(
+    gov_config: &GovConfig,
+    now: Timestamp,
+    expires_at: Expiration,
+    unlocking_period: Option<Duration>,
+) -> GovernanceControllerResult<Timestamp> {
+    let bounds = gov_config
+        .proposal_expiration_bounds
+        .as_ref()
+        .ok_or(InvalidArgument {
+            msg: "The DAO does not allow proposals with an absolute expiration".to_string(),
+        })?;
+
+    let expires_at = match expires_at {
+        Expiration::AtTime(time) => time,
+        Expiration::AtHeight(_) | Never {} => {
+            return Err(InvalidArgument {
+                msg: "Proposal expiration has to be a time".to_string(),
+            });
+        }
+    };
+
+    if expires_at <= now {
+        return Err(InvalidArgument {
+            msg: "Proposal expiration has to be in the future".to_string(),
+        });
+    }
+
+    let duration = expires_at.seconds() - now.seconds();
+
+    if duration < bounds.min_duration || duration > bounds.max_duration {
+        return Err(InvalidArgument {
+            msg: format!(
+                "Proposal voting duration has to be between {} and {} seconds",
+                bounds.min_duration, bounds.max_duration
+            ),
+        });
+    }
+
+    if let Some(Duration::Time(unlocking_time)) = unlocking_period {
+        if unlocking_time < duration {
+            return Err(Dao(VoteDurationLongerThanUnstaking));
+        }
+    }
+
+    Ok(expires_at)
+}
+
 fn validate_quorum_value(quorum: Decimal) -> GovernanceControllerResult<()> {
     validate_gt_zero_lte_one(quorum, "quorum".to_string())
 }
@@ -93,9 +503,105 @@ fn validate_gt_zero_lte_one(value: Decimal, value_name: String) -> GovernanceCon
     Ok(())
 }
 
+/// Validates the proposal description against the given format, rejecting any
+/// constructs denied for that format.
+pub fn validate_proposal_description(
+    description: &str,
+    format: &DescriptionFormat,
+) -> GovernanceControllerResult<()> {
+    let denied_constructs = match format {
+        DescriptionFormat::Markdown => DENIED_MARKDOWN_DESCRIPTION_CONSTRUCTS.to_vec(),
+        DescriptionFormat::Plaintext => [
+            DENIED_MARKDOWN_DESCRIPTION_CONSTRUCTS,
+            DENIED_PLAINTEXT_DESCRIPTION_CONSTRUCTS,
+        ]
+        .concat(),
+    };
+
+    let description = description.to_lowercase();
+
+    for construct in denied_constructs {
+        if contains_construct(&description, construct) {
+            return Err(InvalidDescriptionContent {
+                construct: construct.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the proposal's discussion URL and attachment URIs are well-formed,
+/// and that there are not too many attachments.
+pub fn validate_proposal_metadata(metadata: &ProposalMetadata) -> GovernanceControllerResult<()> {
+    if metadata.attachments.len() > MAXIMUM_PROPOSAL_ATTACHMENTS {
+        return Err(InvalidArgument {
+            msg: format!(
+                "A proposal can have at most {} attachments",
+                MAXIMUM_PROPOSAL_ATTACHMENTS
+            ),
+        });
+    }
+
+    if let Some(discussion_url) = &metadata.discussion_url {
+        validate_proposal_uri(discussion_url, PROPOSAL_DISCUSSION_URL_SCHEMES)?;
+    }
+
+    for attachment in &metadata.attachments {
+        validate_proposal_uri(attachment, PROPOSAL_ATTACHMENT_URI_SCHEMES)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the URI uses one of the schemes, has something after the scheme,
+/// and contains no whitespace or control characters.
+fn validate_proposal_uri(uri: &str, schemes: &[&str]) -> GovernanceControllerResult<()> {
+    let invalid_uri = || InvalidArgument {
+        msg: format!("Invalid proposal metadata URI: {}", uri),
+    };
+
+    if uri.len() > MAXIMUM_PROPOSAL_URI_LENGTH {
+        return Err(InvalidArgument {
+            msg: format!(
+                "Proposal metadata URIs can be at most {} characters long",
+                MAXIMUM_PROPOSAL_URI_LENGTH
+            ),
+        });
+    }
+
+    let (scheme, rest) = uri.split_once("://").ok_or_else(invalid_uri)?;
+
+    if !schemes.contains(&scheme.to_lowercase().as_str()) {
+        return Err(invalid_uri());
+    }
+
+    if rest.is_empty()
+        || rest.starts_with('/')
+        || uri.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        return Err(invalid_uri());
+    }
+
+    Ok(())
+}
+
+/// Checks whether the text contains the construct, where '[a-z]' in the construct
+/// matches any ASCII letter.
+fn contains_construct(text: &str, construct: &str) -> bool {
+    match construct.strip_suffix("[a-z]") {
+        Some(prefix) => text.match_indices(prefix).any(|(index, _)| {
+            text[index + prefix.len()..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic())
+        }),
+        None => text.contains(construct),
+    }
+}
+
 pub fn validate_proposal_actions(
     deps: Deps,
-    dao_type: DaoType,
     proposal_actions: &Vec<ProposalAction>,
 ) -> GovernanceControllerResult<()> {
     if proposal_actions.len() > MAXIMUM_PROPOSAL_ACTIONS as usize {
@@ -105,46 +611,313 @@ pub fn validate_proposal_actions(
     }
 
     for proposal_action in proposal_actions {
-        match proposal_action {
-            UpdateAssetWhitelist(msg) => validate_asset_whitelist_changes(
-                deps,
-                &msg.remote_treasury_target,
-                &msg.add,
-                &msg.remove,
-            )?,
-            UpdateNftWhitelist(msg) => validate_nft_whitelist_changes(deps, &msg.add, &msg.remove)?,
-            UpgradeDao(msg) => validate_upgrade_dao(deps, msg)?,
-            ExecuteMsgs(msg) => validate_execute_msgs(msg)?,
-            ExecuteTreasuryMsgs(msg) => validate_execute_treasury_msgs(msg)?,
-            ProposalAction::ExecuteEnterpriseMsgs(msg) => validate_execute_enterprise_msgs(msg)?,
-            ModifyMultisigMembership(msg) => {
-                validate_modify_multisig_membership(deps, dao_type.clone(), msg)?
-            }
-            UpdateCouncil(msg) => {
-                validate_dao_council(deps, msg.dao_council.clone())?;
-            }
-            DistributeFunds(msg) => validate_distribute_funds(deps, msg)?,
-            RequestFundingFromDao(msg) => validate_request_funding_from_dao(deps, msg)?,
-            UpdateGovConfig(msg) => {
-                let gov_config = GOV_CONFIG.load(deps.storage)?;
+        validate_proposal_action(deps, proposal_action)?;
+    }
 
-                let updated_gov_config = apply_gov_config_changes(gov_config, msg);
+    Ok(())
+}
 
-                validate_dao_gov_config(&dao_type, &updated_gov_config)?;
-            }
-            UpdateMetadata(_) | UpdateMinimumWeightForRewards(_) => {
-                // no-op
-            }
-            ProposalAction::DeployCrossChainTreasury(_) => {
-                // TODO: no-op for now, can we even validate anything here?
-            }
+/// Validates a single proposal action against the current state.
+/// Actions whose validity depends on the DAO's type load the type from the enterprise contract
+/// themselves, so they are always validated against the DAO's actual current type.
+pub fn validate_proposal_action(
+    deps: Deps,
+    proposal_action: &ProposalAction,
+) -> GovernanceControllerResult<()> {
+    match proposal_action {
+        UpdateAssetWhitelist(msg) => {
+            validate_asset_whitelist_update(deps, &query_dao_type(deps)?, msg)?
+        }
+        ProposalAction::UpdateAssetWhitelistWithBundle(msg) => {
+            let msg = expand_asset_bundle(deps.storage, msg)?;
+            validate_asset_whitelist_update(deps, &query_dao_type(deps)?, &msg)?
+        }
+        ProposalAction::ManageAssetBundles(msg) => validate_manage_asset_bundles(deps, msg)?,
+        ProposalAction::ScheduleCouncilElections(msg) => validate_schedule_council_elections(msg)?,
+        UpdateNftWhitelist(msg) => validate_nft_whitelist_changes(deps, &msg.add, &msg.remove)?,
+        UpgradeDao(msg) => validate_upgrade_dao(deps, msg)?,
+        ExecuteMsgs(msg) => validate_execute_msgs(msg)?,
+        ExecuteTreasuryMsgs(msg) => validate_execute_treasury_msgs(msg)?,
+        ProposalAction::ExecuteEnterpriseMsgs(msg) => validate_execute_enterprise_msgs(msg)?,
+        ProposalAction::ExecuteInterchainAccountMsgs(msg) => {
+            validate_execute_interchain_account_msgs(deps, msg)?
+        }
+        ModifyMultisigMembership(msg) => {
+            validate_modify_multisig_membership(deps, query_dao_type(deps)?, msg)?
+        }
+        UpdateCouncil(msg) => {
+            let gov_config = GOV_CONFIG.load(deps.storage)?;
+            validate_dao_council(deps, &gov_config, msg.dao_council.clone())?;
+        }
+        ProposalAction::EditCouncilMembers(msg) => validate_edit_council_members(deps, msg)?,
+        DistributeFunds(msg) => validate_distribute_funds(deps, msg)?,
+        ProposalAction::DistributeFromTreasury(msg) => {
+            validate_distribute_from_treasury(deps, msg)?
+        }
+        RequestFundingFromDao(msg) => validate_request_funding_from_dao(deps, msg)?,
+        UpdateGovConfig(msg) => {
+            let gov_config = GOV_CONFIG.load(deps.storage)?;
+
+            assert_expected_gov_config(deps, &gov_config, msg)?;
+
+            let updated_gov_config = apply_gov_config_changes(deps, gov_config, msg)?;
+
+            validate_dao_gov_config(deps, &query_dao_type(deps)?, &updated_gov_config)?;
+            validate_minimum_yes_votes_attainable(deps, &updated_gov_config)?;
+            validate_council_meets_changed_requirements(deps, &updated_gov_config, msg)?;
+        }
+        UpdateMetadata(_) => {
+            // no-op
+        }
+        UpdateMinimumWeightForRewards(msg) => validate_minimum_weight_for_rewards(deps, msg)?,
+        ProposalAction::UpdateDistributionFee(msg) => validate_update_distribution_fee(deps, msg)?,
+        ProposalAction::SetDistributorClaimsPaused(_) => {
+            // no-op
+        }
+        ProposalAction::TransferNft(msg) => validate_transfer_nft(deps, msg)?,
+        ProposalAction::SetDistributorEmissionSchedule(msg) => {
+            validate_set_distributor_emission_schedule(deps, msg)?
+        }
+        ProposalAction::DistributeToVoters(msg) => validate_distribute_to_voters(deps, msg)?,
+        ProposalAction::RegisterInterchainAccount(msg) => {
+            validate_register_interchain_account(deps, msg)?
+        }
+        ProposalAction::DeployCrossChainTreasury(_) => {
+            // TODO: no-op for now, can we even validate anything here?
+        }
+        ProposalAction::BurnTokens(msg) => validate_burn_tokens(deps, msg)?,
+        ProposalAction::MintTokenfactoryDenom(msg) => validate_mint_tokenfactory_denom(deps, msg)?,
+        ProposalAction::SetDenomAdmin(msg) => validate_set_denom_admin(deps, msg)?,
+        ProposalAction::UpdateEnterpriseFactory(msg) => {
+            validate_update_enterprise_factory(deps, msg)?
+        }
+        ProposalAction::SetSelfAdmin(msg) => validate_set_self_admin(deps, msg)?,
+        ProposalAction::UpdateCouncilPermissions(msg) => {
+            validate_update_council_permissions(deps, msg)?
+        }
+        ProposalAction::GrantSpendingAllowance(msg) => {
+            validate_grant_spending_allowance(deps, msg)?
+        }
+        ProposalAction::RevokeSpendingAllowance(msg) => {
+            validate_revoke_spending_allowance(deps, msg)?
+        }
+        ProposalAction::UpdateVetoCouncil(msg) => {
+            validate_veto_council(deps, msg.veto_council.clone())?;
         }
     }
 
     Ok(())
 }
 
-pub fn apply_gov_config_changes(gov_config: GovConfig, msg: &UpdateGovConfigMsg) -> GovConfig {
+/// Checks that each field the message changes still has the value it had in the config
+/// the changes were drafted against, if the message specifies one.
+/// A changed unlocking period is checked against the DAO's current unlocking period, if the
+/// message specifies the expected one.
+pub fn assert_expected_gov_config(
+    deps: Deps,
+    gov_config: &GovConfig,
+    msg: &UpdateGovConfigMsg,
+) -> GovernanceControllerResult<()> {
+    if let (Change(_), Some(expected_unlocking_period)) =
+        (&msg.unlocking_period, &msg.expected_unlocking_period)
+    {
+        if query_dao_unlocking_period(deps)?.as_ref() != Some(expected_unlocking_period) {
+            return Err(GovConfigValueChanged {
+                field: "unlocking_period".to_string(),
+            });
+        }
+    }
+
+    let Some(expected) = &msg.expected_current_config else {
+        return Ok(());
+    };
+
+    // destructured without a rest pattern, so that new fields have to be checked here
+    let GovConfig {
+        quorum,
+        threshold,
+        veto_threshold,
+        vote_duration,
+        minimum_deposit,
+        allow_early_proposal_execution,
+        invalid_action_policy,
+        proposal_creation_policy,
+        max_open_proposals_per_proposer,
+        // not changeable through UpdateGovConfig
+        veto_threshold_bounds: _,
+        governance_token_whitelist_policy,
+        treasury_address,
+        description_format,
+        accepted_deposit_assets,
+        auto_execute_on_pass,
+        action_type_cooldowns,
+        voting_extension,
+        minimum_yes_votes,
+        voter_unlocking_extensions,
+        proposal_expiration_bounds,
+        private_proposals,
+        max_distribution_assets,
+        max_execution_budget,
+        proposal_notifier,
+        action_type_thresholds,
+        manual_deposit_refunds,
+        council_action_requirements,
+        allow_vote_changes,
+        restrict_late_joiner_votes,
+    } = expected.as_ref();
+
+    let changed_fields = [
+        (
+            "quorum",
+            is_change(&msg.quorum) && gov_config.quorum != *quorum,
+        ),
+        (
+            "threshold",
+            is_change(&msg.threshold) && gov_config.threshold != *threshold,
+        ),
+        (
+            "veto_threshold",
+            is_change(&msg.veto_threshold) && gov_config.veto_threshold != *veto_threshold,
+        ),
+        (
+            "voting_duration",
+            is_change(&msg.voting_duration) && gov_config.vote_duration != *vote_duration,
+        ),
+        (
+            "minimum_deposit",
+            is_change(&msg.minimum_deposit) && gov_config.minimum_deposit != *minimum_deposit,
+        ),
+        (
+            "allow_early_proposal_execution",
+            is_change(&msg.allow_early_proposal_execution)
+                && gov_config.allow_early_proposal_execution != *allow_early_proposal_execution,
+        ),
+        (
+            "invalid_action_policy",
+            is_change(&msg.invalid_action_policy)
+                && gov_config.invalid_action_policy != *invalid_action_policy,
+        ),
+        (
+            "proposal_creation_policy",
+            is_change(&msg.proposal_creation_policy)
+                && gov_config.proposal_creation_policy != *proposal_creation_policy,
+        ),
+        (
+            "max_open_proposals_per_proposer",
+            is_change(&msg.max_open_proposals_per_proposer)
+                && gov_config.max_open_proposals_per_proposer != *max_open_proposals_per_proposer,
+        ),
+        (
+            "governance_token_whitelist_policy",
+            is_change(&msg.governance_token_whitelist_policy)
+                && gov_config.governance_token_whitelist_policy
+                    != *governance_token_whitelist_policy,
+        ),
+        (
+            "treasury_address",
+            is_change(&msg.treasury_address) && gov_config.treasury_address != *treasury_address,
+        ),
+        (
+            "description_format",
+            is_change(&msg.description_format)
+                && gov_config.description_format != *description_format,
+        ),
+        (
+            "accepted_deposit_assets",
+            is_change(&msg.accepted_deposit_assets)
+                && gov_config.accepted_deposit_assets != *accepted_deposit_assets,
+        ),
+        (
+            "auto_execute_on_pass",
+            is_change(&msg.auto_execute_on_pass)
+                && gov_config.auto_execute_on_pass != *auto_execute_on_pass,
+        ),
+        (
+            "action_type_cooldowns",
+            is_change(&msg.action_type_cooldowns)
+                && gov_config.action_type_cooldowns != *action_type_cooldowns,
+        ),
+        (
+            "voting_extension",
+            is_change(&msg.voting_extension) && gov_config.voting_extension != *voting_extension,
+        ),
+        (
+            "minimum_yes_votes",
+            is_change(&msg.minimum_yes_votes) && gov_config.minimum_yes_votes != *minimum_yes_votes,
+        ),
+        (
+            "voter_unlocking_extensions",
+            is_change(&msg.voter_unlocking_extensions)
+                && gov_config.voter_unlocking_extensions != *voter_unlocking_extensions,
+        ),
+        (
+            "proposal_expiration_bounds",
+            is_change(&msg.proposal_expiration_bounds)
+                && gov_config.proposal_expiration_bounds != *proposal_expiration_bounds,
+        ),
+        (
+            "private_proposals",
+            is_change(&msg.private_proposals) && gov_config.private_proposals != *private_proposals,
+        ),
+        (
+            "max_distribution_assets",
+            is_change(&msg.max_distribution_assets)
+                && gov_config.max_distribution_assets != *max_distribution_assets,
+        ),
+        (
+            "max_execution_budget",
+            is_change(&msg.max_execution_budget)
+                && gov_config.max_execution_budget != *max_execution_budget,
+        ),
+        (
+            "proposal_notifier",
+            is_change(&msg.proposal_notifier) && gov_config.proposal_notifier != *proposal_notifier,
+        ),
+        (
+            "action_type_thresholds",
+            is_change(&msg.action_type_thresholds)
+                && gov_config.action_type_thresholds != *action_type_thresholds,
+        ),
+        (
+            "manual_deposit_refunds",
+            is_change(&msg.manual_deposit_refunds)
+                && gov_config.manual_deposit_refunds != *manual_deposit_refunds,
+        ),
+        (
+            "council_action_requirements",
+            is_change(&msg.council_action_requirements)
+                && gov_config.council_action_requirements != *council_action_requirements,
+        ),
+        (
+            "allow_vote_changes",
+            is_change(&msg.allow_vote_changes)
+                && gov_config.allow_vote_changes != *allow_vote_changes,
+        ),
+        (
+            "restrict_late_joiner_votes",
+            is_change(&msg.restrict_late_joiner_votes)
+                && gov_config.restrict_late_joiner_votes != *restrict_late_joiner_votes,
+        ),
+    ];
+
+    match changed_fields.into_iter().find(|(_, changed)| *changed) {
+        Some((field, _)) => Err(GovConfigValueChanged {
+            field: field.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn is_change<T>(value: &ModifyValue<T>) -> bool {
+    matches!(value, Change(_))
+}
+
+/// Applies the changes to the gov config, validating any addresses and assets being changed.
+pub fn apply_gov_config_changes(
+    deps: Deps,
+    gov_config: GovConfig,
+    msg: &UpdateGovConfigMsg,
+) -> GovernanceControllerResult<GovConfig> {
     let mut gov_config = gov_config;
 
     if let Change(quorum) = msg.quorum {
@@ -171,27 +944,169 @@ pub fn apply_gov_config_changes(gov_config: GovConfig, msg: &UpdateGovConfigMsg)
         gov_config.allow_early_proposal_execution = allow_early_proposal_execution;
     }
 
-    gov_config
-}
+    if let Change(invalid_action_policy) = msg.invalid_action_policy.clone() {
+        gov_config.invalid_action_policy = invalid_action_policy;
+    }
 
-// TODO: this is never called, remove? first search where it should be used
-pub fn normalize_asset_whitelist(
-    deps: Deps,
+    if let Change(proposal_creation_policy) = msg.proposal_creation_policy.clone() {
+        gov_config.proposal_creation_policy = proposal_creation_policy;
+    }
+
+    if let Change(max_open_proposals_per_proposer) = msg.max_open_proposals_per_proposer {
+        gov_config.max_open_proposals_per_proposer = max_open_proposals_per_proposer;
+    }
+
+    if let Change(treasury_address) = &msg.treasury_address {
+        gov_config.treasury_address = treasury_address
+            .as_ref()
+            .map(|address| deps.api.addr_validate(address))
+            .transpose()?;
+    }
+
+    if let Change(governance_token_whitelist_policy) = msg.governance_token_whitelist_policy.clone()
+    {
+        gov_config.governance_token_whitelist_policy = governance_token_whitelist_policy;
+    }
+
+    if let Change(description_format) = msg.description_format.clone() {
+        gov_config.description_format = description_format;
+    }
+
+    if let Change(accepted_deposit_assets) = &msg.accepted_deposit_assets {
+        gov_config.accepted_deposit_assets = accepted_deposit_assets
+            .as_ref()
+            .map(|assets| {
+                assets
+                    .iter()
+                    .map(|accepted_deposit_asset| {
+                        Ok(AcceptedDepositAsset {
+                            asset: accepted_deposit_asset.asset.check(deps.api, None)?,
+                            minimum_amount: accepted_deposit_asset.minimum_amount,
+                        })
+                    })
+                    .collect::<GovernanceControllerResult<Vec<AcceptedDepositAsset>>>()
+            })
+            .transpose()?;
+    }
+
+    if let Change(auto_execute_on_pass) = msg.auto_execute_on_pass {
+        gov_config.auto_execute_on_pass = auto_execute_on_pass;
+    }
+
+    if let Change(action_type_cooldowns) = msg.action_type_cooldowns.clone() {
+        gov_config.action_type_cooldowns = action_type_cooldowns;
+    }
+
+    if let Change(voting_extension) = &msg.voting_extension {
+        gov_config.voting_extension = match voting_extension {
+            Some(voting_extension) => Some(VotingExtensionConfig {
+                max_total_extension: voting_extension.max_total_extension,
+                extenders: voting_extension
+                    .extenders
+                    .iter()
+                    .map(|extender| deps.api.addr_validate(extender))
+                    .collect::<StdResult<Vec<Addr>>>()?,
+            }),
+            None => None,
+        };
+    }
+
+    if let Change(minimum_yes_votes) = msg.minimum_yes_votes {
+        gov_config.minimum_yes_votes = minimum_yes_votes;
+    }
+
+    if let Change(voter_unlocking_extensions) = msg.voter_unlocking_extensions.clone() {
+        gov_config.voter_unlocking_extensions = voter_unlocking_extensions;
+    }
+
+    if let Change(proposal_expiration_bounds) = msg.proposal_expiration_bounds.clone() {
+        gov_config.proposal_expiration_bounds = proposal_expiration_bounds;
+    }
+
+    if let Change(private_proposals) = msg.private_proposals {
+        gov_config.private_proposals = private_proposals;
+    }
+
+    if let Change(max_distribution_assets) = msg.max_distribution_assets {
+        gov_config.max_distribution_assets = max_distribution_assets;
+    }
+
+    if let Change(max_execution_budget) = &msg.max_execution_budget {
+        gov_config.max_execution_budget = max_execution_budget
+            .as_ref()
+            .map(|assets| {
+                assets
+                    .iter()
+                    .map(|asset| asset.check(deps.api, None))
+                    .collect::<Result<Vec<Asset>, _>>()
+            })
+            .transpose()?;
+    }
+
+    if let Change(proposal_notifier) = &msg.proposal_notifier {
+        gov_config.proposal_notifier = proposal_notifier
+            .as_ref()
+            .map(|address| deps.api.addr_validate(address))
+            .transpose()?;
+    }
+
+    if let Change(action_type_thresholds) = msg.action_type_thresholds.clone() {
+        gov_config.action_type_thresholds = action_type_thresholds;
+    }
+
+    if let Change(manual_deposit_refunds) = msg.manual_deposit_refunds {
+        gov_config.manual_deposit_refunds = manual_deposit_refunds;
+    }
+
+    if let Change(council_action_requirements) = msg.council_action_requirements.clone() {
+        gov_config.council_action_requirements = council_action_requirements;
+    }
+
+    if let Change(allow_vote_changes) = msg.allow_vote_changes {
+        gov_config.allow_vote_changes = allow_vote_changes;
+    }
+
+    if let Change(restrict_late_joiner_votes) = msg.restrict_late_joiner_votes {
+        gov_config.restrict_late_joiner_votes = restrict_late_joiner_votes;
+    }
+
+    Ok(gov_config)
+}
+
+// TODO: this is never called, remove? first search where it should be used
+pub fn normalize_asset_whitelist(
+    deps: Deps,
     asset_whitelist: &Vec<AssetInfoUnchecked>,
 ) -> GovernanceControllerResult<Vec<AssetInfo>> {
     let mut normalized_asset_whitelist: Vec<AssetInfo> = vec![];
 
     let asset_hashsets = split_asset_hashsets(deps, asset_whitelist)?;
 
-    for denom in asset_hashsets.native {
+    // hash sets iterate in no particular order, so each category is sorted to keep the
+    // resulting whitelist the same regardless of the input order
+    let mut native_assets: Vec<String> = asset_hashsets.native.into_iter().collect();
+    native_assets.sort();
+
+    let mut cw20_assets: Vec<Addr> = asset_hashsets.cw20.into_iter().collect();
+    cw20_assets.sort();
+
+    // the treasury stores cw1155 assets under composite keys, whose address part is prefixed by
+    // its length - sorting the same way keeps this order identical to the one the treasury's
+    // paginated AssetWhitelist query returns
+    let mut cw1155_assets: Vec<(Addr, String)> = asset_hashsets.cw1155.into_iter().collect();
+    cw1155_assets.sort_by(|(addr1, id1), (addr2, id2)| {
+        (addr1.as_str().len(), addr1, id1).cmp(&(addr2.as_str().len(), addr2, id2))
+    });
+
+    for denom in native_assets {
         normalized_asset_whitelist.push(AssetInfo::native(denom))
     }
 
-    for cw20 in asset_hashsets.cw20 {
+    for cw20 in cw20_assets {
         normalized_asset_whitelist.push(AssetInfo::cw20(cw20))
     }
 
-    for (addr, token_id) in asset_hashsets.cw1155 {
+    for (addr, token_id) in cw1155_assets {
         normalized_asset_whitelist.push(AssetInfo::cw1155(addr, token_id))
     }
 
@@ -240,6 +1155,136 @@ fn validate_asset_whitelist_changes(
     Ok(())
 }
 
+fn validate_asset_whitelist_update(
+    deps: Deps,
+    dao_type: &DaoType,
+    msg: &UpdateAssetWhitelistProposalActionMsg,
+) -> GovernanceControllerResult<()> {
+    validate_asset_whitelist_changes(deps, &msg.remote_treasury_target, &msg.add, &msg.remove)?;
+
+    if adds_governance_token_to_whitelist(deps, dao_type, msg)? {
+        let policy = GOV_CONFIG
+            .load(deps.storage)?
+            .governance_token_whitelist_policy
+            .unwrap_or(GovernanceTokenWhitelistPolicy::Reject);
+        if policy == GovernanceTokenWhitelistPolicy::Reject {
+            return Err(GovernanceTokenInWhitelist);
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_schedule_council_elections(
+    msg: &ScheduleCouncilElectionsMsg,
+) -> GovernanceControllerResult<()> {
+    if let Some(schedule) = &msg.schedule {
+        if schedule.interval == 0 {
+            return Err(InvalidArgument {
+                msg: "Council election interval must be greater than 0".to_string(),
+            });
+        }
+
+        if schedule.nomination_period == 0 {
+            return Err(InvalidArgument {
+                msg: "Council election nomination period must be greater than 0".to_string(),
+            });
+        }
+
+        if schedule.seats == 0 || schedule.seats > MAXIMUM_COUNCIL_ELECTION_SEATS {
+            return Err(InvalidArgument {
+                msg: format!(
+                    "Council elections must fill between 1 and {} seats",
+                    MAXIMUM_COUNCIL_ELECTION_SEATS
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a candidate nominated in a council election, who has to be a member of the DAO.
+pub fn validate_council_candidate(
+    deps: Deps,
+    candidate: String,
+) -> GovernanceControllerResult<Addr> {
+    let candidate = deps.api.addr_validate(&candidate)?;
+
+    let components = query_enterprise_components(deps)?;
+
+    let dao_weight: UserWeightResponse = deps.querier.query_wasm_smart(
+        components.membership_contract.to_string(),
+        &membership_common_api::msg::QueryMsg::UserWeight(UserWeightParams {
+            user: candidate.to_string(),
+        }),
+    )?;
+
+    if dao_weight.weight.is_zero() {
+        return Err(NotDaoMember {
+            member: candidate.to_string(),
+        });
+    }
+
+    Ok(candidate)
+}
+
+fn validate_manage_asset_bundles(
+    deps: Deps,
+    msg: &ManageAssetBundlesMsg,
+) -> GovernanceControllerResult<()> {
+    for (i, bundle) in msg.register.iter().enumerate() {
+        if bundle.name.trim().is_empty() {
+            return Err(InvalidArgument {
+                msg: "Asset bundle name cannot be empty".to_string(),
+            });
+        }
+
+        if bundle.assets.is_empty() {
+            return Err(InvalidArgument {
+                msg: format!("Asset bundle {} cannot be empty", bundle.name),
+            });
+        }
+
+        if msg.register[..i].iter().any(|it| it.name == bundle.name)
+            || msg.remove.contains(&bundle.name)
+        {
+            return Err(InvalidArgument {
+                msg: format!("Asset bundle {} is referenced more than once", bundle.name),
+            });
+        }
+
+        // validates addresses and rejects duplicate assets
+        split_asset_hashsets(deps, &bundle.assets)?;
+    }
+
+    Ok(())
+}
+
+/// Whether the whitelist changes add the DAO's own governance token to the whitelist.
+/// Having the governance token both staked and held as a treasury asset makes the two balances
+/// easy to mix up, so this is rejected unless the DAO opted in.
+pub fn adds_governance_token_to_whitelist(
+    deps: Deps,
+    dao_type: &DaoType,
+    msg: &UpdateAssetWhitelistProposalActionMsg,
+) -> GovernanceControllerResult<bool> {
+    if dao_type != &Token || msg.remote_treasury_target.is_some() {
+        return Ok(false);
+    }
+
+    let membership_contract = query_enterprise_components(deps)?.membership_contract;
+    let token_config: TokenConfigResponse = deps
+        .querier
+        .query_wasm_smart(membership_contract.to_string(), &TokenConfig {})?;
+
+    let add_asset_hashsets = split_asset_hashsets(deps, &msg.add)?;
+
+    Ok(add_asset_hashsets
+        .cw20
+        .contains(&token_config.token_contract))
+}
+
 fn split_asset_hashsets(
     deps: Deps,
     assets: &Vec<AssetInfoUnchecked>,
@@ -342,6 +1387,46 @@ pub fn validate_upgrade_dao(deps: Deps, msg: &UpgradeDaoMsg) -> GovernanceContro
     Ok(())
 }
 
+/// Checks that the new factory answers the enterprise factory's config query.
+pub fn validate_update_enterprise_factory(
+    deps: Deps,
+    msg: &UpdateEnterpriseFactoryMsg,
+) -> GovernanceControllerResult<()> {
+    let new_factory = deps.api.addr_validate(&msg.new_factory)?;
+
+    deps.querier
+        .query_wasm_smart::<ConfigResponse>(
+            new_factory.to_string(),
+            &enterprise_factory_api::msg::QueryMsg::Config {},
+        )
+        .map_err(|_| NotAnEnterpriseFactory {
+            address: new_factory.to_string(),
+        })?;
+
+    Ok(())
+}
+
+/// Validates the new admin of the enterprise contract. Since the enterprise contract migrates
+/// itself when the DAO is upgraded, anything but itself as the admin has to be explicitly allowed.
+pub fn validate_set_self_admin(
+    deps: Deps,
+    msg: &SetSelfAdminMsg,
+) -> GovernanceControllerResult<()> {
+    let new_admin = msg
+        .new_admin
+        .as_ref()
+        .map(|new_admin| deps.api.addr_validate(new_admin))
+        .transpose()?;
+
+    let enterprise_contract = ENTERPRISE_CONTRACT.load(deps.storage)?;
+
+    if new_admin != Some(enterprise_contract) && !msg.allow_losing_upgradability {
+        return Err(SelfAdminWouldPreventUpgrades);
+    }
+
+    Ok(())
+}
+
 fn validate_execute_msgs(msg: &ExecuteMsgsMsg) -> GovernanceControllerResult<()> {
     validate_custom_execute_msgs(&msg.msgs)
 }
@@ -356,6 +1441,106 @@ fn validate_execute_enterprise_msgs(
     validate_custom_execute_msgs(&msg.msgs)
 }
 
+/// Checks that the governance controller has no interchain account registered over the
+/// given connection yet.
+fn validate_register_interchain_account(
+    deps: Deps,
+    msg: &RegisterInterchainAccountMsg,
+) -> GovernanceControllerResult<()> {
+    validate_connection_id(&msg.connection_id)?;
+
+    let governance_controller = query_enterprise_components(deps)?
+        .enterprise_governance_controller_contract
+        .to_string();
+
+    if query_interchain_account(deps, governance_controller, msg.connection_id.clone()).is_ok() {
+        return Err(InterchainAccountAlreadyRegistered {
+            connection_id: msg.connection_id.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the messages have fully-qualified protobuf type URLs, and that the governance
+/// controller has an interchain account registered over the given connection.
+fn validate_execute_interchain_account_msgs(
+    deps: Deps,
+    msg: &ExecuteInterchainAccountMsgsMsg,
+) -> GovernanceControllerResult<()> {
+    validate_connection_id(&msg.connection_id)?;
+
+    if msg.msgs.is_empty() {
+        return Err(InvalidArgument {
+            msg: "At least one interchain account message has to be given".to_string(),
+        });
+    }
+
+    for ica_msg in &msg.msgs {
+        if !is_valid_type_url(&ica_msg.type_url) {
+            return Err(InvalidArgument {
+                msg: format!(
+                    "Invalid interchain account message type URL: {}",
+                    ica_msg.type_url
+                ),
+            });
+        }
+    }
+
+    if msg.timeout_nanos == Some(0) {
+        return Err(InvalidArgument {
+            msg: "Interchain account packet timeout has to be greater than 0".to_string(),
+        });
+    }
+
+    let governance_controller = query_enterprise_components(deps)?
+        .enterprise_governance_controller_contract
+        .to_string();
+
+    query_interchain_account(deps, governance_controller, msg.connection_id.clone()).map_err(
+        |_| NoInterchainAccount {
+            connection_id: msg.connection_id.clone(),
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Checks that the connection ID is an IBC connection identifier, e.g. 'connection-0'.
+fn validate_connection_id(connection_id: &str) -> GovernanceControllerResult<()> {
+    let is_valid = connection_id
+        .strip_prefix("connection-")
+        .map(|sequence| !sequence.is_empty() && sequence.chars().all(|c| c.is_ascii_digit()))
+        .unwrap_or(false);
+
+    if !is_valid {
+        return Err(InvalidArgument {
+            msg: format!("Invalid IBC connection ID: {}", connection_id),
+        });
+    }
+
+    Ok(())
+}
+
+/// Type URLs are a '/' followed by a fully-qualified protobuf message name,
+/// e.g. '/cosmos.bank.v1beta1.MsgSend'.
+fn is_valid_type_url(type_url: &str) -> bool {
+    match type_url.strip_prefix('/') {
+        Some(name) => {
+            let segments = name.split('.').collect::<Vec<&str>>();
+
+            segments.len() >= 2
+                && segments.iter().all(|segment| {
+                    !segment.is_empty()
+                        && segment
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                })
+        }
+        None => false,
+    }
+}
+
 fn validate_custom_execute_msgs(msgs: &[String]) -> GovernanceControllerResult<()> {
     for msg in msgs.iter() {
         serde_json_wasm::from_str::<CosmosMsg>(msg.as_str()).map_err(|_| InvalidCosmosMessage)?;
@@ -387,16 +1572,96 @@ pub fn validate_modify_multisig_membership(
         }
     }
 
+    validate_multisig_not_emptied(deps, deduped_addr_validated_members)?;
+
+    Ok(())
+}
+
+/// Applies the given member weight edits to the current multisig membership in memory, and
+/// checks that the multisig would still have some voting weight afterwards.
+fn validate_multisig_not_emptied(
+    deps: Deps,
+    edited_members: HashMap<Addr, Uint128>,
+) -> GovernanceControllerResult<()> {
+    let membership_contract = query_enterprise_components(deps)?.membership_contract;
+
+    let total_weight: TotalWeightResponse = deps.querier.query_wasm_smart(
+        membership_contract.to_string(),
+        &multisig_membership_api::msg::QueryMsg::TotalWeight(TotalWeightParams {
+            expiration: Never {},
+        }),
+    )?;
+
+    let mut new_total_weight = total_weight.total_weight;
+
+    for (member, new_weight) in edited_members {
+        let old_weight: UserWeightResponse = deps.querier.query_wasm_smart(
+            membership_contract.to_string(),
+            &multisig_membership_api::msg::QueryMsg::UserWeight(UserWeightParams {
+                user: member.to_string(),
+            }),
+        )?;
+
+        new_total_weight = new_total_weight
+            .checked_sub(old_weight.weight)?
+            .checked_add(new_weight)?;
+    }
+
+    if new_total_weight.is_zero() {
+        return Err(MultisigWouldBeEmpty);
+    }
+
     Ok(())
 }
 
+fn query_dao_type(deps: Deps) -> GovernanceControllerResult<DaoType> {
+    let enterprise_contract = ENTERPRISE_CONTRACT.load(deps.storage)?;
+
+    let response: DaoInfoResponse = deps
+        .querier
+        .query_wasm_smart(enterprise_contract.to_string(), &DaoInfo {})?;
+
+    Ok(response.dao_type)
+}
+
+fn query_enterprise_components(
+    deps: Deps,
+) -> GovernanceControllerResult<ComponentContractsResponse> {
+    let enterprise_contract = ENTERPRISE_CONTRACT.load(deps.storage)?;
+
+    let response: ComponentContractsResponse = deps
+        .querier
+        .query_wasm_smart(enterprise_contract.to_string(), &ComponentContracts {})?;
+
+    Ok(response)
+}
+
 pub fn validate_dao_council(
     deps: Deps,
+    gov_config: &GovConfig,
     dao_council: Option<DaoCouncilSpec>,
 ) -> GovernanceControllerResult<Option<CouncilGovConfig>> {
     match dao_council {
         None => Ok(None),
         Some(dao_council) => {
+            if let Some(weights) = &dao_council.weights {
+                if weights.len() != dao_council.members.len() {
+                    return Err(InvalidArgument {
+                        msg: "Council must have exactly one weight per member".to_string(),
+                    });
+                }
+                if weights.iter().any(|weight| weight.is_zero()) {
+                    return Err(InvalidArgument {
+                        msg: "Council member weights must be greater than 0".to_string(),
+                    });
+                }
+            }
+
+            let member_weights = dao_council
+                .weights
+                .clone()
+                .unwrap_or_else(|| vec![Uint128::one(); dao_council.members.len()]);
+
             validate_no_duplicate_council_members(deps, dao_council.members)?;
             validate_allowed_council_proposal_types(
                 dao_council.allowed_proposal_action_types.clone(),
@@ -405,12 +1670,403 @@ pub fn validate_dao_council(
             validate_quorum_value(dao_council.quorum)?;
             validate_threshold_value(dao_council.threshold)?;
 
-            Ok(Some(CouncilGovConfig {
+            let council_gov_config = CouncilGovConfig {
                 allowed_proposal_action_types: dao_council
                     .allowed_proposal_action_types
                     .unwrap_or_else(|| vec![ProposalActionType::UpgradeDao]),
                 quorum: dao_council.quorum,
                 threshold: dao_council.threshold,
+            };
+
+            validate_council_action_requirements(gov_config, &council_gov_config, &member_weights)?;
+
+            Ok(Some(council_gov_config))
+        }
+    }
+}
+
+/// Checks that the council with the given member weights meets the DAO's requirements for each
+/// of the action types it is allowed.
+///
+/// Besides having enough members and a high enough threshold, the council cannot let fewer
+/// members reach its threshold than an equally weighted council meeting the requirement would,
+/// so that a few heavily weighted members do not make up for the required number of members.
+fn validate_council_action_requirements(
+    gov_config: &GovConfig,
+    council_gov_config: &CouncilGovConfig,
+    member_weights: &[Uint128],
+) -> GovernanceControllerResult<()> {
+    let Some(requirements) = &gov_config.council_action_requirements else {
+        return Ok(());
+    };
+
+    let threshold_reaching_members =
+        minimum_members_reaching_threshold(member_weights, council_gov_config.threshold)?;
+
+    for requirement in requirements {
+        if !council_gov_config
+            .allowed_proposal_action_types
+            .contains(&requirement.action_type)
+        {
+            continue;
+        }
+
+        let required_threshold_reaching_members = Uint128::from(requirement.minimum_members)
+            .mul_ceil(requirement.minimum_threshold)
+            .u128();
+
+        if member_weights.len() < requirement.minimum_members as usize
+            || council_gov_config.threshold < requirement.minimum_threshold
+            || (threshold_reaching_members as u128) < required_threshold_reaching_members
+        {
+            return Err(CouncilTooPowerfulForAction {
+                action: requirement.action_type.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Smallest number of members whose combined weight reaches the given share of the council's
+/// total weight, i.e. the heaviest members taken first.
+fn minimum_members_reaching_threshold(
+    member_weights: &[Uint128],
+    threshold: Decimal,
+) -> GovernanceControllerResult<usize> {
+    let mut member_weights = member_weights.to_vec();
+    member_weights.sort_by_key(|weight| Reverse(*weight));
+
+    let mut total_weight = Uint128::zero();
+    for weight in &member_weights {
+        total_weight = total_weight.checked_add(*weight)?;
+    }
+
+    let threshold_weight = total_weight.mul_ceil(threshold);
+
+    let mut members = 0;
+    let mut combined_weight = Uint128::zero();
+
+    for weight in member_weights {
+        if combined_weight >= threshold_weight {
+            break;
+        }
+        combined_weight = combined_weight.checked_add(weight)?;
+        members += 1;
+    }
+
+    Ok(members)
+}
+
+/// Checks that the DAO's current council, once the given members are added to and removed from
+/// it, meets the requirements of the given gov config for the given allowed action types.
+/// Added members get a weight of 1, as in [`EditCouncilMembersMsg`].
+fn validate_current_council_action_requirements(
+    deps: Deps,
+    gov_config: &GovConfig,
+    council_gov_config: &CouncilGovConfig,
+    added_members: &[Addr],
+    removed_members: &[Addr],
+) -> GovernanceControllerResult<()> {
+    if gov_config.council_action_requirements.is_none() {
+        return Ok(());
+    }
+
+    let mut member_weights: Vec<Uint128> = query_council_members(deps)?
+        .into_iter()
+        .filter(|member| !removed_members.contains(&member.user))
+        .map(|member| member.weight)
+        .collect();
+    member_weights.extend(added_members.iter().map(|_| Uint128::one()));
+
+    validate_council_action_requirements(gov_config, council_gov_config, &member_weights)
+}
+
+/// Loads all the members of the DAO's council.
+/// The council's size is set by governance, which already lists all of its members when
+/// replacing the council.
+fn query_council_members(deps: Deps) -> GovernanceControllerResult<Vec<UserWeightResponse>> {
+    let council_membership_contract =
+        query_enterprise_components(deps)?.council_membership_contract;
+
+    let mut members: Vec<UserWeightResponse> = vec![];
+
+    loop {
+        let page: MembersResponse = deps.querier.query_wasm_smart(
+            council_membership_contract.to_string(),
+            &multisig_membership_api::msg::QueryMsg::Members(MembersParams {
+                start_after: members.last().map(|member| member.user.to_string()),
+                limit: Some(COUNCIL_MEMBERS_PAGE_SIZE),
+            }),
+        )?;
+
+        let is_last_page = page.members.len() < COUNCIL_MEMBERS_PAGE_SIZE as usize;
+
+        members.extend(
+            page.members
+                .into_iter()
+                .filter(|member| !member.weight.is_zero()),
+        );
+
+        if is_last_page {
+            return Ok(members);
+        }
+    }
+}
+
+/// Checks that the DAO's council, if any, meets the council action requirements, if the
+/// message changes them.
+pub fn validate_council_meets_changed_requirements(
+    deps: Deps,
+    updated_gov_config: &GovConfig,
+    msg: &UpdateGovConfigMsg,
+) -> GovernanceControllerResult<()> {
+    if !is_change(&msg.council_action_requirements) {
+        return Ok(());
+    }
+
+    match COUNCIL_GOV_CONFIG.load(deps.storage)? {
+        Some(council_gov_config) => validate_current_council_action_requirements(
+            deps,
+            updated_gov_config,
+            &council_gov_config,
+            &[],
+            &[],
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the minimum 'yes' votes, if any, do not exceed the DAO's current total weight,
+/// as proposals would then never be able to pass.
+fn validate_minimum_yes_votes_attainable(
+    deps: Deps,
+    gov_config: &GovConfig,
+) -> GovernanceControllerResult<()> {
+    if let Some(minimum_yes_votes) = gov_config.minimum_yes_votes {
+        let total_weight = query_dao_total_weight(deps)?;
+
+        if minimum_yes_votes > total_weight {
+            return Err(InvalidArgument {
+                msg: format!(
+                    "Minimum 'yes' votes cannot exceed the DAO's total weight of {}",
+                    total_weight
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the minimum weight for rewards does not exceed the DAO's total weight, since
+/// no member could be eligible for rewards then, and distributions would fail.
+fn validate_minimum_weight_for_rewards(
+    deps: Deps,
+    msg: &UpdateMinimumWeightForRewardsMsg,
+) -> GovernanceControllerResult<()> {
+    let total_weight = query_dao_total_weight(deps)?;
+
+    if msg.minimum_weight_for_rewards > total_weight {
+        return Err(InvalidArgument {
+            msg: format!(
+                "Minimum weight for rewards cannot exceed the DAO's total weight of {}",
+                total_weight
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the fee stays within the funds distributor's bounds, and that fees have
+/// somewhere valid to go.
+fn validate_update_distribution_fee(
+    deps: Deps,
+    msg: &UpdateDistributionFeeMsg,
+) -> GovernanceControllerResult<()> {
+    if msg.bps > MAX_DISTRIBUTION_FEE_BPS {
+        return Err(InvalidArgument {
+            msg: format!(
+                "Distribution fee cannot be higher than {} basis points",
+                MAX_DISTRIBUTION_FEE_BPS
+            ),
+        });
+    }
+
+    deps.api.addr_validate(&msg.recipient)?;
+
+    Ok(())
+}
+
+/// Checks that the token's address is valid and that the schedule emits something.
+/// Whether the source can cover the schedule is only known once the proposal executes,
+/// so it is left to the funds distributor.
+fn validate_set_distributor_emission_schedule(
+    deps: Deps,
+    msg: &SetDistributorEmissionScheduleMsg,
+) -> GovernanceControllerResult<()> {
+    deps.api.addr_validate(&msg.asset)?;
+
+    if let Some(schedule) = &msg.schedule {
+        if schedule.amount_per_period.is_zero() || schedule.period == 0 || schedule.periods == 0 {
+            return Err(InvalidArgument {
+                msg: "Emission schedule has to emit a non-zero amount over a non-zero number of non-zero periods".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn query_dao_total_weight(deps: Deps) -> GovernanceControllerResult<Uint128> {
+    let membership_contract = query_enterprise_components(deps)?.membership_contract;
+
+    let total_weight: TotalWeightResponse = deps.querier.query_wasm_smart(
+        membership_contract.to_string(),
+        &membership_common_api::msg::QueryMsg::TotalWeight(TotalWeightParams {
+            expiration: Never {},
+        }),
+    )?;
+
+    Ok(total_weight.total_weight)
+}
+
+/// Checks that the added council members are DAO members not yet on the council, that the removed
+/// ones are currently on the council, and that the council would not be left without members.
+pub fn validate_edit_council_members(
+    deps: Deps,
+    msg: &EditCouncilMembersMsg,
+) -> GovernanceControllerResult<()> {
+    let council_gov_config = COUNCIL_GOV_CONFIG.load(deps.storage)?.ok_or(NoDaoCouncil)?;
+
+    let added_members = validate_no_duplicate_council_members(deps, msg.add.clone())?;
+    let removed_members = validate_no_duplicate_council_members(deps, msg.remove.clone())?;
+
+    if added_members
+        .iter()
+        .any(|member| removed_members.contains(member))
+    {
+        return Err(CouncilMemberPresentInBothAddAndRemove);
+    }
+
+    let components = query_enterprise_components(deps)?;
+
+    let council_weight = |member: &Addr| -> GovernanceControllerResult<Uint128> {
+        let response: UserWeightResponse = deps.querier.query_wasm_smart(
+            components.council_membership_contract.to_string(),
+            &multisig_membership_api::msg::QueryMsg::UserWeight(UserWeightParams {
+                user: member.to_string(),
+            }),
+        )?;
+        Ok(response.weight)
+    };
+
+    for member in &added_members {
+        if !council_weight(member)?.is_zero() {
+            return Err(DuplicateCouncilMember {
+                member: member.to_string(),
+            });
+        }
+
+        let dao_weight: UserWeightResponse = deps.querier.query_wasm_smart(
+            components.membership_contract.to_string(),
+            &membership_common_api::msg::QueryMsg::UserWeight(UserWeightParams {
+                user: member.to_string(),
+            }),
+        )?;
+
+        if dao_weight.weight.is_zero() {
+            return Err(NotDaoMember {
+                member: member.to_string(),
+            });
+        }
+    }
+
+    let total_weight: TotalWeightResponse = deps.querier.query_wasm_smart(
+        components.council_membership_contract.to_string(),
+        &multisig_membership_api::msg::QueryMsg::TotalWeight(TotalWeightParams {
+            expiration: Never {},
+        }),
+    )?;
+
+    let mut new_total_weight = total_weight.total_weight;
+
+    for member in &removed_members {
+        let weight = council_weight(member)?;
+
+        if weight.is_zero() {
+            return Err(NotCouncilMember {
+                member: member.to_string(),
+            });
+        }
+
+        new_total_weight = new_total_weight.checked_sub(weight)?;
+    }
+
+    new_total_weight += Uint128::from(added_members.len() as u128);
+
+    if new_total_weight.is_zero() {
+        return Err(CouncilWouldBeEmpty);
+    }
+
+    validate_current_council_action_requirements(
+        deps,
+        &GOV_CONFIG.load(deps.storage)?,
+        &council_gov_config,
+        &added_members,
+        &removed_members,
+    )
+}
+
+/// Checks that the DAO has a council, and that the new permissions are allowed for councils.
+pub fn validate_update_council_permissions(
+    deps: Deps,
+    msg: &UpdateCouncilPermissionsMsg,
+) -> GovernanceControllerResult<()> {
+    let council_gov_config = COUNCIL_GOV_CONFIG.load(deps.storage)?.ok_or(NoDaoCouncil)?;
+
+    validate_allowed_council_proposal_types(Some(msg.allowed_proposal_action_types.clone()))?;
+
+    validate_current_council_action_requirements(
+        deps,
+        &GOV_CONFIG.load(deps.storage)?,
+        &CouncilGovConfig {
+            allowed_proposal_action_types: msg.allowed_proposal_action_types.clone(),
+            ..council_gov_config
+        },
+        &[],
+        &[],
+    )
+}
+
+pub fn validate_veto_council(
+    deps: Deps,
+    veto_council: Option<VetoCouncilSpec>,
+) -> GovernanceControllerResult<Option<VetoCouncilConfig>> {
+    match veto_council {
+        None => Ok(None),
+        Some(veto_council) => {
+            if veto_council.members.is_empty() {
+                return Err(InvalidArgument {
+                    msg: "Veto council must have at least one member".to_string(),
+                });
+            }
+
+            let members = validate_no_duplicate_council_members(deps, veto_council.members)?;
+
+            validate_threshold_value(veto_council.threshold)?;
+
+            if veto_council.challenge_window == 0 {
+                return Err(InvalidArgument {
+                    msg: "Veto council challenge window must be greater than 0".to_string(),
+                });
+            }
+
+            Ok(Some(VetoCouncilConfig {
+                members,
+                threshold: veto_council.threshold,
+                challenge_window: veto_council.challenge_window,
             }))
         }
     }
@@ -420,6 +2076,12 @@ pub fn validate_distribute_funds(
     deps: Deps,
     msg: &DistributeFundsMsg,
 ) -> GovernanceControllerResult<()> {
+    if let Some(max) = GOV_CONFIG.load(deps.storage)?.max_distribution_assets {
+        if msg.funds.len() > max as usize {
+            return Err(TooManyDistributionAssets { max });
+        }
+    }
+
     for asset in &msg.funds {
         match &asset.info {
             AssetInfoBase::Native(_) => {
@@ -440,6 +2102,208 @@ pub fn validate_distribute_funds(
     Ok(())
 }
 
+/// Checks the assets the same way as when distributing funds, and that the treasury currently
+/// holds enough of each asset.
+pub fn validate_distribute_from_treasury(
+    deps: Deps,
+    msg: &DistributeFromTreasuryMsg,
+) -> GovernanceControllerResult<()> {
+    validate_distribute_funds(
+        deps,
+        &DistributeFundsMsg {
+            funds: msg.assets.clone(),
+        },
+    )?;
+
+    let mut required_amounts: Vec<(AssetInfo, Uint128)> = vec![];
+
+    for asset in &msg.assets {
+        let asset = asset.check(deps.api, None)?;
+
+        match required_amounts
+            .iter_mut()
+            .find(|(info, _)| info == &asset.info)
+        {
+            Some((_, amount)) => *amount = amount.checked_add(asset.amount)?,
+            None => required_amounts.push((asset.info, asset.amount)),
+        }
+    }
+
+    let treasury = query_enterprise_components(deps)?.enterprise_treasury_contract;
+
+    for (info, amount) in required_amounts {
+        let balance = info.query_balance(&deps.querier, treasury.to_string())?;
+
+        if balance < amount {
+            return Err(InsufficientTreasuryBalance {
+                asset: info.to_string(),
+                balance,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the parts of the allowance grant that do not depend on the current time.
+/// The expiry is checked using validate_spending_allowance_expiry.
+pub fn validate_grant_spending_allowance(
+    deps: Deps,
+    msg: &GrantSpendingAllowanceMsg,
+) -> GovernanceControllerResult<()> {
+    deps.api.addr_validate(&msg.grantee)?;
+
+    if msg.amount.is_zero() {
+        return Err(InvalidArgument {
+            msg: "Spending allowance amount must be greater than 0".to_string(),
+        });
+    }
+
+    match msg.asset.check(deps.api, None)? {
+        AssetInfo::Cw20(_) => Ok(()),
+        AssetInfo::Native(_) => Err(NativeSpendingAllowanceNotSupported),
+        _ => Err(UnsupportedCw1155Asset),
+    }
+}
+
+/// Checks that spending allowances being granted by the given actions have not expired yet.
+pub fn validate_spending_allowance_expiry(
+    block: &BlockInfo,
+    proposal_actions: &[ProposalAction],
+) -> GovernanceControllerResult<()> {
+    for proposal_action in proposal_actions {
+        if let ProposalAction::GrantSpendingAllowance(msg) = proposal_action {
+            if msg.expiry.is_expired(block) {
+                return Err(InvalidArgument {
+                    msg: "Spending allowance expiry must be in the future".to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the grantee currently has an unexpired allowance over the treasury's tokens.
+pub fn validate_revoke_spending_allowance(
+    deps: Deps,
+    msg: &RevokeSpendingAllowanceMsg,
+) -> GovernanceControllerResult<()> {
+    query_treasury_spending_allowance(deps, msg)?;
+
+    Ok(())
+}
+
+/// Queries the grantee's current allowance over the treasury's tokens, failing if there is none.
+pub fn query_treasury_spending_allowance(
+    deps: Deps,
+    msg: &RevokeSpendingAllowanceMsg,
+) -> GovernanceControllerResult<Uint128> {
+    let grantee = deps.api.addr_validate(&msg.grantee)?;
+
+    let token = match msg.asset.check(deps.api, None)? {
+        AssetInfo::Cw20(token) => token,
+        AssetInfo::Native(_) => return Err(NativeSpendingAllowanceNotSupported),
+        _ => return Err(UnsupportedCw1155Asset),
+    };
+
+    let treasury = query_enterprise_components(deps)?.enterprise_treasury_contract;
+
+    // expired allowances are reported as zero
+    let allowance: AllowanceResponse = deps.querier.query_wasm_smart(
+        token.to_string(),
+        &Cw20QueryMsg::Allowance {
+            owner: treasury.to_string(),
+            spender: grantee.to_string(),
+        },
+    )?;
+
+    if allowance.allowance.is_zero() {
+        return Err(NoAllowanceToRevoke);
+    }
+
+    Ok(allowance.allowance)
+}
+
+pub fn validate_burn_tokens(deps: Deps, msg: &BurnTokensMsg) -> GovernanceControllerResult<()> {
+    if msg.amount.is_zero() {
+        return Err(InvalidArgument {
+            msg: "Amount of tokens to burn must be greater than 0".to_string(),
+        });
+    }
+
+    match msg.asset.check(deps.api, None)? {
+        AssetInfo::Native(_) => Err(CannotBurnNativeAsset),
+        AssetInfo::Cw20(token) => {
+            let treasury = query_enterprise_components(deps)?.enterprise_treasury_contract;
+
+            let balance: BalanceResponse = deps.querier.query_wasm_smart(
+                token.to_string(),
+                &Cw20QueryMsg::Balance {
+                    address: treasury.to_string(),
+                },
+            )?;
+
+            if balance.balance < msg.amount {
+                return Err(InvalidArgument {
+                    msg: format!(
+                        "DAO treasury holds only {} of the tokens to be burned",
+                        balance.balance
+                    ),
+                });
+            }
+
+            Ok(())
+        }
+        _ => Err(UnsupportedCw1155Asset),
+    }
+}
+
+pub fn validate_mint_tokenfactory_denom(
+    deps: Deps,
+    msg: &MintTokenfactoryDenomMsg,
+) -> GovernanceControllerResult<()> {
+    if msg.amount.is_zero() {
+        return Err(InvalidArgument {
+            msg: "Amount of tokens to mint must be greater than 0".to_string(),
+        });
+    }
+
+    if let Some(recipient) = &msg.recipient {
+        deps.api.addr_validate(recipient)?;
+    }
+
+    validate_denom_owned_by_dao(deps, &msg.denom)
+}
+
+pub fn validate_set_denom_admin(
+    deps: Deps,
+    msg: &SetDenomAdminMsg,
+) -> GovernanceControllerResult<()> {
+    deps.api.addr_validate(&msg.new_admin)?;
+
+    validate_denom_owned_by_dao(deps, &msg.denom)
+}
+
+/// Checks that the denom is a token-factory denom whose admin is the DAO's treasury.
+fn validate_denom_owned_by_dao(deps: Deps, denom: &str) -> GovernanceControllerResult<()> {
+    if !is_token_factory_denom(denom) {
+        return Err(NotTokenFactoryDenom {
+            denom: denom.to_string(),
+        });
+    }
+
+    let treasury = query_enterprise_components(deps)?.enterprise_treasury_contract;
+
+    if query_denom_admin(deps, denom.to_string())? != treasury {
+        return Err(DenomNotOwnedByDao {
+            denom: denom.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
 pub fn validate_request_funding_from_dao(
     deps: Deps,
     msg: &RequestFundingFromDaoMsg,
@@ -462,6 +2326,44 @@ pub fn validate_request_funding_from_dao(
     Ok(())
 }
 
+/// Checks that the recipient is valid, and that the DAO's treasury owns each of the NFTs.
+pub fn validate_transfer_nft(deps: Deps, msg: &TransferNftMsg) -> GovernanceControllerResult<()> {
+    let nft_contract = deps.api.addr_validate(&msg.contract)?;
+    deps.api.addr_validate(&msg.recipient)?;
+
+    if msg.token_ids.is_empty() {
+        return Err(InvalidArgument {
+            msg: "At least one NFT has to be transferred".to_string(),
+        });
+    }
+
+    let treasury = query_enterprise_components(deps)?.enterprise_treasury_contract;
+
+    for (i, token_id) in msg.token_ids.iter().enumerate() {
+        if msg.token_ids[..i].contains(token_id) {
+            return Err(InvalidArgument {
+                msg: format!("Duplicate NFT {}", token_id),
+            });
+        }
+
+        let owner_response: OwnerOfResponse = deps.querier.query_wasm_smart(
+            nft_contract.to_string(),
+            &OwnerOf {
+                token_id: token_id.clone(),
+                include_expired: Some(false),
+            },
+        )?;
+
+        if owner_response.owner != treasury.as_str() {
+            return Err(NftNotOwnedByDao {
+                token_id: token_id.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 pub fn validate_no_duplicate_council_members(
     deps: Deps,
     members: Vec<String>,
@@ -500,16 +2402,37 @@ pub fn validate_allowed_council_proposal_types(
                     | ProposalActionType::ExecuteEnterpriseMsgs
                     | ProposalActionType::ModifyMultisigMembership
                     | ProposalActionType::DistributeFunds
-                    | ProposalActionType::UpdateMinimumWeightForRewards => {
+                    | ProposalActionType::UpdateMinimumWeightForRewards
+                    | ProposalActionType::BurnTokens
+                    | ProposalActionType::UpdateVetoCouncil
+                    | ProposalActionType::MintTokenfactoryDenom
+                    | ProposalActionType::SetDenomAdmin
+                    | ProposalActionType::UpdateEnterpriseFactory
+                    | ProposalActionType::SetSelfAdmin
+                    | ProposalActionType::UpdateCouncilPermissions
+                    | ProposalActionType::GrantSpendingAllowance
+                    | ProposalActionType::RevokeSpendingAllowance
+                    | ProposalActionType::DistributeFromTreasury
+                    | ProposalActionType::EditCouncilMembers
+                    | ProposalActionType::ExecuteInterchainAccountMsgs
+                    | ProposalActionType::ManageAssetBundles
+                    | ProposalActionType::ScheduleCouncilElections
+                    | ProposalActionType::UpdateDistributionFee
+                    | ProposalActionType::TransferNft
+                    | ProposalActionType::SetDistributorEmissionSchedule
+                    | ProposalActionType::DistributeToVoters
+                    | ProposalActionType::RegisterInterchainAccount => {
                         return Err(UnsupportedCouncilProposalAction {
                             action: action_type,
                         });
                     }
                     ProposalActionType::UpdateMetadata
                     | ProposalActionType::UpdateAssetWhitelist
+                    | ProposalActionType::UpdateAssetWhitelistWithBundle
                     | ProposalActionType::UpdateNftWhitelist
                     | ProposalActionType::UpgradeDao
-                    | ProposalActionType::DeployCrossChainTreasury => {
+                    | ProposalActionType::DeployCrossChainTreasury
+                    | ProposalActionType::SetDistributorClaimsPaused => {
                         // allowed proposal action types
                     }
                 }
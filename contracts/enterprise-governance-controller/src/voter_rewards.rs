@@ -0,0 +1,132 @@
+use crate::contract::{query_enterprise_governance_addr, MAX_QUERY_LIMIT};
+use crate::proposals::PROPOSAL_INFOS;
+use common::cw::Pagination;
+use cosmwasm_std::{to_json_string, CosmosMsg, Deps, Uint128};
+use cw_asset::{Asset, AssetInfo};
+use enterprise_governance_controller_api::api::{DistributeToVotersMsg, ProposalId};
+use enterprise_governance_controller_api::error::GovernanceControllerError::{
+    InvalidArgument, NoSuchProposal, UnsupportedCw1155Asset,
+};
+use enterprise_governance_controller_api::error::GovernanceControllerResult;
+use poll_engine_api::api::{
+    PollParams, PollResponse, PollStatus, PollVotersParams, PollVotersResponse, Vote,
+};
+use std::ops::Not;
+
+/// Maximum number of voters a proposal can have for its voters to be rewarded,
+/// to keep the number of transfers made by a single action bounded.
+const MAXIMUM_REWARDED_VOTERS: usize = 500;
+
+/// Checks that the asset can be distributed, and that the proposal is resolved and had voters.
+pub fn validate_distribute_to_voters(
+    deps: Deps,
+    msg: &DistributeToVotersMsg,
+) -> GovernanceControllerResult<()> {
+    let asset = msg.asset.check(deps.api, None)?;
+
+    if let AssetInfo::Cw1155(..) = asset.info {
+        return Err(UnsupportedCw1155Asset);
+    }
+
+    if asset.amount.is_zero() {
+        return Err(InvalidArgument {
+            msg: "Amount distributed to voters has to be greater than 0".to_string(),
+        });
+    }
+
+    if PROPOSAL_INFOS.has(deps.storage, msg.proposal_id).not() {
+        return Err(NoSuchProposal);
+    }
+
+    let governance_contract = query_enterprise_governance_addr(deps)?;
+
+    let poll: PollResponse = deps.querier.query_wasm_smart(
+        governance_contract.to_string(),
+        &enterprise_governance_api::msg::QueryMsg::Poll(PollParams {
+            poll_id: msg.proposal_id,
+        }),
+    )?;
+
+    if let PollStatus::InProgress { .. } = poll.poll.status {
+        return Err(InvalidArgument {
+            msg: "Voters can only be rewarded once the proposal is resolved".to_string(),
+        });
+    }
+
+    if query_all_votes(deps, msg.proposal_id)?.is_empty() {
+        return Err(InvalidArgument {
+            msg: "The proposal has no voters to reward".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Messages transferring each voter's share of the asset, to be executed by the treasury.
+/// Shares are rounded down, leaving any remainder in the treasury.
+pub fn distribute_to_voters_msgs(
+    deps: Deps,
+    msg: DistributeToVotersMsg,
+) -> GovernanceControllerResult<Vec<String>> {
+    let asset = msg.asset.check(deps.api, None)?;
+
+    let votes = query_all_votes(deps, msg.proposal_id)?;
+
+    let total_weight = votes.iter().try_fold(Uint128::zero(), |total, vote| {
+        total.checked_add(vote.amount.into())
+    })?;
+
+    let mut transfer_msgs = vec![];
+
+    for vote in votes {
+        let share = asset.amount.multiply_ratio(vote.amount, total_weight);
+
+        if share.is_zero() {
+            continue;
+        }
+
+        let transfer_msg: CosmosMsg =
+            Asset::new(asset.info.clone(), share).transfer_msg(vote.voter)?;
+        transfer_msgs.push(to_json_string(&transfer_msg)?);
+    }
+
+    Ok(transfer_msgs)
+}
+
+/// Loads all votes cast on the proposal, failing if there are too many voters to reward.
+fn query_all_votes(deps: Deps, proposal_id: ProposalId) -> GovernanceControllerResult<Vec<Vote>> {
+    let governance_contract = query_enterprise_governance_addr(deps)?;
+
+    let mut votes: Vec<Vote> = vec![];
+
+    loop {
+        let page: PollVotersResponse = deps.querier.query_wasm_smart(
+            governance_contract.to_string(),
+            &enterprise_governance_api::msg::QueryMsg::PollVoters(PollVotersParams {
+                poll_id: proposal_id,
+                pagination: Pagination {
+                    start_after: votes.last().map(|vote| vote.voter.to_string()),
+                    end_at: None,
+                    limit: Some(MAX_QUERY_LIMIT as u64),
+                    order_by: None,
+                },
+            }),
+        )?;
+
+        let page_size = page.votes.len();
+        votes.extend(page.votes);
+
+        if votes.len() > MAXIMUM_REWARDED_VOTERS {
+            return Err(InvalidArgument {
+                msg: format!(
+                    "Voters of proposals with more than {} voters cannot be rewarded",
+                    MAXIMUM_REWARDED_VOTERS
+                ),
+            });
+        }
+
+        if page_size < MAX_QUERY_LIMIT as usize {
+            return Ok(votes);
+        }
+    }
+}
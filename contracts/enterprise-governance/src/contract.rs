@@ -12,7 +12,8 @@ use poll_engine::query::{
     query_simulate_end_poll_status, query_voter,
 };
 use poll_engine_api::api::{
-    CastVoteParams, CreatePollParams, EndPollParams, PollStatus, UpdateVotesParams, VoteOutcome,
+    CastVoteParams, CreatePollParams, EndPollParams, ExtendPollParams, PollStatus,
+    UpdateVotesParams, VoteOutcome,
 };
 use poll_engine_api::error::PollError::Unauthorized;
 use poll_engine_api::error::PollResult;
@@ -61,6 +62,7 @@ pub fn execute(
         ExecuteMsg::CastVote(params) => cast_vote(ctx, params),
         ExecuteMsg::UpdateVotes(params) => update_votes(ctx, params),
         ExecuteMsg::EndPoll(params) => end_poll(ctx, params),
+        ExecuteMsg::ExtendPoll(params) => extend_poll(ctx, params),
     }
 }
 
@@ -113,6 +115,15 @@ fn end_poll(ctx: &mut Context, params: EndPollParams) -> PollResult<Response> {
     Ok(Response::new().add_attribute("action", "end_poll"))
 }
 
+fn extend_poll(ctx: &mut Context, params: ExtendPollParams) -> PollResult<Response> {
+    let poll = poll_engine::execute::extend_poll(ctx, params)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "extend_poll")
+        .add_attribute("poll_id", poll.id.to_string())
+        .add_attribute("ends_at", poll.ends_at.to_string()))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(_deps: DepsMut, _env: Env, _msg: Reply) -> PollResult<Response> {
     Ok(Response::new())
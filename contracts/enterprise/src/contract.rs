@@ -12,15 +12,15 @@ use common::cw::{Context, QueryContext};
 use cosmwasm_std::CosmosMsg::Wasm;
 use cosmwasm_std::WasmMsg::Migrate;
 use cosmwasm_std::{
-    entry_point, to_json_binary, wasm_instantiate, Binary, CosmosMsg, Deps, DepsMut, Empty, Env,
-    MessageInfo, Reply, Response, StdError, StdResult, SubMsg,
+    entry_point, to_json_binary, wasm_instantiate, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty,
+    Env, MessageInfo, Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_utils::parse_reply_instantiate_data;
 use enterprise_protocol::api::{
     ComponentContractsResponse, DaoInfoResponse, DaoType, ExecuteMsgsMsg, FinalizeInstantiationMsg,
-    IsRestrictedUserParams, IsRestrictedUserResponse, SetAttestationMsg, UpdateConfigMsg,
-    UpdateMetadataMsg, UpgradeDaoMsg,
+    IsRestrictedUserParams, IsRestrictedUserResponse, SetAttestationMsg, SetSelfAdminMsg,
+    UpdateConfigMsg, UpdateMetadataMsg, UpgradeDaoMsg,
 };
 use enterprise_protocol::error::DaoError::{
     AlreadyInitialized, DuplicateVersionMigrateMsgFound, MigratingToLowerVersion, Unauthorized,
@@ -30,15 +30,19 @@ use enterprise_protocol::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg}
 use enterprise_protocol::response::{
     execute_execute_msgs_response, execute_finalize_instantiation_response,
     execute_remove_attestation_response, execute_set_attestation_response,
-    execute_update_config_response, execute_update_metadata_response, execute_upgrade_dao_response,
-    instantiate_response,
+    execute_set_self_admin_response, execute_update_config_response,
+    execute_update_metadata_response, execute_upgrade_dao_response, instantiate_response,
 };
 use enterprise_versioning_api::api::{
     Version, VersionInfo, VersionParams, VersionResponse, VersionsParams, VersionsResponse,
 };
 use enterprise_versioning_api::msg::QueryMsg::Versions;
+use nft_staking_api::api::{NftContract, NftContractConfigResponse};
+use nft_staking_api::msg::QueryMsg::NftContractConfig;
 use std::collections::HashMap;
 use std::ops::Not;
+use token_staking_api::api::TokenConfigResponse;
+use token_staking_api::msg::QueryMsg::TokenConfig;
 use DaoType::{Denom, Multisig, Nft, Token};
 use ModifyValue::NoChange;
 
@@ -93,6 +97,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> D
         ExecuteMsg::UpdateMetadata(msg) => update_metadata(ctx, msg),
         ExecuteMsg::UpgradeDao(msg) => upgrade_dao(ctx, msg),
         ExecuteMsg::UpdateConfig(msg) => update_config(ctx, msg),
+        ExecuteMsg::SetSelfAdmin(msg) => set_self_admin(ctx, msg),
         ExecuteMsg::SetAttestation(msg) => set_attestation(ctx, msg),
         ExecuteMsg::RemoveAttestation {} => remove_attestation(ctx),
         ExecuteMsg::ExecuteMsgs(msg) => execute_msgs(ctx, msg),
@@ -375,6 +380,22 @@ fn remove_attestation(ctx: &mut Context) -> DaoResult<Response> {
     Ok(execute_remove_attestation_response())
 }
 
+fn set_self_admin(ctx: &mut Context, msg: SetSelfAdminMsg) -> DaoResult<Response> {
+    enterprise_governance_controller_caller_only(ctx)?;
+
+    let contract_addr = ctx.env.contract.address.to_string();
+
+    let admin_msg = match msg.new_admin.clone() {
+        Some(new_admin) => WasmMsg::UpdateAdmin {
+            contract_addr,
+            admin: ctx.deps.api.addr_validate(&new_admin)?.to_string(),
+        },
+        None => WasmMsg::ClearAdmin { contract_addr },
+    };
+
+    Ok(execute_set_self_admin_response(msg.new_admin).add_message(admin_msg))
+}
+
 fn execute_msgs(ctx: &mut Context, msg: ExecuteMsgsMsg) -> DaoResult<Response> {
     enterprise_governance_controller_caller_only(ctx)?;
 
@@ -433,11 +454,59 @@ pub fn query_dao_info(qctx: QueryContext) -> DaoResult<DaoInfoResponse> {
     let dao_type = DAO_TYPE.load(qctx.deps.storage)?;
     let dao_version = DAO_VERSION.load(qctx.deps.storage)?;
 
+    let component_contracts = COMPONENT_CONTRACTS.load(qctx.deps.storage)?;
+    let membership_contract = component_contracts.membership_contract;
+
+    // the membership asset and component code IDs are reported on a best-effort basis, so that
+    // a misbehaving component contract cannot make the DAO's info unavailable
+    let membership_asset_contract = match dao_type {
+        Token => qctx
+            .deps
+            .querier
+            .query_wasm_smart::<TokenConfigResponse>(
+                membership_contract.to_string(),
+                &TokenConfig {},
+            )
+            .ok()
+            .map(|token_config| token_config.token_contract),
+        Nft => qctx
+            .deps
+            .querier
+            .query_wasm_smart::<NftContractConfigResponse>(
+                membership_contract.to_string(),
+                &NftContractConfig {},
+            )
+            .ok()
+            .and_then(|nft_config| {
+                let nft_contract = match nft_config.nft_contract {
+                    NftContract::Cw721 { contract } => contract,
+                    NftContract::Ics721 { contract, .. } => contract,
+                };
+                qctx.deps.api.addr_validate(&nft_contract).ok()
+            }),
+        Denom | Multisig => None,
+    };
+
+    let code_id = |contract: &Addr| {
+        qctx.deps
+            .querier
+            .query_wasm_contract_info(contract.to_string())
+            .ok()
+            .map(|contract_info| contract_info.code_id)
+    };
+    let enterprise_governance_code_id =
+        code_id(&component_contracts.enterprise_governance_contract);
+    let funds_distributor_code_id = code_id(&component_contracts.funds_distributor_contract);
+
     Ok(DaoInfoResponse {
         creation_date,
         metadata,
         dao_type,
         dao_version,
+        membership_contract: Some(membership_contract),
+        membership_asset_contract,
+        enterprise_governance_code_id,
+        funds_distributor_code_id,
     })
 }
 
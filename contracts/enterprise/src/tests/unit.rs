@@ -1 +1,139 @@
+use crate::contract::{execute, instantiate, query};
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockQuerier, MOCK_CONTRACT_ADDR,
+};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, ContractInfoResponse, ContractResult, Deps, SystemError,
+    SystemResult, WasmQuery,
+};
+use cw_utils::Duration;
+use enterprise_protocol::api::{
+    DaoInfoResponse, DaoMetadata, DaoSocialData, DaoType, FinalizeInstantiationMsg, Logo,
+};
+use enterprise_protocol::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use enterprise_versioning_api::api::Version;
+use token_staking_api::api::TokenConfigResponse;
 
+#[test]
+fn dao_info_reports_membership_and_code_ids_of_responding_components() {
+    let mut deps = mock_dependencies();
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            enterprise_factory_contract: "factory".to_string(),
+            enterprise_versioning_contract: "versioning".to_string(),
+            dao_metadata: DaoMetadata {
+                name: "DAO".to_string(),
+                description: None,
+                logo: Logo::None,
+                socials: DaoSocialData {
+                    github_username: None,
+                    discord_username: None,
+                    twitter_username: None,
+                    telegram_username: None,
+                },
+            },
+            dao_creation_date: None,
+            dao_type: DaoType::Token,
+            dao_version: Version {
+                major: 1,
+                minor: 0,
+                patch: 0,
+            },
+        },
+    )
+    .unwrap();
+
+    mock_components(&mut deps.querier, true);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        ExecuteMsg::FinalizeInstantiation(FinalizeInstantiationMsg {
+            enterprise_treasury_contract: "treasury".to_string(),
+            enterprise_governance_contract: "governance".to_string(),
+            enterprise_governance_controller_contract: "controller".to_string(),
+            enterprise_outposts_contract: "outposts".to_string(),
+            funds_distributor_contract: "funds_distributor".to_string(),
+            membership_contract: "membership".to_string(),
+            council_membership_contract: "council".to_string(),
+            attestation_contract: None,
+        }),
+    )
+    .unwrap();
+
+    let dao_info = query_dao_info(deps.as_ref());
+    assert_eq!(
+        dao_info.membership_contract,
+        Some(Addr::unchecked("membership"))
+    );
+    assert_eq!(
+        dao_info.membership_asset_contract,
+        Some(Addr::unchecked("token"))
+    );
+    assert_eq!(dao_info.enterprise_governance_code_id, Some(7));
+    assert_eq!(dao_info.funds_distributor_code_id, Some(8));
+
+    // components that cannot be queried leave their fields empty, instead of failing the query
+    mock_components(&mut deps.querier, false);
+
+    let dao_info = query_dao_info(deps.as_ref());
+    assert_eq!(dao_info.metadata.name, "DAO");
+    assert_eq!(
+        dao_info.membership_contract,
+        Some(Addr::unchecked("membership"))
+    );
+    assert_eq!(dao_info.membership_asset_contract, None);
+    assert_eq!(dao_info.enterprise_governance_code_id, Some(7));
+    assert_eq!(dao_info.funds_distributor_code_id, None);
+}
+
+fn query_dao_info(deps: Deps) -> DaoInfoResponse {
+    from_json(query(deps, mock_env(), QueryMsg::DaoInfo {}).unwrap()).unwrap()
+}
+
+/// Mocks the component contracts queried for the DAO's info. Unless `responding` is set,
+/// the membership and funds distributor contracts fail every query.
+fn mock_components(querier: &mut MockQuerier, responding: bool) {
+    querier.update_wasm(move |query| {
+        let contract_addr = match query {
+            WasmQuery::Smart { contract_addr, .. } => contract_addr,
+            WasmQuery::ContractInfo { contract_addr } => contract_addr,
+            _ => panic!("unexpected query"),
+        };
+
+        if !responding && (contract_addr == "membership" || contract_addr == "funds_distributor") {
+            return SystemResult::Err(SystemError::NoSuchContract {
+                addr: contract_addr.to_string(),
+            });
+        }
+
+        let response = match (query, contract_addr.as_str()) {
+            (WasmQuery::ContractInfo { .. }, MOCK_CONTRACT_ADDR) => {
+                to_json_binary(&contract_info(1))
+            }
+            (WasmQuery::ContractInfo { .. }, "governance") => to_json_binary(&contract_info(7)),
+            (WasmQuery::ContractInfo { .. }, "funds_distributor") => {
+                to_json_binary(&contract_info(8))
+            }
+            (WasmQuery::Smart { .. }, "membership") => to_json_binary(&TokenConfigResponse {
+                enterprise_contract: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                token_contract: Addr::unchecked("token"),
+                unlocking_period: Duration::Time(100),
+            }),
+            _ => panic!("unexpected query"),
+        };
+        SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+    });
+}
+
+fn contract_info(code_id: u64) -> ContractInfoResponse {
+    let mut contract_info = ContractInfoResponse::default();
+    contract_info.code_id = code_id;
+    contract_info.creator = "creator".to_string();
+    contract_info
+}
@@ -0,0 +1,51 @@
+use crate::state::ADMIN;
+use common::cw::Context;
+use cosmwasm_std::{Addr, Response};
+use cw_storage_plus::Map;
+use funds_distributor_api::api::{RegisterRewardAssetMsg, RewardAsset, RewardAssetMetadata};
+use funds_distributor_api::error::DistributorError::Unauthorized;
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::execute_register_reward_asset_response;
+
+/// Display metadata registered for native reward denominations.
+pub const NATIVE_ASSET_METADATA: Map<String, RewardAssetMetadata> =
+    Map::new("native_asset_metadata");
+
+/// Display metadata registered for CW20 reward assets.
+pub const CW20_ASSET_METADATA: Map<Addr, RewardAssetMetadata> = Map::new("cw20_asset_metadata");
+
+/// Registers (or removes) display metadata for a reward asset. Only the admin can do this.
+pub fn register_reward_asset(
+    ctx: &mut Context,
+    msg: RegisterRewardAssetMsg,
+) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let asset = match msg.asset {
+        RewardAsset::Native { denom } => {
+            match msg.metadata {
+                Some(metadata) => {
+                    NATIVE_ASSET_METADATA.save(ctx.deps.storage, denom.clone(), &metadata)?
+                }
+                None => NATIVE_ASSET_METADATA.remove(ctx.deps.storage, denom.clone()),
+            }
+            denom
+        }
+        RewardAsset::Cw20 { asset } => {
+            let asset = ctx.deps.api.addr_validate(&asset)?;
+            match msg.metadata {
+                Some(metadata) => {
+                    CW20_ASSET_METADATA.save(ctx.deps.storage, asset.clone(), &metadata)?
+                }
+                None => CW20_ASSET_METADATA.remove(ctx.deps.storage, asset.clone()),
+            }
+            asset.to_string()
+        }
+    };
+
+    Ok(execute_register_reward_asset_response(asset))
+}
@@ -1,44 +1,201 @@
+use crate::claim_deadlines::{record_user_distribution, unexpired_distribution};
+use crate::claim_history::record_claims;
 use crate::cw20_distributions::{Cw20Distribution, CW20_DISTRIBUTIONS};
+use crate::fee_on_transfer::record_cw20_outflow;
+use crate::index_precision::asset_index_scale;
 use crate::native_distributions::{NativeDistribution, NATIVE_DISTRIBUTIONS};
-use crate::rewards::calculate_user_reward;
-use crate::state::{CW20_GLOBAL_INDICES, ENTERPRISE_CONTRACT, NATIVE_GLOBAL_INDICES};
+use crate::rate_limit::{cw20_asset_key, native_asset_key};
+use crate::rewards::{calculate_user_reward, query_user_rewards, split_user_reward};
+use crate::state::{ADMIN, CW20_GLOBAL_INDICES, ENTERPRISE_CONTRACT, NATIVE_GLOBAL_INDICES};
 use crate::user_weights::EFFECTIVE_USER_WEIGHTS;
-use common::cw::Context;
-use cosmwasm_std::{Deps, Response, SubMsg, Uint128};
-use cw_asset::Asset;
+use crate::weight_basis::asset_user_weight;
+use common::cw::{Context, QueryContext};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{
+    coins, from_json, wasm_execute, Addr, Binary, CosmosMsg, Decimal256, Deps, Response, StdResult,
+    SubMsg, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_asset::{Asset, AssetInfo};
+use cw_storage_plus::Item;
 use enterprise_protocol::api::{IsRestrictedUserParams, IsRestrictedUserResponse};
 use enterprise_protocol::msg::QueryMsg::IsRestrictedUser;
-use funds_distributor_api::api::ClaimRewardsMsg;
-use funds_distributor_api::error::DistributorError::Unauthorized;
+use funds_distributor_api::api::{
+    AssetClass, ClaimDestination, ClaimPreviewParams, ClaimPreviewResponse, ClaimRewardsMsg,
+    ClaimsPausedResponse, Cw20ClaimPreview, NativeClaimPreview, RewardAsset, UserRewardsParams,
+};
+use funds_distributor_api::error::DistributorError::{
+    ClaimsPaused, InvalidClaimHookMsg, Unauthorized,
+};
 use funds_distributor_api::error::{DistributorError, DistributorResult};
-use funds_distributor_api::response::execute_claim_rewards_response;
+use funds_distributor_api::response::{
+    execute_claim_rewards_response, execute_set_claims_paused_response,
+};
+use serde::de::IgnoredAny;
+use std::collections::{HashMap, HashSet};
 use DistributorError::RestrictedUser;
 
+/// Whether claims of rewards are paused. Distributions and weight updates are not affected.
+const CLAIMS_PAUSED: Item<bool> = Item::new("claims_paused");
+
+/// Validated version of the ClaimDestination.
+struct ClaimDestinationChecked {
+    contract: Addr,
+    msg: Binary,
+}
+
 /// Attempt to claim rewards for the given parameters.
 ///
 /// Calculates rewards currently available to the user, and marks them as claimed.
+/// Only the asset classes selected are claimed, either for the listed assets or for all assets
+/// of the class if claiming all.
+/// If a maximum claim amount is given for an asset, only up to that amount is claimed, and
+/// the remainder is left in user's pending rewards.
+///
+/// If a destination is given for an asset, claimed rewards are sent to that contract along with
+/// the destination's hook message, instead of being sent to the user.
+///
+/// Claimed amounts are aggregated per asset, so that each asset is sent in a single transfer.
+///
+/// Users that are currently below the minimum eligible weight can still claim rewards they had
+/// accrued while eligible, as those were moved to their pending rewards when they dropped out.
 ///
 /// Returns a Response containing submessages that will send available rewards to the user.
 pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResult<Response> {
-    if is_restricted_user(ctx.deps.as_ref(), msg.user.clone())? {
-        return Err(RestrictedUser);
-    }
-
     let user = ctx.deps.api.addr_validate(&msg.user)?;
 
     if ctx.info.sender != user {
         return Err(Unauthorized);
     }
 
+    claim_user_rewards(ctx, user, msg)
+}
+
+/// Pauses or resumes claims of rewards. Only the admin can do this.
+pub fn set_claims_paused(ctx: &mut Context, paused: bool) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    CLAIMS_PAUSED.save(ctx.deps.storage, &paused)?;
+
+    Ok(execute_set_claims_paused_response(paused))
+}
+
+pub fn query_claims_paused(qctx: QueryContext) -> DistributorResult<ClaimsPausedResponse> {
+    let paused = CLAIMS_PAUSED.may_load(qctx.deps.storage)?.unwrap_or(false);
+
+    Ok(ClaimsPausedResponse { paused })
+}
+
+/// Claims the user's rewards as described for `claim_rewards`.
+/// Callers are responsible for checking that the claim was authorized by the user.
+pub fn claim_user_rewards(
+    ctx: &mut Context,
+    user: Addr,
+    msg: ClaimRewardsMsg,
+) -> DistributorResult<Response> {
+    if CLAIMS_PAUSED.may_load(ctx.deps.storage)?.unwrap_or(false) {
+        return Err(ClaimsPaused);
+    }
+
+    if is_restricted_user(ctx.deps.as_ref(), user.to_string())? {
+        return Err(RestrictedUser);
+    }
+
     let user_weight = EFFECTIVE_USER_WEIGHTS
         .may_load(ctx.deps.storage, user.clone())?
         .unwrap_or_default();
 
-    let mut submsgs: Vec<SubMsg> = vec![];
+    let native_claim_limits: HashMap<String, Uint128> = msg
+        .native_claim_limits
+        .unwrap_or_default()
+        .into_iter()
+        .map(|limit| (limit.denom, limit.max_amount))
+        .collect();
+
+    let cw20_claim_limits = msg
+        .cw20_claim_limits
+        .unwrap_or_default()
+        .into_iter()
+        .map(|limit| {
+            ctx.deps
+                .api
+                .addr_validate(&limit.asset)
+                .map(|asset| (asset, limit.max_amount))
+        })
+        .collect::<Result<HashMap<Addr, Uint128>, _>>()?;
+
+    let destination = msg
+        .destination
+        .map(|destination| validate_claim_destination(ctx.deps.as_ref(), destination))
+        .transpose()?;
+
+    let native_destinations = msg
+        .native_destinations
+        .unwrap_or_default()
+        .into_iter()
+        .map(|native_destination| {
+            validate_claim_destination(ctx.deps.as_ref(), native_destination.destination)
+                .map(|destination| (native_destination.denom, destination))
+        })
+        .collect::<DistributorResult<HashMap<String, ClaimDestinationChecked>>>()?;
+
+    let cw20_destinations = msg
+        .cw20_destinations
+        .unwrap_or_default()
+        .into_iter()
+        .map(|cw20_destination| {
+            let asset = ctx.deps.api.addr_validate(&cw20_destination.asset)?;
+            validate_claim_destination(ctx.deps.as_ref(), cw20_destination.destination)
+                .map(|destination| (asset, destination))
+        })
+        .collect::<DistributorResult<HashMap<Addr, ClaimDestinationChecked>>>()?;
+
+    let asset_class = msg.asset_class.unwrap_or(AssetClass::Both);
+    let claim_all = msg.claim_all.unwrap_or(false);
 
-    for denom in msg.native_denoms {
-        let distribution =
-            NATIVE_DISTRIBUTIONS().may_load(ctx.deps.storage, (user.clone(), denom.clone()))?;
+    let native_denoms = match asset_class {
+        AssetClass::Cw20 => vec![],
+        AssetClass::Native | AssetClass::Both if claim_all => NATIVE_GLOBAL_INDICES
+            .keys(ctx.deps.storage, None, None, Ascending)
+            .collect::<StdResult<Vec<String>>>()?,
+        AssetClass::Native | AssetClass::Both => msg.native_denoms,
+    };
+
+    let cw20_assets = match asset_class {
+        AssetClass::Native => vec![],
+        AssetClass::Cw20 | AssetClass::Both if claim_all => CW20_GLOBAL_INDICES
+            .keys(ctx.deps.storage, None, None, Ascending)
+            .map(|asset| asset.map(|asset| asset.to_string()))
+            .collect::<StdResult<Vec<String>>>()?,
+        AssetClass::Cw20 | AssetClass::Both => msg.cw20_assets,
+    };
+
+    let mut transfers: Vec<Asset> = vec![];
+    let mut claims: Vec<(RewardAsset, Uint128)> = vec![];
+
+    let mut denom_set: HashSet<String> = HashSet::new();
+
+    for denom in native_denoms {
+        if denom_set.contains(&denom) {
+            continue;
+        }
+
+        denom_set.insert(denom.clone());
+
+        let asset_key = native_asset_key(&denom);
+
+        let distribution = unexpired_distribution(
+            ctx.deps.storage,
+            &asset_key,
+            &user,
+            NATIVE_DISTRIBUTIONS()
+                .may_load(ctx.deps.storage, (user.clone(), denom.clone()))?
+                .map(<(Decimal256, Uint128)>::from),
+        )?;
         let global_index = NATIVE_GLOBAL_INDICES
             .may_load(ctx.deps.storage, denom.clone())?
             .unwrap_or_default();
@@ -48,15 +205,30 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
             continue;
         }
 
-        let reward = calculate_user_reward(global_index, distribution, user_weight)?;
+        let index_scale = asset_index_scale(ctx.deps.storage, &asset_key)?;
+        let asset_user_weight =
+            asset_user_weight(ctx.deps.storage, asset_key.clone(), user_weight)?;
+
+        let reward =
+            calculate_user_reward(global_index, distribution, asset_user_weight, index_scale)?;
 
         // if no user rewards due for the given asset, just skip - no need to send or store anything
         if reward.is_zero() {
             continue;
         }
 
-        let submsg = Asset::native(denom.clone(), reward).transfer_msg(user.clone())?;
-        submsgs.push(SubMsg::new(submsg));
+        let (claimed_reward, remaining_reward) =
+            split_user_reward(reward, native_claim_limits.get(&denom).copied());
+
+        if !claimed_reward.is_zero() {
+            add_transfer(&mut transfers, Asset::native(denom.clone(), claimed_reward))?;
+            claims.push((
+                RewardAsset::Native {
+                    denom: denom.clone(),
+                },
+                claimed_reward,
+            ));
+        }
 
         NATIVE_DISTRIBUTIONS().save(
             ctx.deps.storage,
@@ -65,16 +237,41 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
                 user: user.clone(),
                 denom,
                 user_index: global_index,
-                pending_rewards: Uint128::zero(),
+                pending_rewards: remaining_reward,
             },
         )?;
+
+        record_user_distribution(
+            ctx.deps.storage,
+            &asset_key,
+            &user,
+            distribution,
+            (global_index, remaining_reward),
+            asset_user_weight,
+        )?;
     }
 
-    for asset in msg.cw20_assets {
+    let mut asset_set: HashSet<Addr> = HashSet::new();
+
+    for asset in cw20_assets {
         let asset = ctx.deps.api.addr_validate(&asset)?;
 
-        let distribution =
-            CW20_DISTRIBUTIONS().may_load(ctx.deps.storage, (user.clone(), asset.clone()))?;
+        if asset_set.contains(&asset) {
+            continue;
+        }
+
+        asset_set.insert(asset.clone());
+
+        let asset_key = cw20_asset_key(asset.as_ref());
+
+        let distribution = unexpired_distribution(
+            ctx.deps.storage,
+            &asset_key,
+            &user,
+            CW20_DISTRIBUTIONS()
+                .may_load(ctx.deps.storage, (user.clone(), asset.clone()))?
+                .map(<(Decimal256, Uint128)>::from),
+        )?;
         let global_index = CW20_GLOBAL_INDICES
             .may_load(ctx.deps.storage, asset.clone())?
             .unwrap_or_default();
@@ -84,15 +281,31 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
             continue;
         }
 
-        let reward = calculate_user_reward(global_index, distribution, user_weight)?;
+        let index_scale = asset_index_scale(ctx.deps.storage, &asset_key)?;
+        let asset_user_weight =
+            asset_user_weight(ctx.deps.storage, asset_key.clone(), user_weight)?;
+
+        let reward =
+            calculate_user_reward(global_index, distribution, asset_user_weight, index_scale)?;
 
         // if no user rewards due for the given asset, just skip - no need to send or store anything
         if reward.is_zero() {
             continue;
         }
 
-        let submsg = Asset::cw20(asset.clone(), reward).transfer_msg(user.clone())?;
-        submsgs.push(SubMsg::new(submsg));
+        let (claimed_reward, remaining_reward) =
+            split_user_reward(reward, cw20_claim_limits.get(&asset).copied());
+
+        if !claimed_reward.is_zero() {
+            add_transfer(&mut transfers, Asset::cw20(asset.clone(), claimed_reward))?;
+            record_cw20_outflow(ctx.deps.storage, asset.clone(), claimed_reward)?;
+            claims.push((
+                RewardAsset::Cw20 {
+                    asset: asset.to_string(),
+                },
+                claimed_reward,
+            ));
+        }
 
         CW20_DISTRIBUTIONS().save(
             ctx.deps.storage,
@@ -101,14 +314,140 @@ pub fn claim_rewards(ctx: &mut Context, msg: ClaimRewardsMsg) -> DistributorResu
                 user: user.clone(),
                 cw20_asset: asset,
                 user_index: global_index,
-                pending_rewards: Uint128::zero(),
+                pending_rewards: remaining_reward,
             },
         )?;
+
+        record_user_distribution(
+            ctx.deps.storage,
+            &asset_key,
+            &user,
+            distribution,
+            (global_index, remaining_reward),
+            asset_user_weight,
+        )?;
     }
 
+    let submsgs = transfers
+        .into_iter()
+        .map(|reward| {
+            let asset_destination = match &reward.info {
+                AssetInfo::Native(denom) => native_destinations.get(denom),
+                AssetInfo::Cw20(asset) => cw20_destinations.get(asset),
+                _ => None,
+            };
+            reward_transfer_msg(reward, &user, asset_destination.or(destination.as_ref()))
+                .map(SubMsg::new)
+        })
+        .collect::<DistributorResult<Vec<SubMsg>>>()?;
+
+    record_claims(ctx.deps.storage, user.clone(), ctx.env.block.time, claims)?;
+
     Ok(execute_claim_rewards_response(user.to_string()).add_submessages(submsgs))
 }
 
+/// Checks which of the given assets would produce a transfer if the user claimed them now,
+/// following the same rules for skipping assets as claiming does.
+pub fn query_claim_preview(
+    qctx: QueryContext,
+    params: ClaimPreviewParams,
+) -> DistributorResult<ClaimPreviewResponse> {
+    let user = qctx.deps.api.addr_validate(&params.user)?;
+
+    let rewards = query_user_rewards(
+        qctx,
+        UserRewardsParams {
+            user: user.to_string(),
+            native_denoms: params.native_denoms,
+            cw20_assets: params.cw20_assets,
+        },
+    )?;
+
+    let native_claims = rewards
+        .native_rewards
+        .into_iter()
+        .map(|reward| NativeClaimPreview {
+            denom: reward.denom,
+            transfers: !reward.amount.is_zero(),
+        })
+        .collect();
+
+    let cw20_claims = rewards
+        .cw20_rewards
+        .into_iter()
+        .map(|reward| Cw20ClaimPreview {
+            asset: reward.asset,
+            transfers: !reward.amount.is_zero(),
+        })
+        .collect();
+
+    Ok(ClaimPreviewResponse {
+        native_claims,
+        cw20_claims,
+    })
+}
+
+fn validate_claim_destination(
+    deps: Deps,
+    destination: ClaimDestination,
+) -> DistributorResult<ClaimDestinationChecked> {
+    let contract = deps.api.addr_validate(&destination.contract)?;
+
+    from_json::<IgnoredAny>(&destination.msg).map_err(|_| InvalidClaimHookMsg)?;
+
+    Ok(ClaimDestinationChecked {
+        contract,
+        msg: destination.msg,
+    })
+}
+
+/// Adds the claimed reward to the transfers to be made, merging it into an existing transfer
+/// of the same asset if there is one.
+fn add_transfer(transfers: &mut Vec<Asset>, reward: Asset) -> DistributorResult<()> {
+    match transfers
+        .iter_mut()
+        .find(|transfer| transfer.info == reward.info)
+    {
+        Some(transfer) => transfer.amount = transfer.amount.checked_add(reward.amount)?,
+        None => transfers.push(reward),
+    }
+
+    Ok(())
+}
+
+/// Creates a message sending the claimed reward either directly to the user, or to the
+/// destination contract along with its hook message.
+fn reward_transfer_msg(
+    reward: Asset,
+    user: &Addr,
+    destination: Option<&ClaimDestinationChecked>,
+) -> DistributorResult<CosmosMsg> {
+    let msg = match destination {
+        None => reward.transfer_msg(user.clone())?,
+        Some(destination) => match reward.info {
+            AssetInfo::Native(denom) => WasmMsg::Execute {
+                contract_addr: destination.contract.to_string(),
+                msg: destination.msg.clone(),
+                funds: coins(reward.amount.u128(), denom),
+            }
+            .into(),
+            AssetInfo::Cw20(token) => wasm_execute(
+                token.to_string(),
+                &Cw20ExecuteMsg::Send {
+                    contract: destination.contract.to_string(),
+                    amount: reward.amount,
+                    msg: destination.msg.clone(),
+                },
+                vec![],
+            )?
+            .into(),
+            _ => reward.transfer_msg(user.clone())?,
+        },
+    };
+
+    Ok(msg)
+}
+
 fn is_restricted_user(deps: Deps, user: String) -> DistributorResult<bool> {
     let enterprise_contract = ENTERPRISE_CONTRACT.load(deps.storage)?;
 
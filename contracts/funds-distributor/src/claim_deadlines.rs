@@ -0,0 +1,470 @@
+use crate::distributing::query_enterprise_components;
+use crate::fee_on_transfer::record_cw20_outflow;
+use crate::index_precision::asset_index_scale;
+use crate::rate_limit::{cw20_asset_key, native_asset_key};
+use crate::rewards::calculate_new_user_reward;
+use crate::state::{ADMIN, CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use common::cw::Context;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Order::{Ascending, Descending};
+use cosmwasm_std::{
+    coins, wasm_execute, Addr, BankMsg, CosmosMsg, Decimal256, Response, StdResult, Storage,
+    Timestamp, Uint128,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::{Bound, Map};
+use enterprise_governance_controller_api::api::GovConfigResponse;
+use enterprise_governance_controller_api::msg::QueryMsg::GovConfig;
+use funds_distributor_api::api::{RewardAsset, SetAssetClaimPeriodMsg};
+use funds_distributor_api::error::DistributorError::{
+    AssetAlreadyDistributed, NoExpiredRewards, Unauthorized, ZeroClaimPeriod,
+};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::{
+    execute_set_asset_claim_period_response, execute_sweep_expired_rewards_response,
+};
+
+/// Claim periods set for reward assets, in seconds, keyed by the asset's key.
+/// Rewards of assets without a claim period never expire.
+const CLAIM_PERIODS: Map<String, u64> = Map::new("claim_periods");
+
+/// Distributions of assets with a claim period, keyed by the asset's key and an ID
+/// incrementing from 1.
+const EXPIRING_DISTRIBUTIONS: Map<(String, u64), ExpiringDistribution> =
+    Map::new("expiring_distributions");
+
+/// ID of the last distribution of the asset whose unclaimed rewards were swept.
+const LAST_SWEPT_DISTRIBUTIONS: Map<String, u64> = Map::new("last_swept_distributions");
+
+/// Total pending rewards of users whose entries were last updated while the given distribution
+/// was the asset's latest.
+const PENDING_REWARDS_BY_DISTRIBUTION: Map<(String, u64), Uint128> =
+    Map::new("pending_rewards_by_distribution");
+
+/// Latest distribution of the asset at the time the user's entry was last updated.
+const USER_LAST_DISTRIBUTIONS: Map<(Addr, String), u64> = Map::new("user_last_distributions");
+
+/// Changes to the total weight of users whose rewards from a whole distribution were moved into
+/// their pending rewards, taking effect from the given distribution on.
+/// Accrual of such distributions is only computed once they are swept, so updating a user's
+/// entry does not have to go through every distribution since their last update.
+const FULLY_ACCRUED_WEIGHT_CHANGES: Map<(String, u64), WeightChange> =
+    Map::new("fully_accrued_weight_changes");
+
+/// Total weight whose rewards from the asset's last swept distribution were fully accrued.
+const SWEPT_FULLY_ACCRUED_WEIGHTS: Map<String, Uint128> = Map::new("swept_fully_accrued_weights");
+
+/// Maximum number of distributions swept at once, to keep the sweep's gas cost bounded.
+/// Any remaining expired distributions are swept by sweeping again.
+const MAX_SWEPT_DISTRIBUTIONS: usize = 30;
+
+#[cw_serde]
+/// A single distribution whose unclaimed rewards can be swept after its deadline.
+pub struct ExpiringDistribution {
+    pub distributed_at: Timestamp,
+    pub deadline: Timestamp,
+    /// Amount distributed, after the distribution fee
+    pub amount: Uint128,
+    /// Asset's global index right after the distribution
+    pub global_index: Decimal256,
+    /// Amount of the distribution already moved into users' pending rewards, rounded up.
+    /// Does not include the rewards of users that accrued the whole distribution at once,
+    /// which are tracked in [FULLY_ACCRUED_WEIGHT_CHANGES].
+    pub accrued: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+struct WeightChange {
+    added: Uint128,
+    removed: Uint128,
+}
+
+/// Sets the claim period of an asset. Only the admin can do this, and only before the asset
+/// is first distributed, since expiry is tracked for every distribution of the asset.
+pub fn set_asset_claim_period(
+    ctx: &mut Context,
+    msg: SetAssetClaimPeriodMsg,
+) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    if msg.claim_period == 0 {
+        return Err(ZeroClaimPeriod);
+    }
+
+    let (asset_key, already_distributed) = match msg.asset {
+        RewardAsset::Native { denom } => (
+            native_asset_key(&denom),
+            NATIVE_GLOBAL_INDICES.has(ctx.deps.storage, denom),
+        ),
+        RewardAsset::Cw20 { asset } => {
+            let asset = ctx.deps.api.addr_validate(&asset)?;
+            (
+                cw20_asset_key(asset.as_ref()),
+                CW20_GLOBAL_INDICES.has(ctx.deps.storage, asset),
+            )
+        }
+    };
+
+    if already_distributed {
+        return Err(AssetAlreadyDistributed);
+    }
+
+    CLAIM_PERIODS.save(ctx.deps.storage, asset_key.clone(), &msg.claim_period)?;
+
+    Ok(execute_set_asset_claim_period_response(
+        asset_key,
+        msg.claim_period,
+    ))
+}
+
+pub fn has_claim_period(storage: &dyn Storage, asset_key: &str) -> bool {
+    CLAIM_PERIODS.has(storage, asset_key.to_string())
+}
+
+/// Records a distribution of the asset, if the asset has a claim period.
+/// Its rewards can be swept once the claim period has passed.
+pub fn record_expiring_distribution(
+    storage: &mut dyn Storage,
+    asset_key: &str,
+    amount: Uint128,
+    global_index: Decimal256,
+    now: Timestamp,
+) -> DistributorResult<()> {
+    let Some(claim_period) = CLAIM_PERIODS.may_load(storage, asset_key.to_string())? else {
+        return Ok(());
+    };
+
+    let id = latest_distribution_id(storage, asset_key)? + 1;
+
+    EXPIRING_DISTRIBUTIONS.save(
+        storage,
+        (asset_key.to_string(), id),
+        &ExpiringDistribution {
+            distributed_at: now,
+            deadline: now.plus_seconds(claim_period),
+            amount,
+            global_index,
+            accrued: Uint128::zero(),
+        },
+    )?;
+
+    Ok(())
+}
+
+/// User's entry for the asset, with rewards that were already swept taken out of it.
+///
+/// Pending rewards are swept if all distributions up to the user's last update were swept.
+/// Rewards not yet accrued are swept up to the global index of the last swept distribution.
+pub fn unexpired_distribution(
+    storage: &dyn Storage,
+    asset_key: &str,
+    user: &Addr,
+    distribution: Option<(Decimal256, Uint128)>,
+) -> DistributorResult<Option<(Decimal256, Uint128)>> {
+    let last_swept = last_swept_distribution_id(storage, asset_key)?;
+
+    if last_swept == 0 || !has_claim_period(storage, asset_key) {
+        return Ok(distribution);
+    }
+
+    let (user_index, pending_rewards) = distribution.unwrap_or_default();
+
+    let swept_index = distribution_global_index(storage, asset_key, last_swept)?;
+
+    let pending_swept = USER_LAST_DISTRIBUTIONS
+        .may_load(storage, (user.clone(), asset_key.to_string()))?
+        .is_some_and(|id| id <= last_swept);
+
+    let pending_rewards = if pending_swept {
+        Uint128::zero()
+    } else {
+        pending_rewards
+    };
+
+    Ok(Some((user_index.max(swept_index), pending_rewards)))
+}
+
+/// Keeps track of the asset's unclaimed rewards after the user's entry was updated
+/// from the old (unexpired) entry to the new one, accruing at the given weight.
+pub fn record_user_distribution(
+    storage: &mut dyn Storage,
+    asset_key: &str,
+    user: &Addr,
+    old_distribution: Option<(Decimal256, Uint128)>,
+    new_distribution: (Decimal256, Uint128),
+    user_weight: Uint128,
+) -> DistributorResult<()> {
+    if !has_claim_period(storage, asset_key) {
+        return Ok(());
+    }
+
+    let last_swept = last_swept_distribution_id(storage, asset_key)?;
+    let (old_index, old_pending_rewards) = old_distribution.unwrap_or_default();
+    let (new_index, new_pending_rewards) = new_distribution;
+
+    let user_key = (user.clone(), asset_key.to_string());
+
+    // old pending rewards are replaced by the new ones, unless they were already swept
+    if let Some(id) = USER_LAST_DISTRIBUTIONS.may_load(storage, user_key.clone())? {
+        if id > last_swept {
+            let key = (asset_key.to_string(), id);
+            let total = PENDING_REWARDS_BY_DISTRIBUTION
+                .may_load(storage, key.clone())?
+                .unwrap_or_default();
+            PENDING_REWARDS_BY_DISTRIBUTION.save(
+                storage,
+                key,
+                &total.saturating_sub(old_pending_rewards),
+            )?;
+        }
+    }
+
+    let latest = latest_distribution_id(storage, asset_key)?;
+
+    // rewards accrued since the user's index are now pending, so they are attributed
+    // to the distributions they came from.
+    // The user's index was last updated while their last recorded distribution was the latest,
+    // so only the distributions after it (and after the ones already swept) can contribute.
+    let first = USER_LAST_DISTRIBUTIONS
+        .may_load(storage, user_key.clone())?
+        .unwrap_or_default()
+        .max(last_swept)
+        + 1;
+
+    if !user_weight.is_zero() && new_index > old_index && first <= latest {
+        let index_scale = asset_index_scale(storage, asset_key)?;
+
+        // the first distribution may have been partially accrued before
+        let mut distribution =
+            EXPIRING_DISTRIBUTIONS.load(storage, (asset_key.to_string(), first))?;
+        let accrued_from = distribution_global_index(storage, asset_key, first - 1)?.max(old_index);
+        let accrued_to = distribution.global_index.min(new_index);
+
+        if accrued_to > accrued_from {
+            // rounded up, so that sweeping never takes anything still owed to users
+            let accrued =
+                calculate_new_user_reward(accrued_to, accrued_from, user_weight, index_scale)?
+                    .checked_add(Uint128::one())?;
+            distribution.accrued = distribution.accrued.checked_add(accrued)?;
+            EXPIRING_DISTRIBUTIONS.save(storage, (asset_key.to_string(), first), &distribution)?;
+        }
+
+        // the following ones up to the latest were accrued whole, as the user's new index
+        // is the asset's current global index
+        if latest > first {
+            update_weight_change(storage, asset_key, first + 1, |change| {
+                change.added = change.added.checked_add(user_weight)?;
+                Ok(())
+            })?;
+            update_weight_change(storage, asset_key, latest + 1, |change| {
+                change.removed = change.removed.checked_add(user_weight)?;
+                Ok(())
+            })?;
+        }
+    }
+
+    let key = (asset_key.to_string(), latest);
+    let total = PENDING_REWARDS_BY_DISTRIBUTION
+        .may_load(storage, key.clone())?
+        .unwrap_or_default();
+    PENDING_REWARDS_BY_DISTRIBUTION.save(storage, key, &total.checked_add(new_pending_rewards)?)?;
+    USER_LAST_DISTRIBUTIONS.save(storage, user_key, &latest)?;
+
+    Ok(())
+}
+
+/// Removes expiry tracking of the user's entry for the asset, once the entry is deleted.
+/// The entry must have no pending rewards.
+pub fn remove_user_distribution(storage: &mut dyn Storage, asset_key: &str, user: &Addr) {
+    USER_LAST_DISTRIBUTIONS.remove(storage, (user.clone(), asset_key.to_string()));
+}
+
+/// Sends the asset's rewards that were not claimed before their distributions' deadlines
+/// to the DAO's forfeiture recipient. Only the admin can do this.
+///
+/// Distributions are swept in order, so a distribution is only swept once all the previous ones
+/// have passed their deadlines as well. Rewards of distributions still within their deadline
+/// are never swept.
+pub fn sweep_expired_rewards(ctx: &mut Context, asset: RewardAsset) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let asset_key = match &asset {
+        RewardAsset::Native { denom } => native_asset_key(denom),
+        RewardAsset::Cw20 { asset } => cw20_asset_key(ctx.deps.api.addr_validate(asset)?.as_ref()),
+    };
+
+    let last_swept = last_swept_distribution_id(ctx.deps.storage, &asset_key)?;
+
+    let expired_distributions = EXPIRING_DISTRIBUTIONS
+        .prefix(asset_key.clone())
+        .range(
+            ctx.deps.storage,
+            Some(Bound::exclusive(last_swept)),
+            None,
+            Ascending,
+        )
+        .take_while(|res| {
+            res.as_ref().map_or(true, |(_, distribution)| {
+                distribution.deadline <= ctx.env.block.time
+            })
+        })
+        .take(MAX_SWEPT_DISTRIBUTIONS)
+        .collect::<StdResult<Vec<(u64, ExpiringDistribution)>>>()?;
+
+    let Some((newly_swept, _)) = expired_distributions.last() else {
+        return Err(NoExpiredRewards);
+    };
+    let newly_swept = *newly_swept;
+
+    let index_scale = asset_index_scale(ctx.deps.storage, &asset_key)?;
+    let mut previous_index = distribution_global_index(ctx.deps.storage, &asset_key, last_swept)?;
+    let mut fully_accrued_weight = SWEPT_FULLY_ACCRUED_WEIGHTS
+        .may_load(ctx.deps.storage, asset_key.clone())?
+        .unwrap_or_default();
+
+    let mut amount = Uint128::zero();
+
+    for (id, distribution) in expired_distributions {
+        let change = FULLY_ACCRUED_WEIGHT_CHANGES
+            .may_load(ctx.deps.storage, (asset_key.clone(), id))?
+            .unwrap_or_default();
+        FULLY_ACCRUED_WEIGHT_CHANGES.remove(ctx.deps.storage, (asset_key.clone(), id));
+        fully_accrued_weight = fully_accrued_weight
+            .checked_add(change.added)?
+            .checked_sub(change.removed)?;
+
+        // rounded up, so that sweeping never takes anything still owed to users
+        let fully_accrued = if fully_accrued_weight.is_zero() {
+            Uint128::zero()
+        } else {
+            calculate_new_user_reward(
+                distribution.global_index,
+                previous_index,
+                fully_accrued_weight,
+                index_scale,
+            )?
+            .checked_add(Uint128::one())?
+        };
+        previous_index = distribution.global_index;
+
+        let accrued = distribution.accrued.checked_add(fully_accrued)?;
+
+        let pending_rewards = PENDING_REWARDS_BY_DISTRIBUTION
+            .may_load(ctx.deps.storage, (asset_key.clone(), id))?
+            .unwrap_or_default();
+        PENDING_REWARDS_BY_DISTRIBUTION.remove(ctx.deps.storage, (asset_key.clone(), id));
+
+        amount = amount
+            .checked_add(distribution.amount.saturating_sub(accrued))?
+            .checked_add(pending_rewards)?;
+    }
+
+    LAST_SWEPT_DISTRIBUTIONS.save(ctx.deps.storage, asset_key.clone(), &newly_swept)?;
+    SWEPT_FULLY_ACCRUED_WEIGHTS.save(ctx.deps.storage, asset_key.clone(), &fully_accrued_weight)?;
+
+    let treasury = query_forfeiture_recipient(ctx)?.to_string();
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+
+    if !amount.is_zero() {
+        match &asset {
+            RewardAsset::Native { denom } => msgs.push(
+                BankMsg::Send {
+                    to_address: treasury,
+                    amount: coins(amount.u128(), denom),
+                }
+                .into(),
+            ),
+            RewardAsset::Cw20 { asset } => {
+                let asset = ctx.deps.api.addr_validate(asset)?;
+                record_cw20_outflow(ctx.deps.storage, asset.clone(), amount)?;
+                msgs.push(
+                    wasm_execute(
+                        asset.to_string(),
+                        &Cw20ExecuteMsg::Transfer {
+                            recipient: treasury,
+                            amount,
+                        },
+                        vec![],
+                    )?
+                    .into(),
+                );
+            }
+        }
+    }
+
+    Ok(execute_sweep_expired_rewards_response(asset_key, amount, newly_swept).add_messages(msgs))
+}
+
+/// Address that the DAO sends all forfeited funds to, i.e. the treasury address configured
+/// in its governance, or the DAO's treasury if none is configured.
+fn query_forfeiture_recipient(ctx: &Context) -> DistributorResult<Addr> {
+    let component_contracts = query_enterprise_components(ctx)?;
+
+    let gov_config: GovConfigResponse = ctx.deps.querier.query_wasm_smart(
+        component_contracts
+            .enterprise_governance_controller_contract
+            .to_string(),
+        &GovConfig {},
+    )?;
+
+    Ok(gov_config
+        .gov_config
+        .treasury_address
+        .unwrap_or(component_contracts.enterprise_treasury_contract))
+}
+
+fn update_weight_change(
+    storage: &mut dyn Storage,
+    asset_key: &str,
+    id: u64,
+    update: impl FnOnce(&mut WeightChange) -> DistributorResult<()>,
+) -> DistributorResult<()> {
+    let key = (asset_key.to_string(), id);
+    let mut change = FULLY_ACCRUED_WEIGHT_CHANGES
+        .may_load(storage, key.clone())?
+        .unwrap_or_default();
+    update(&mut change)?;
+    FULLY_ACCRUED_WEIGHT_CHANGES.save(storage, key, &change)?;
+
+    Ok(())
+}
+
+fn latest_distribution_id(storage: &dyn Storage, asset_key: &str) -> StdResult<u64> {
+    let latest = EXPIRING_DISTRIBUTIONS
+        .prefix(asset_key.to_string())
+        .keys(storage, None, None, Descending)
+        .next()
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(latest)
+}
+
+fn last_swept_distribution_id(storage: &dyn Storage, asset_key: &str) -> StdResult<u64> {
+    Ok(LAST_SWEPT_DISTRIBUTIONS
+        .may_load(storage, asset_key.to_string())?
+        .unwrap_or_default())
+}
+
+/// Global index right after the given distribution, or zero before the first one.
+fn distribution_global_index(
+    storage: &dyn Storage,
+    asset_key: &str,
+    id: u64,
+) -> StdResult<Decimal256> {
+    let global_index = EXPIRING_DISTRIBUTIONS
+        .may_load(storage, (asset_key.to_string(), id))?
+        .map_or(Decimal256::zero(), |distribution| distribution.global_index);
+
+    Ok(global_index)
+}
@@ -0,0 +1,164 @@
+use crate::rate_limit::{cw20_asset_key, native_asset_key};
+use common::cw::QueryContext;
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use funds_distributor_api::api::{
+    ClaimHistoryEntry, ClaimHistoryParams, ClaimHistoryResponse, ClaimHistoryRetention,
+    RewardAsset, UserClaimTime, UserClaimTimesParams, UserClaimTimesResponse,
+};
+use funds_distributor_api::error::DistributorResult;
+
+const DEFAULT_QUERY_LIMIT: u32 = 50;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+/// Retention used when no retention policy was configured.
+const DEFAULT_CLAIM_HISTORY_RETENTION: ClaimHistoryRetention =
+    ClaimHistoryRetention::LastEntries(100);
+
+pub const CLAIM_HISTORY_RETENTION: Item<ClaimHistoryRetention> =
+    Item::new("claim_history_retention");
+
+/// Users' past claims, keyed by user and an incrementing per-user entry ID.
+pub const CLAIM_HISTORY: Map<(Addr, u64), ClaimHistoryEntry> = Map::new("claim_history");
+
+/// ID to be assigned to the next claim history entry of a user.
+const NEXT_CLAIM_HISTORY_ID: Map<Addr, u64> = Map::new("next_claim_history_id");
+
+/// Times at which users last claimed each asset, keyed by user and the asset's key.
+/// Unlike the claim history, these are never pruned.
+const LAST_CLAIM_TIMES: Map<(Addr, String), Timestamp> = Map::new("last_claim_times");
+
+/// Appends the given claims to the user's claim history, and prunes entries that fall outside
+/// of the configured retention.
+pub fn record_claims(
+    storage: &mut dyn Storage,
+    user: Addr,
+    now: Timestamp,
+    claims: Vec<(RewardAsset, Uint128)>,
+) -> DistributorResult<()> {
+    if claims.is_empty() {
+        return Ok(());
+    }
+
+    for (asset, _) in &claims {
+        LAST_CLAIM_TIMES.save(storage, (user.clone(), reward_asset_key(asset)), &now)?;
+    }
+
+    let mut next_id = NEXT_CLAIM_HISTORY_ID
+        .may_load(storage, user.clone())?
+        .unwrap_or_default();
+
+    for (asset, amount) in claims {
+        CLAIM_HISTORY.save(
+            storage,
+            (user.clone(), next_id),
+            &ClaimHistoryEntry {
+                id: next_id,
+                timestamp: now,
+                asset,
+                amount,
+            },
+        )?;
+        next_id += 1;
+    }
+
+    NEXT_CLAIM_HISTORY_ID.save(storage, user.clone(), &next_id)?;
+
+    let retention = CLAIM_HISTORY_RETENTION
+        .may_load(storage)?
+        .unwrap_or(DEFAULT_CLAIM_HISTORY_RETENTION);
+
+    let expired_ids = match retention {
+        ClaimHistoryRetention::LastEntries(entries) => {
+            let first_kept_id = next_id.saturating_sub(entries as u64);
+            CLAIM_HISTORY
+                .prefix(user.clone())
+                .keys(
+                    storage,
+                    None,
+                    Some(Bound::exclusive(first_kept_id)),
+                    Ascending,
+                )
+                .collect::<StdResult<Vec<u64>>>()?
+        }
+        ClaimHistoryRetention::LastSeconds(seconds) => {
+            let oldest_kept = Timestamp::from_seconds(now.seconds().saturating_sub(seconds));
+            let mut expired_ids = vec![];
+            for entry in CLAIM_HISTORY
+                .prefix(user.clone())
+                .range(storage, None, None, Ascending)
+            {
+                let (id, entry) = entry?;
+                if entry.timestamp >= oldest_kept {
+                    break;
+                }
+                expired_ids.push(id);
+            }
+            expired_ids
+        }
+    };
+
+    for id in expired_ids {
+        CLAIM_HISTORY.remove(storage, (user.clone(), id));
+    }
+
+    Ok(())
+}
+
+pub fn query_claim_history(
+    qctx: QueryContext,
+    params: ClaimHistoryParams,
+) -> DistributorResult<ClaimHistoryResponse> {
+    let user = qctx.deps.api.addr_validate(&params.user)?;
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT) as usize;
+
+    let entries = CLAIM_HISTORY
+        .prefix(user)
+        .range(
+            qctx.deps.storage,
+            params.start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(_, entry)| entry))
+        .collect::<StdResult<Vec<ClaimHistoryEntry>>>()?;
+
+    Ok(ClaimHistoryResponse { entries })
+}
+
+/// Times at which the user last claimed the given assets, in the order the assets were given.
+/// Assets that the user never claimed have no time.
+pub fn query_user_claim_times(
+    qctx: QueryContext,
+    params: UserClaimTimesParams,
+) -> DistributorResult<UserClaimTimesResponse> {
+    let user = qctx.deps.api.addr_validate(&params.user)?;
+
+    let claim_times = params
+        .assets
+        .into_iter()
+        .map(|asset| {
+            LAST_CLAIM_TIMES
+                .may_load(qctx.deps.storage, (user.clone(), reward_asset_key(&asset)))
+                .map(|last_claimed_at| UserClaimTime {
+                    asset,
+                    last_claimed_at,
+                })
+        })
+        .collect::<StdResult<Vec<UserClaimTime>>>()?;
+
+    Ok(UserClaimTimesResponse { claim_times })
+}
+
+fn reward_asset_key(asset: &RewardAsset) -> String {
+    match asset {
+        RewardAsset::Native { denom } => native_asset_key(denom),
+        RewardAsset::Cw20 { asset } => cw20_asset_key(asset),
+    }
+}
@@ -0,0 +1,115 @@
+use crate::claim_history::CLAIM_HISTORY_RETENTION;
+use crate::fees::DISTRIBUTION_FEE;
+use crate::rate_limit::DISTRIBUTION_RATE_LIMIT;
+use crate::state::{ADMIN, ENTERPRISE_CONTRACT, WEIGHT_ORACLE};
+use crate::vesting::DISTRIBUTION_MODE;
+use common::cw::Context;
+use cosmwasm_std::Response;
+use funds_distributor_api::api::{
+    DistributionFee, DistributionMode, UpdateConfigMsg, UpdateWeightOracleMsg,
+    MAX_DISTRIBUTION_FEE_BPS,
+};
+use funds_distributor_api::error::DistributorError::{
+    DistributionFeeTooHigh, InvalidDistributionRateLimit, Unauthorized, ZeroVestingDuration,
+};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::{
+    execute_update_config_response, execute_update_weight_oracle_response,
+};
+
+/// Updates the admin, the enterprise contract, the claim history retention,
+/// the distribution rate limit, the distribution mode, and/or the distribution fee.
+/// Only the current admin can do this.
+pub fn update_config(ctx: &mut Context, msg: UpdateConfigMsg) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let admin = match msg.new_admin {
+        Some(new_admin) => {
+            let new_admin = ctx.deps.api.addr_validate(&new_admin)?;
+            ADMIN.save(ctx.deps.storage, &new_admin)?;
+            new_admin
+        }
+        None => admin,
+    };
+
+    let enterprise_contract = match msg.new_enterprise_contract {
+        Some(new_enterprise_contract) => {
+            let new_enterprise_contract = ctx.deps.api.addr_validate(&new_enterprise_contract)?;
+            ENTERPRISE_CONTRACT.save(ctx.deps.storage, &new_enterprise_contract)?;
+            new_enterprise_contract
+        }
+        None => ENTERPRISE_CONTRACT.load(ctx.deps.storage)?,
+    };
+
+    if let Some(claim_history_retention) = msg.new_claim_history_retention {
+        CLAIM_HISTORY_RETENTION.save(ctx.deps.storage, &claim_history_retention)?;
+    }
+
+    if let Some(distribution_rate_limit) = msg.new_distribution_rate_limit {
+        if distribution_rate_limit.max_distributions == 0
+            || distribution_rate_limit.window_seconds == 0
+        {
+            return Err(InvalidDistributionRateLimit);
+        }
+        DISTRIBUTION_RATE_LIMIT.save(ctx.deps.storage, &distribution_rate_limit)?;
+    }
+
+    if let Some(distribution_mode) = msg.new_distribution_mode {
+        if distribution_mode == (DistributionMode::Vesting { duration: 0 }) {
+            return Err(ZeroVestingDuration);
+        }
+        DISTRIBUTION_MODE.save(ctx.deps.storage, &distribution_mode)?;
+    }
+
+    if let Some(distribution_fee) = msg.new_distribution_fee {
+        if distribution_fee.fee_bps > MAX_DISTRIBUTION_FEE_BPS {
+            return Err(DistributionFeeTooHigh {
+                max_bps: MAX_DISTRIBUTION_FEE_BPS,
+            });
+        }
+        let recipient = ctx.deps.api.addr_validate(&distribution_fee.recipient)?;
+        DISTRIBUTION_FEE.save(
+            ctx.deps.storage,
+            &DistributionFee {
+                recipient: recipient.to_string(),
+                ..distribution_fee
+            },
+        )?;
+    }
+
+    Ok(execute_update_config_response(
+        admin.to_string(),
+        enterprise_contract.to_string(),
+    ))
+}
+
+/// Sets (or removes) the address allowed to update user weights in addition to the admin.
+/// Only the admin can do this.
+pub fn update_weight_oracle(
+    ctx: &mut Context,
+    msg: UpdateWeightOracleMsg,
+) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let weight_oracle = match msg.weight_oracle {
+        Some(weight_oracle) => {
+            let weight_oracle = ctx.deps.api.addr_validate(&weight_oracle)?;
+            WEIGHT_ORACLE.save(ctx.deps.storage, &weight_oracle)?;
+            Some(weight_oracle.to_string())
+        }
+        None => {
+            WEIGHT_ORACLE.remove(ctx.deps.storage);
+            None
+        }
+    };
+
+    Ok(execute_update_weight_oracle_response(weight_oracle))
+}
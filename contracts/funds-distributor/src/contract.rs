@@ -1,11 +1,32 @@
-use crate::claim::claim_rewards;
+use crate::asset_metadata::register_reward_asset;
+use crate::claim::{claim_rewards, query_claim_preview, query_claims_paused, set_claims_paused};
+use crate::claim_deadlines::{set_asset_claim_period, sweep_expired_rewards};
+use crate::claim_history::{query_claim_history, query_user_claim_times};
+use crate::config::{update_config, update_weight_oracle};
 use crate::distributing::{distribute_cw20, distribute_native};
+use crate::distribution_rates::query_distribution_rate;
 use crate::eligibility::{
-    execute_update_minimum_eligible_weight, query_minimum_eligible_weight, MINIMUM_ELIGIBLE_WEIGHT,
+    count_eligible_users, execute_update_minimum_eligible_weight, query_minimum_eligible_weight,
+    MINIMUM_ELIGIBLE_WEIGHT,
 };
-use crate::rewards::query_user_rewards;
-use crate::state::{ADMIN, ENTERPRISE_CONTRACT};
-use crate::user_weights::{save_initial_weights, update_user_weights};
+use crate::emissions::{poke_emissions, query_emission_schedule, set_emission_schedule};
+use crate::epochs::{query_epochs, roll_epoch};
+use crate::fee_on_transfer::set_fee_on_transfer_asset;
+use crate::fees::query_distribution_fee;
+use crate::index_precision::set_asset_index_precision;
+use crate::opt_out::{opt_out_of_rewards, query_rewards_opt_out};
+use crate::permit::{claim_rewards_with_permit, query_claim_permit_nonce};
+use crate::prune::prune_distributions;
+use crate::reconcile::reconcile_user;
+use crate::rewards::{
+    query_multi_user_rewards, query_outstanding_rewards_by_user, query_user_rewards,
+};
+use crate::state::{ADMIN, ELIGIBLE_USERS_COUNT, ENTERPRISE_CONTRACT, WEIGHT_ORACLE};
+use crate::user_weights::{
+    query_total_effective_weight, save_initial_weights, update_user_weights,
+};
+use crate::vesting::{query_user_vesting_rewards, release_vested_rewards};
+use crate::weight_basis::{query_asset_weight_basis, set_asset_weight_basis};
 use common::cw::{Context, QueryContext};
 use cosmwasm_std::{
     entry_point, from_json, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply,
@@ -36,6 +57,11 @@ pub fn instantiate(
     let enterprise_contract = deps.api.addr_validate(&msg.enterprise_contract)?;
     ENTERPRISE_CONTRACT.save(deps.storage, &enterprise_contract)?;
 
+    if let Some(weight_oracle) = msg.weight_oracle {
+        let weight_oracle = deps.api.addr_validate(&weight_oracle)?;
+        WEIGHT_ORACLE.save(deps.storage, &weight_oracle)?;
+    }
+
     let minimum_eligible_weight = msg.minimum_eligible_weight.unwrap_or_default();
     MINIMUM_ELIGIBLE_WEIGHT.save(deps.storage, &minimum_eligible_weight)?;
 
@@ -53,6 +79,9 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> DistributorResult<Response> {
+    // release vested rewards before anything can read global indices or change weights
+    release_vested_rewards(deps.storage, env.block.time)?;
+
     let ctx = &mut Context { deps, env, info };
     match msg {
         ExecuteMsg::UpdateUserWeights(msg) => update_user_weights(ctx, msg),
@@ -61,7 +90,23 @@ pub fn execute(
         }
         ExecuteMsg::DistributeNative {} => distribute_native(ctx),
         ExecuteMsg::ClaimRewards(msg) => claim_rewards(ctx, msg),
+        ExecuteMsg::ClaimRewardsWithPermit(msg) => claim_rewards_with_permit(ctx, msg),
+        ExecuteMsg::RollEpoch {} => roll_epoch(ctx),
         ExecuteMsg::Receive(msg) => receive_cw20(ctx, msg),
+        ExecuteMsg::RegisterRewardAsset(msg) => register_reward_asset(ctx, msg),
+        ExecuteMsg::UpdateConfig(msg) => update_config(ctx, msg),
+        ExecuteMsg::UpdateWeightOracle(msg) => update_weight_oracle(ctx, msg),
+        ExecuteMsg::ReconcileUser(msg) => reconcile_user(ctx, msg),
+        ExecuteMsg::SetAssetWeightBasis(msg) => set_asset_weight_basis(ctx, msg),
+        ExecuteMsg::PruneDistributions(msg) => prune_distributions(ctx, msg),
+        ExecuteMsg::SetFeeOnTransferAsset(msg) => set_fee_on_transfer_asset(ctx, msg),
+        ExecuteMsg::SetAssetClaimPeriod(msg) => set_asset_claim_period(ctx, msg),
+        ExecuteMsg::SweepExpiredRewards { asset } => sweep_expired_rewards(ctx, asset),
+        ExecuteMsg::SetAssetIndexPrecision(msg) => set_asset_index_precision(ctx, msg),
+        ExecuteMsg::OptOutOfRewards { opted_out } => opt_out_of_rewards(ctx, opted_out),
+        ExecuteMsg::SetClaimsPaused { paused } => set_claims_paused(ctx, paused),
+        ExecuteMsg::SetEmissionSchedule(msg) => set_emission_schedule(ctx, msg),
+        ExecuteMsg::PokeEmissions { asset } => poke_emissions(ctx, asset),
     }
 }
 
@@ -83,9 +128,38 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> DistributorResult<Binary> {
 
     let response = match msg {
         QueryMsg::UserRewards(params) => to_json_binary(&query_user_rewards(qctx, params)?)?,
+        QueryMsg::MultiUserRewards(params) => {
+            to_json_binary(&query_multi_user_rewards(qctx, params)?)?
+        }
         QueryMsg::MinimumEligibleWeight {} => {
             to_json_binary(&query_minimum_eligible_weight(qctx)?)?
         }
+        QueryMsg::TotalEffectiveWeight {} => to_json_binary(&query_total_effective_weight(qctx)?)?,
+        QueryMsg::ClaimHistory(params) => to_json_binary(&query_claim_history(qctx, params)?)?,
+        QueryMsg::UserClaimTimes(params) => to_json_binary(&query_user_claim_times(qctx, params)?)?,
+        QueryMsg::ClaimPreview(params) => to_json_binary(&query_claim_preview(qctx, params)?)?,
+        QueryMsg::DistributionFee {} => to_json_binary(&query_distribution_fee(qctx)?)?,
+        QueryMsg::AssetWeightBasis(params) => {
+            to_json_binary(&query_asset_weight_basis(qctx, params)?)?
+        }
+        QueryMsg::OutstandingRewardsByUser(params) => {
+            to_json_binary(&query_outstanding_rewards_by_user(qctx, params)?)?
+        }
+        QueryMsg::UserVestingRewards(params) => {
+            to_json_binary(&query_user_vesting_rewards(qctx, params)?)?
+        }
+        QueryMsg::ClaimPermitNonce(params) => {
+            to_json_binary(&query_claim_permit_nonce(qctx, params)?)?
+        }
+        QueryMsg::Epochs(params) => to_json_binary(&query_epochs(qctx, params)?)?,
+        QueryMsg::RewardsOptOut(params) => to_json_binary(&query_rewards_opt_out(qctx, params)?)?,
+        QueryMsg::ClaimsPaused {} => to_json_binary(&query_claims_paused(qctx)?)?,
+        QueryMsg::DistributionRate(params) => {
+            to_json_binary(&query_distribution_rate(qctx, params)?)?
+        }
+        QueryMsg::EmissionSchedule(params) => {
+            to_json_binary(&query_emission_schedule(qctx, params)?)?
+        }
     };
     Ok(response)
 }
@@ -94,5 +168,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> DistributorResult<Binary> {
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> DistributorResult<Response> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    // eligible users were not counted before, so count them once for existing contracts
+    if !ELIGIBLE_USERS_COUNT.exists(deps.storage) {
+        let eligible_users = count_eligible_users(deps.storage)?;
+        ELIGIBLE_USERS_COUNT.save(deps.storage, &eligible_users)?;
+    }
+
     Ok(Response::new().add_attribute("action", "migrate"))
 }
@@ -1,15 +1,26 @@
+use crate::claim_deadlines::{has_claim_period, record_expiring_distribution};
+use crate::distribution_rates::record_rate_distribution;
+use crate::epochs::record_epoch_distribution;
+use crate::fee_on_transfer::{received_cw20_amount, record_cw20_inflow};
+use crate::fees::deduct_distribution_fee;
+use crate::index_precision::index_increment;
+use crate::rate_limit::{cw20_asset_key, native_asset_key, record_distribution};
 use crate::state::{CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
 use crate::state::{EFFECTIVE_TOTAL_WEIGHT, ENTERPRISE_CONTRACT};
+use crate::vesting::{add_vesting_distribution, DISTRIBUTION_MODE};
+use crate::weight_basis::asset_total_weight;
 use common::cw::Context;
-use cosmwasm_std::{Decimal, Response, Uint128};
-use cw20::Cw20ReceiveMsg;
+use cosmwasm_std::{coins, wasm_execute, Addr, BankMsg, Decimal256, Response, Uint128, WasmMsg};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 use cw_asset::AssetInfo;
 use enterprise_protocol::api::ComponentContractsResponse;
 use enterprise_protocol::msg::QueryMsg::ComponentContracts;
 use enterprise_treasury_api::api::{AssetWhitelistParams, AssetWhitelistResponse};
 use enterprise_treasury_api::msg::QueryMsg::AssetWhitelist;
+use funds_distributor_api::api::DistributionMode::{Immediate, Vesting};
+use funds_distributor_api::api::RewardAsset;
 use funds_distributor_api::error::DistributorError::{
-    DistributingNonWhitelistedAsset, ZeroTotalWeight,
+    ClaimPeriodInVestingMode, DistributingNonWhitelistedAsset, ZeroTotalWeight,
 };
 use funds_distributor_api::error::DistributorResult;
 use funds_distributor_api::response::{
@@ -18,7 +29,12 @@ use funds_distributor_api::response::{
 use std::ops::Not;
 
 /// Distributes new rewards for a native asset, using funds found in MessageInfo.
-/// Will increase global index for each of the assets being distributed.
+/// If a distribution fee is configured, it is deducted and sent to the fee recipient first.
+/// Will increase global index for each of the assets being distributed, or start vesting them
+/// if the distributor is in vesting mode.
+/// Each asset is spread over the total weight of its weight basis.
+/// Distributions of assets with a claim period are recorded, so that their unclaimed rewards
+/// can be swept once the claim period has passed.
 pub fn distribute_native(ctx: &mut Context) -> DistributorResult<Response> {
     let funds = ctx.info.funds.clone();
 
@@ -29,60 +45,195 @@ pub fn distribute_native(ctx: &mut Context) -> DistributorResult<Response> {
     assert_assets_whitelisted(ctx, distribution_assets)?;
 
     let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(ctx.deps.storage)?;
-    if total_weight == Uint128::zero() {
-        return Err(ZeroTotalWeight);
-    }
+
+    let distribution_mode = DISTRIBUTION_MODE
+        .may_load(ctx.deps.storage)?
+        .unwrap_or(Immediate);
+
+    let mut fee_msgs: Vec<BankMsg> = vec![];
 
     for fund in funds {
+        let asset_key = native_asset_key(&fund.denom);
+
+        let asset_total_weight = asset_total_weight(ctx.deps.storage, asset_key.clone())?;
+        if asset_total_weight.is_zero() {
+            return Err(ZeroTotalWeight);
+        }
+
+        record_distribution(ctx.deps.storage, asset_key.clone(), ctx.env.block.time)?;
+
+        let (fee, amount) = deduct_distribution_fee(ctx.deps.storage, fund.amount)?;
+        if let Some((recipient, fee_amount)) = fee {
+            fee_msgs.push(BankMsg::Send {
+                to_address: recipient,
+                amount: coins(fee_amount.u128(), fund.denom.clone()),
+            });
+        }
+
+        record_epoch_distribution(
+            ctx.deps.storage,
+            &RewardAsset::Native {
+                denom: fund.denom.clone(),
+            },
+            amount,
+        )?;
+        record_rate_distribution(ctx.deps.storage, &asset_key, ctx.env.block.time, amount)?;
+
         let global_index = NATIVE_GLOBAL_INDICES
             .may_load(ctx.deps.storage, fund.denom.clone())?
-            .unwrap_or(Decimal::zero());
+            .unwrap_or(Decimal256::zero());
 
         // calculate how many units of the asset we're distributing per unit of total user weight
         // and add that to the global index for the asset
-        let index_increment = Decimal::from_ratio(fund.amount, total_weight);
+        let index_increment = match distribution_mode {
+            Immediate => index_increment(ctx.deps.storage, &asset_key, amount, asset_total_weight)?,
+            Vesting { duration } => {
+                if has_claim_period(ctx.deps.storage, &asset_key) {
+                    return Err(ClaimPeriodInVestingMode);
+                }
+                add_vesting_distribution(
+                    ctx.deps.storage,
+                    RewardAsset::Native {
+                        denom: fund.denom.clone(),
+                    },
+                    amount,
+                    ctx.env.block.time,
+                    duration,
+                )?;
+                // vested rewards are added to the index as they vest
+                Decimal256::zero()
+            }
+        };
+
+        let global_index = global_index.checked_add(index_increment)?;
 
-        NATIVE_GLOBAL_INDICES.save(
+        NATIVE_GLOBAL_INDICES.save(ctx.deps.storage, fund.denom, &global_index)?;
+
+        record_expiring_distribution(
             ctx.deps.storage,
-            fund.denom,
-            &global_index.checked_add(index_increment)?,
+            &asset_key,
+            amount,
+            global_index,
+            ctx.env.block.time,
         )?;
     }
 
-    Ok(execute_distribute_native_response(total_weight))
+    Ok(execute_distribute_native_response(total_weight).add_messages(fee_msgs))
 }
 
 /// Distributes new rewards for a CW20 asset.
-/// Will increase global index for the asset being distributed.
+/// For tokens taking a fee on transfers, only the amount actually received is distributed.
+/// If a distribution fee is configured, it is deducted and sent to the fee recipient first.
+/// Will increase global index for the asset being distributed, or start vesting it
+/// if the distributor is in vesting mode.
+/// The asset is spread over the total weight of its weight basis.
+/// Distributions of assets with a claim period are recorded, so that their unclaimed rewards
+/// can be swept once the claim period has passed.
 pub fn distribute_cw20(ctx: &mut Context, cw20_msg: Cw20ReceiveMsg) -> DistributorResult<Response> {
     let cw20_addr = ctx.info.sender.clone();
 
+    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(ctx.deps.storage)?;
+
+    let received_amount = received_cw20_amount(
+        ctx.deps.as_ref(),
+        &cw20_addr,
+        &ctx.env.contract.address,
+        cw20_msg.amount,
+    )?;
+
+    let (amount, fee_msg) = distribute_received_cw20(ctx, cw20_addr.clone(), received_amount)?;
+
+    Ok(
+        cw20_hook_distribute_cw20_response(total_weight, cw20_addr.to_string(), amount)
+            .add_messages(fee_msg),
+    )
+}
+
+/// Distributes an amount of a CW20 asset that the contract has received, or is about to receive
+/// within the same transaction.
+/// Returns the amount distributed after the distribution fee, and the message sending the fee.
+pub fn distribute_received_cw20(
+    ctx: &mut Context,
+    cw20_addr: Addr,
+    received_amount: Uint128,
+) -> DistributorResult<(Uint128, Option<WasmMsg>)> {
     assert_assets_whitelisted(ctx, vec![AssetInfo::cw20(cw20_addr.clone())])?;
 
-    let total_weight = EFFECTIVE_TOTAL_WEIGHT.load(ctx.deps.storage)?;
-    if total_weight == Uint128::zero() {
+    let asset_key = cw20_asset_key(cw20_addr.as_ref());
+
+    let asset_total_weight = asset_total_weight(ctx.deps.storage, asset_key.clone())?;
+    if asset_total_weight.is_zero() {
         return Err(ZeroTotalWeight);
     }
 
+    record_distribution(ctx.deps.storage, asset_key.clone(), ctx.env.block.time)?;
+
+    let (fee, amount) = deduct_distribution_fee(ctx.deps.storage, received_amount)?;
+    record_cw20_inflow(ctx.deps.storage, cw20_addr.clone(), amount)?;
+    record_epoch_distribution(
+        ctx.deps.storage,
+        &RewardAsset::Cw20 {
+            asset: cw20_addr.to_string(),
+        },
+        amount,
+    )?;
+    record_rate_distribution(ctx.deps.storage, &asset_key, ctx.env.block.time, amount)?;
+
+    let fee_msg = fee
+        .map(|(recipient, fee_amount)| {
+            wasm_execute(
+                cw20_addr.to_string(),
+                &Cw20ExecuteMsg::Transfer {
+                    recipient,
+                    amount: fee_amount,
+                },
+                vec![],
+            )
+        })
+        .transpose()?;
+
     let global_index = CW20_GLOBAL_INDICES
         .may_load(ctx.deps.storage, cw20_addr.clone())?
-        .unwrap_or(Decimal::zero());
+        .unwrap_or(Decimal256::zero());
 
     // calculate how many units of the asset we're distributing per unit of total user weight
     // and add that to the global index for the asset
-    let global_index_increment = Decimal::from_ratio(cw20_msg.amount, total_weight);
+    let global_index_increment = match DISTRIBUTION_MODE
+        .may_load(ctx.deps.storage)?
+        .unwrap_or(Immediate)
+    {
+        Immediate => index_increment(ctx.deps.storage, &asset_key, amount, asset_total_weight)?,
+        Vesting { duration } => {
+            if has_claim_period(ctx.deps.storage, &asset_key) {
+                return Err(ClaimPeriodInVestingMode);
+            }
+            add_vesting_distribution(
+                ctx.deps.storage,
+                RewardAsset::Cw20 {
+                    asset: cw20_addr.to_string(),
+                },
+                amount,
+                ctx.env.block.time,
+                duration,
+            )?;
+            // vested rewards are added to the index as they vest
+            Decimal256::zero()
+        }
+    };
 
-    CW20_GLOBAL_INDICES.save(
+    let global_index = global_index.checked_add(global_index_increment)?;
+
+    CW20_GLOBAL_INDICES.save(ctx.deps.storage, cw20_addr, &global_index)?;
+
+    record_expiring_distribution(
         ctx.deps.storage,
-        cw20_addr.clone(),
-        &global_index.checked_add(global_index_increment)?,
+        &asset_key,
+        amount,
+        global_index,
+        ctx.env.block.time,
     )?;
 
-    Ok(cw20_hook_distribute_cw20_response(
-        total_weight,
-        cw20_addr.to_string(),
-        cw20_msg.amount,
-    ))
+    Ok((amount, fee_msg))
 }
 
 fn assert_assets_whitelisted(ctx: &Context, mut assets: Vec<AssetInfo>) -> DistributorResult<()> {
@@ -145,7 +296,7 @@ fn assert_assets_whitelisted(ctx: &Context, mut assets: Vec<AssetInfo>) -> Distr
     }
 }
 
-fn query_enterprise_components(ctx: &Context) -> DistributorResult<ComponentContractsResponse> {
+pub fn query_enterprise_components(ctx: &Context) -> DistributorResult<ComponentContractsResponse> {
     let enterprise_contract = ENTERPRISE_CONTRACT.load(ctx.deps.storage)?;
 
     let component_contracts: ComponentContractsResponse = ctx
@@ -0,0 +1,114 @@
+use crate::rate_limit::{cw20_asset_key, native_asset_key};
+use crate::weight_basis::asset_total_weight;
+use common::cw::QueryContext;
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Decimal256, StdResult, Storage, Timestamp, Uint128, Uint256};
+use cw_storage_plus::{Bound, Map};
+use funds_distributor_api::api::{DistributionRateParams, DistributionRateResponse, RewardAsset};
+use funds_distributor_api::error::DistributorResult;
+
+/// Length of the time buckets that distributed amounts are summed in.
+const BUCKET_SECONDS: u64 = 24 * 60 * 60;
+
+/// Number of buckets kept for each asset. Older buckets are removed as new distributions come in.
+const RETAINED_BUCKETS: u64 = 365;
+
+const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// Total amounts distributed of each asset, keyed by the asset's key and the bucket's index.
+/// Buckets in which the asset was not distributed have no entry.
+const DISTRIBUTION_BUCKETS: Map<(String, u64), Uint128> = Map::new("distribution_buckets");
+
+fn bucket_of(time: Timestamp) -> u64 {
+    time.seconds() / BUCKET_SECONDS
+}
+
+/// Adds the distributed amount to the asset's bucket for the current time, and removes
+/// the asset's buckets that are no longer retained.
+pub fn record_rate_distribution(
+    storage: &mut dyn Storage,
+    asset_key: &str,
+    now: Timestamp,
+    amount: Uint128,
+) -> DistributorResult<()> {
+    let bucket = bucket_of(now);
+
+    let key = (asset_key.to_string(), bucket);
+    let total = DISTRIBUTION_BUCKETS
+        .may_load(storage, key.clone())?
+        .unwrap_or_default();
+    DISTRIBUTION_BUCKETS.save(storage, key, &total.checked_add(amount)?)?;
+
+    let first_retained_bucket = bucket.saturating_sub(RETAINED_BUCKETS - 1);
+    let expired_buckets = DISTRIBUTION_BUCKETS
+        .prefix(asset_key.to_string())
+        .keys(
+            storage,
+            None,
+            Some(Bound::exclusive(first_retained_bucket)),
+            Ascending,
+        )
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    for expired_bucket in expired_buckets {
+        DISTRIBUTION_BUCKETS.remove(storage, (asset_key.to_string(), expired_bucket));
+    }
+
+    Ok(())
+}
+
+/// Sums up the asset's distributions over the trailing window, and annualizes them relative
+/// to the total weight the asset is currently distributed over.
+/// Distributions are summed in whole buckets, so the window is rounded up to start at
+/// the beginning of its first bucket, and cannot reach further back than the retained buckets.
+pub fn query_distribution_rate(
+    qctx: QueryContext,
+    params: DistributionRateParams,
+) -> DistributorResult<DistributionRateResponse> {
+    let asset_key = match params.asset {
+        RewardAsset::Native { denom } => native_asset_key(&denom),
+        RewardAsset::Cw20 { asset } => {
+            cw20_asset_key(qctx.deps.api.addr_validate(&asset)?.as_ref())
+        }
+    };
+
+    let window = params.window.min(RETAINED_BUCKETS * BUCKET_SECONDS);
+
+    let now = qctx.env.block.time;
+    let first_bucket = bucket_of(Timestamp::from_seconds(
+        now.seconds().saturating_sub(window),
+    ));
+
+    let distributed = DISTRIBUTION_BUCKETS
+        .prefix(asset_key.clone())
+        .range(
+            qctx.deps.storage,
+            Some(Bound::inclusive(first_bucket)),
+            None,
+            Ascending,
+        )
+        .try_fold(
+            Uint128::zero(),
+            |total, res| -> DistributorResult<Uint128> {
+                let (_, amount) = res?;
+                Ok(total.checked_add(amount)?)
+            },
+        )?;
+
+    let total_weight = asset_total_weight(qctx.deps.storage, asset_key)?;
+
+    let annualized_rate = if window == 0 || total_weight.is_zero() {
+        Decimal256::zero()
+    } else {
+        Decimal256::from_ratio(
+            Uint256::from(distributed).checked_mul(Uint256::from(SECONDS_PER_YEAR))?,
+            Uint256::from(window).checked_mul(Uint256::from(total_weight))?,
+        )
+    };
+
+    Ok(DistributionRateResponse {
+        window,
+        distributed,
+        annualized_rate,
+    })
+}
@@ -1,9 +1,10 @@
 use crate::cw20_distributions::update_user_cw20_distributions;
 use crate::native_distributions::update_user_native_distributions;
-use crate::state::{ADMIN, EFFECTIVE_TOTAL_WEIGHT};
-use crate::user_weights::{EFFECTIVE_USER_WEIGHTS, USER_WEIGHTS};
+use crate::opt_out::is_opted_out;
+use crate::state::{ADMIN, EFFECTIVE_TOTAL_WEIGHT, ELIGIBLE_USERS_COUNT};
+use crate::user_weights::{replace_effective_weight, EFFECTIVE_USER_WEIGHTS, USER_WEIGHTS};
 use common::cw::{Context, QueryContext};
-use cosmwasm_std::{Addr, DepsMut, Order, Response, StdResult, Uint128};
+use cosmwasm_std::{Addr, DepsMut, Order, Response, StdResult, Storage, Uint128};
 use cw_storage_plus::Item;
 use funds_distributor_api::api::{MinimumEligibleWeightResponse, UpdateMinimumEligibleWeightMsg};
 use funds_distributor_api::error::DistributorError::Unauthorized;
@@ -70,7 +71,8 @@ pub fn update_minimum_eligible_weight(
         .collect::<StdResult<Vec<(Addr, Uint128)>>>()?
         .into_iter()
         .filter_map(|(user, weight)| {
-            if weight_range.contains(&weight) {
+            // users opted out of rewards have no effective weight regardless of the minimum
+            if weight_range.contains(&weight) && !is_opted_out(deps.storage, user.clone()) {
                 Some((user, weight))
             } else {
                 None
@@ -103,9 +105,14 @@ pub fn update_minimum_eligible_weight(
         // change user's effective weight to account for the change in effective weight
         EFFECTIVE_USER_WEIGHTS.save(deps.storage, user, &new_effective_weight)?;
 
+        update_eligible_users_count(deps.storage, old_effective_weight, new_effective_weight)?;
+
         // update total weight
-        effective_total_weight =
-            effective_total_weight - old_effective_weight + new_effective_weight;
+        effective_total_weight = replace_effective_weight(
+            effective_total_weight,
+            old_effective_weight,
+            new_effective_weight,
+        )?;
     }
 
     MINIMUM_ELIGIBLE_WEIGHT.save(deps.storage, &new_minimum_weight)?;
@@ -115,12 +122,55 @@ pub fn update_minimum_eligible_weight(
     Ok(())
 }
 
+/// Updates the count of eligible users to reflect a user's effective weight changing
+/// from the old to the new one.
+pub fn update_eligible_users_count(
+    storage: &mut dyn Storage,
+    old_effective_weight: Uint128,
+    new_effective_weight: Uint128,
+) -> DistributorResult<()> {
+    let eligible_users = ELIGIBLE_USERS_COUNT.may_load(storage)?.unwrap_or_default();
+
+    let eligible_users = match (
+        old_effective_weight.is_zero(),
+        new_effective_weight.is_zero(),
+    ) {
+        (true, false) => eligible_users + 1,
+        (false, true) => eligible_users.saturating_sub(1),
+        _ => eligible_users,
+    };
+
+    ELIGIBLE_USERS_COUNT.save(storage, &eligible_users)?;
+
+    Ok(())
+}
+
+/// Counts eligible users by going through all the users' effective weights.
+/// Only meant for initializing the count, as it iterates over all users.
+pub fn count_eligible_users(storage: &dyn Storage) -> DistributorResult<u64> {
+    let eligible_users = EFFECTIVE_USER_WEIGHTS
+        .range(storage, None, None, Order::Ascending)
+        .try_fold(0u64, |count, res| {
+            res.map(|(_, weight)| if weight.is_zero() { count } else { count + 1 })
+        })?;
+
+    Ok(eligible_users)
+}
+
 pub fn query_minimum_eligible_weight(
     qctx: QueryContext,
 ) -> DistributorResult<MinimumEligibleWeightResponse> {
     let minimum_eligible_weight = MINIMUM_ELIGIBLE_WEIGHT.load(qctx.deps.storage)?;
+    let eligible_users = ELIGIBLE_USERS_COUNT
+        .may_load(qctx.deps.storage)?
+        .unwrap_or_default();
+    let total_eligible_weight = EFFECTIVE_TOTAL_WEIGHT
+        .may_load(qctx.deps.storage)?
+        .unwrap_or_default();
 
     Ok(MinimumEligibleWeightResponse {
         minimum_eligible_weight,
+        eligible_users,
+        total_eligible_weight,
     })
 }
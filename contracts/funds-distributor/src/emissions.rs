@@ -0,0 +1,252 @@
+use crate::distributing::{distribute_received_cw20, query_enterprise_components};
+use crate::fee_on_transfer::is_fee_on_transfer_asset;
+use crate::state::ADMIN;
+use common::cw::{Context, QueryContext};
+use cosmwasm_std::{wasm_execute, Addr, Response, Timestamp, Uint128};
+use cw20::{
+    AllowanceResponse, BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse,
+    TokenInfoResponse,
+};
+use cw_storage_plus::Map;
+use funds_distributor_api::api::{
+    EmissionSchedule, EmissionScheduleParams, EmissionScheduleResponse, EmissionSource,
+    SetEmissionScheduleMsg,
+};
+use funds_distributor_api::error::DistributorError::{
+    DistributorNotMinter, FeeOnTransferEmission, InsufficientEmissionFunding,
+    InvalidEmissionSchedule, NoEmissionSchedule, NoEmissionsAccrued, Unauthorized,
+};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::{
+    execute_poke_emissions_response, execute_set_emission_schedule_response,
+};
+
+/// Emission schedules of CW20 tokens, keyed by the token's address.
+/// Native assets can neither be minted by the distributor nor pulled from the treasury,
+/// so only CW20 tokens can be emitted.
+const EMISSION_SCHEDULES: Map<Addr, EmissionSchedule> = Map::new("emission_schedules");
+
+/// Sets the token's emission schedule, starting now, or removes it. Only the admin can do this.
+/// The schedule's source has to be able to cover all of its emissions up front.
+/// Emissions accrued under a replaced schedule and not yet poked are not emitted.
+/// Tokens taking a fee on transfers cannot be emitted.
+pub fn set_emission_schedule(
+    ctx: &mut Context,
+    msg: SetEmissionScheduleMsg,
+) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let asset = ctx.deps.api.addr_validate(&msg.asset)?;
+
+    let scheduled = match msg.schedule {
+        Some(schedule_msg) => {
+            if is_fee_on_transfer_asset(ctx.deps.storage, asset.clone()) {
+                return Err(FeeOnTransferEmission);
+            }
+
+            let now = ctx.env.block.time;
+            let schedule = EmissionSchedule {
+                amount_per_period: schedule_msg.amount_per_period,
+                period: schedule_msg.period,
+                periods: schedule_msg.periods,
+                source: schedule_msg.source,
+                start: now,
+                last_poke: now,
+                emitted: Uint128::zero(),
+            };
+
+            if schedule.amount_per_period.is_zero()
+                || schedule.period == 0
+                || schedule.periods == 0
+                || schedule.period.checked_mul(schedule.periods).is_none()
+            {
+                return Err(InvalidEmissionSchedule);
+            }
+
+            let scheduled = total_emission(&schedule)?;
+            assert_emission_source_covers(ctx, &asset, &schedule.source, scheduled)?;
+
+            EMISSION_SCHEDULES.save(ctx.deps.storage, asset.clone(), &schedule)?;
+
+            scheduled
+        }
+        None => {
+            EMISSION_SCHEDULES.remove(ctx.deps.storage, asset.clone());
+            Uint128::zero()
+        }
+    };
+
+    Ok(execute_set_emission_schedule_response(
+        asset.to_string(),
+        scheduled,
+    ))
+}
+
+/// Mints or pulls the token's emissions accrued since the last poke, and distributes them
+/// through the token's global index. Anyone can do this.
+///
+/// The accrued amount is distributed as it is minted or pulled, before it arrives, so the token
+/// must not take a fee on transfers - the poke fails if it was marked as such after the schedule
+/// was set.
+pub fn poke_emissions(ctx: &mut Context, asset: String) -> DistributorResult<Response> {
+    let asset = ctx.deps.api.addr_validate(&asset)?;
+
+    if is_fee_on_transfer_asset(ctx.deps.storage, asset.clone()) {
+        return Err(FeeOnTransferEmission);
+    }
+
+    let mut schedule = EMISSION_SCHEDULES
+        .may_load(ctx.deps.storage, asset.clone())?
+        .ok_or(NoEmissionSchedule)?;
+
+    let now = ctx.env.block.time;
+
+    let accrued = accrued_emission(&schedule, now)?;
+    if accrued.is_zero() {
+        return Err(NoEmissionsAccrued);
+    }
+
+    schedule.emitted = schedule.emitted.checked_add(accrued)?;
+    schedule.last_poke = now;
+    EMISSION_SCHEDULES.save(ctx.deps.storage, asset.clone(), &schedule)?;
+
+    let contract = ctx.env.contract.address.to_string();
+    let funding_msg = match schedule.source {
+        EmissionSource::Mint => Cw20ExecuteMsg::Mint {
+            recipient: contract,
+            amount: accrued,
+        },
+        EmissionSource::Treasury => Cw20ExecuteMsg::TransferFrom {
+            owner: query_enterprise_components(ctx)?
+                .enterprise_treasury_contract
+                .to_string(),
+            recipient: contract,
+            amount: accrued,
+        },
+    };
+    let funding_msg = wasm_execute(asset.to_string(), &funding_msg, vec![])?;
+
+    // the emitted tokens arrive before the fee is sent out, since messages run in order
+    let (_, fee_msg) = distribute_received_cw20(ctx, asset.clone(), accrued)?;
+
+    Ok(execute_poke_emissions_response(asset.to_string(), accrued)
+        .add_message(funding_msg)
+        .add_messages(fee_msg))
+}
+
+pub fn query_emission_schedule(
+    qctx: QueryContext,
+    params: EmissionScheduleParams,
+) -> DistributorResult<EmissionScheduleResponse> {
+    let asset = qctx.deps.api.addr_validate(&params.asset)?;
+
+    let schedule = EMISSION_SCHEDULES.may_load(qctx.deps.storage, asset)?;
+
+    let (accrued, remaining) = match &schedule {
+        Some(schedule) => (
+            accrued_emission(schedule, qctx.env.block.time)?,
+            total_emission(schedule)?.checked_sub(schedule.emitted)?,
+        ),
+        None => (Uint128::zero(), Uint128::zero()),
+    };
+
+    Ok(EmissionScheduleResponse {
+        schedule,
+        accrued,
+        remaining,
+    })
+}
+
+fn total_emission(schedule: &EmissionSchedule) -> DistributorResult<Uint128> {
+    Ok(schedule
+        .amount_per_period
+        .checked_mul(Uint128::from(schedule.periods))?)
+}
+
+/// Amount emitted linearly since the schedule's start, up to the given time, that was not
+/// emitted yet.
+fn accrued_emission(schedule: &EmissionSchedule, now: Timestamp) -> DistributorResult<Uint128> {
+    let duration = schedule.period * schedule.periods;
+    let elapsed = now
+        .seconds()
+        .saturating_sub(schedule.start.seconds())
+        .min(duration);
+
+    let emitted_by_now = total_emission(schedule)?.checked_multiply_ratio(elapsed, duration)?;
+
+    Ok(emitted_by_now.saturating_sub(schedule.emitted))
+}
+
+/// Checks that the source can provide the given amount of the token.
+/// For minting, the distributor has to be the token's minter, with the amount fitting under
+/// the token's cap. For the treasury, both the treasury's balance and its unexpired allowance
+/// to the distributor have to cover the amount.
+fn assert_emission_source_covers(
+    ctx: &Context,
+    asset: &Addr,
+    source: &EmissionSource,
+    required: Uint128,
+) -> DistributorResult<()> {
+    let available = match source {
+        EmissionSource::Mint => {
+            let minter: Option<MinterResponse> = ctx
+                .deps
+                .querier
+                .query_wasm_smart(asset.to_string(), &Cw20QueryMsg::Minter {})?;
+
+            let minter = minter.ok_or(DistributorNotMinter)?;
+            if minter.minter != ctx.env.contract.address {
+                return Err(DistributorNotMinter);
+            }
+
+            match minter.cap {
+                None => Uint128::MAX,
+                Some(cap) => {
+                    let token_info: TokenInfoResponse = ctx
+                        .deps
+                        .querier
+                        .query_wasm_smart(asset.to_string(), &Cw20QueryMsg::TokenInfo {})?;
+                    cap.saturating_sub(token_info.total_supply)
+                }
+            }
+        }
+        EmissionSource::Treasury => {
+            let treasury = query_enterprise_components(ctx)?
+                .enterprise_treasury_contract
+                .to_string();
+
+            let allowance: AllowanceResponse = ctx.deps.querier.query_wasm_smart(
+                asset.to_string(),
+                &Cw20QueryMsg::Allowance {
+                    owner: treasury.clone(),
+                    spender: ctx.env.contract.address.to_string(),
+                },
+            )?;
+            let allowance = if allowance.expires.is_expired(&ctx.env.block) {
+                Uint128::zero()
+            } else {
+                allowance.allowance
+            };
+
+            let balance: BalanceResponse = ctx.deps.querier.query_wasm_smart(
+                asset.to_string(),
+                &Cw20QueryMsg::Balance { address: treasury },
+            )?;
+
+            allowance.min(balance.balance)
+        }
+    };
+
+    if available < required {
+        return Err(InsufficientEmissionFunding {
+            required,
+            available,
+        });
+    }
+
+    Ok(())
+}
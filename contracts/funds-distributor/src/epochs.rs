@@ -0,0 +1,130 @@
+use crate::state::ADMIN;
+use common::cw::{Context, QueryContext};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Response, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use funds_distributor_api::api::{
+    EpochDistributedAmount, EpochInfo, EpochsParams, EpochsResponse, RewardAsset,
+};
+use funds_distributor_api::error::DistributorError::Unauthorized;
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::execute_roll_epoch_response;
+
+const DEFAULT_QUERY_LIMIT: u32 = 10;
+const MAX_QUERY_LIMIT: u32 = 30;
+
+/// Epoch that distributions are currently tagged with. Starts at 0.
+const CURRENT_EPOCH: Item<u64> = Item::new("current_epoch");
+
+/// Times at which epochs were rolled to. The initial epoch has no entry.
+const EPOCH_STARTS: Map<u64, Timestamp> = Map::new("epoch_starts");
+
+/// Total amounts of native assets distributed in each epoch.
+const EPOCH_NATIVE_DISTRIBUTED: Map<(u64, String), Uint128> = Map::new("epoch_native_distributed");
+
+/// Total amounts of CW20 assets distributed in each epoch.
+const EPOCH_CW20_DISTRIBUTED: Map<(u64, Addr), Uint128> = Map::new("epoch_cw20_distributed");
+
+/// Starts a new epoch. Only the admin can do this.
+/// Epochs are only used for reporting, and do not affect the reward calculations.
+pub fn roll_epoch(ctx: &mut Context) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let new_epoch = current_epoch(ctx.deps.storage)? + 1;
+
+    CURRENT_EPOCH.save(ctx.deps.storage, &new_epoch)?;
+    EPOCH_STARTS.save(ctx.deps.storage, new_epoch, &ctx.env.block.time)?;
+
+    Ok(execute_roll_epoch_response(new_epoch))
+}
+
+fn current_epoch(storage: &dyn Storage) -> StdResult<u64> {
+    Ok(CURRENT_EPOCH.may_load(storage)?.unwrap_or_default())
+}
+
+/// Adds the distributed amount to the asset's total for the current epoch.
+pub fn record_epoch_distribution(
+    storage: &mut dyn Storage,
+    asset: &RewardAsset,
+    amount: Uint128,
+) -> DistributorResult<()> {
+    let epoch = current_epoch(storage)?;
+
+    match asset {
+        RewardAsset::Native { denom } => {
+            let key = (epoch, denom.clone());
+            let total = EPOCH_NATIVE_DISTRIBUTED
+                .may_load(storage, key.clone())?
+                .unwrap_or_default();
+            EPOCH_NATIVE_DISTRIBUTED.save(storage, key, &total.checked_add(amount)?)?;
+        }
+        RewardAsset::Cw20 { asset } => {
+            let key = (epoch, Addr::unchecked(asset));
+            let total = EPOCH_CW20_DISTRIBUTED
+                .may_load(storage, key.clone())?
+                .unwrap_or_default();
+            EPOCH_CW20_DISTRIBUTED.save(storage, key, &total.checked_add(amount)?)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn query_epochs(qctx: QueryContext, params: EpochsParams) -> DistributorResult<EpochsResponse> {
+    let storage = qctx.deps.storage;
+
+    let current_epoch = current_epoch(storage)?;
+
+    let first_epoch = params
+        .start_after
+        .map(|epoch| epoch + 1)
+        .unwrap_or_default();
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT) as usize;
+
+    let mut epochs = vec![];
+
+    for epoch in (first_epoch..=current_epoch).take(limit) {
+        let mut distributed: Vec<EpochDistributedAmount> = EPOCH_NATIVE_DISTRIBUTED
+            .prefix(epoch)
+            .range(storage, None, None, Ascending)
+            .map(|res| {
+                res.map(|(denom, amount)| EpochDistributedAmount {
+                    asset: RewardAsset::Native { denom },
+                    amount,
+                })
+            })
+            .collect::<StdResult<Vec<EpochDistributedAmount>>>()?;
+
+        for res in EPOCH_CW20_DISTRIBUTED
+            .prefix(epoch)
+            .range(storage, None, None, Ascending)
+        {
+            let (asset, amount) = res?;
+            distributed.push(EpochDistributedAmount {
+                asset: RewardAsset::Cw20 {
+                    asset: asset.to_string(),
+                },
+                amount,
+            });
+        }
+
+        epochs.push(EpochInfo {
+            epoch,
+            started_at: EPOCH_STARTS.may_load(storage, epoch)?,
+            ended_at: EPOCH_STARTS.may_load(storage, epoch + 1)?,
+            distributed,
+        });
+    }
+
+    Ok(EpochsResponse {
+        current_epoch,
+        epochs,
+    })
+}
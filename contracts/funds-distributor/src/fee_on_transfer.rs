@@ -0,0 +1,104 @@
+use crate::state::ADMIN;
+use common::cw::Context;
+use cosmwasm_std::{Addr, Deps, Response, Storage, Uint128};
+use cw20::{BalanceResponse, Cw20QueryMsg};
+use cw_storage_plus::Map;
+use funds_distributor_api::api::SetFeeOnTransferAssetMsg;
+use funds_distributor_api::error::DistributorError::Unauthorized;
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::execute_set_fee_on_transfer_asset_response;
+
+/// CW20 assets that take a fee on transfers, mapped to the balance of the asset that this
+/// contract has accounted for - i.e. received through distributions and not yet claimed.
+const FEE_ON_TRANSFER_BALANCES: Map<Addr, Uint128> = Map::new("fee_on_transfer_balances");
+
+/// Marks a CW20 asset as taking a fee on transfers, or unmarks it. Only the admin can do this.
+///
+/// When marked, the asset's current balance is considered accounted for, and further
+/// distributions only index the increase in the balance over the accounted one.
+pub fn set_fee_on_transfer_asset(
+    ctx: &mut Context,
+    msg: SetFeeOnTransferAssetMsg,
+) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let asset = ctx.deps.api.addr_validate(&msg.asset)?;
+
+    if msg.fee_on_transfer {
+        let balance = query_cw20_balance(ctx.deps.as_ref(), &asset, &ctx.env.contract.address)?;
+        FEE_ON_TRANSFER_BALANCES.save(ctx.deps.storage, asset.clone(), &balance)?;
+    } else {
+        FEE_ON_TRANSFER_BALANCES.remove(ctx.deps.storage, asset.clone());
+    }
+
+    Ok(execute_set_fee_on_transfer_asset_response(
+        asset.to_string(),
+        msg.fee_on_transfer,
+    ))
+}
+
+/// Whether the CW20 asset is marked as taking a fee on transfers.
+pub fn is_fee_on_transfer_asset(storage: &dyn Storage, asset: Addr) -> bool {
+    FEE_ON_TRANSFER_BALANCES.has(storage, asset)
+}
+
+/// Amount of a CW20 asset actually received in a distribution.
+///
+/// For assets taking a fee on transfers, this is the increase of the contract's balance over
+/// the accounted balance, capped at the stated amount. For other assets, the stated amount
+/// is returned without querying anything.
+pub fn received_cw20_amount(
+    deps: Deps,
+    asset: &Addr,
+    contract: &Addr,
+    stated_amount: Uint128,
+) -> DistributorResult<Uint128> {
+    match FEE_ON_TRANSFER_BALANCES.may_load(deps.storage, asset.clone())? {
+        None => Ok(stated_amount),
+        Some(accounted_balance) => {
+            let balance = query_cw20_balance(deps, asset, contract)?;
+            Ok(balance.saturating_sub(accounted_balance).min(stated_amount))
+        }
+    }
+}
+
+/// Records that the given amount of a CW20 asset is now held for users' rewards.
+pub fn record_cw20_inflow(
+    storage: &mut dyn Storage,
+    asset: Addr,
+    amount: Uint128,
+) -> DistributorResult<()> {
+    if let Some(accounted_balance) = FEE_ON_TRANSFER_BALANCES.may_load(storage, asset.clone())? {
+        FEE_ON_TRANSFER_BALANCES.save(storage, asset, &accounted_balance.checked_add(amount)?)?;
+    }
+
+    Ok(())
+}
+
+/// Records that the given amount of a CW20 asset was paid out of the contract.
+pub fn record_cw20_outflow(
+    storage: &mut dyn Storage,
+    asset: Addr,
+    amount: Uint128,
+) -> DistributorResult<()> {
+    if let Some(accounted_balance) = FEE_ON_TRANSFER_BALANCES.may_load(storage, asset.clone())? {
+        FEE_ON_TRANSFER_BALANCES.save(storage, asset, &accounted_balance.saturating_sub(amount))?;
+    }
+
+    Ok(())
+}
+
+fn query_cw20_balance(deps: Deps, asset: &Addr, address: &Addr) -> DistributorResult<Uint128> {
+    let response: BalanceResponse = deps.querier.query_wasm_smart(
+        asset.to_string(),
+        &Cw20QueryMsg::Balance {
+            address: address.to_string(),
+        },
+    )?;
+
+    Ok(response.balance)
+}
@@ -0,0 +1,39 @@
+use common::cw::QueryContext;
+use cosmwasm_std::{Storage, Uint128};
+use cw_storage_plus::Item;
+use funds_distributor_api::api::{DistributionFee, DistributionFeeResponse};
+use funds_distributor_api::error::DistributorResult;
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Fee taken from each distribution. If not set, no fee is taken.
+pub const DISTRIBUTION_FEE: Item<DistributionFee> = Item::new("distribution_fee");
+
+/// Splits a distributed amount into the fee and the remainder to be distributed to users.
+/// Returns the fee recipient along with the fee, or None if no fee is to be taken.
+pub fn deduct_distribution_fee(
+    storage: &dyn Storage,
+    amount: Uint128,
+) -> DistributorResult<(Option<(String, Uint128)>, Uint128)> {
+    let fee = match DISTRIBUTION_FEE.may_load(storage)? {
+        Some(fee) if fee.fee_bps > 0 => fee,
+        _ => return Ok((None, amount)),
+    };
+
+    let fee_amount = amount.multiply_ratio(fee.fee_bps, BPS_DENOMINATOR);
+
+    if fee_amount.is_zero() {
+        return Ok((None, amount));
+    }
+
+    Ok((
+        Some((fee.recipient, fee_amount)),
+        amount.checked_sub(fee_amount)?,
+    ))
+}
+
+pub fn query_distribution_fee(qctx: QueryContext) -> DistributorResult<DistributionFeeResponse> {
+    let distribution_fee = DISTRIBUTION_FEE.may_load(qctx.deps.storage)?;
+
+    Ok(DistributionFeeResponse { distribution_fee })
+}
@@ -0,0 +1,87 @@
+use crate::rate_limit::{cw20_asset_key, native_asset_key};
+use crate::state::{ADMIN, CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use common::cw::Context;
+use cosmwasm_std::{Decimal256, Response, Storage, Uint128, Uint256};
+use cw_storage_plus::Map;
+use funds_distributor_api::api::{RewardAsset, SetAssetIndexPrecisionMsg};
+use funds_distributor_api::error::DistributorError::{
+    AssetAlreadyDistributed, IndexPrecisionTooHigh, Unauthorized,
+};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::execute_set_asset_index_precision_response;
+
+/// Maximum number of extra decimals an asset's global index can be kept with.
+/// Keeps the scaled index of even the largest distribution within Decimal256's range.
+const MAX_EXTRA_DECIMALS: u8 = 18;
+
+/// Extra decimals the global indices of reward assets are kept with, keyed by the asset's key.
+/// Assets without an entry have indices with no extra decimals, which is also the case for all
+/// indices stored before index precision could be configured.
+const ASSET_INDEX_PRECISIONS: Map<String, u8> = Map::new("asset_index_precisions");
+
+/// Sets the index precision of an asset. Only the admin can do this, and only before the asset
+/// is first distributed, since the asset's global index is scaled by its precision.
+pub fn set_asset_index_precision(
+    ctx: &mut Context,
+    msg: SetAssetIndexPrecisionMsg,
+) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    if msg.extra_decimals > MAX_EXTRA_DECIMALS {
+        return Err(IndexPrecisionTooHigh {
+            max: MAX_EXTRA_DECIMALS,
+        });
+    }
+
+    let (asset_key, already_distributed) = match msg.asset {
+        RewardAsset::Native { denom } => (
+            native_asset_key(&denom),
+            NATIVE_GLOBAL_INDICES.has(ctx.deps.storage, denom),
+        ),
+        RewardAsset::Cw20 { asset } => {
+            let asset = ctx.deps.api.addr_validate(&asset)?;
+            (
+                cw20_asset_key(asset.as_ref()),
+                CW20_GLOBAL_INDICES.has(ctx.deps.storage, asset),
+            )
+        }
+    };
+
+    if already_distributed {
+        return Err(AssetAlreadyDistributed);
+    }
+
+    ASSET_INDEX_PRECISIONS.save(ctx.deps.storage, asset_key.clone(), &msg.extra_decimals)?;
+
+    Ok(execute_set_asset_index_precision_response(
+        asset_key,
+        msg.extra_decimals,
+    ))
+}
+
+/// Factor the asset's global index is scaled up by, given its extra decimals.
+pub fn asset_index_scale(storage: &dyn Storage, asset_key: &str) -> DistributorResult<Uint256> {
+    let extra_decimals = ASSET_INDEX_PRECISIONS
+        .may_load(storage, asset_key.to_string())?
+        .unwrap_or_default();
+
+    Ok(Uint256::from(10u8).checked_pow(extra_decimals as u32)?)
+}
+
+/// Calculates how much the asset's global index grows when the amount is spread over
+/// the given total weight.
+pub fn index_increment(
+    storage: &dyn Storage,
+    asset_key: &str,
+    amount: Uint128,
+    total_weight: Uint128,
+) -> DistributorResult<Decimal256> {
+    let scale = asset_index_scale(storage, asset_key)?;
+    let scaled_amount = Uint256::from(amount).checked_mul(scale)?;
+
+    Ok(Decimal256::from_ratio(scaled_amount, total_weight))
+}
@@ -1,15 +1,32 @@
 extern crate core;
 
+mod asset_metadata;
 mod claim;
+mod claim_deadlines;
+mod claim_history;
+mod config;
 pub mod contract;
 mod cw20_distributions;
 mod distributing;
+mod distribution_rates;
 mod eligibility;
+mod emissions;
+mod epochs;
+mod fee_on_transfer;
+mod fees;
+mod index_precision;
 mod migration;
 mod native_distributions;
+mod opt_out;
+mod permit;
+mod prune;
+mod rate_limit;
+mod reconcile;
 mod rewards;
 mod state;
 mod user_weights;
+mod vesting;
+mod weight_basis;
 
 #[cfg(test)]
 mod tests;
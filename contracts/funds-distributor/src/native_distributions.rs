@@ -1,8 +1,12 @@
+use crate::claim_deadlines::{record_user_distribution, unexpired_distribution};
+use crate::index_precision::asset_index_scale;
+use crate::rate_limit::native_asset_key;
 use crate::rewards::calculate_user_reward;
 use crate::state::NATIVE_GLOBAL_INDICES;
+use crate::weight_basis::asset_user_weight;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Order::Ascending;
-use cosmwasm_std::{Addr, Decimal, DepsMut, StdResult, Uint128};
+use cosmwasm_std::{Addr, Decimal256, DepsMut, StdResult, Uint128};
 use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
 use funds_distributor_api::error::DistributorResult;
 
@@ -12,7 +16,7 @@ pub struct NativeDistribution {
     pub user: Addr,
     pub denom: String,
     /// The last global index at which the user's pending rewards were calculated
-    pub user_index: Decimal,
+    pub user_index: Decimal256,
     /// User's unclaimed rewards
     pub pending_rewards: Uint128,
 }
@@ -42,7 +46,7 @@ pub fn NATIVE_DISTRIBUTIONS<'a>(
 }
 
 // convenience trait to unify duplicate code between this and CW20 distributions
-impl From<NativeDistribution> for (Decimal, Uint128) {
+impl From<NativeDistribution> for (Decimal256, Uint128) {
     fn from(item: NativeDistribution) -> Self {
         (item.user_index, item.pending_rewards)
     }
@@ -59,13 +63,26 @@ pub fn update_user_native_distributions(
 ) -> DistributorResult<()> {
     let native_global_indices = NATIVE_GLOBAL_INDICES
         .range(deps.storage, None, None, Ascending)
-        .collect::<StdResult<Vec<(String, Decimal)>>>()?;
+        .collect::<StdResult<Vec<(String, Decimal256)>>>()?;
 
     for (denom, global_index) in native_global_indices {
-        let distribution =
-            NATIVE_DISTRIBUTIONS().may_load(deps.storage, (user.clone(), denom.clone()))?;
+        let asset_key = native_asset_key(&denom);
 
-        let reward = calculate_user_reward(global_index, distribution, old_user_weight)?;
+        let distribution = unexpired_distribution(
+            deps.storage,
+            &asset_key,
+            &user,
+            NATIVE_DISTRIBUTIONS()
+                .may_load(deps.storage, (user.clone(), denom.clone()))?
+                .map(<(Decimal256, Uint128)>::from),
+        )?;
+
+        let index_scale = asset_index_scale(deps.storage, &asset_key)?;
+        let asset_user_weight =
+            asset_user_weight(deps.storage, asset_key.clone(), old_user_weight)?;
+
+        let reward =
+            calculate_user_reward(global_index, distribution, asset_user_weight, index_scale)?;
 
         NATIVE_DISTRIBUTIONS().save(
             deps.storage,
@@ -77,6 +94,15 @@ pub fn update_user_native_distributions(
                 pending_rewards: reward,
             },
         )?;
+
+        record_user_distribution(
+            deps.storage,
+            &asset_key,
+            &user,
+            distribution,
+            (global_index, reward),
+            asset_user_weight,
+        )?;
     }
 
     Ok(())
@@ -0,0 +1,80 @@
+use crate::cw20_distributions::update_user_cw20_distributions;
+use crate::eligibility::{update_eligible_users_count, MINIMUM_ELIGIBLE_WEIGHT};
+use crate::native_distributions::update_user_native_distributions;
+use crate::state::EFFECTIVE_TOTAL_WEIGHT;
+use crate::user_weights::{
+    calculate_effective_weight, replace_effective_weight, EFFECTIVE_USER_WEIGHTS, USER_WEIGHTS,
+};
+use common::cw::{Context, QueryContext};
+use cosmwasm_std::{Addr, Response, Storage, Uint128};
+use cw_storage_plus::Map;
+use funds_distributor_api::api::{RewardsOptOutParams, RewardsOptOutResponse};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::execute_opt_out_of_rewards_response;
+
+/// Users that have opted out of rewards. Their effective weight is 0 for as long as they are
+/// opted out, regardless of their actual weight.
+const OPTED_OUT_USERS: Map<Addr, ()> = Map::new("opted_out_users");
+
+/// Opts the sender out of rewards, or back into them.
+/// Rewards accrued up to now are placed into the sender's pending rewards first, so opting out
+/// only affects distributions from now on, and opting back in resumes accruing from now on.
+pub fn opt_out_of_rewards(ctx: &mut Context, opted_out: bool) -> DistributorResult<Response> {
+    let user = ctx.info.sender.clone();
+
+    if opted_out {
+        OPTED_OUT_USERS.save(ctx.deps.storage, user.clone(), &())?;
+    } else {
+        OPTED_OUT_USERS.remove(ctx.deps.storage, user.clone());
+    }
+
+    // users we have not encountered yet have no weight to exclude, and will get their
+    // effective weight once they are given weight
+    if let Some(old_effective_weight) =
+        EFFECTIVE_USER_WEIGHTS.may_load(ctx.deps.storage, user.clone())?
+    {
+        update_user_native_distributions(ctx.deps.branch(), user.clone(), old_effective_weight)?;
+        update_user_cw20_distributions(ctx.deps.branch(), user.clone(), old_effective_weight)?;
+
+        let new_effective_weight = if opted_out {
+            Uint128::zero()
+        } else {
+            let user_weight = USER_WEIGHTS
+                .may_load(ctx.deps.storage, user.clone())?
+                .unwrap_or_default();
+            let minimum_eligible_weight = MINIMUM_ELIGIBLE_WEIGHT.load(ctx.deps.storage)?;
+            calculate_effective_weight(user_weight, minimum_eligible_weight)
+        };
+
+        EFFECTIVE_USER_WEIGHTS.save(ctx.deps.storage, user.clone(), &new_effective_weight)?;
+
+        update_eligible_users_count(ctx.deps.storage, old_effective_weight, new_effective_weight)?;
+
+        let effective_total_weight = replace_effective_weight(
+            EFFECTIVE_TOTAL_WEIGHT.load(ctx.deps.storage)?,
+            old_effective_weight,
+            new_effective_weight,
+        )?;
+        EFFECTIVE_TOTAL_WEIGHT.save(ctx.deps.storage, &effective_total_weight)?;
+    }
+
+    Ok(execute_opt_out_of_rewards_response(
+        user.to_string(),
+        opted_out,
+    ))
+}
+
+pub fn is_opted_out(storage: &dyn Storage, user: Addr) -> bool {
+    OPTED_OUT_USERS.has(storage, user)
+}
+
+pub fn query_rewards_opt_out(
+    qctx: QueryContext,
+    params: RewardsOptOutParams,
+) -> DistributorResult<RewardsOptOutResponse> {
+    let user = qctx.deps.api.addr_validate(&params.user)?;
+
+    Ok(RewardsOptOutResponse {
+        opted_out: is_opted_out(qctx.deps.storage, user),
+    })
+}
@@ -0,0 +1,101 @@
+use crate::claim::claim_user_rewards;
+use bech32_no_std::FromBase32;
+use common::cw::{Context, QueryContext};
+use cosmwasm_std::{to_json_vec, Addr, Response};
+use cw_storage_plus::Map;
+use funds_distributor_api::api::{
+    ClaimPermitNonceParams, ClaimPermitNonceResponse, ClaimPermitPayload, ClaimRewardsWithPermitMsg,
+};
+use funds_distributor_api::error::DistributorError::{
+    InvalidPermitNonce, InvalidPermitSignature, PermitClaimDestinationNotAllowed,
+};
+use funds_distributor_api::error::DistributorResult;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+/// Nonces that users' next claim permits have to use.
+const CLAIM_PERMIT_NONCES: Map<Addr, u64> = Map::new("claim_permit_nonces");
+
+/// Claims the user's rewards on their behalf, authorized by their signature over the claim.
+/// Anyone can submit the permit, but rewards are always sent to the user.
+/// Each permit can only be used once, as its nonce has to match the user's current nonce.
+pub fn claim_rewards_with_permit(
+    ctx: &mut Context,
+    msg: ClaimRewardsWithPermitMsg,
+) -> DistributorResult<Response> {
+    let claim = &msg.claim;
+    if claim.destination.is_some()
+        || claim.native_destinations.is_some()
+        || claim.cw20_destinations.is_some()
+    {
+        return Err(PermitClaimDestinationNotAllowed);
+    }
+
+    let user = ctx.deps.api.addr_validate(&claim.user)?;
+
+    let nonce = CLAIM_PERMIT_NONCES
+        .may_load(ctx.deps.storage, user.clone())?
+        .unwrap_or_default();
+
+    if msg.nonce != nonce {
+        return Err(InvalidPermitNonce { expected: nonce });
+    }
+
+    if !is_users_public_key(user.as_str(), &msg.public_key) {
+        return Err(InvalidPermitSignature);
+    }
+
+    let payload = ClaimPermitPayload {
+        chain_id: ctx.env.block.chain_id.clone(),
+        contract: ctx.env.contract.address.to_string(),
+        nonce,
+        claim: msg.claim.clone(),
+    };
+    let payload_hash = Sha256::digest(to_json_vec(&payload)?);
+
+    let is_signature_valid = ctx
+        .deps
+        .api
+        .secp256k1_verify(&payload_hash, &msg.signature, &msg.public_key)
+        .map_err(|_| InvalidPermitSignature)?;
+
+    if !is_signature_valid {
+        return Err(InvalidPermitSignature);
+    }
+
+    CLAIM_PERMIT_NONCES.save(ctx.deps.storage, user.clone(), &(nonce + 1))?;
+
+    let response = claim_user_rewards(ctx, user, msg.claim)?
+        .add_attribute("relayer", ctx.info.sender.to_string())
+        .add_attribute("permit_nonce", nonce.to_string());
+
+    Ok(response)
+}
+
+/// Checks whether the address is derived from the given secp256k1 public key, the same way
+/// Cosmos SDK account addresses are.
+fn is_users_public_key(user: &str, public_key: &[u8]) -> bool {
+    let Ok((_, data)) = bech32_no_std::decode(user) else {
+        return false;
+    };
+    let Ok(address_bytes) = Vec::<u8>::from_base32(&data) else {
+        return false;
+    };
+
+    let public_key_hash = Ripemd160::digest(Sha256::digest(public_key));
+
+    address_bytes.as_slice() == public_key_hash.as_slice()
+}
+
+pub fn query_claim_permit_nonce(
+    qctx: QueryContext,
+    params: ClaimPermitNonceParams,
+) -> DistributorResult<ClaimPermitNonceResponse> {
+    let user = qctx.deps.api.addr_validate(&params.user)?;
+
+    let nonce = CLAIM_PERMIT_NONCES
+        .may_load(qctx.deps.storage, user)?
+        .unwrap_or_default();
+
+    Ok(ClaimPermitNonceResponse { nonce })
+}
@@ -0,0 +1,93 @@
+use crate::claim_deadlines::{remove_user_distribution, unexpired_distribution};
+use crate::cw20_distributions::CW20_DISTRIBUTIONS;
+use crate::native_distributions::NATIVE_DISTRIBUTIONS;
+use crate::rate_limit::{cw20_asset_key, native_asset_key};
+use crate::state::{CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use crate::user_weights::EFFECTIVE_USER_WEIGHTS;
+use crate::weight_basis::asset_user_weight;
+use common::cw::Context;
+use cosmwasm_std::{Decimal256, Response, Uint128};
+use funds_distributor_api::api::{PruneDistributionsMsg, RewardAsset};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::execute_prune_distributions_response;
+
+/// Deletes the user's distribution entries for the given assets that no longer owe them anything,
+/// to reclaim storage. Anyone can do this, since pruned entries have no effect on rewards.
+///
+/// An entry is pruned only if its index is at the global index, it has no pending rewards,
+/// and the user's weight for the asset is zero.
+/// A missing entry is treated as an index of zero, so entries of users with weight
+/// are never pruned - their rewards would otherwise be recalculated from the beginning.
+/// Entries that cannot be pruned are skipped.
+pub fn prune_distributions(
+    ctx: &mut Context,
+    msg: PruneDistributionsMsg,
+) -> DistributorResult<Response> {
+    let user = ctx.deps.api.addr_validate(&msg.user)?;
+
+    let user_weight = EFFECTIVE_USER_WEIGHTS
+        .may_load(ctx.deps.storage, user.clone())?
+        .unwrap_or_default();
+
+    let mut pruned_assets: Vec<String> = vec![];
+
+    for asset in msg.assets {
+        let (asset_key, global_index, distribution) = match &asset {
+            RewardAsset::Native { denom } => (
+                native_asset_key(denom),
+                NATIVE_GLOBAL_INDICES.may_load(ctx.deps.storage, denom.clone())?,
+                NATIVE_DISTRIBUTIONS()
+                    .may_load(ctx.deps.storage, (user.clone(), denom.clone()))?
+                    .map(<(Decimal256, Uint128)>::from),
+            ),
+            RewardAsset::Cw20 { asset } => {
+                let asset = ctx.deps.api.addr_validate(asset)?;
+                (
+                    cw20_asset_key(asset.as_ref()),
+                    CW20_GLOBAL_INDICES.may_load(ctx.deps.storage, asset.clone())?,
+                    CW20_DISTRIBUTIONS()
+                        .may_load(ctx.deps.storage, (user.clone(), asset))?
+                        .map(<(Decimal256, Uint128)>::from),
+                )
+            }
+        };
+
+        let distribution =
+            unexpired_distribution(ctx.deps.storage, &asset_key, &user, distribution)?;
+
+        let (Some(global_index), Some((user_index, pending_rewards))) =
+            (global_index, distribution)
+        else {
+            continue;
+        };
+
+        let prunable = user_index == global_index
+            && pending_rewards.is_zero()
+            && asset_user_weight(ctx.deps.storage, asset_key.clone(), user_weight)?.is_zero();
+
+        if !prunable {
+            continue;
+        }
+
+        remove_user_distribution(ctx.deps.storage, &asset_key, &user);
+
+        let asset = match asset {
+            RewardAsset::Native { denom } => {
+                NATIVE_DISTRIBUTIONS().remove(ctx.deps.storage, (user.clone(), denom.clone()))?;
+                denom
+            }
+            RewardAsset::Cw20 { asset } => {
+                let asset_addr = ctx.deps.api.addr_validate(&asset)?;
+                CW20_DISTRIBUTIONS().remove(ctx.deps.storage, (user.clone(), asset_addr))?;
+                asset
+            }
+        };
+
+        pruned_assets.push(asset);
+    }
+
+    Ok(execute_prune_distributions_response(
+        user.to_string(),
+        pruned_assets.join(","),
+    ))
+}
@@ -0,0 +1,66 @@
+use cosmwasm_std::{Storage, Timestamp};
+use cw_storage_plus::{Item, Map};
+use funds_distributor_api::api::{DistributionRateLimit, RewardAsset};
+use funds_distributor_api::error::DistributorError::DistributionRateLimited;
+use funds_distributor_api::error::DistributorResult;
+
+/// Rate limit used when no rate limit was configured.
+const DEFAULT_DISTRIBUTION_RATE_LIMIT: DistributionRateLimit = DistributionRateLimit {
+    max_distributions: 100,
+    window_seconds: 60,
+};
+
+pub const DISTRIBUTION_RATE_LIMIT: Item<DistributionRateLimit> =
+    Item::new("distribution_rate_limit");
+
+/// Timestamps of distributions made within the current rate limit window, keyed by asset.
+const RECENT_DISTRIBUTIONS: Map<String, Vec<Timestamp>> = Map::new("recent_distributions");
+
+/// Records a new distribution of the given asset, failing if the asset has already been
+/// distributed the maximum allowed number of times within the rate limit window.
+pub fn record_distribution(
+    storage: &mut dyn Storage,
+    asset_key: String,
+    now: Timestamp,
+) -> DistributorResult<()> {
+    let rate_limit = DISTRIBUTION_RATE_LIMIT
+        .may_load(storage)?
+        .unwrap_or(DEFAULT_DISTRIBUTION_RATE_LIMIT);
+
+    let window_start =
+        Timestamp::from_seconds(now.seconds().saturating_sub(rate_limit.window_seconds));
+
+    let mut recent_distributions = RECENT_DISTRIBUTIONS
+        .may_load(storage, asset_key.clone())?
+        .unwrap_or_default();
+
+    recent_distributions.retain(|timestamp| *timestamp > window_start);
+
+    if recent_distributions.len() >= rate_limit.max_distributions as usize {
+        return Err(DistributionRateLimited {
+            max_distributions: rate_limit.max_distributions,
+            window_seconds: rate_limit.window_seconds,
+        });
+    }
+
+    recent_distributions.push(now);
+
+    RECENT_DISTRIBUTIONS.save(storage, asset_key, &recent_distributions)?;
+
+    Ok(())
+}
+
+pub fn native_asset_key(denom: &str) -> String {
+    format!("native:{}", denom)
+}
+
+pub fn cw20_asset_key(asset: &str) -> String {
+    format!("cw20:{}", asset)
+}
+
+pub fn reward_asset_key(asset: &RewardAsset) -> String {
+    match asset {
+        RewardAsset::Native { denom } => native_asset_key(denom),
+        RewardAsset::Cw20 { asset } => cw20_asset_key(asset),
+    }
+}
@@ -0,0 +1,135 @@
+use crate::claim_deadlines::{record_user_distribution, unexpired_distribution};
+use crate::cw20_distributions::{Cw20Distribution, CW20_DISTRIBUTIONS};
+use crate::index_precision::asset_index_scale;
+use crate::native_distributions::{NativeDistribution, NATIVE_DISTRIBUTIONS};
+use crate::rate_limit::{cw20_asset_key, native_asset_key};
+use crate::rewards::calculate_new_user_reward;
+use crate::state::{ADMIN, CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use crate::user_weights::EFFECTIVE_USER_WEIGHTS;
+use crate::weight_basis::asset_user_weight;
+use common::cw::Context;
+use cosmwasm_std::{Decimal256, Event, Response, Uint128};
+use funds_distributor_api::api::{ReconcileUserMsg, RewardAsset};
+use funds_distributor_api::error::DistributorError::Unauthorized;
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::execute_reconcile_user_response;
+
+/// Recomputes the user's distribution entries for the given assets against current global indices.
+///
+/// Rewards accrued since the user's index at their current effective weight are added to their
+/// pending rewards, and their index is set to the global index. If the user's index is somehow
+/// ahead of the global index, it is reset to the global index without accruing anything.
+/// Nothing is paid out.
+///
+/// Emits a 'reconcile_user' event per asset, containing the entry before and after.
+pub fn reconcile_user(ctx: &mut Context, msg: ReconcileUserMsg) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let user = ctx.deps.api.addr_validate(&msg.user)?;
+
+    let user_weight = EFFECTIVE_USER_WEIGHTS
+        .may_load(ctx.deps.storage, user.clone())?
+        .unwrap_or_default();
+
+    let mut events: Vec<Event> = vec![];
+
+    for asset in msg.assets {
+        let (asset_key, global_index, distribution) = match &asset {
+            RewardAsset::Native { denom } => (
+                native_asset_key(denom),
+                NATIVE_GLOBAL_INDICES.may_load(ctx.deps.storage, denom.clone())?,
+                NATIVE_DISTRIBUTIONS()
+                    .may_load(ctx.deps.storage, (user.clone(), denom.clone()))?
+                    .map(<(Decimal256, Uint128)>::from),
+            ),
+            RewardAsset::Cw20 { asset } => {
+                let asset = ctx.deps.api.addr_validate(asset)?;
+                (
+                    cw20_asset_key(asset.as_ref()),
+                    CW20_GLOBAL_INDICES.may_load(ctx.deps.storage, asset.clone())?,
+                    CW20_DISTRIBUTIONS()
+                        .may_load(ctx.deps.storage, (user.clone(), asset))?
+                        .map(<(Decimal256, Uint128)>::from),
+                )
+            }
+        };
+
+        // nothing was ever distributed for this asset, so there is nothing to reconcile
+        let Some(global_index) = global_index else {
+            continue;
+        };
+
+        let distribution =
+            unexpired_distribution(ctx.deps.storage, &asset_key, &user, distribution)?;
+
+        let (old_user_index, old_pending_rewards) = distribution.unwrap_or_default();
+
+        let asset_user_weight =
+            asset_user_weight(ctx.deps.storage, asset_key.clone(), user_weight)?;
+        let index_scale = asset_index_scale(ctx.deps.storage, &asset_key)?;
+
+        let new_pending_rewards = if old_user_index > global_index {
+            old_pending_rewards
+        } else {
+            calculate_new_user_reward(global_index, old_user_index, asset_user_weight, index_scale)?
+                .checked_add(old_pending_rewards)?
+        };
+
+        match &asset {
+            RewardAsset::Native { denom } => NATIVE_DISTRIBUTIONS().save(
+                ctx.deps.storage,
+                (user.clone(), denom.clone()),
+                &NativeDistribution {
+                    user: user.clone(),
+                    denom: denom.clone(),
+                    user_index: global_index,
+                    pending_rewards: new_pending_rewards,
+                },
+            )?,
+            RewardAsset::Cw20 { asset } => {
+                let asset = ctx.deps.api.addr_validate(asset)?;
+                CW20_DISTRIBUTIONS().save(
+                    ctx.deps.storage,
+                    (user.clone(), asset.clone()),
+                    &Cw20Distribution {
+                        user: user.clone(),
+                        cw20_asset: asset,
+                        user_index: global_index,
+                        pending_rewards: new_pending_rewards,
+                    },
+                )?
+            }
+        }
+
+        record_user_distribution(
+            ctx.deps.storage,
+            &asset_key,
+            &user,
+            distribution,
+            (global_index, new_pending_rewards),
+            asset_user_weight,
+        )?;
+
+        let asset = match asset {
+            RewardAsset::Native { denom } => denom,
+            RewardAsset::Cw20 { asset } => asset,
+        };
+
+        events.push(
+            Event::new("reconcile_user")
+                .add_attribute("user", user.to_string())
+                .add_attribute("asset", asset)
+                .add_attribute("user_weight", user_weight.to_string())
+                .add_attribute("old_user_index", old_user_index.to_string())
+                .add_attribute("new_user_index", global_index.to_string())
+                .add_attribute("old_pending_rewards", old_pending_rewards.to_string())
+                .add_attribute("new_pending_rewards", new_pending_rewards.to_string()),
+        );
+    }
+
+    Ok(execute_reconcile_user_response(admin.to_string(), user.to_string()).add_events(events))
+}
@@ -1,43 +1,87 @@
+use crate::asset_metadata::{CW20_ASSET_METADATA, NATIVE_ASSET_METADATA};
+use crate::claim_deadlines::unexpired_distribution;
 use crate::cw20_distributions::CW20_DISTRIBUTIONS;
+use crate::index_precision::asset_index_scale;
 use crate::native_distributions::NATIVE_DISTRIBUTIONS;
+use crate::rate_limit::{cw20_asset_key, native_asset_key, reward_asset_key};
 use crate::state::{CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
 use crate::user_weights::EFFECTIVE_USER_WEIGHTS;
+use crate::vesting::unreleased_index_increment;
+use crate::weight_basis::asset_user_weight;
 use common::cw::QueryContext;
-use cosmwasm_std::{Addr, Decimal, Fraction, Uint128};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Decimal256, Fraction, Uint128, Uint256};
+use cw_storage_plus::Bound;
 use funds_distributor_api::api::{
-    Cw20Reward, NativeReward, UserRewardsParams, UserRewardsResponse,
+    Cw20Reward, MultiUserRewardsParams, MultiUserRewardsResponse, NativeReward,
+    OutstandingRewardsByUserParams, OutstandingRewardsByUserResponse, RewardAsset,
+    UserOutstandingReward, UserRewards, UserRewardsParams, UserRewardsResponse,
 };
+use funds_distributor_api::error::DistributorError::TooManyUsers;
 use funds_distributor_api::error::DistributorResult;
 use std::collections::HashSet;
 
-/// Calculates user's currently available rewards for an asset, given its current global index
-/// and user's weight.
+const DEFAULT_QUERY_LIMIT: u32 = 50;
+const MAX_QUERY_LIMIT: u32 = 100;
+
+/// Maximum number of users whose rewards can be queried at once.
+const MAX_MULTI_USER_REWARDS_USERS: u32 = 30;
+
+/// Maximum number of users a filtering query looks at in a single page.
+const MAX_SCANNED_USERS: usize = 500;
+
+/// Calculates user's currently available rewards for an asset, given its current global index,
+/// user's weight, and the scale of the asset's index.
+///
+/// Rewards still vesting are not part of the global index yet, so they are never included.
+/// Pending rewards recorded in the distribution are always included, even if the user's weight
+/// has since dropped to zero.
 pub fn calculate_user_reward(
-    global_index: Decimal,
-    distribution: Option<impl Into<(Decimal, Uint128)>>,
+    global_index: Decimal256,
+    distribution: Option<impl Into<(Decimal256, Uint128)>>,
     user_weight: Uint128,
+    index_scale: Uint256,
 ) -> DistributorResult<Uint128> {
     let (user_index, pending_rewards) =
-        distribution.map_or((Decimal::zero(), Uint128::zero()), |it| it.into());
+        distribution.map_or((Decimal256::zero(), Uint128::zero()), |it| it.into());
 
-    let user_reward = calculate_new_user_reward(global_index, user_index, user_weight)?
-        .checked_add(pending_rewards)?;
+    // pending rewards were already earned, so they are payable regardless of current weight
+    if user_weight.is_zero() {
+        return Ok(pending_rewards);
+    }
+
+    let user_reward =
+        calculate_new_user_reward(global_index, user_index, user_weight, index_scale)?
+            .checked_add(pending_rewards)?;
 
     Ok(user_reward)
 }
 
 /// Calculates reward accrued for the given asset since the last update to the user's reward
 /// index for the given asset.
+/// Indices of assets with extra precision are scaled up, so the reward is scaled back down.
 pub fn calculate_new_user_reward(
-    global_index: Decimal,
-    user_index: Decimal,
+    global_index: Decimal256,
+    user_index: Decimal256,
     user_weight: Uint128,
+    index_scale: Uint256,
 ) -> DistributorResult<Uint128> {
     let user_index_diff = global_index.checked_sub(user_index)?;
-    let new_user_reward = user_weight
-        .checked_multiply_ratio(user_index_diff.numerator(), user_index_diff.denominator())?;
+    let new_user_reward = Uint256::from(user_weight).checked_multiply_ratio(
+        user_index_diff.numerator(),
+        user_index_diff.denominator().checked_mul(index_scale)?,
+    )?;
 
-    Ok(new_user_reward)
+    Ok(new_user_reward.try_into()?)
+}
+
+/// Splits user's reward into the amount to be claimed now and the amount that remains pending,
+/// given an optional maximum amount to be claimed.
+pub fn split_user_reward(reward: Uint128, max_amount: Option<Uint128>) -> (Uint128, Uint128) {
+    match max_amount {
+        Some(max_amount) if max_amount < reward => (max_amount, reward - max_amount),
+        _ => (reward, Uint128::zero()),
+    }
 }
 
 pub fn query_user_rewards(
@@ -63,16 +107,38 @@ pub fn query_user_rewards(
 
         let global_index = NATIVE_GLOBAL_INDICES
             .may_load(qctx.deps.storage, denom.clone())?
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .checked_add(unreleased_index_increment(
+                qctx.deps.storage,
+                &RewardAsset::Native {
+                    denom: denom.clone(),
+                },
+                qctx.env.block.time,
+            )?)?;
 
-        let distribution =
-            NATIVE_DISTRIBUTIONS().may_load(qctx.deps.storage, (user.clone(), denom.clone()))?;
+        let asset_key = native_asset_key(&denom);
+
+        let distribution = unexpired_distribution(
+            qctx.deps.storage,
+            &asset_key,
+            &user,
+            NATIVE_DISTRIBUTIONS()
+                .may_load(qctx.deps.storage, (user.clone(), denom.clone()))?
+                .map(<(Decimal256, Uint128)>::from),
+        )?;
 
-        let reward = calculate_user_reward(global_index, distribution, user_weight)?;
+        let index_scale = asset_index_scale(qctx.deps.storage, &asset_key)?;
+        let asset_user_weight = asset_user_weight(qctx.deps.storage, asset_key, user_weight)?;
+
+        let reward =
+            calculate_user_reward(global_index, distribution, asset_user_weight, index_scale)?;
+
+        let metadata = NATIVE_ASSET_METADATA.may_load(qctx.deps.storage, denom.clone())?;
 
         native_rewards.push(NativeReward {
             denom,
             amount: reward,
+            metadata,
         });
     }
 
@@ -91,16 +157,38 @@ pub fn query_user_rewards(
 
         let global_index = CW20_GLOBAL_INDICES
             .may_load(qctx.deps.storage, asset.clone())?
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .checked_add(unreleased_index_increment(
+                qctx.deps.storage,
+                &RewardAsset::Cw20 {
+                    asset: asset.to_string(),
+                },
+                qctx.env.block.time,
+            )?)?;
 
-        let distribution =
-            CW20_DISTRIBUTIONS().may_load(qctx.deps.storage, (user.clone(), asset.clone()))?;
+        let asset_key = cw20_asset_key(asset.as_ref());
 
-        let reward = calculate_user_reward(global_index, distribution, user_weight)?;
+        let distribution = unexpired_distribution(
+            qctx.deps.storage,
+            &asset_key,
+            &user,
+            CW20_DISTRIBUTIONS()
+                .may_load(qctx.deps.storage, (user.clone(), asset.clone()))?
+                .map(<(Decimal256, Uint128)>::from),
+        )?;
+
+        let index_scale = asset_index_scale(qctx.deps.storage, &asset_key)?;
+        let asset_user_weight = asset_user_weight(qctx.deps.storage, asset_key, user_weight)?;
+
+        let reward =
+            calculate_user_reward(global_index, distribution, asset_user_weight, index_scale)?;
+
+        let metadata = CW20_ASSET_METADATA.may_load(qctx.deps.storage, asset.clone())?;
 
         cw20_rewards.push(Cw20Reward {
             asset: asset.to_string(),
             amount: reward,
+            metadata,
         });
     }
 
@@ -109,3 +197,155 @@ pub fn query_user_rewards(
         cw20_rewards,
     })
 }
+
+pub fn query_multi_user_rewards(
+    qctx: QueryContext,
+    params: MultiUserRewardsParams,
+) -> DistributorResult<MultiUserRewardsResponse> {
+    if params.users.len() > MAX_MULTI_USER_REWARDS_USERS as usize {
+        return Err(TooManyUsers {
+            max_users: MAX_MULTI_USER_REWARDS_USERS,
+        });
+    }
+
+    let mut users: Vec<UserRewards> = vec![];
+
+    for user in params.users {
+        let rewards = query_user_rewards(
+            qctx.clone(),
+            UserRewardsParams {
+                user: user.clone(),
+                native_denoms: params.native_denoms.clone(),
+                cw20_assets: params.cw20_assets.clone(),
+            },
+        )?;
+
+        users.push(UserRewards {
+            user,
+            native_rewards: rewards.native_rewards,
+            cw20_rewards: rewards.cw20_rewards,
+        });
+    }
+
+    Ok(MultiUserRewardsResponse { users })
+}
+
+/// Lists pending rewards for the given asset of users with an effective weight of at least
+/// the given minimum, ordered by user address.
+///
+/// Users under the minimum are skipped, so a page may cover more users than its limit.
+/// At most [MAX_SCANNED_USERS] users are looked at per page.
+pub fn query_outstanding_rewards_by_user(
+    qctx: QueryContext,
+    params: OutstandingRewardsByUserParams,
+) -> DistributorResult<OutstandingRewardsByUserResponse> {
+    let start_after = params
+        .start_after
+        .map(|user| qctx.deps.api.addr_validate(&user))
+        .transpose()?;
+
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_QUERY_LIMIT)
+        .min(MAX_QUERY_LIMIT) as usize;
+
+    let (global_index, asset) = match params.asset {
+        RewardAsset::Native { denom } => (
+            NATIVE_GLOBAL_INDICES
+                .may_load(qctx.deps.storage, denom.clone())?
+                .unwrap_or_default(),
+            RewardAsset::Native { denom },
+        ),
+        RewardAsset::Cw20 { asset } => {
+            let asset = qctx.deps.api.addr_validate(&asset)?;
+            (
+                CW20_GLOBAL_INDICES
+                    .may_load(qctx.deps.storage, asset.clone())?
+                    .unwrap_or_default(),
+                RewardAsset::Cw20 {
+                    asset: asset.to_string(),
+                },
+            )
+        }
+    };
+    let global_index = global_index.checked_add(unreleased_index_increment(
+        qctx.deps.storage,
+        &asset,
+        qctx.env.block.time,
+    )?)?;
+
+    let asset_key = reward_asset_key(&asset);
+
+    let min_weight = params.min_weight;
+
+    let mut scanned_users = EFFECTIVE_USER_WEIGHTS
+        .range(
+            qctx.deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(MAX_SCANNED_USERS);
+
+    let mut rewards: Vec<UserOutstandingReward> = vec![];
+    let mut last_scanned: Option<Addr> = None;
+
+    while rewards.len() < limit {
+        let Some(res) = scanned_users.next() else {
+            break;
+        };
+        let (user, weight) = res?;
+        last_scanned = Some(user.clone());
+
+        if weight.is_zero() || weight < min_weight {
+            continue;
+        }
+
+        let distribution = match &asset {
+            RewardAsset::Native { denom } => NATIVE_DISTRIBUTIONS()
+                .may_load(qctx.deps.storage, (user.clone(), denom.clone()))?
+                .map(<(Decimal256, Uint128)>::from),
+            RewardAsset::Cw20 { asset } => CW20_DISTRIBUTIONS()
+                .may_load(
+                    qctx.deps.storage,
+                    (user.clone(), Addr::unchecked(asset.as_str())),
+                )?
+                .map(<(Decimal256, Uint128)>::from),
+        };
+
+        let distribution =
+            unexpired_distribution(qctx.deps.storage, &asset_key, &user, distribution)?;
+
+        let index_scale = asset_index_scale(qctx.deps.storage, &asset_key)?;
+        let asset_user_weight = asset_user_weight(qctx.deps.storage, asset_key.clone(), weight)?;
+
+        let amount =
+            calculate_user_reward(global_index, distribution, asset_user_weight, index_scale)?;
+
+        rewards.push(UserOutstandingReward {
+            user: user.to_string(),
+            weight,
+            amount,
+        });
+    }
+
+    // the scan may have stopped at its bound or at the limit, with more users left to look at
+    let next_start_after = last_scanned
+        .filter(|last_scanned| {
+            EFFECTIVE_USER_WEIGHTS
+                .keys(
+                    qctx.deps.storage,
+                    Some(Bound::exclusive(last_scanned.clone())),
+                    None,
+                    Ascending,
+                )
+                .next()
+                .is_some()
+        })
+        .map(|user| user.to_string());
+
+    Ok(OutstandingRewardsByUserResponse {
+        rewards,
+        next_start_after,
+    })
+}
@@ -1,18 +1,28 @@
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Decimal256, Uint128};
 use cw_storage_plus::{Item, Map};
 
 pub const ADMIN: Item<Addr> = Item::new("admin");
 pub const ENTERPRISE_CONTRACT: Item<Addr> = Item::new("enterprise_contract");
 
+/// Optional address that is allowed to update user weights, in addition to the admin.
+pub const WEIGHT_ORACLE: Item<Addr> = Item::new("weight_oracle");
+
 /// Total weight of all users eligible for rewards.
 pub const EFFECTIVE_TOTAL_WEIGHT: Item<Uint128> = Item::new("total_weight");
 
+/// Number of users eligible for rewards, i.e. users with a non-zero effective weight.
+pub const ELIGIBLE_USERS_COUNT: Item<u64> = Item::new("eligible_users_count");
+
 /// Tracks global index for native denomination rewards.
 /// Global index is simply a decimal number representing the amount of currency rewards paid
-/// for a unit of user weight, since the beginning of time.
-pub const NATIVE_GLOBAL_INDICES: Map<String, Decimal> = Map::new("native_global_indices");
+/// for a unit of user weight, since the beginning of time, scaled up by the asset's
+/// index precision.
+/// Indices are stored as decimal strings, so indices stored as `Decimal` before the switch to
+/// `Decimal256` load unchanged, with no extra precision.
+pub const NATIVE_GLOBAL_INDICES: Map<String, Decimal256> = Map::new("native_global_indices");
 
 /// Tracks global index for CW20 token rewards.
 /// Global index is simply a decimal number representing the amount of currency rewards paid
-/// for a unit of user weight, since the beginning of time.
-pub const CW20_GLOBAL_INDICES: Map<Addr, Decimal> = Map::new("cw20_global_indices");
+/// for a unit of user weight, since the beginning of time, scaled up by the asset's
+/// index precision.
+pub const CW20_GLOBAL_INDICES: Map<Addr, Decimal256> = Map::new("cw20_global_indices");
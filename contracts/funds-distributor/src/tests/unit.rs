@@ -1 +1,2238 @@
+use crate::contract::{execute, instantiate, query};
+use crate::distribution_rates::record_rate_distribution;
+use crate::epochs::record_epoch_distribution;
+use crate::index_precision::{asset_index_scale, index_increment};
+use crate::native_distributions::{NativeDistribution, NATIVE_DISTRIBUTIONS};
+use crate::rewards::{calculate_user_reward, split_user_reward};
+use crate::state::NATIVE_GLOBAL_INDICES;
+use crate::vesting::VESTING_STREAMS;
+use bech32_no_std::ToBase32;
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockQuerier, MOCK_CONTRACT_ADDR,
+};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, to_json_string, Addr, BankMsg, Binary, Coin, ContractResult,
+    CosmosMsg, Decimal256, Deps, DepsMut, Env, OverflowError, OverflowOperation, Response,
+    StdError, SystemResult, Uint128, Uint256, WasmMsg, WasmQuery,
+};
+use cw20::{
+    BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg, MinterResponse,
+    TokenInfoResponse,
+};
+use cw_asset::AssetInfo;
+use enterprise_protocol::api::{ComponentContractsResponse, IsRestrictedUserResponse};
+use enterprise_protocol::msg::QueryMsg as EnterpriseQueryMsg;
+use enterprise_treasury_api::api::AssetWhitelistResponse;
+use funds_distributor_api::api::{
+    AssetClass, AssetWeightBasisParams, AssetWeightBasisResponse, ClaimPermitPayload,
+    ClaimPreviewParams, ClaimPreviewResponse, ClaimRewardsMsg, ClaimRewardsWithPermitMsg,
+    ClaimsPausedResponse, Cw20ClaimLimit, DistributionFee, DistributionFeeResponse,
+    DistributionMode, DistributionRateLimit, DistributionRateParams, DistributionRateResponse,
+    EmissionScheduleMsg, EmissionScheduleParams, EmissionScheduleResponse, EmissionSource,
+    EpochDistributedAmount, EpochsParams, EpochsResponse, MinimumEligibleWeightResponse,
+    NativeClaimLimit, NativeClaimPreview, OutstandingRewardsByUserParams,
+    OutstandingRewardsByUserResponse, PruneDistributionsMsg, ReconcileUserMsg, RewardAsset,
+    RewardsOptOutParams, RewardsOptOutResponse, SetAssetClaimPeriodMsg, SetAssetIndexPrecisionMsg,
+    SetAssetWeightBasisMsg, SetEmissionScheduleMsg, SetFeeOnTransferAssetMsg,
+    TotalEffectiveWeightResponse, UpdateConfigMsg, UpdateMinimumEligibleWeightMsg,
+    UpdateUserWeightsMsg, UserClaimTimesParams, UserClaimTimesResponse, UserRewardsParams,
+    UserRewardsResponse, UserWeight, WeightBasis,
+};
+use funds_distributor_api::error::DistributorError::{
+    AssetAlreadyDistributed, ClaimsPaused, DistributionFeeTooHigh, DistributionRateLimited,
+    FeeOnTransferEmission, InsufficientEmissionFunding, InvalidPermitNonce, NoEmissionsAccrued,
+    NoExpiredRewards, Unauthorized,
+};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::msg::{Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg};
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::ecdsa::{Signature, SigningKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
 
+#[test]
+fn split_user_reward_respects_max_amount() {
+    let reward = Uint128::new(100);
+
+    assert_eq!(split_user_reward(reward, None), (reward, Uint128::zero()));
+    assert_eq!(
+        split_user_reward(reward, Some(Uint128::new(30))),
+        (Uint128::new(30), Uint128::new(70))
+    );
+    assert_eq!(
+        split_user_reward(reward, Some(Uint128::new(150))),
+        (reward, Uint128::zero())
+    );
+    assert_eq!(
+        split_user_reward(reward, Some(Uint128::zero())),
+        (Uint128::zero(), reward)
+    );
+}
+
+#[test]
+fn user_reward_includes_pending_rewards_at_zero_weight() {
+    let distribution = Some((Decimal256::percent(50), Uint128::new(40)));
+
+    assert_eq!(
+        calculate_user_reward(
+            Decimal256::percent(150),
+            distribution,
+            Uint128::zero(),
+            Uint256::one()
+        )
+        .unwrap(),
+        Uint128::new(40)
+    );
+    assert_eq!(
+        calculate_user_reward(
+            Decimal256::percent(150),
+            distribution,
+            Uint128::new(10),
+            Uint256::one()
+        )
+        .unwrap(),
+        Uint128::new(50)
+    );
+    assert_eq!(
+        calculate_user_reward(
+            Decimal256::percent(150),
+            None::<(Decimal256, Uint128)>,
+            Uint128::zero(),
+            Uint256::one()
+        )
+        .unwrap(),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn vesting_distributions_are_released_linearly_as_one_stream_per_asset() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight("user1", 10), user_weight("user2", 30)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            new_admin: None,
+            new_enterprise_contract: None,
+            new_claim_history_retention: None,
+            new_distribution_rate_limit: None,
+            new_distribution_mode: Some(DistributionMode::Vesting { duration: 100 }),
+            new_distribution_fee: None,
+        }),
+    )
+    .unwrap();
+
+    let start = mock_env().block.time;
+    let env_at = |seconds: u64| {
+        let mut env = mock_env();
+        env.block.time = start.plus_seconds(seconds);
+        env
+    };
+
+    for seconds in [0, 50] {
+        execute(
+            deps.as_mut(),
+            env_at(seconds),
+            mock_info("distributor", &coins(400, "uluna")),
+            ExecuteMsg::DistributeNative {},
+        )
+        .unwrap();
+    }
+
+    // both distributions vest through the same stream
+    assert_eq!(
+        VESTING_STREAMS
+            .keys(&deps.storage, None, None, Ascending)
+            .count(),
+        1
+    );
+
+    // half of the first distribution has vested
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(50), "user1"),
+        Uint128::new(50)
+    );
+    // all of the first one, and half of the second one
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(100), "user1"),
+        Uint128::new(150)
+    );
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(100), "user2"),
+        Uint128::new(450)
+    );
+
+    let response = execute(
+        deps.as_mut(),
+        env_at(100),
+        mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards(uluna_claim("user1")),
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "user1".to_string(),
+            amount: coins(150, "uluna"),
+        })
+    );
+
+    // releasing everything that vested removes the stream
+    update_user_weights_at(deps.as_mut(), env_at(200), vec![user_weight("user3", 10)]);
+    assert_eq!(
+        VESTING_STREAMS
+            .keys(&deps.storage, None, None, Ascending)
+            .count(),
+        0
+    );
+
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(200), "user1"),
+        Uint128::new(50)
+    );
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(200), "user2"),
+        Uint128::new(600)
+    );
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(200), "user3"),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn ineligible_user_can_claim_rewards_accrued_while_eligible() {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+            to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+        )),
+        _ => panic!("unexpected query"),
+    });
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![
+                UserWeight {
+                    user: "user1".to_string(),
+                    weight: Uint128::new(10),
+                },
+                UserWeight {
+                    user: "user2".to_string(),
+                    weight: Uint128::new(30),
+                },
+            ],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    // 100uluna distributed over a total weight of 40
+    NATIVE_GLOBAL_INDICES
+        .save(
+            deps.as_mut().storage,
+            "uluna".to_string(),
+            &Decimal256::from_ratio(5u8, 2u8),
+        )
+        .unwrap();
+
+    // user1 drops below the minimum
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::UpdateMinimumEligibleWeight(UpdateMinimumEligibleWeightMsg {
+            minimum_eligible_weight: Uint128::new(20),
+        }),
+    )
+    .unwrap();
+
+    // 90uluna distributed over a total weight of 30, which only includes user2
+    NATIVE_GLOBAL_INDICES
+        .save(
+            deps.as_mut().storage,
+            "uluna".to_string(),
+            &Decimal256::from_ratio(11u8, 2u8),
+        )
+        .unwrap();
+
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(25));
+    assert_eq!(uluna_rewards(deps.as_ref(), "user2"), Uint128::new(165));
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards(ClaimRewardsMsg {
+            user: "user1".to_string(),
+            native_denoms: vec!["uluna".to_string()],
+            cw20_assets: vec![],
+            native_claim_limits: None,
+            cw20_claim_limits: None,
+            destination: None,
+            native_destinations: None,
+            cw20_destinations: None,
+            asset_class: None,
+            claim_all: None,
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "user1".to_string(),
+            amount: coins(25, "uluna"),
+        })
+    );
+
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::zero());
+}
+
+#[test]
+fn pruned_distribution_reconstructs_identically_when_user_re_accrues() {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+            to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+        )),
+        _ => panic!("unexpected query"),
+    });
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![
+                user_weight("user1", 10),
+                user_weight("user2", 10),
+                user_weight("user3", 10),
+            ],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    set_uluna_global_index(deps.as_mut(), 1);
+
+    for user in ["user1", "user2", "user3"] {
+        claim_uluna(deps.as_mut(), user);
+    }
+
+    update_user_weights(
+        deps.as_mut(),
+        vec![user_weight("user1", 0), user_weight("user2", 0)],
+    );
+
+    for user in ["user1", "user3"] {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::PruneDistributions(PruneDistributionsMsg {
+                user: user.to_string(),
+                assets: vec![RewardAsset::Native {
+                    denom: "uluna".to_string(),
+                }],
+            }),
+        )
+        .unwrap();
+    }
+
+    // user3 still has weight, so their entry is kept
+    assert!(uluna_distribution(deps.as_ref(), "user1").is_none());
+    assert!(uluna_distribution(deps.as_ref(), "user2").is_some());
+    assert!(uluna_distribution(deps.as_ref(), "user3").is_some());
+
+    set_uluna_global_index(deps.as_mut(), 2);
+
+    update_user_weights(
+        deps.as_mut(),
+        vec![user_weight("user1", 10), user_weight("user2", 10)],
+    );
+
+    assert_eq!(
+        uluna_distribution(deps.as_ref(), "user1").map(|it| (it.user_index, it.pending_rewards)),
+        uluna_distribution(deps.as_ref(), "user2").map(|it| (it.user_index, it.pending_rewards)),
+    );
+
+    set_uluna_global_index(deps.as_mut(), 3);
+
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(10));
+    assert_eq!(uluna_rewards(deps.as_ref(), "user2"), Uint128::new(10));
+    assert_eq!(uluna_rewards(deps.as_ref(), "user3"), Uint128::new(20));
+}
+
+#[test]
+fn claim_permit_pays_user_and_cannot_be_replayed() {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+            to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+        )),
+        _ => panic!("unexpected query"),
+    });
+
+    let signing_key = SigningKey::from_slice(&[7u8; 32]).unwrap();
+    let public_key = signing_key.verifying_key().to_sec1_bytes().to_vec();
+    let user = bech32_no_std::encode(
+        "terra",
+        Ripemd160::digest(Sha256::digest(&public_key)).to_base32(),
+    )
+    .unwrap();
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight(&user, 10)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    set_uluna_global_index(deps.as_mut(), 1);
+
+    let claim = uluna_claim(&user);
+    let payload = ClaimPermitPayload {
+        chain_id: mock_env().block.chain_id,
+        contract: mock_env().contract.address.to_string(),
+        nonce: 0,
+        claim: claim.clone(),
+    };
+    let signature: Signature = signing_key
+        .sign_prehash(&Sha256::digest(to_json_binary(&payload).unwrap()))
+        .unwrap();
+
+    let permit = ExecuteMsg::ClaimRewardsWithPermit(ClaimRewardsWithPermitMsg {
+        claim,
+        nonce: 0,
+        public_key: public_key.into(),
+        signature: signature.to_bytes().to_vec().into(),
+    });
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("relayer", &[]),
+        permit.clone(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: user.clone(),
+            amount: coins(10, "uluna"),
+        })
+    );
+
+    let replay_result = execute(deps.as_mut(), mock_env(), mock_info("relayer", &[]), permit);
+    assert_eq!(replay_result, Err(InvalidPermitNonce { expected: 1 }));
+}
+
+#[test]
+fn distributions_are_totalled_per_epoch() {
+    let mut deps = mock_dependencies();
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    let uluna = RewardAsset::Native {
+        denom: "uluna".to_string(),
+    };
+
+    record_epoch_distribution(deps.as_mut().storage, &uluna, Uint128::new(100)).unwrap();
+    record_epoch_distribution(deps.as_mut().storage, &uluna, Uint128::new(20)).unwrap();
+
+    let roll_result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[]),
+        ExecuteMsg::RollEpoch {},
+    );
+    assert_eq!(roll_result, Err(Unauthorized));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::RollEpoch {},
+    )
+    .unwrap();
+
+    record_epoch_distribution(deps.as_mut().storage, &uluna, Uint128::new(50)).unwrap();
+
+    let response: EpochsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Epochs(EpochsParams {
+                start_after: None,
+                limit: None,
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(response.current_epoch, 1);
+    assert_eq!(response.epochs.len(), 2);
+
+    assert_eq!(response.epochs[0].started_at, None);
+    assert_eq!(response.epochs[0].ended_at, Some(mock_env().block.time));
+    assert_eq!(
+        response.epochs[0].distributed,
+        vec![EpochDistributedAmount {
+            asset: uluna.clone(),
+            amount: Uint128::new(120),
+        }]
+    );
+
+    assert_eq!(response.epochs[1].started_at, Some(mock_env().block.time));
+    assert_eq!(response.epochs[1].ended_at, None);
+    assert_eq!(
+        response.epochs[1].distributed,
+        vec![EpochDistributedAmount {
+            asset: uluna,
+            amount: Uint128::new(50),
+        }]
+    );
+}
+
+#[test]
+fn user_claim_times_record_last_claim_per_asset() {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+            to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+        )),
+        _ => panic!("unexpected query"),
+    });
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight("user", 10)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    set_uluna_global_index(deps.as_mut(), 1);
+    claim_uluna(deps.as_mut(), "user");
+
+    let uluna = RewardAsset::Native {
+        denom: "uluna".to_string(),
+    };
+    let uatom = RewardAsset::Native {
+        denom: "uatom".to_string(),
+    };
+
+    let response: UserClaimTimesResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::UserClaimTimes(UserClaimTimesParams {
+                user: "user".to_string(),
+                assets: vec![uluna.clone(), uatom.clone()],
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(response.claim_times[0].asset, uluna);
+    assert_eq!(
+        response.claim_times[0].last_claimed_at,
+        Some(mock_env().block.time)
+    );
+    assert_eq!(response.claim_times[1].asset, uatom);
+    assert_eq!(response.claim_times[1].last_claimed_at, None);
+}
+
+#[test]
+fn total_weight_overflow_is_rejected_instead_of_wrapping() {
+    let mut deps = mock_dependencies();
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![UserWeight {
+                user: "user1".to_string(),
+                weight: Uint128::MAX - Uint128::new(10),
+            }],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    // replacing a user's weight only counts the difference towards the total
+    update_user_weights(
+        deps.as_mut(),
+        vec![UserWeight {
+            user: "user1".to_string(),
+            weight: Uint128::MAX,
+        }],
+    );
+    assert_eq!(total_effective_weight(deps.as_ref()), Uint128::MAX);
+
+    let overflow_result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::UpdateUserWeights(UpdateUserWeightsMsg {
+            new_user_weights: vec![user_weight("user2", 1)],
+        }),
+    );
+    assert_eq!(
+        overflow_result,
+        Err(StdError::generic_err(
+            OverflowError::new(OverflowOperation::Add, Uint128::MAX, Uint128::one()).to_string()
+        )
+        .into())
+    );
+}
+
+#[test]
+fn sweep_takes_only_rewards_unclaimed_past_their_deadline() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight("user1", 10), user_weight("user2", 30)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetAssetClaimPeriod(SetAssetClaimPeriodMsg {
+            asset: RewardAsset::Native {
+                denom: "uluna".to_string(),
+            },
+            claim_period: 100,
+        }),
+    )
+    .unwrap();
+
+    let start = mock_env().block.time;
+    let env_at = |seconds: u64| {
+        let mut env = mock_env();
+        env.block.time = start.plus_seconds(seconds);
+        env
+    };
+    let distribute = |deps: DepsMut, seconds: u64| {
+        execute(
+            deps,
+            env_at(seconds),
+            mock_info("distributor", &coins(400, "uluna")),
+            ExecuteMsg::DistributeNative {},
+        )
+        .unwrap();
+    };
+    let claim = |deps: DepsMut, seconds: u64, user: &str| {
+        execute(
+            deps,
+            env_at(seconds),
+            mock_info(user, &[]),
+            ExecuteMsg::ClaimRewards(uluna_claim(user)),
+        )
+        .unwrap();
+    };
+    let sweep = |deps: DepsMut, seconds: u64| {
+        execute(
+            deps,
+            env_at(seconds),
+            mock_info("admin", &[]),
+            ExecuteMsg::SweepExpiredRewards {
+                asset: RewardAsset::Native {
+                    denom: "uluna".to_string(),
+                },
+            },
+        )
+    };
+
+    // each distribution gives 100uluna to user1 and 300uluna to user2
+    distribute(deps.as_mut(), 0);
+    claim(deps.as_mut(), 5, "user1");
+    distribute(deps.as_mut(), 10);
+    distribute(deps.as_mut(), 20);
+
+    // user1 claims the last two distributions at once, user2 leaves without claiming anything
+    claim(deps.as_mut(), 30, "user1");
+    update_user_weights_at(deps.as_mut(), env_at(30), vec![user_weight("user2", 0)]);
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(30), "user2"),
+        Uint128::new(900)
+    );
+
+    assert_eq!(sweep(deps.as_mut(), 99), Err(NoExpiredRewards));
+
+    // the first two distributions have passed their deadlines, but user2's unclaimed rewards
+    // from them became pending together with those of the third one
+    let response = sweep(deps.as_mut(), 115).unwrap();
+    assert!(response.messages.is_empty());
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(115), "user2"),
+        Uint128::new(900)
+    );
+
+    let response = sweep(deps.as_mut(), 120).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: coins(900, "uluna"),
+        })
+    );
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(120), "user1"),
+        Uint128::zero()
+    );
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(120), "user2"),
+        Uint128::zero()
+    );
+
+    assert_eq!(sweep(deps.as_mut(), 120), Err(NoExpiredRewards));
+}
+
+#[test]
+fn sweep_sends_rewards_to_treasury_address_configured_in_governance() {
+    let mut deps = mock_dependencies();
+    mock_enterprise_with(&mut deps.querier, Some("dao_treasury"), 0);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetAssetClaimPeriod(SetAssetClaimPeriodMsg {
+            asset: RewardAsset::Native {
+                denom: "uluna".to_string(),
+            },
+            claim_period: 100,
+        }),
+    )
+    .unwrap();
+
+    distribute_uluna(deps.as_mut(), mock_env(), 100);
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    let response = execute(
+        deps.as_mut(),
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::SweepExpiredRewards {
+            asset: RewardAsset::Native {
+                denom: "uluna".to_string(),
+            },
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "dao_treasury".to_string(),
+            amount: coins(100, "uluna"),
+        })
+    );
+}
+
+#[test]
+fn sweep_takes_rewards_of_distributions_accrued_at_once_by_inactive_users() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight("user1", 10), user_weight("user2", 30)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetAssetClaimPeriod(SetAssetClaimPeriodMsg {
+            asset: RewardAsset::Native {
+                denom: "uluna".to_string(),
+            },
+            claim_period: 100,
+        }),
+    )
+    .unwrap();
+
+    let start = mock_env().block.time;
+    let env_at = |seconds: u64| {
+        let mut env = mock_env();
+        env.block.time = start.plus_seconds(seconds);
+        env
+    };
+
+    for seconds in [0, 10, 20] {
+        execute(
+            deps.as_mut(),
+            env_at(seconds),
+            mock_info("distributor", &coins(400, "uluna")),
+            ExecuteMsg::DistributeNative {},
+        )
+        .unwrap();
+    }
+
+    // user1 claims all three distributions in one go, user2 never claims
+    execute(
+        deps.as_mut(),
+        env_at(30),
+        mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards(uluna_claim("user1")),
+    )
+    .unwrap();
+
+    let response = execute(
+        deps.as_mut(),
+        env_at(120),
+        mock_info("admin", &[]),
+        ExecuteMsg::SweepExpiredRewards {
+            asset: RewardAsset::Native {
+                denom: "uluna".to_string(),
+            },
+        },
+    )
+    .unwrap();
+
+    // user1's claimed share is accounted for, rounded up by 1 for each distribution
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "treasury".to_string(),
+            amount: coins(897, "uluna"),
+        })
+    );
+    assert_eq!(
+        uluna_rewards_at(deps.as_ref(), env_at(120), "user2"),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn small_distribution_over_large_weight_accrues_with_extra_index_precision() {
+    let mut deps = mock_dependencies();
+
+    let user_weight_amount = Uint128::new(500_000_000_000_000_000_000_000);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![
+                user_weight("user1", user_weight_amount.u128()),
+                user_weight("user2", user_weight_amount.u128()),
+            ],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetAssetIndexPrecision(SetAssetIndexPrecisionMsg {
+            asset: RewardAsset::Native {
+                denom: "uusd".to_string(),
+            },
+            extra_decimals: 9,
+        }),
+    )
+    .unwrap();
+
+    let total_weight = total_effective_weight(deps.as_ref());
+    let amount = Uint128::new(1_000);
+
+    // without extra precision, 1000 over a total weight of 10^24 rounds down to nothing
+    let uluna_increment =
+        index_increment(&deps.storage, "native:uluna", amount, total_weight).unwrap();
+    assert_eq!(uluna_increment, Decimal256::zero());
+
+    let uusd_increment =
+        index_increment(&deps.storage, "native:uusd", amount, total_weight).unwrap();
+    let uusd_scale = asset_index_scale(&deps.storage, "native:uusd").unwrap();
+    assert_eq!(
+        calculate_user_reward(
+            uusd_increment,
+            None::<(Decimal256, Uint128)>,
+            user_weight_amount,
+            uusd_scale
+        )
+        .unwrap(),
+        Uint128::new(500)
+    );
+
+    // precision cannot change once the asset's index exists
+    NATIVE_GLOBAL_INDICES
+        .save(deps.as_mut().storage, "uusd".to_string(), &uusd_increment)
+        .unwrap();
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetAssetIndexPrecision(SetAssetIndexPrecisionMsg {
+            asset: RewardAsset::Native {
+                denom: "uusd".to_string(),
+            },
+            extra_decimals: 12,
+        }),
+    );
+    assert_eq!(result, Err(AssetAlreadyDistributed));
+}
+
+#[test]
+fn opted_out_user_accrues_nothing_until_opting_back_in() {
+    let mut deps = mock_dependencies();
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight("user1", 10), user_weight("user2", 30)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    set_uluna_global_index(deps.as_mut(), 2);
+    opt_out_of_rewards(deps.as_mut(), "user2", true);
+
+    assert!(rewards_opted_out(deps.as_ref(), "user2"));
+    assert_eq!(total_effective_weight(deps.as_ref()), Uint128::new(10));
+
+    // weight changes while opted out still leave the user's weight out of distributions
+    update_user_weights(deps.as_mut(), vec![user_weight("user2", 50)]);
+    assert_eq!(total_effective_weight(deps.as_ref()), Uint128::new(10));
+
+    set_uluna_global_index(deps.as_mut(), 5);
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(50));
+    assert_eq!(uluna_rewards(deps.as_ref(), "user2"), Uint128::new(60));
+
+    opt_out_of_rewards(deps.as_mut(), "user2", false);
+
+    assert!(!rewards_opted_out(deps.as_ref(), "user2"));
+    assert_eq!(total_effective_weight(deps.as_ref()), Uint128::new(60));
+
+    set_uluna_global_index(deps.as_mut(), 6);
+    assert_eq!(uluna_rewards(deps.as_ref(), "user2"), Uint128::new(110));
+}
+
+#[test]
+fn claims_cannot_be_made_while_paused() {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+            to_json_binary(&IsRestrictedUserResponse {
+                is_restricted: false,
+            })
+            .unwrap(),
+        )),
+        _ => panic!("unexpected query"),
+    });
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight("user1", 10)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    set_uluna_global_index(deps.as_mut(), 2);
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user1", &[]),
+        ExecuteMsg::SetClaimsPaused { paused: true },
+    );
+    assert_eq!(result, Err(Unauthorized));
+
+    set_claims_paused(deps.as_mut(), true);
+    assert!(claims_paused(deps.as_ref()));
+
+    let result = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards(uluna_claim("user1")),
+    );
+    assert_eq!(result, Err(ClaimsPaused));
+
+    // rewards keep accruing while claims are paused
+    set_uluna_global_index(deps.as_mut(), 3);
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(30));
+
+    set_claims_paused(deps.as_mut(), false);
+    assert!(!claims_paused(deps.as_ref()));
+
+    claim_uluna(deps.as_mut(), "user1");
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::zero());
+}
+
+#[test]
+fn distribution_rate_sums_trailing_window_and_drops_old_buckets() {
+    let mut deps = mock_dependencies();
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight("user1", 100)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    const DAY: u64 = 24 * 60 * 60;
+    let now = mock_env().block.time;
+
+    for (time, amount) in [
+        (now.minus_seconds(400 * DAY), 1_000),
+        (now.minus_seconds(10 * DAY), 50),
+        (now, 50),
+    ] {
+        record_rate_distribution(
+            deps.as_mut().storage,
+            "native:uluna",
+            time,
+            Uint128::new(amount),
+        )
+        .unwrap();
+    }
+
+    // 100 distributed over a year to a total weight of 100
+    let response = distribution_rate(deps.as_ref(), "uluna", 365 * DAY);
+    assert_eq!(response.distributed, Uint128::new(100));
+    assert_eq!(response.annualized_rate, Decimal256::one());
+
+    let response = distribution_rate(deps.as_ref(), "uluna", 1000 * DAY);
+    assert_eq!(response.window, 365 * DAY);
+    assert_eq!(response.distributed, Uint128::new(100));
+
+    let response = distribution_rate(deps.as_ref(), "uluna", DAY);
+    assert_eq!(response.distributed, Uint128::new(50));
+
+    let response = distribution_rate(deps.as_ref(), "uusd", 365 * DAY);
+    assert_eq!(response.distributed, Uint128::zero());
+    assert_eq!(response.annualized_rate, Decimal256::zero());
+}
+
+#[test]
+fn emissions_are_distributed_as_they_accrue_within_funded_schedule() {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } => {
+            let response = match contract_addr.as_str() {
+                "enterprise" => to_json_binary(&ComponentContractsResponse {
+                    enterprise_factory_contract: Addr::unchecked("factory"),
+                    enterprise_versioning_contract: Addr::unchecked("versioning"),
+                    enterprise_governance_contract: Addr::unchecked("governance"),
+                    enterprise_governance_controller_contract: Addr::unchecked("controller"),
+                    enterprise_outposts_contract: Addr::unchecked("outposts"),
+                    enterprise_treasury_contract: Addr::unchecked("treasury"),
+                    funds_distributor_contract: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                    membership_contract: Addr::unchecked("membership"),
+                    council_membership_contract: Addr::unchecked("council"),
+                    attestation_contract: None,
+                }),
+                "treasury" => to_json_binary(&AssetWhitelistResponse {
+                    assets: vec![AssetInfo::cw20(Addr::unchecked("token"))],
+                }),
+                "factory" => to_json_binary(&AssetWhitelistResponse { assets: vec![] }),
+                "token" => match from_json(msg).unwrap() {
+                    Cw20QueryMsg::Minter {} => to_json_binary(&Some(MinterResponse {
+                        minter: MOCK_CONTRACT_ADDR.to_string(),
+                        cap: Some(Uint128::new(40_000)),
+                    })),
+                    Cw20QueryMsg::TokenInfo {} => to_json_binary(&TokenInfoResponse {
+                        name: "token".to_string(),
+                        symbol: "TKN".to_string(),
+                        decimals: 6,
+                        total_supply: Uint128::new(10_000),
+                    }),
+                    Cw20QueryMsg::Balance { .. } => to_json_binary(&BalanceResponse {
+                        balance: Uint128::new(1_500),
+                    }),
+                    _ => panic!("unexpected query"),
+                },
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        _ => panic!("unexpected query"),
+    });
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights: vec![user_weight("user1", 100)],
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    // the token's cap leaves room for 30 days of emissions only
+    let result = set_token_emission_schedule(deps.as_mut(), 31);
+    assert_eq!(
+        result,
+        Err(InsufficientEmissionFunding {
+            required: Uint128::new(31_000),
+            available: Uint128::new(30_000),
+        })
+    );
+
+    set_token_emission_schedule(deps.as_mut(), 30).unwrap();
+
+    let result = poke_token_emissions(deps.as_mut(), mock_env());
+    assert_eq!(result, Err(NoEmissionsAccrued));
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(DAY + DAY / 2);
+
+    let response = poke_token_emissions(deps.as_mut(), env.clone()).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "token".to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+                recipient: MOCK_CONTRACT_ADDR.to_string(),
+                amount: Uint128::new(1_500),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    let rewards: UserRewardsResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::UserRewards(UserRewardsParams {
+                user: "user1".to_string(),
+                native_denoms: vec![],
+                cw20_assets: vec!["token".to_string()],
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(rewards.cw20_rewards[0].amount, Uint128::new(1_500));
+
+    // accrual stops at the end of the schedule
+    env.block.time = env.block.time.plus_seconds(40 * DAY);
+
+    let response: EmissionScheduleResponse = from_json(
+        query(
+            deps.as_ref(),
+            env,
+            QueryMsg::EmissionSchedule(EmissionScheduleParams {
+                asset: "token".to_string(),
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(response.accrued, Uint128::new(28_500));
+    assert_eq!(response.remaining, Uint128::new(28_500));
+
+    // tokens taking a fee on transfers cannot be emitted, since the emissions are distributed
+    // before the amount actually received is known
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetFeeOnTransferAsset(SetFeeOnTransferAssetMsg {
+            asset: "token".to_string(),
+            fee_on_transfer: true,
+        }),
+    )
+    .unwrap();
+
+    let result = poke_token_emissions(deps.as_mut(), mock_env());
+    assert_eq!(result, Err(FeeOnTransferEmission));
+
+    let result = set_token_emission_schedule(deps.as_mut(), 30);
+    assert_eq!(result, Err(FeeOnTransferEmission));
+}
+
+#[test]
+fn claim_preview_shows_which_assets_would_transfer() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    distribute_uluna(deps.as_mut(), mock_env(), 100);
+
+    let response: ClaimPreviewResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ClaimPreview(ClaimPreviewParams {
+                user: "user1".to_string(),
+                native_denoms: vec!["uluna".to_string(), "uatom".to_string()],
+                cw20_assets: vec![],
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response.native_claims,
+        vec![
+            NativeClaimPreview {
+                denom: "uluna".to_string(),
+                transfers: true,
+            },
+            NativeClaimPreview {
+                denom: "uatom".to_string(),
+                transfers: false,
+            },
+        ]
+    );
+    assert!(response.cw20_claims.is_empty());
+}
+
+#[test]
+fn claims_can_select_an_asset_class_and_claim_all_of_its_assets() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(
+            "distributor",
+            &[Coin::new(100, "uluna"), Coin::new(50, "uatom")],
+        ),
+        ExecuteMsg::DistributeNative {},
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("token", &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "distributor".to_string(),
+            amount: Uint128::new(40),
+            msg: to_json_binary(&Cw20HookMsg::Distribute {}).unwrap(),
+        }),
+    )
+    .unwrap();
+
+    let mut claim = |asset_class: AssetClass, claim_all: bool| {
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user1", &[]),
+            ExecuteMsg::ClaimRewards(ClaimRewardsMsg {
+                cw20_assets: vec!["token".to_string()],
+                asset_class: Some(asset_class),
+                claim_all: Some(claim_all),
+                ..uluna_claim("user1")
+            }),
+        )
+        .unwrap();
+        response
+            .messages
+            .into_iter()
+            .map(|submsg| submsg.msg)
+            .collect::<Vec<CosmosMsg>>()
+    };
+
+    // the listed CW20 asset is ignored when only native assets are selected
+    assert_eq!(
+        claim(AssetClass::Native, false),
+        vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "user1".to_string(),
+            amount: coins(100, "uluna"),
+        })]
+    );
+
+    assert_eq!(
+        claim(AssetClass::Cw20, true),
+        vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "token".to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "user1".to_string(),
+                amount: Uint128::new(40),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+
+    // claiming all picks up the native asset that was never listed
+    assert_eq!(
+        claim(AssetClass::Both, true),
+        vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "user1".to_string(),
+            amount: coins(50, "uatom"),
+        })]
+    );
+}
+
+#[test]
+fn minimum_eligible_weight_query_reports_eligible_users_and_weight() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(
+        deps.as_mut(),
+        vec![user_weight("user1", 10), user_weight("user2", 5)],
+    );
+
+    let eligibility = |deps: Deps| {
+        let response: MinimumEligibleWeightResponse =
+            from_json(query(deps, mock_env(), QueryMsg::MinimumEligibleWeight {}).unwrap())
+                .unwrap();
+        (
+            response.eligible_users,
+            response.total_eligible_weight.u128(),
+        )
+    };
+
+    assert_eq!(eligibility(deps.as_ref()), (2, 15));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::UpdateMinimumEligibleWeight(UpdateMinimumEligibleWeightMsg {
+            minimum_eligible_weight: Uint128::new(6),
+        }),
+    )
+    .unwrap();
+    assert_eq!(eligibility(deps.as_ref()), (1, 10));
+
+    update_user_weights(deps.as_mut(), vec![user_weight("user3", 20)]);
+    assert_eq!(eligibility(deps.as_ref()), (2, 30));
+
+    update_user_weights(deps.as_mut(), vec![user_weight("user1", 0)]);
+    assert_eq!(eligibility(deps.as_ref()), (1, 20));
+}
+
+#[test]
+fn distribution_fee_is_sent_to_recipient_before_distributing_the_rest() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    let mut set_fee = |fee_bps: u16| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+                new_admin: None,
+                new_enterprise_contract: None,
+                new_claim_history_retention: None,
+                new_distribution_rate_limit: None,
+                new_distribution_mode: None,
+                new_distribution_fee: Some(DistributionFee {
+                    fee_bps,
+                    recipient: "fee_collector".to_string(),
+                }),
+            }),
+        )
+    };
+
+    assert_eq!(
+        set_fee(1001).unwrap_err(),
+        DistributionFeeTooHigh { max_bps: 1000 }
+    );
+    set_fee(500).unwrap();
+
+    let response: DistributionFeeResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::DistributionFee {}).unwrap()).unwrap();
+    assert_eq!(
+        response.distribution_fee,
+        Some(DistributionFee {
+            fee_bps: 500,
+            recipient: "fee_collector".to_string(),
+        })
+    );
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("distributor", &coins(1000, "uluna")),
+        ExecuteMsg::DistributeNative {},
+    )
+    .unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "fee_collector".to_string(),
+            amount: coins(50, "uluna"),
+        })
+    );
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(950));
+}
+
+#[test]
+fn reconciling_user_accrues_rewards_up_to_global_index_without_paying_out() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    distribute_uluna(deps.as_mut(), mock_env(), 100);
+    assert_eq!(uluna_distribution(deps.as_ref(), "user1"), None);
+
+    let mut reconcile = |sender: &str| {
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::ReconcileUser(ReconcileUserMsg {
+                user: "user1".to_string(),
+                assets: vec![
+                    RewardAsset::Native {
+                        denom: "uluna".to_string(),
+                    },
+                    // never distributed, so skipped
+                    RewardAsset::Native {
+                        denom: "uatom".to_string(),
+                    },
+                ],
+            }),
+        )
+    };
+
+    assert_eq!(reconcile("user1").unwrap_err(), Unauthorized);
+
+    let response = reconcile("admin").unwrap();
+    assert!(response.messages.is_empty());
+    assert_eq!(response.events.len(), 1);
+    let attribute = |key: &str| {
+        response.events[0]
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.clone())
+    };
+    assert_eq!(attribute("asset"), Some("uluna".to_string()));
+    assert_eq!(attribute("old_pending_rewards"), Some("0".to_string()));
+    assert_eq!(attribute("new_pending_rewards"), Some("100".to_string()));
+
+    let distribution = uluna_distribution(deps.as_ref(), "user1").unwrap();
+    assert_eq!(distribution.pending_rewards, Uint128::new(100));
+    assert_eq!(
+        Some(distribution.user_index),
+        NATIVE_GLOBAL_INDICES
+            .may_load(deps.as_ref().storage, "uluna".to_string())
+            .unwrap()
+    );
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(100));
+}
+
+#[test]
+fn outstanding_rewards_by_user_are_filtered_before_limiting_each_page() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(
+        deps.as_mut(),
+        vec![
+            user_weight("user1", 10),
+            user_weight("user2", 1),
+            user_weight("user3", 10),
+            user_weight("user4", 10),
+        ],
+    );
+    distribute_uluna(deps.as_mut(), mock_env(), 310);
+
+    let outstanding_rewards = |deps: Deps, start_after: Option<String>| {
+        let response: OutstandingRewardsByUserResponse = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::OutstandingRewardsByUser(OutstandingRewardsByUserParams {
+                    min_weight: Uint128::new(5),
+                    asset: RewardAsset::Native {
+                        denom: "uluna".to_string(),
+                    },
+                    start_after,
+                    limit: Some(2),
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        (
+            response
+                .rewards
+                .into_iter()
+                .map(|reward| (reward.user, reward.amount.u128()))
+                .collect::<Vec<(String, u128)>>(),
+            response.next_start_after,
+        )
+    };
+
+    // user2 is under the minimum weight, and does not take up a spot on the page
+    let (rewards, next_start_after) = outstanding_rewards(deps.as_ref(), None);
+    assert_eq!(
+        rewards,
+        vec![("user1".to_string(), 100), ("user3".to_string(), 100)]
+    );
+    assert_eq!(next_start_after, Some("user3".to_string()));
+
+    let (rewards, next_start_after) = outstanding_rewards(deps.as_ref(), next_start_after);
+    assert_eq!(rewards, vec![("user4".to_string(), 100)]);
+    assert_eq!(next_start_after, None);
+
+    // a long run of users under the minimum ends the page before reaching the next eligible user
+    update_user_weights(
+        deps.as_mut(),
+        (0..500)
+            .map(|i| user_weight(&format!("user3{:03}", i), 1))
+            .collect(),
+    );
+    let (rewards, next_start_after) = outstanding_rewards(deps.as_ref(), Some("user3".to_string()));
+    assert!(rewards.is_empty());
+    assert_eq!(next_start_after, Some("user3499".to_string()));
+
+    let (rewards, next_start_after) = outstanding_rewards(deps.as_ref(), next_start_after);
+    assert_eq!(rewards, vec![("user4".to_string(), 100)]);
+    assert_eq!(next_start_after, None);
+}
+
+#[test]
+fn claimed_rewards_are_sent_in_a_single_transfer_per_asset() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    distribute_uluna(deps.as_mut(), mock_env(), 100);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("token", &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "distributor".to_string(),
+            amount: Uint128::new(40),
+            msg: to_json_binary(&Cw20HookMsg::Distribute {}).unwrap(),
+        }),
+    )
+    .unwrap();
+
+    // assets listed more than once are still claimed only once, in full
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards(ClaimRewardsMsg {
+            native_denoms: vec!["uluna".to_string(), "uluna".to_string()],
+            cw20_assets: vec!["token".to_string(), "token".to_string()],
+            ..uluna_claim("user1")
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response
+            .messages
+            .into_iter()
+            .map(|submsg| submsg.msg)
+            .collect::<Vec<CosmosMsg>>(),
+        vec![
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "user1".to_string(),
+                amount: coins(100, "uluna"),
+            }),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "token".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "user1".to_string(),
+                    amount: Uint128::new(40),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+        ]
+    );
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::zero());
+}
+
+#[test]
+fn claim_limits_apply_once_to_assets_listed_more_than_once() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    distribute_uluna(deps.as_mut(), mock_env(), 100);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("token", &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "distributor".to_string(),
+            amount: Uint128::new(40),
+            msg: to_json_binary(&Cw20HookMsg::Distribute {}).unwrap(),
+        }),
+    )
+    .unwrap();
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user1", &[]),
+        ExecuteMsg::ClaimRewards(ClaimRewardsMsg {
+            native_denoms: vec!["uluna".to_string(), "uluna".to_string()],
+            cw20_assets: vec!["token".to_string(), "token".to_string()],
+            native_claim_limits: Some(vec![NativeClaimLimit {
+                denom: "uluna".to_string(),
+                max_amount: Uint128::new(30),
+            }]),
+            cw20_claim_limits: Some(vec![Cw20ClaimLimit {
+                asset: "token".to_string(),
+                max_amount: Uint128::new(15),
+            }]),
+            ..uluna_claim("user1")
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(
+        response
+            .messages
+            .into_iter()
+            .map(|submsg| submsg.msg)
+            .collect::<Vec<CosmosMsg>>(),
+        vec![
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "user1".to_string(),
+                amount: coins(30, "uluna"),
+            }),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "token".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "user1".to_string(),
+                    amount: Uint128::new(15),
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+        ]
+    );
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(70));
+}
+
+#[test]
+fn asset_distributed_equally_per_user_ignores_user_weights() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(
+        deps.as_mut(),
+        vec![user_weight("user1", 10), user_weight("user2", 30)],
+    );
+
+    let set_uatom_weight_basis = |deps: DepsMut, sender: &str| {
+        execute(
+            deps,
+            mock_env(),
+            mock_info(sender, &[]),
+            ExecuteMsg::SetAssetWeightBasis(SetAssetWeightBasisMsg {
+                asset: RewardAsset::Native {
+                    denom: "uatom".to_string(),
+                },
+                weight_basis: WeightBasis::EqualPerUser,
+            }),
+        )
+    };
+
+    assert_eq!(
+        set_uatom_weight_basis(deps.as_mut(), "user1").unwrap_err(),
+        Unauthorized
+    );
+    set_uatom_weight_basis(deps.as_mut(), "admin").unwrap();
+
+    let weight_basis = |deps: Deps, denom: &str| {
+        from_json::<AssetWeightBasisResponse>(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::AssetWeightBasis(AssetWeightBasisParams {
+                    asset: RewardAsset::Native {
+                        denom: denom.to_string(),
+                    },
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .weight_basis
+    };
+    assert_eq!(
+        weight_basis(deps.as_ref(), "uatom"),
+        WeightBasis::EqualPerUser
+    );
+    assert_eq!(
+        weight_basis(deps.as_ref(), "uluna"),
+        WeightBasis::ProportionalToWeight
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(
+            "distributor",
+            &[Coin::new(100, "uluna"), Coin::new(100, "uatom")],
+        ),
+        ExecuteMsg::DistributeNative {},
+    )
+    .unwrap();
+
+    let rewards = |deps: Deps, user: &str| {
+        let response: UserRewardsResponse = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::UserRewards(UserRewardsParams {
+                    user: user.to_string(),
+                    native_denoms: vec!["uluna".to_string(), "uatom".to_string()],
+                    cw20_assets: vec![],
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        response
+            .native_rewards
+            .into_iter()
+            .map(|reward| reward.amount.u128())
+            .collect::<Vec<u128>>()
+    };
+    assert_eq!(rewards(deps.as_ref(), "user1"), vec![25, 50]);
+    assert_eq!(rewards(deps.as_ref(), "user2"), vec![75, 50]);
+
+    // the basis cannot change once the asset was distributed
+    assert_eq!(
+        set_uatom_weight_basis(deps.as_mut(), "admin").unwrap_err(),
+        AssetAlreadyDistributed
+    );
+}
+
+#[test]
+fn second_distribution_within_rate_limit_window_is_rejected() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::UpdateConfig(UpdateConfigMsg {
+            new_admin: None,
+            new_enterprise_contract: None,
+            new_claim_history_retention: None,
+            new_distribution_rate_limit: Some(DistributionRateLimit {
+                max_distributions: 1,
+                window_seconds: 60,
+            }),
+            new_distribution_mode: None,
+            new_distribution_fee: None,
+        }),
+    )
+    .unwrap();
+
+    distribute_uluna(deps.as_mut(), mock_env(), 100);
+
+    let distribute_at = |deps: DepsMut, seconds_later: u64| {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds_later);
+        execute(
+            deps,
+            env,
+            mock_info("distributor", &coins(100, "uluna")),
+            ExecuteMsg::DistributeNative {},
+        )
+    };
+
+    assert_eq!(
+        distribute_at(deps.as_mut(), 59).unwrap_err(),
+        DistributionRateLimited {
+            max_distributions: 1,
+            window_seconds: 60,
+        }
+    );
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(100));
+
+    // once the first distribution falls out of the window, the asset can be distributed again
+    distribute_at(deps.as_mut(), 61).unwrap();
+    assert_eq!(uluna_rewards(deps.as_ref(), "user1"), Uint128::new(200));
+}
+
+#[test]
+fn fee_on_transfer_token_distribution_indexes_only_the_amount_received() {
+    let mut deps = mock_dependencies();
+    mock_enterprise(&mut deps.querier);
+    instantiate_with_weights(deps.as_mut(), vec![user_weight("user1", 10)]);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetFeeOnTransferAsset(SetFeeOnTransferAssetMsg {
+            asset: "token".to_string(),
+            fee_on_transfer: true,
+        }),
+    )
+    .unwrap();
+
+    let mut distribute_token = |amount_sent: u128, balance_after: u128| {
+        mock_enterprise_with(&mut deps.querier, None, balance_after);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("token", &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: "distributor".to_string(),
+                amount: Uint128::new(amount_sent),
+                msg: to_json_binary(&Cw20HookMsg::Distribute {}).unwrap(),
+            }),
+        )
+        .unwrap();
+
+        let rewards: UserRewardsResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::UserRewards(UserRewardsParams {
+                    user: "user1".to_string(),
+                    native_denoms: vec![],
+                    cw20_assets: vec!["token".to_string()],
+                }),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        rewards.cw20_rewards[0].amount
+    };
+
+    // the token takes 5% of each transfer, so only 95 out of 100 sent is received
+    assert_eq!(distribute_token(100, 95), Uint128::new(95));
+
+    // the already received balance is not credited again
+    assert_eq!(distribute_token(200, 285), Uint128::new(285));
+}
+
+fn set_token_emission_schedule(deps: DepsMut, periods: u64) -> DistributorResult<Response> {
+    execute(
+        deps,
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetEmissionSchedule(SetEmissionScheduleMsg {
+            asset: "token".to_string(),
+            schedule: Some(EmissionScheduleMsg {
+                amount_per_period: Uint128::new(1_000),
+                period: 24 * 60 * 60,
+                periods,
+                source: EmissionSource::Mint,
+            }),
+        }),
+    )
+}
+
+fn poke_token_emissions(deps: DepsMut, env: Env) -> DistributorResult<Response> {
+    execute(
+        deps,
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::PokeEmissions {
+            asset: "token".to_string(),
+        },
+    )
+}
+
+fn distribution_rate(deps: Deps, denom: &str, window: u64) -> DistributionRateResponse {
+    from_json(
+        query(
+            deps,
+            mock_env(),
+            QueryMsg::DistributionRate(DistributionRateParams {
+                asset: RewardAsset::Native {
+                    denom: denom.to_string(),
+                },
+                window,
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn user_weight(user: &str, weight: u128) -> UserWeight {
+    UserWeight {
+        user: user.to_string(),
+        weight: Uint128::new(weight),
+    }
+}
+
+fn set_uluna_global_index(deps: DepsMut, index: u64) {
+    NATIVE_GLOBAL_INDICES
+        .save(
+            deps.storage,
+            "uluna".to_string(),
+            &Decimal256::from_ratio(index, 1u8),
+        )
+        .unwrap();
+}
+
+fn update_user_weights(deps: DepsMut, new_user_weights: Vec<UserWeight>) {
+    execute(
+        deps,
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::UpdateUserWeights(UpdateUserWeightsMsg { new_user_weights }),
+    )
+    .unwrap();
+}
+
+fn opt_out_of_rewards(deps: DepsMut, user: &str, opted_out: bool) {
+    execute(
+        deps,
+        mock_env(),
+        mock_info(user, &[]),
+        ExecuteMsg::OptOutOfRewards { opted_out },
+    )
+    .unwrap();
+}
+
+fn rewards_opted_out(deps: Deps, user: &str) -> bool {
+    let response: RewardsOptOutResponse = from_json(
+        query(
+            deps,
+            mock_env(),
+            QueryMsg::RewardsOptOut(RewardsOptOutParams {
+                user: user.to_string(),
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    response.opted_out
+}
+
+fn set_claims_paused(deps: DepsMut, paused: bool) {
+    execute(
+        deps,
+        mock_env(),
+        mock_info("admin", &[]),
+        ExecuteMsg::SetClaimsPaused { paused },
+    )
+    .unwrap();
+}
+
+fn claims_paused(deps: Deps) -> bool {
+    let response: ClaimsPausedResponse =
+        from_json(query(deps, mock_env(), QueryMsg::ClaimsPaused {}).unwrap()).unwrap();
+    response.paused
+}
+
+fn total_effective_weight(deps: Deps) -> Uint128 {
+    let response: TotalEffectiveWeightResponse =
+        from_json(query(deps, mock_env(), QueryMsg::TotalEffectiveWeight {}).unwrap()).unwrap();
+    response.total_effective_weight
+}
+
+fn claim_uluna(deps: DepsMut, user: &str) {
+    execute(
+        deps,
+        mock_env(),
+        mock_info(user, &[]),
+        ExecuteMsg::ClaimRewards(uluna_claim(user)),
+    )
+    .unwrap();
+}
+
+fn uluna_claim(user: &str) -> ClaimRewardsMsg {
+    ClaimRewardsMsg {
+        user: user.to_string(),
+        native_denoms: vec!["uluna".to_string()],
+        cw20_assets: vec![],
+        native_claim_limits: None,
+        cw20_claim_limits: None,
+        destination: None,
+        native_destinations: None,
+        cw20_destinations: None,
+        asset_class: None,
+        claim_all: None,
+    }
+}
+
+fn uluna_distribution(deps: Deps, user: &str) -> Option<NativeDistribution> {
+    NATIVE_DISTRIBUTIONS()
+        .may_load(deps.storage, (Addr::unchecked(user), "uluna".to_string()))
+        .unwrap()
+}
+
+fn uluna_rewards(deps: Deps, user: &str) -> Uint128 {
+    let response: UserRewardsResponse = from_json(
+        query(
+            deps,
+            mock_env(),
+            QueryMsg::UserRewards(UserRewardsParams {
+                user: user.to_string(),
+                native_denoms: vec!["uluna".to_string()],
+                cw20_assets: vec![],
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    response.native_rewards[0].amount
+}
+
+/// Answers the enterprise contract's queries, and whitelists uluna in the treasury.
+fn mock_enterprise(querier: &mut MockQuerier) {
+    mock_enterprise_with(querier, None, 0)
+}
+
+/// Mocks the DAO's contracts, with the given treasury address configured in its governance,
+/// and the distributor holding the given balance of the CW20 "token".
+fn mock_enterprise_with(
+    querier: &mut MockQuerier,
+    treasury_address: Option<&str>,
+    token_balance: u128,
+) {
+    let treasury_address = to_json_string(&treasury_address).unwrap();
+    querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } => {
+            let response = match contract_addr.as_str() {
+                "enterprise" => match from_json(msg).unwrap() {
+                    EnterpriseQueryMsg::IsRestrictedUser(_) => {
+                        to_json_binary(&IsRestrictedUserResponse {
+                            is_restricted: false,
+                        })
+                    }
+                    EnterpriseQueryMsg::ComponentContracts {} => {
+                        to_json_binary(&ComponentContractsResponse {
+                            enterprise_factory_contract: Addr::unchecked("factory"),
+                            enterprise_versioning_contract: Addr::unchecked("versioning"),
+                            enterprise_governance_contract: Addr::unchecked("governance"),
+                            enterprise_governance_controller_contract: Addr::unchecked(
+                                "controller",
+                            ),
+                            enterprise_outposts_contract: Addr::unchecked("outposts"),
+                            enterprise_treasury_contract: Addr::unchecked("treasury"),
+                            funds_distributor_contract: Addr::unchecked("funds_distributor"),
+                            membership_contract: Addr::unchecked("membership"),
+                            council_membership_contract: Addr::unchecked("council"),
+                            attestation_contract: None,
+                        })
+                    }
+                    _ => panic!("unexpected query"),
+                },
+                "treasury" => to_json_binary(&AssetWhitelistResponse {
+                    assets: vec![
+                        AssetInfo::native("uluna"),
+                        AssetInfo::native("uatom"),
+                        AssetInfo::cw20(Addr::unchecked("token")),
+                    ],
+                }),
+                "factory" => to_json_binary(&AssetWhitelistResponse { assets: vec![] }),
+                "token" => match from_json(msg).unwrap() {
+                    Cw20QueryMsg::Balance { .. } => to_json_binary(&BalanceResponse {
+                        balance: Uint128::new(token_balance),
+                    }),
+                    _ => panic!("unexpected query"),
+                },
+                "controller" => Ok(Binary::from(
+                    format!(
+                        r#"{{
+                            "gov_config": {{
+                                "quorum": "0.3",
+                                "threshold": "0.5",
+                                "vote_duration": 100,
+                                "allow_early_proposal_execution": false,
+                                "treasury_address": {treasury_address}
+                            }},
+                            "council_gov_config": null,
+                            "dao_membership_contract": "membership",
+                            "dao_council_membership_contract": "council"
+                        }}"#
+                    )
+                    .as_bytes(),
+                )),
+                _ => panic!("unexpected query"),
+            };
+            SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+        }
+        _ => panic!("unexpected query"),
+    });
+}
+
+fn update_user_weights_at(deps: DepsMut, env: Env, new_user_weights: Vec<UserWeight>) {
+    execute(
+        deps,
+        env,
+        mock_info("admin", &[]),
+        ExecuteMsg::UpdateUserWeights(UpdateUserWeightsMsg { new_user_weights }),
+    )
+    .unwrap();
+}
+
+fn uluna_rewards_at(deps: Deps, env: Env, user: &str) -> Uint128 {
+    let response: UserRewardsResponse = from_json(
+        query(
+            deps,
+            env,
+            QueryMsg::UserRewards(UserRewardsParams {
+                user: user.to_string(),
+                native_denoms: vec!["uluna".to_string()],
+                cw20_assets: vec![],
+            }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    response.native_rewards[0].amount
+}
+
+fn instantiate_with_weights(deps: DepsMut, initial_weights: Vec<UserWeight>) {
+    instantiate(
+        deps,
+        mock_env(),
+        mock_info("admin", &[]),
+        InstantiateMsg {
+            admin: "admin".to_string(),
+            enterprise_contract: "enterprise".to_string(),
+            initial_weights,
+            minimum_eligible_weight: None,
+            weight_oracle: None,
+        },
+    )
+    .unwrap();
+}
+
+fn distribute_uluna(deps: DepsMut, env: Env, amount: u128) {
+    execute(
+        deps,
+        env,
+        mock_info("distributor", &coins(amount, "uluna")),
+        ExecuteMsg::DistributeNative {},
+    )
+    .unwrap();
+}
@@ -1,14 +1,17 @@
 use crate::cw20_distributions::{Cw20Distribution, CW20_DISTRIBUTIONS};
-use crate::eligibility::MINIMUM_ELIGIBLE_WEIGHT;
+use crate::eligibility::{update_eligible_users_count, MINIMUM_ELIGIBLE_WEIGHT};
 use crate::native_distributions::{NativeDistribution, NATIVE_DISTRIBUTIONS};
-use crate::state::{ADMIN, CW20_GLOBAL_INDICES, EFFECTIVE_TOTAL_WEIGHT, NATIVE_GLOBAL_INDICES};
+use crate::opt_out::is_opted_out;
+use crate::state::{
+    ADMIN, CW20_GLOBAL_INDICES, EFFECTIVE_TOTAL_WEIGHT, NATIVE_GLOBAL_INDICES, WEIGHT_ORACLE,
+};
 use crate::{cw20_distributions, native_distributions};
-use common::cw::Context;
+use common::cw::{Context, QueryContext};
 use cosmwasm_std::Order::Ascending;
-use cosmwasm_std::{Addr, Decimal, DepsMut, Response, StdResult, Uint128};
+use cosmwasm_std::{Addr, Decimal256, DepsMut, Response, StdResult, Uint128};
 use cw20_distributions::update_user_cw20_distributions;
 use cw_storage_plus::Map;
-use funds_distributor_api::api::{UpdateUserWeightsMsg, UserWeight};
+use funds_distributor_api::api::{TotalEffectiveWeightResponse, UpdateUserWeightsMsg, UserWeight};
 use funds_distributor_api::error::DistributorError::Unauthorized;
 use funds_distributor_api::error::{DistributorError, DistributorResult};
 use funds_distributor_api::response::execute_update_user_weights_response;
@@ -51,7 +54,9 @@ pub fn save_initial_weights(
             calculate_effective_weight(user_weight.weight, minimum_eligible_weight);
         EFFECTIVE_USER_WEIGHTS.save(ctx.deps.storage, user, &effective_user_weight)?;
 
-        effective_total_weight += effective_user_weight;
+        update_eligible_users_count(ctx.deps.storage, Uint128::zero(), effective_user_weight)?;
+
+        effective_total_weight = effective_total_weight.checked_add(effective_user_weight)?;
     }
 
     EFFECTIVE_TOTAL_WEIGHT.save(ctx.deps.storage, &effective_total_weight)?;
@@ -59,15 +64,16 @@ pub fn save_initial_weights(
     Ok(())
 }
 
-/// Updates the users' weights to new ones.
+/// Updates the users' weights to new ones. Can be called by the admin or the weight oracle.
 /// Will calculate any accrued rewards since the last update to their rewards.
 pub fn update_user_weights(
     ctx: &mut Context,
     msg: UpdateUserWeightsMsg,
 ) -> DistributorResult<Response> {
     let admin = ADMIN.load(ctx.deps.storage)?;
+    let weight_oracle = WEIGHT_ORACLE.may_load(ctx.deps.storage)?;
 
-    if ctx.info.sender != admin {
+    if ctx.info.sender != admin && Some(&ctx.info.sender) != weight_oracle.as_ref() {
         return Err(Unauthorized);
     }
 
@@ -113,14 +119,27 @@ pub fn update_user_weights_checked(
 
         USER_WEIGHTS.save(deps.storage, user.clone(), &user_weight_change.weight)?;
 
-        let effective_user_weight =
-            calculate_effective_weight(user_weight_change.weight, minimum_eligible_weight);
+        // users opted out of rewards keep their weight, but none of it counts for rewards
+        let effective_user_weight = if is_opted_out(deps.storage, user.clone()) {
+            Uint128::zero()
+        } else {
+            calculate_effective_weight(user_weight_change.weight, minimum_eligible_weight)
+        };
         EFFECTIVE_USER_WEIGHTS.save(deps.storage, user, &effective_user_weight)?;
 
         let old_user_effective_weight = old_user_effective_weight.unwrap_or_default();
 
-        effective_total_weight =
-            effective_total_weight - old_user_effective_weight + effective_user_weight;
+        update_eligible_users_count(
+            deps.storage,
+            old_user_effective_weight,
+            effective_user_weight,
+        )?;
+
+        effective_total_weight = replace_effective_weight(
+            effective_total_weight,
+            old_user_effective_weight,
+            effective_user_weight,
+        )?;
     }
 
     EFFECTIVE_TOTAL_WEIGHT.save(deps.storage, &effective_total_weight)?;
@@ -128,9 +147,23 @@ pub fn update_user_weights_checked(
     Ok(execute_update_user_weights_response())
 }
 
+/// Replaces a user's old effective weight in the effective total weight with their new one.
+/// Fails rather than wrapping around if the total would overflow or underflow.
+pub fn replace_effective_weight(
+    effective_total_weight: Uint128,
+    old_effective_weight: Uint128,
+    new_effective_weight: Uint128,
+) -> DistributorResult<Uint128> {
+    let effective_total_weight = effective_total_weight
+        .checked_sub(old_effective_weight)?
+        .checked_add(new_effective_weight)?;
+
+    Ok(effective_total_weight)
+}
+
 /// Calculate user's effective rewards weight, given their actual weight and minimum weight for
 /// rewards eligibility
-fn calculate_effective_weight(weight: Uint128, minimum_eligible_weight: Uint128) -> Uint128 {
+pub fn calculate_effective_weight(weight: Uint128, minimum_eligible_weight: Uint128) -> Uint128 {
     if weight >= minimum_eligible_weight {
         weight
     } else {
@@ -145,7 +178,7 @@ fn calculate_effective_weight(weight: Uint128, minimum_eligible_weight: Uint128)
 fn initialize_user_indices(deps: DepsMut, user: Addr) -> DistributorResult<()> {
     let native_global_indices = NATIVE_GLOBAL_INDICES
         .range(deps.storage, None, None, Ascending)
-        .collect::<StdResult<Vec<(String, Decimal)>>>()?;
+        .collect::<StdResult<Vec<(String, Decimal256)>>>()?;
 
     for (denom, global_index) in native_global_indices {
         NATIVE_DISTRIBUTIONS().update(
@@ -167,7 +200,7 @@ fn initialize_user_indices(deps: DepsMut, user: Addr) -> DistributorResult<()> {
 
     let cw20_global_indices = CW20_GLOBAL_INDICES
         .range(deps.storage, None, None, Ascending)
-        .collect::<StdResult<Vec<(Addr, Decimal)>>>()?;
+        .collect::<StdResult<Vec<(Addr, Decimal256)>>>()?;
 
     for (asset, global_index) in cw20_global_indices {
         CW20_DISTRIBUTIONS().update(
@@ -189,3 +222,15 @@ fn initialize_user_indices(deps: DepsMut, user: Addr) -> DistributorResult<()> {
 
     Ok(())
 }
+
+pub fn query_total_effective_weight(
+    qctx: QueryContext,
+) -> DistributorResult<TotalEffectiveWeightResponse> {
+    let total_effective_weight = EFFECTIVE_TOTAL_WEIGHT
+        .may_load(qctx.deps.storage)?
+        .unwrap_or_default();
+
+    Ok(TotalEffectiveWeightResponse {
+        total_effective_weight,
+    })
+}
@@ -0,0 +1,366 @@
+use crate::index_precision::index_increment;
+use crate::rate_limit::reward_asset_key;
+use crate::rewards::query_user_rewards;
+use crate::state::{CW20_GLOBAL_INDICES, NATIVE_GLOBAL_INDICES};
+use crate::user_weights::EFFECTIVE_USER_WEIGHTS;
+use crate::weight_basis::{asset_total_weight, asset_user_weight};
+use common::cw::QueryContext;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Decimal256, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use funds_distributor_api::api::{
+    Cw20VestingReward, DistributionMode, NativeVestingReward, RewardAsset, UserRewardsParams,
+    UserVestingRewardsResponse,
+};
+use funds_distributor_api::error::DistributorResult;
+
+/// How newly distributed rewards are made available to users.
+/// If not set, rewards are available immediately.
+pub const DISTRIBUTION_MODE: Item<DistributionMode> = Item::new("distribution_mode");
+
+/// Maximum number of vesting rate changes processed for a single asset when vesting its rewards.
+/// If more of them are due, the asset's rewards are vested up to the last one processed, and
+/// the rest is vested by later executions.
+const MAX_VESTING_RATE_CHANGES: usize = 50;
+
+/// Rewards of each asset that are still vesting, keyed by the asset's key.
+/// Every distribution vests linearly, so all of an asset's vesting distributions are tracked
+/// as a single stream, whose rate changes as distributions start and finish vesting.
+pub const VESTING_STREAMS: Map<String, VestingStream> = Map::new("vesting_streams");
+
+/// Changes to the vesting rate of each asset, keyed by the asset's key and the time
+/// (in seconds) at which they happen.
+const VESTING_RATE_CHANGES: Map<(String, u64), VestingRateChange> =
+    Map::new("vesting_rate_changes");
+
+#[cw_serde]
+/// Rewards of a single asset that are vesting, or vested but not yet added to its global index.
+pub struct VestingStream {
+    pub asset: RewardAsset,
+    /// Amount currently vesting per second
+    pub rate: Decimal256,
+    /// Amount distributed that was not yet added to the asset's global index
+    pub unreleased: Uint128,
+    /// Amount vested that was not yet added to the asset's global index
+    pub vested: Decimal256,
+    /// Time up to which the stream has been vested
+    pub vested_until: Timestamp,
+}
+
+#[cw_serde]
+struct VestingRateChange {
+    /// Rate of distributions starting to vest at this time
+    increase: Decimal256,
+    /// Rate of distributions finishing vesting at this time
+    decrease: Decimal256,
+}
+
+impl VestingStream {
+    /// Amount vested so far that can be added to the asset's global index.
+    fn releasable(&self) -> Uint128 {
+        let vested = Uint128::try_from(self.vested.to_uint_floor()).unwrap_or(Uint128::MAX);
+        vested.min(self.unreleased)
+    }
+}
+
+/// Starts vesting a new distribution of the given asset now, over the given duration.
+pub fn add_vesting_distribution(
+    storage: &mut dyn Storage,
+    asset: RewardAsset,
+    amount: Uint128,
+    now: Timestamp,
+    duration: u64,
+) -> DistributorResult<()> {
+    let asset_key = reward_asset_key(&asset);
+
+    let mut stream = VESTING_STREAMS
+        .may_load(storage, asset_key.clone())?
+        .unwrap_or(VestingStream {
+            asset,
+            rate: Decimal256::zero(),
+            unreleased: Uint128::zero(),
+            vested: Decimal256::zero(),
+            vested_until: now,
+        });
+    stream.unreleased = stream.unreleased.checked_add(amount)?;
+    VESTING_STREAMS.save(storage, asset_key.clone(), &stream)?;
+
+    // the rate only goes up once the stream gets vested past now, in case it lags behind
+    let rate = Decimal256::from_ratio(amount, duration);
+    update_rate_change(storage, &asset_key, now, |change| {
+        change.increase = change.increase.checked_add(rate)?;
+        Ok(())
+    })?;
+    update_rate_change(storage, &asset_key, now.plus_seconds(duration), |change| {
+        change.decrease = change.decrease.checked_add(rate)?;
+        Ok(())
+    })?;
+
+    Ok(())
+}
+
+fn update_rate_change(
+    storage: &mut dyn Storage,
+    asset_key: &str,
+    at: Timestamp,
+    update: impl FnOnce(&mut VestingRateChange) -> DistributorResult<()>,
+) -> DistributorResult<()> {
+    let key = (asset_key.to_string(), at.seconds());
+    let mut change = VESTING_RATE_CHANGES
+        .may_load(storage, key.clone())?
+        .unwrap_or(VestingRateChange {
+            increase: Decimal256::zero(),
+            decrease: Decimal256::zero(),
+        });
+    update(&mut change)?;
+    VESTING_RATE_CHANGES.save(storage, key, &change)?;
+    Ok(())
+}
+
+/// Vests the stream up to the given time, applying its rate changes due by then.
+/// Returns the times of the rate changes applied, which the caller can remove.
+fn vest_stream(
+    storage: &dyn Storage,
+    asset_key: &str,
+    stream: &mut VestingStream,
+    now: Timestamp,
+) -> DistributorResult<Vec<u64>> {
+    let due_changes = VESTING_RATE_CHANGES
+        .prefix(asset_key.to_string())
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(now.seconds())),
+            Ascending,
+        )
+        .take(MAX_VESTING_RATE_CHANGES + 1)
+        .collect::<StdResult<Vec<(u64, VestingRateChange)>>>()?;
+
+    let all_changes_applied = due_changes.len() <= MAX_VESTING_RATE_CHANGES;
+
+    let mut applied_changes = vec![];
+
+    for (at, change) in due_changes.into_iter().take(MAX_VESTING_RATE_CHANGES) {
+        vest_stream_until(stream, Timestamp::from_seconds(at))?;
+        stream.rate = stream
+            .rate
+            .checked_add(change.increase)?
+            .checked_sub(change.decrease)?;
+        applied_changes.push(at);
+    }
+
+    if all_changes_applied {
+        vest_stream_until(stream, now)?;
+    }
+
+    let has_pending_changes = VESTING_RATE_CHANGES
+        .prefix(asset_key.to_string())
+        .keys(
+            storage,
+            applied_changes.last().map(|at| Bound::exclusive(*at)),
+            None,
+            Ascending,
+        )
+        .next()
+        .is_some();
+
+    // once everything finished vesting, whatever was lost to rounding the rates is vested too
+    if !has_pending_changes {
+        stream.rate = Decimal256::zero();
+        stream.vested = Decimal256::from_ratio(stream.unreleased, 1u8);
+    }
+
+    Ok(applied_changes)
+}
+
+fn vest_stream_until(stream: &mut VestingStream, until: Timestamp) -> DistributorResult<()> {
+    if until <= stream.vested_until {
+        return Ok(());
+    }
+
+    let elapsed = until.seconds() - stream.vested_until.seconds();
+    stream.vested = stream.vested.checked_add(
+        stream
+            .rate
+            .checked_mul(Decimal256::from_ratio(elapsed, 1u8))?,
+    )?;
+    stream.vested_until = until;
+
+    Ok(())
+}
+
+/// Adds rewards vested since the last release to the assets' global indices, spreading them
+/// over the assets' current total weights. Streams that were fully released are removed.
+///
+/// Has to be called before anything that reads global indices or changes users' weights, so that
+/// vested rewards are attributed to the users that held weight while they were vesting.
+/// The work done is bounded per asset, regardless of how many distributions are vesting.
+pub fn release_vested_rewards(storage: &mut dyn Storage, now: Timestamp) -> DistributorResult<()> {
+    let streams = VESTING_STREAMS
+        .range(storage, None, None, Ascending)
+        .collect::<StdResult<Vec<(String, VestingStream)>>>()?;
+
+    for (asset_key, mut stream) in streams {
+        let applied_changes = vest_stream(storage, &asset_key, &mut stream, now)?;
+        for at in applied_changes {
+            VESTING_RATE_CHANGES.remove(storage, (asset_key.clone(), at));
+        }
+
+        let total_weight = asset_total_weight(storage, asset_key.clone())?;
+
+        // with no one to attribute them to, vested rewards wait until there is some weight
+        let released = if total_weight.is_zero() {
+            Uint128::zero()
+        } else {
+            stream.releasable()
+        };
+
+        if !released.is_zero() {
+            let index_increment = index_increment(storage, &asset_key, released, total_weight)?;
+
+            match &stream.asset {
+                RewardAsset::Native { denom } => {
+                    let global_index = NATIVE_GLOBAL_INDICES
+                        .may_load(storage, denom.clone())?
+                        .unwrap_or_default();
+                    NATIVE_GLOBAL_INDICES.save(
+                        storage,
+                        denom.clone(),
+                        &global_index.checked_add(index_increment)?,
+                    )?;
+                }
+                RewardAsset::Cw20 { asset } => {
+                    let asset = Addr::unchecked(asset);
+                    let global_index = CW20_GLOBAL_INDICES
+                        .may_load(storage, asset.clone())?
+                        .unwrap_or_default();
+                    CW20_GLOBAL_INDICES.save(
+                        storage,
+                        asset,
+                        &global_index.checked_add(index_increment)?,
+                    )?;
+                }
+            }
+
+            stream.unreleased = stream.unreleased.checked_sub(released)?;
+            stream.vested = stream
+                .vested
+                .checked_sub(Decimal256::from_ratio(released, 1u8))?;
+        }
+
+        if stream.unreleased.is_zero() {
+            VESTING_STREAMS.remove(storage, asset_key);
+        } else {
+            VESTING_STREAMS.save(storage, asset_key, &stream)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads the asset's stream, vested up to the given time, without storing the result.
+fn load_vested_stream(
+    storage: &dyn Storage,
+    asset_key: &str,
+    now: Timestamp,
+) -> DistributorResult<Option<VestingStream>> {
+    let stream = VESTING_STREAMS.may_load(storage, asset_key.to_string())?;
+
+    match stream {
+        Some(mut stream) => {
+            vest_stream(storage, asset_key, &mut stream, now)?;
+            Ok(Some(stream))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Calculates how much the asset's global index will grow once rewards vested so far are released.
+/// Used by queries, which cannot release the rewards themselves.
+pub fn unreleased_index_increment(
+    storage: &dyn Storage,
+    asset: &RewardAsset,
+    now: Timestamp,
+) -> DistributorResult<Decimal256> {
+    let asset_key = reward_asset_key(asset);
+    let total_weight = asset_total_weight(storage, asset_key.clone())?;
+
+    if total_weight.is_zero() {
+        return Ok(Decimal256::zero());
+    }
+
+    match load_vested_stream(storage, &asset_key, now)? {
+        Some(stream) => Ok(index_increment(
+            storage,
+            &asset_key,
+            stream.releasable(),
+            total_weight,
+        )?),
+        None => Ok(Decimal256::zero()),
+    }
+}
+
+/// Shows, for each of the given assets, how much of the user's rewards has vested, and how much
+/// of the still-vesting rewards would go to the user at their current weight.
+pub fn query_user_vesting_rewards(
+    qctx: QueryContext,
+    params: UserRewardsParams,
+) -> DistributorResult<UserVestingRewardsResponse> {
+    let user = qctx.deps.api.addr_validate(&params.user)?;
+
+    let effective_user_weight = EFFECTIVE_USER_WEIGHTS
+        .may_load(qctx.deps.storage, user.clone())?
+        .unwrap_or_default();
+
+    let user_unvested = |asset: RewardAsset| -> DistributorResult<Uint128> {
+        let asset_key = reward_asset_key(&asset);
+        let total_weight = asset_total_weight(qctx.deps.storage, asset_key.clone())?;
+        if total_weight.is_zero() {
+            return Ok(Uint128::zero());
+        }
+        let user_weight =
+            asset_user_weight(qctx.deps.storage, asset_key.clone(), effective_user_weight)?;
+        let unvested = match load_vested_stream(qctx.deps.storage, &asset_key, qctx.env.block.time)?
+        {
+            Some(stream) => stream.unreleased.checked_sub(stream.releasable())?,
+            None => Uint128::zero(),
+        };
+        Ok(unvested.checked_multiply_ratio(user_weight, total_weight)?)
+    };
+
+    let user_rewards = query_user_rewards(qctx.clone(), params)?;
+
+    let native_rewards = user_rewards
+        .native_rewards
+        .into_iter()
+        .map(|reward| {
+            Ok(NativeVestingReward {
+                unvested: user_unvested(RewardAsset::Native {
+                    denom: reward.denom.clone(),
+                })?,
+                denom: reward.denom,
+                vested: reward.amount,
+            })
+        })
+        .collect::<DistributorResult<Vec<NativeVestingReward>>>()?;
+
+    let cw20_rewards = user_rewards
+        .cw20_rewards
+        .into_iter()
+        .map(|reward| {
+            Ok(Cw20VestingReward {
+                unvested: user_unvested(RewardAsset::Cw20 {
+                    asset: reward.asset.clone(),
+                })?,
+                asset: reward.asset,
+                vested: reward.amount,
+            })
+        })
+        .collect::<DistributorResult<Vec<Cw20VestingReward>>>()?;
+
+    Ok(UserVestingRewardsResponse {
+        native_rewards,
+        cw20_rewards,
+    })
+}
@@ -0,0 +1,118 @@
+use crate::rate_limit::{cw20_asset_key, native_asset_key};
+use crate::state::{
+    ADMIN, CW20_GLOBAL_INDICES, EFFECTIVE_TOTAL_WEIGHT, ELIGIBLE_USERS_COUNT, NATIVE_GLOBAL_INDICES,
+};
+use common::cw::{Context, QueryContext};
+use cosmwasm_std::{Response, Storage, Uint128};
+use cw_storage_plus::Map;
+use funds_distributor_api::api::{
+    AssetWeightBasisParams, AssetWeightBasisResponse, RewardAsset, SetAssetWeightBasisMsg,
+    WeightBasis,
+};
+use funds_distributor_api::error::DistributorError::{AssetAlreadyDistributed, Unauthorized};
+use funds_distributor_api::error::DistributorResult;
+use funds_distributor_api::response::execute_set_asset_weight_basis_response;
+use WeightBasis::{EqualPerUser, ProportionalToWeight};
+
+/// Weight bases set for reward assets, keyed by the asset's key.
+/// Assets without a weight basis are distributed proportionally to users' weights.
+const ASSET_WEIGHT_BASES: Map<String, WeightBasis> = Map::new("asset_weight_bases");
+
+/// Sets the weight basis of an asset. Only the admin can do this, and only before the asset
+/// is first distributed, since the asset's global index is expressed in units of its basis.
+pub fn set_asset_weight_basis(
+    ctx: &mut Context,
+    msg: SetAssetWeightBasisMsg,
+) -> DistributorResult<Response> {
+    let admin = ADMIN.load(ctx.deps.storage)?;
+
+    if ctx.info.sender != admin {
+        return Err(Unauthorized);
+    }
+
+    let (asset_key, already_distributed) = match msg.asset {
+        RewardAsset::Native { denom } => (
+            native_asset_key(&denom),
+            NATIVE_GLOBAL_INDICES.has(ctx.deps.storage, denom),
+        ),
+        RewardAsset::Cw20 { asset } => {
+            let asset = ctx.deps.api.addr_validate(&asset)?;
+            (
+                cw20_asset_key(asset.as_ref()),
+                CW20_GLOBAL_INDICES.has(ctx.deps.storage, asset),
+            )
+        }
+    };
+
+    if already_distributed {
+        return Err(AssetAlreadyDistributed);
+    }
+
+    ASSET_WEIGHT_BASES.save(ctx.deps.storage, asset_key.clone(), &msg.weight_basis)?;
+
+    let weight_basis = match msg.weight_basis {
+        ProportionalToWeight => "proportional_to_weight",
+        EqualPerUser => "equal_per_user",
+    };
+
+    Ok(execute_set_asset_weight_basis_response(
+        asset_key,
+        weight_basis.to_string(),
+    ))
+}
+
+pub fn asset_weight_basis(
+    storage: &dyn Storage,
+    asset_key: String,
+) -> DistributorResult<WeightBasis> {
+    let weight_basis = ASSET_WEIGHT_BASES
+        .may_load(storage, asset_key)?
+        .unwrap_or(ProportionalToWeight);
+
+    Ok(weight_basis)
+}
+
+/// Weight of the user in the asset's distributions, given their effective weight.
+/// When distributed equally per user, every eligible user has a weight of 1.
+pub fn asset_user_weight(
+    storage: &dyn Storage,
+    asset_key: String,
+    effective_user_weight: Uint128,
+) -> DistributorResult<Uint128> {
+    let user_weight = match asset_weight_basis(storage, asset_key)? {
+        ProportionalToWeight => effective_user_weight,
+        EqualPerUser if effective_user_weight.is_zero() => Uint128::zero(),
+        EqualPerUser => Uint128::one(),
+    };
+
+    Ok(user_weight)
+}
+
+/// Total weight that the asset's distributions are divided by.
+/// When distributed equally per user, that is the number of users eligible for rewards.
+pub fn asset_total_weight(storage: &dyn Storage, asset_key: String) -> DistributorResult<Uint128> {
+    let total_weight = match asset_weight_basis(storage, asset_key)? {
+        ProportionalToWeight => EFFECTIVE_TOTAL_WEIGHT
+            .may_load(storage)?
+            .unwrap_or_default(),
+        EqualPerUser => Uint128::from(ELIGIBLE_USERS_COUNT.may_load(storage)?.unwrap_or_default()),
+    };
+
+    Ok(total_weight)
+}
+
+pub fn query_asset_weight_basis(
+    qctx: QueryContext,
+    params: AssetWeightBasisParams,
+) -> DistributorResult<AssetWeightBasisResponse> {
+    let asset_key = match params.asset {
+        RewardAsset::Native { denom } => native_asset_key(&denom),
+        RewardAsset::Cw20 { asset } => {
+            cw20_asset_key(qctx.deps.api.addr_validate(&asset)?.as_ref())
+        }
+    };
+
+    let weight_basis = asset_weight_basis(qctx.deps.storage, asset_key)?;
+
+    Ok(AssetWeightBasisResponse { weight_basis })
+}
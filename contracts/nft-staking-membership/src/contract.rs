@@ -3,6 +3,7 @@ use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
 };
 use cw2::set_contract_version;
+use membership_common::unlocking_extensions::extend_unlocking;
 use membership_common::weight_change_hooks::{add_weight_change_hook, remove_weight_change_hook};
 use nft_staking_api::error::NftStakingResult;
 use nft_staking_api::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
@@ -49,6 +50,7 @@ pub fn execute(
         ExecuteMsg::ReceiveNft(msg) => receive_nft(ctx, msg)?,
         ExecuteMsg::AddWeightChangeHook(msg) => add_weight_change_hook(ctx, msg)?,
         ExecuteMsg::RemoveWeightChangeHook(msg) => remove_weight_change_hook(ctx, msg)?,
+        ExecuteMsg::ExtendUnlocking(msg) => extend_unlocking(ctx, msg)?,
     };
 
     Ok(response)
@@ -3,6 +3,7 @@ use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
 };
 use cw2::set_contract_version;
+use membership_common::unlocking_extensions::extend_unlocking;
 use membership_common::weight_change_hooks::{add_weight_change_hook, remove_weight_change_hook};
 use token_staking_api::error::TokenStakingResult;
 use token_staking_api::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
@@ -48,6 +49,7 @@ pub fn execute(
         ExecuteMsg::Receive(msg) => receive_cw20(ctx, msg)?,
         ExecuteMsg::AddWeightChangeHook(msg) => add_weight_change_hook(ctx, msg)?,
         ExecuteMsg::RemoveWeightChangeHook(msg) => remove_weight_change_hook(ctx, msg)?,
+        ExecuteMsg::ExtendUnlocking(msg) => extend_unlocking(ctx, msg)?,
     };
 
     Ok(response)
@@ -5,8 +5,8 @@ use crate::api::{
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw_utils::Duration;
 use membership_common_api::api::{
-    MembersParams, MembersResponse, TotalWeightParams, TotalWeightResponse, UserWeightParams,
-    UserWeightResponse, WeightChangeHookMsg,
+    ExtendUnlockingMsg, MembersParams, MembersResponse, TotalWeightParams, TotalWeightResponse,
+    UserWeightParams, UserWeightResponse, WeightChangeHookMsg,
 };
 
 #[cw_serde]
@@ -25,6 +25,7 @@ pub enum ExecuteMsg {
     UpdateUnlockingPeriod(UpdateUnlockingPeriodMsg),
     AddWeightChangeHook(WeightChangeHookMsg),
     RemoveWeightChangeHook(WeightChangeHookMsg),
+    ExtendUnlocking(ExtendUnlockingMsg),
 }
 
 #[cw_serde]
@@ -380,6 +380,9 @@ pub enum ProposalStatus {
     Passed,
     Rejected,
     Executed,
+    Vetoed,
+    ConditionNotMet,
+    ExecutionFailed,
 }
 
 #[cw_serde]
@@ -1,9 +1,9 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
 use poll_engine_api::api::{
-    CastVoteParams, CreatePollParams, EndPollParams, PollId, PollParams, PollResponse,
-    PollStatusResponse, PollVoterParams, PollVoterResponse, PollVotersParams, PollVotersResponse,
-    PollsParams, PollsResponse, UpdateVotesParams, VoterParams, VoterResponse,
+    CastVoteParams, CreatePollParams, EndPollParams, ExtendPollParams, PollId, PollParams,
+    PollResponse, PollStatusResponse, PollVoterParams, PollVoterResponse, PollVotersParams,
+    PollVotersResponse, PollsParams, PollsResponse, UpdateVotesParams, VoterParams, VoterResponse,
 };
 
 #[cw_serde]
@@ -17,6 +17,7 @@ pub enum ExecuteMsg {
     CastVote(CastVoteParams),
     UpdateVotes(UpdateVotesParams),
     EndPoll(EndPollParams),
+    ExtendPoll(ExtendPollParams),
 }
 
 #[cw_serde]
@@ -1,7 +1,7 @@
 use common::commons::ModifyValue;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, BlockInfo, Decimal, Timestamp, Uint128, Uint64};
-use cw_asset::{AssetInfoUnchecked, AssetUnchecked};
+use cosmwasm_std::{Addr, Binary, BlockInfo, CosmosMsg, Decimal, Timestamp, Uint128, Uint64};
+use cw_asset::{Asset, AssetInfo, AssetInfoUnchecked, AssetUnchecked};
 use cw_utils::{Duration, Expiration};
 use enterprise_outposts_api::api::{DeployCrossChainTreasuryMsg, RemoteTreasuryTarget};
 use enterprise_protocol::api::{UpdateMetadataMsg, UpgradeDaoMsg};
@@ -10,7 +10,7 @@ use nft_staking_api::api::NftTokenId;
 use poll_engine_api::api::{Vote, VoteOutcome};
 use serde_with::serde_as;
 use std::collections::BTreeMap;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
 pub type ProposalId = u64;
 
@@ -23,6 +23,15 @@ pub struct ProposalInfo {
     pub earliest_execution: Option<Timestamp>,
     pub proposal_deposit: Option<ProposalDeposit>,
     pub proposal_actions: Vec<ProposalAction>,
+    /// How the proposal's actions are executed. If None, they are executed atomically.
+    pub execution_atomicity: Option<ExecutionAtomicity>,
+    /// Condition that has to hold for the proposal's actions to be executed.
+    pub execution_condition: Option<ExecutionCondition>,
+    /// Maximum amounts of assets the proposal's actions can move out of the treasury.
+    /// If None, the proposal's execution is not budgeted.
+    pub execution_budget: Option<Vec<Asset>>,
+    /// Links to the proposal's off-chain context, e.g. its forum discussion.
+    pub metadata: Option<ProposalMetadata>,
 }
 
 impl ProposalInfo {
@@ -47,7 +56,8 @@ pub struct GovConfig {
     /// required to veto the proposal.
     /// If None, will default to the threshold set for all proposal options.
     pub veto_threshold: Option<Decimal>,
-    /// Duration of proposals before they end, expressed in seconds
+    /// Duration of proposals before they end, expressed in seconds.
+    /// Changing it does not affect the end of proposals that are already in progress.
     pub vote_duration: u64,
     // TODO: change from u64 to Duration
     /// Optional minimum amount of DAO's governance unit to be required to create a deposit.
@@ -55,6 +65,247 @@ pub struct GovConfig {
     /// If set to true, this will allow DAOs to execute proposals that have reached quorum and
     /// threshold, even before their voting period ends.
     pub allow_early_proposal_execution: bool,
+    /// How to handle actions of a passed proposal that are no longer valid at the time of
+    /// its execution.
+    /// If None, will default to failing the execution of the whole proposal.
+    pub invalid_action_policy: Option<InvalidProposalActionPolicy>,
+    /// Who is allowed to create general proposals.
+    /// If None, will default to any member being able to create proposals.
+    pub proposal_creation_policy: Option<ProposalCreationPolicy>,
+    /// Maximum number of general proposals a single proposer can have in progress at once.
+    /// If None, there is no limit.
+    pub max_open_proposals_per_proposer: Option<u32>,
+    /// Bounds that the veto threshold (or the threshold, if veto threshold is None) must stay
+    /// within. Cannot be changed through governance.
+    /// If None, the veto threshold is not bounded.
+    pub veto_threshold_bounds: Option<VetoThresholdBounds>,
+    /// How to treat proposals adding the DAO's own governance token to the asset whitelist.
+    /// If None, will default to rejecting such proposals.
+    pub governance_token_whitelist_policy: Option<GovernanceTokenWhitelistPolicy>,
+    /// Where forfeited funds, such as confiscated proposal deposits, are sent.
+    /// If None, will default to the DAO's treasury.
+    pub treasury_address: Option<Addr>,
+    /// Format that proposal descriptions are validated against when proposals are created.
+    /// If None, descriptions are not validated.
+    pub description_format: Option<DescriptionFormat>,
+    /// Assets that proposal deposits can be made in, each with its own minimum amount.
+    /// When set, takes precedence over the minimum deposit, and deposits in any other asset
+    /// are rejected.
+    /// If None, deposits are made in the DAO's membership asset, with the minimum deposit.
+    pub accepted_deposit_assets: Option<Vec<AcceptedDepositAsset>>,
+    /// If set to true, a vote that makes a proposal pass will also execute it, as long as the
+    /// proposal can be executed at that point (e.g. is not in an execution delay or a veto
+    /// challenge window).
+    /// In practice, this applies to council proposals, and to general proposals in multisig
+    /// DAOs. General proposals are only auto-executed if early proposal execution is allowed,
+    /// and outside of multisig DAOs the vote that makes one pass starts an execution delay of
+    /// a tenth of the vote duration, so the proposal is only executed by the next vote cast
+    /// after the delay. Proposals that only pass once voting ends are never auto-executed,
+    /// and are left to be executed as usual.
+    /// If None, will default to false.
+    pub auto_execute_on_pass: Option<bool>,
+    /// Minimum time between creating general proposals containing actions of the same type.
+    /// If None, there are no cooldowns.
+    pub action_type_cooldowns: Option<Vec<ActionTypeCooldown>>,
+    /// Who can extend proposals' voting periods, and by how much.
+    /// If None, voting periods cannot be extended.
+    pub voting_extension: Option<VotingExtensionConfig>,
+    /// Minimum amount of 'yes' votes (in voting weight) a general proposal needs to pass,
+    /// even if its quorum and threshold are reached.
+    /// If None, there is no minimum.
+    pub minimum_yes_votes: Option<Uint128>,
+    /// Extensions of the unlocking period applied to everyone who voted on a general proposal
+    /// containing actions of the given types, once the proposal passes. Discourages voting on
+    /// sensitive proposals and then immediately exiting the DAO.
+    /// Not available for multisig DAOs, since they have no unlocking period.
+    /// If None, voters' unlocking periods are never extended.
+    pub voter_unlocking_extensions: Option<Vec<VoterUnlockingExtension>>,
+    /// Bounds on the voting duration of general proposals created with an absolute expiration,
+    /// instead of expiring after the vote duration.
+    /// If None, proposals cannot be created with an absolute expiration.
+    pub proposal_expiration_bounds: Option<ProposalExpirationBounds>,
+    /// If set to true, descriptions and actions of proposals are redacted when querying proposals
+    /// for viewers that are not members of the DAO. This is best-effort only, intended for
+    /// front-ends to honor, since all contract state is public on chain.
+    /// If None, will default to false.
+    pub private_proposals: Option<bool>,
+    /// Maximum number of assets a single funds distribution action can distribute, to keep its
+    /// execution within gas limits.
+    /// If None, there is no limit.
+    pub max_distribution_assets: Option<u32>,
+    /// Upper limits on proposals' execution budgets, per asset. When set, proposals moving assets
+    /// out of the treasury have to be created with an execution budget within these limits,
+    /// and actions whose outflow cannot be budgeted, such as arbitrary messages, are not allowed.
+    /// If None, execution budgets are optional and unbounded.
+    pub max_execution_budget: Option<Vec<Asset>>,
+    /// Contract notified of every newly created proposal, e.g. to relay it off-chain.
+    /// A failing notification never prevents the proposal from being created.
+    /// If None, no notifications are sent.
+    pub proposal_notifier: Option<Addr>,
+    /// Quorum and threshold overrides for general proposals containing actions of given types.
+    /// A proposal is resolved using the requirements of its action with the highest threshold,
+    /// with actions without an override requiring the DAO's quorum and threshold.
+    /// If None, all general proposals use the DAO's quorum and threshold.
+    pub action_type_thresholds: Option<Vec<ActionTypeThreshold>>,
+    /// If set to true, deposits of proposals that resolve favorably are not sent back when
+    /// the proposal is executed or ended, but marked refundable, and their depositors claim them
+    /// through ClaimDeposit. This keeps a misbehaving deposit asset from failing the resolution.
+    /// If None, will default to false.
+    pub manual_deposit_refunds: Option<bool>,
+    /// Minimum council size and threshold a council needs to be allowed actions of given types,
+    /// to keep small councils from unilaterally taking high-risk actions such as upgrading
+    /// the DAO. Checked whenever a council is set.
+    /// If None, councils can be allowed any action types allowed in council proposals.
+    pub council_action_requirements: Option<Vec<CouncilActionRequirement>>,
+    /// Whether members can change their vote on a general proposal to another option while
+    /// the proposal is still being voted on. Voting for the same option again only refreshes
+    /// the vote's weight, and is always allowed.
+    /// If None, will default to true, which is how votes behaved before this was configurable.
+    pub allow_vote_changes: Option<bool>,
+    /// If set to true, users that became members after a general proposal started cannot vote
+    /// on it. Only covers users that joined after membership changes started being tracked.
+    /// If None, will default to false, which is how votes behaved before this was configurable.
+    pub restrict_late_joiner_votes: Option<bool>,
+}
+
+#[cw_serde]
+pub struct VotingExtensionConfig {
+    /// Maximum total time a single proposal's voting period can be extended by,
+    /// expressed in seconds
+    pub max_total_extension: u64,
+    /// Addresses allowed to extend voting periods, in addition to the council members
+    pub extenders: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct VotingExtensionConfigUnchecked {
+    /// Maximum total time a single proposal's voting period can be extended by,
+    /// expressed in seconds
+    pub max_total_extension: u64,
+    /// Addresses allowed to extend voting periods, in addition to the council members
+    pub extenders: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ActionTypeCooldown {
+    pub action_type: ProposalActionType,
+    /// Cooldown after a proposal with this action type is created, expressed in seconds
+    pub cooldown: u64,
+}
+
+#[cw_serde]
+pub struct ActionTypeThreshold {
+    pub action_type: ProposalActionType,
+    /// Quorum required for proposals containing actions of this type
+    pub quorum: Decimal,
+    /// Threshold required for proposals containing actions of this type
+    pub threshold: Decimal,
+}
+
+#[cw_serde]
+pub struct CouncilActionRequirement {
+    pub action_type: ProposalActionType,
+    /// Minimum number of members a council allowed this action type has to have
+    pub minimum_members: u32,
+    /// Minimum threshold a council allowed this action type has to have
+    pub minimum_threshold: Decimal,
+}
+
+#[cw_serde]
+pub struct ProposalExpirationBounds {
+    /// Shortest allowed voting duration, expressed in seconds
+    pub min_duration: u64,
+    /// Longest allowed voting duration, expressed in seconds
+    pub max_duration: u64,
+}
+
+#[cw_serde]
+pub struct VoterUnlockingExtension {
+    pub action_type: ProposalActionType,
+    /// How long voters' unstaked assets stay locked after the proposal passes,
+    /// expressed in seconds
+    pub extension: u64,
+}
+
+#[cw_serde]
+pub struct AcceptedDepositAsset {
+    /// Native or CW20 asset that deposits can be made in
+    pub asset: AssetInfo,
+    /// Minimum deposit amount in this asset required to create a proposal
+    pub minimum_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct AcceptedDepositAssetUnchecked {
+    /// Native or CW20 asset that deposits can be made in
+    pub asset: AssetInfoUnchecked,
+    /// Minimum deposit amount in this asset required to create a proposal
+    pub minimum_amount: Uint128,
+}
+
+#[cw_serde]
+pub enum DescriptionFormat {
+    /// Descriptions are rendered as markdown - HTML tags and script URLs are not allowed
+    Markdown,
+    /// Descriptions are plain text - in addition to what is not allowed in markdown,
+    /// markdown links and images are not allowed either
+    Plaintext,
+}
+
+#[cw_serde]
+pub enum GovernanceTokenWhitelistPolicy {
+    /// Proposals whitelisting the governance token are rejected
+    Reject,
+    /// Proposals whitelisting the governance token are allowed, with a warning attribute
+    /// added when they are created
+    Warn,
+}
+
+#[cw_serde]
+pub struct VetoThresholdBounds {
+    /// Lowest allowed veto threshold. If None, there is no lower bound.
+    pub floor: Option<Decimal>,
+    /// Highest allowed veto threshold. If None, there is no upper bound.
+    pub ceiling: Option<Decimal>,
+}
+
+#[cw_serde]
+pub enum InvalidProposalActionPolicy {
+    /// Execution of the proposal fails if any of its actions is invalid
+    FailAll,
+    /// Invalid actions are skipped, and the remaining actions are executed
+    SkipInvalid,
+}
+
+#[cw_serde]
+pub enum ProposalCreationPolicy {
+    /// Any member of the DAO can create proposals
+    AnyMember,
+    /// Only users with at least the given voting weight can create proposals
+    MinWeight(Uint128),
+    /// Only the given addresses can create proposals
+    Allowlist(Vec<Addr>),
+}
+
+/// A council that can veto general proposals that passed, during a challenge window
+/// after their voting period ends.
+#[cw_serde]
+pub struct VetoCouncilSpec {
+    /// Addresses of veto council members. Each member has equal voting power.
+    pub members: Vec<String>,
+    /// Portion of veto council members that have to veto a proposal for it to be vetoed
+    pub threshold: Decimal,
+    /// Duration of the challenge window following the end of a proposal's voting period,
+    /// expressed in seconds. Passing proposals cannot be executed before their challenge window
+    /// ends, while rejected ones can be executed right away.
+    pub challenge_window: u64,
+}
+
+#[cw_serde]
+pub struct VetoCouncilConfig {
+    pub members: Vec<Addr>,
+    pub threshold: Decimal,
+    pub challenge_window: u64,
 }
 
 #[cw_serde]
@@ -66,22 +317,48 @@ pub struct CouncilGovConfig {
 
 #[cw_serde]
 pub struct DaoCouncilSpec {
-    /// Addresses of council members. Each member has equal voting power.
+    /// Addresses of council members.
     pub members: Vec<String>,
+    /// Voting weights of council members, in the same order as the members.
+    /// If None, each member has equal voting power.
+    pub weights: Option<Vec<Uint128>>,
     /// Portion of total available votes cast in a proposal to consider it valid
     /// e.g. quorum of 30% means that 30% of all available votes have to be cast in the proposal,
-    /// otherwise it fails automatically when it expires
+    /// otherwise it fails automatically when it expires.
+    /// Applies to council proposals instead of the DAO's general quorum.
     pub quorum: Decimal,
     /// Portion of votes assigned to a single option from all the votes cast in the given proposal
     /// required to determine the 'winning' option
-    /// e.g. 51% threshold means that an option has to have at least 51% of the cast votes to win
+    /// e.g. 51% threshold means that an option has to have at least 51% of the cast votes to win.
+    /// Applies to council proposals instead of the DAO's general threshold.
     pub threshold: Decimal,
     /// Proposal action types allowed in proposals that are voted on by the council.
     /// Effectively defines what types of actions council can propose and vote on.
+    /// Council proposals are re-checked against these when executed, so narrowing them
+    /// prevents execution of pending council proposals with action types no longer allowed.
     /// If None, will default to a predefined set of actions.
     pub allowed_proposal_action_types: Option<Vec<ProposalActionType>>,
 }
 
+impl DaoCouncilSpec {
+    /// Pairs each council member with their voting weight.
+    pub fn member_weights(&self) -> Vec<(String, Uint128)> {
+        match &self.weights {
+            Some(weights) => self
+                .members
+                .iter()
+                .cloned()
+                .zip(weights.iter().copied())
+                .collect(),
+            None => self
+                .members
+                .iter()
+                .map(|member| (member.clone(), Uint128::one()))
+                .collect(),
+        }
+    }
+}
+
 #[cw_serde]
 pub struct MultisigMember {
     pub address: String,
@@ -102,6 +379,73 @@ pub struct CreateProposalMsg {
     #[serde(skip_serializing_if = "Option::is_none")]
     // this flag is here to allow the facade v2 to work with gov controller <v1.1.0
     pub deposit_owner: Option<String>,
+    /// Whether the proposal's actions all succeed or fail together when executed.
+    /// If None, will default to Atomic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_atomicity: Option<ExecutionAtomicity>,
+    /// Absolute moment at which the proposal expires, overriding the vote duration.
+    /// Has to be a time, and within the DAO's proposal expiration bounds.
+    /// If None, the proposal expires after the vote duration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Expiration>,
+    /// Condition checked when the proposal is executed. If it does not hold, the proposal's
+    /// actions are not executed and the proposal ends up with ConditionNotMet status.
+    /// If None, the actions are executed unconditionally.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_condition: Option<ExecutionCondition>,
+    /// Maximum amounts of assets that the proposal's actions can move out of the treasury,
+    /// through funding requests and funds distributions. Assets not in the budget cannot be
+    /// moved at all. Execution fails if the actions would move more than the budget allows.
+    /// If None, the execution is not budgeted, unless the DAO requires it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_budget: Option<Vec<AssetUnchecked>>,
+    /// Links to the proposal's off-chain context, e.g. its forum discussion.
+    /// Stored with the proposal and returned with it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ProposalMetadata>,
+}
+
+/// Durable on-chain pointers to a proposal's off-chain context.
+#[cw_serde]
+pub struct ProposalMetadata {
+    /// URL of the proposal's discussion, e.g. a forum thread. Has to be an http(s) URL.
+    pub discussion_url: Option<String>,
+    /// URIs of documents attached to the proposal. Each has to be an http(s), ipfs or ar URI.
+    pub attachments: Vec<String>,
+}
+
+/// Predicate evaluated by querying a contract. It holds if the contract's response to the query
+/// is the same JSON value as the expected result.
+#[cw_serde]
+pub struct ExecutionCondition {
+    /// Address of the contract to query
+    pub contract: String,
+    /// JSON-encoded query message sent to the contract
+    pub query_msg: String,
+    /// JSON-encoded response expected from the contract. It is compared as a parsed value,
+    /// so formatting and the order of object keys do not matter. Numbers have to be integers.
+    /// A failing query never matches.
+    pub expected_result: String,
+}
+
+#[cw_serde]
+pub enum ExecutionAtomicity {
+    /// All actions are executed together - if any of them fails, none of them take effect
+    Atomic,
+    /// Each action is executed on its own - actions that fail don't affect the others.
+    /// Outcome of each action is recorded.
+    BestEffort,
+}
+
+#[cw_serde]
+pub struct CreateProposalsMsg {
+    pub proposals: Vec<CreateProposalMsg>,
+}
+
+#[cw_serde]
+pub struct CreateProposalsResponse {
+    /// IDs of the created proposals, in the order they were given in
+    pub proposal_ids: Vec<ProposalId>,
 }
 
 #[cw_serde]
@@ -147,7 +491,7 @@ impl ProposalDeposit {
 
 // TODO: try to find a (Rust) language construct allowing us to merge this with ProposalAction
 #[cw_serde]
-#[derive(Display)]
+#[derive(Display, EnumIter)]
 pub enum ProposalActionType {
     UpdateMetadata,
     UpdateGovConfig,
@@ -163,9 +507,31 @@ pub enum ProposalActionType {
     DistributeFunds,
     UpdateMinimumWeightForRewards,
     DeployCrossChainTreasury,
+    BurnTokens,
+    UpdateVetoCouncil,
+    MintTokenfactoryDenom,
+    SetDenomAdmin,
+    UpdateEnterpriseFactory,
+    SetSelfAdmin,
+    UpdateCouncilPermissions,
+    GrantSpendingAllowance,
+    RevokeSpendingAllowance,
+    DistributeFromTreasury,
+    EditCouncilMembers,
+    ExecuteInterchainAccountMsgs,
+    ManageAssetBundles,
+    UpdateAssetWhitelistWithBundle,
+    ScheduleCouncilElections,
+    UpdateDistributionFee,
+    SetDistributorClaimsPaused,
+    TransferNft,
+    SetDistributorEmissionSchedule,
+    DistributeToVoters,
+    RegisterInterchainAccount,
 }
 
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ProposalAction {
     UpdateMetadata(UpdateMetadataMsg),
     UpdateGovConfig(UpdateGovConfigMsg),
@@ -181,6 +547,27 @@ pub enum ProposalAction {
     DistributeFunds(DistributeFundsMsg),
     UpdateMinimumWeightForRewards(UpdateMinimumWeightForRewardsMsg),
     DeployCrossChainTreasury(DeployCrossChainTreasuryMsg),
+    BurnTokens(BurnTokensMsg),
+    UpdateVetoCouncil(UpdateVetoCouncilMsg),
+    MintTokenfactoryDenom(MintTokenfactoryDenomMsg),
+    SetDenomAdmin(SetDenomAdminMsg),
+    UpdateEnterpriseFactory(UpdateEnterpriseFactoryMsg),
+    SetSelfAdmin(SetSelfAdminMsg),
+    UpdateCouncilPermissions(UpdateCouncilPermissionsMsg),
+    GrantSpendingAllowance(GrantSpendingAllowanceMsg),
+    RevokeSpendingAllowance(RevokeSpendingAllowanceMsg),
+    DistributeFromTreasury(DistributeFromTreasuryMsg),
+    EditCouncilMembers(EditCouncilMembersMsg),
+    ExecuteInterchainAccountMsgs(ExecuteInterchainAccountMsgsMsg),
+    ManageAssetBundles(ManageAssetBundlesMsg),
+    UpdateAssetWhitelistWithBundle(UpdateAssetWhitelistWithBundleMsg),
+    ScheduleCouncilElections(ScheduleCouncilElectionsMsg),
+    UpdateDistributionFee(UpdateDistributionFeeMsg),
+    SetDistributorClaimsPaused(SetDistributorClaimsPausedMsg),
+    TransferNft(TransferNftMsg),
+    SetDistributorEmissionSchedule(SetDistributorEmissionScheduleMsg),
+    DistributeToVoters(DistributeToVotersMsg),
+    RegisterInterchainAccount(RegisterInterchainAccountMsg),
 }
 
 #[cw_serde]
@@ -192,6 +579,61 @@ pub struct UpdateGovConfigMsg {
     pub unlocking_period: ModifyValue<Duration>,
     pub minimum_deposit: ModifyValue<Option<Uint128>>,
     pub allow_early_proposal_execution: ModifyValue<bool>,
+    #[serde(default)]
+    pub invalid_action_policy: ModifyValue<Option<InvalidProposalActionPolicy>>,
+    #[serde(default)]
+    pub proposal_creation_policy: ModifyValue<Option<ProposalCreationPolicy>>,
+    #[serde(default)]
+    pub max_open_proposals_per_proposer: ModifyValue<Option<u32>>,
+    #[serde(default)]
+    pub governance_token_whitelist_policy: ModifyValue<Option<GovernanceTokenWhitelistPolicy>>,
+    #[serde(default)]
+    pub treasury_address: ModifyValue<Option<String>>,
+    #[serde(default)]
+    pub description_format: ModifyValue<Option<DescriptionFormat>>,
+    #[serde(default)]
+    pub accepted_deposit_assets: ModifyValue<Option<Vec<AcceptedDepositAssetUnchecked>>>,
+    #[serde(default)]
+    pub auto_execute_on_pass: ModifyValue<Option<bool>>,
+    #[serde(default)]
+    pub action_type_cooldowns: ModifyValue<Option<Vec<ActionTypeCooldown>>>,
+    #[serde(default)]
+    pub voting_extension: ModifyValue<Option<VotingExtensionConfigUnchecked>>,
+    #[serde(default)]
+    pub minimum_yes_votes: ModifyValue<Option<Uint128>>,
+    #[serde(default)]
+    pub voter_unlocking_extensions: ModifyValue<Option<Vec<VoterUnlockingExtension>>>,
+    #[serde(default)]
+    pub proposal_expiration_bounds: ModifyValue<Option<ProposalExpirationBounds>>,
+    #[serde(default)]
+    pub private_proposals: ModifyValue<Option<bool>>,
+    #[serde(default)]
+    pub max_distribution_assets: ModifyValue<Option<u32>>,
+    #[serde(default)]
+    pub max_execution_budget: ModifyValue<Option<Vec<AssetUnchecked>>>,
+    #[serde(default)]
+    pub proposal_notifier: ModifyValue<Option<String>>,
+    #[serde(default)]
+    pub action_type_thresholds: ModifyValue<Option<Vec<ActionTypeThreshold>>>,
+    #[serde(default)]
+    pub manual_deposit_refunds: ModifyValue<Option<bool>>,
+    #[serde(default)]
+    pub council_action_requirements: ModifyValue<Option<Vec<CouncilActionRequirement>>>,
+    #[serde(default)]
+    pub allow_vote_changes: ModifyValue<Option<bool>>,
+    #[serde(default)]
+    pub restrict_late_joiner_votes: ModifyValue<Option<bool>>,
+    /// Gov config that the changes were drafted against, usually queried when drafting them.
+    /// If set, each field being changed has to still have the value it has in this config,
+    /// otherwise the changes are rejected. The unlocking period is checked against
+    /// `expected_unlocking_period` instead.
+    /// If None, changes are applied regardless of the current values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_current_config: Option<Box<GovConfig>>,
+    /// Unlocking period that the changes were drafted against. If set and the unlocking period
+    /// is being changed, the DAO's current unlocking period has to still be this one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_unlocking_period: Option<Duration>,
 }
 
 #[cw_serde]
@@ -199,6 +641,80 @@ pub struct UpdateCouncilMsg {
     pub dao_council: Option<DaoCouncilSpec>,
 }
 
+#[cw_serde]
+pub struct EditCouncilMembersMsg {
+    /// Addresses to add to the council, each with a voting weight of 1.
+    /// Have to be members of the DAO, and not already on the council.
+    pub add: Vec<String>,
+    /// Addresses of current council members to remove from the council.
+    pub remove: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ScheduleCouncilElectionsMsg {
+    /// New council election schedule. If None, council elections are no longer held.
+    pub schedule: Option<CouncilElectionSchedule>,
+}
+
+/// Cadence of recurring council elections. Once an election is due, DAO members nominate
+/// candidates for a while. Once nominations close, anyone can trigger the election, which
+/// creates a general proposal to replace the council with the best supported candidates.
+#[cw_serde]
+pub struct CouncilElectionSchedule {
+    /// Time between council elections, in seconds.
+    /// The first election is due this long after the schedule is set.
+    pub interval: u64,
+    /// Number of council seats, i.e. the number of candidates elected in each election
+    pub seats: u32,
+    /// Time after an election is due during which members can nominate candidates, in seconds
+    pub nomination_period: u64,
+}
+
+#[cw_serde]
+pub struct NominateCouncilCandidateMsg {
+    /// Member of the DAO to nominate for a council seat
+    pub candidate: String,
+}
+
+#[cw_serde]
+pub struct TriggerCouncilElectionMsg {}
+
+#[cw_serde]
+pub struct ClaimDepositMsg {
+    pub proposal_id: ProposalId,
+}
+
+#[cw_serde]
+pub struct ExtendVoterUnlockingMsg {
+    pub proposal_id: ProposalId,
+    /// Maximum number of voters whose unlocking is extended in this execution
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct CouncilElectionScheduleResponse {
+    pub schedule: Option<CouncilElectionSchedule>,
+    /// Time at which the next election is due, opening its nominations
+    pub next_election: Option<Timestamp>,
+    /// Time at which nominations for the next election close, and it can be triggered
+    pub nominations_close: Option<Timestamp>,
+    /// Candidates nominated for the next election, best supported first
+    pub candidates: Vec<CouncilCandidate>,
+}
+
+#[cw_serde]
+pub struct CouncilCandidate {
+    pub candidate: Addr,
+    /// Combined weight of the members nominating the candidate
+    pub support: Uint128,
+}
+
+#[cw_serde]
+pub struct UpdateVetoCouncilMsg {
+    /// New veto council. If None, the DAO will no longer have a veto council.
+    pub veto_council: Option<VetoCouncilSpec>,
+}
+
 #[cw_serde]
 pub struct RequestFundingFromDaoMsg {
     pub remote_treasury_target: Option<RemoteTreasuryTarget>,
@@ -215,6 +731,54 @@ pub struct UpdateAssetWhitelistProposalActionMsg {
     pub remove: Vec<AssetInfoUnchecked>,
 }
 
+/// Named set of assets that can be added to or removed from the whitelist in a single action.
+#[cw_serde]
+pub struct AssetBundle {
+    pub name: String,
+    pub assets: Vec<AssetInfoUnchecked>,
+}
+
+#[cw_serde]
+pub struct ManageAssetBundlesMsg {
+    /// Bundles to register. Registering a bundle under an existing name replaces it.
+    pub register: Vec<AssetBundle>,
+    /// Names of bundles to remove. Will ignore bundles that are not registered.
+    pub remove: Vec<String>,
+}
+
+#[cw_serde]
+pub struct UpdateAssetWhitelistWithBundleMsg {
+    pub remote_treasury_target: Option<RemoteTreasuryTarget>,
+    /// Name of a registered asset bundle
+    pub bundle: String,
+    pub change: AssetBundleChange,
+    /// The bundle's assets, filled in when the proposal is created, so that the proposal
+    /// changes the whitelist by the assets it was voted on with, even if the bundle changes
+    /// in the meantime. If given, it has to match the bundle's currently registered assets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assets: Option<Vec<AssetInfoUnchecked>>,
+}
+
+#[cw_serde]
+pub enum AssetBundleChange {
+    /// Adds the bundle's assets to the whitelist, ignoring those already whitelisted
+    Add,
+    /// Removes the bundle's assets from the whitelist, ignoring those not whitelisted
+    Remove,
+}
+
+#[cw_serde]
+pub struct AssetBundlesParams {
+    /// Optional pagination data, will return bundles after the given name
+    pub start_after: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct AssetBundlesResponse {
+    pub bundles: Vec<AssetBundle>,
+}
+
 #[cw_serde]
 pub struct UpdateNftWhitelistProposalActionMsg {
     pub remote_treasury_target: Option<RemoteTreasuryTarget>,
@@ -224,6 +788,75 @@ pub struct UpdateNftWhitelistProposalActionMsg {
     pub remove: Vec<String>,
 }
 
+#[cw_serde]
+pub struct BurnTokensMsg {
+    /// CW20 token held by the DAO's treasury to be burned. Native assets cannot be burned.
+    pub asset: AssetInfoUnchecked,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct GrantSpendingAllowanceMsg {
+    /// Contract allowed to pull the tokens from the DAO's treasury
+    pub grantee: String,
+    /// CW20 token held by the DAO's treasury. Native assets are not supported.
+    pub asset: AssetInfoUnchecked,
+    /// Amount the grantee's allowance is increased by
+    pub amount: Uint128,
+    /// When the allowance expires. Has to be in the future.
+    pub expiry: Expiration,
+}
+
+#[cw_serde]
+pub struct RevokeSpendingAllowanceMsg {
+    /// Contract whose allowance over the DAO treasury's tokens is revoked
+    pub grantee: String,
+    /// CW20 token the allowance was granted for
+    pub asset: AssetInfoUnchecked,
+}
+
+#[cw_serde]
+pub struct MintTokenfactoryDenomMsg {
+    /// Token-factory denom administered by the DAO's treasury
+    pub denom: String,
+    pub amount: Uint128,
+    /// Recipient of the minted tokens. If None, the tokens are minted to the DAO's treasury.
+    pub recipient: Option<String>,
+}
+
+#[cw_serde]
+pub struct SetDenomAdminMsg {
+    /// Token-factory denom administered by the DAO's treasury
+    pub denom: String,
+    pub new_admin: String,
+}
+
+#[cw_serde]
+pub struct UpdateEnterpriseFactoryMsg {
+    /// Address of the enterprise factory the DAO should use from now on
+    pub new_factory: String,
+}
+
+/// Sets the CosmWasm admin of the DAO's enterprise contract, or clears it if no admin is given.
+#[cw_serde]
+pub struct SetSelfAdminMsg {
+    pub new_admin: Option<String>,
+    /// The enterprise contract migrates itself when upgrading the DAO, so any admin other than
+    /// the contract itself will prevent further upgrades. This has to be explicitly set to true
+    /// for such changes to be accepted.
+    #[serde(default)]
+    pub allow_losing_upgradability: bool,
+}
+
+#[cw_serde]
+pub struct UpdateCouncilPermissionsMsg {
+    /// Proposal action types the existing council will be allowed to propose.
+    /// Council members, quorum and threshold remain unchanged.
+    /// Council proposals already created are re-checked against these when executed, and
+    /// cannot be executed if they contain action types that are no longer allowed.
+    pub allowed_proposal_action_types: Vec<ProposalActionType>,
+}
+
 #[cw_serde]
 pub struct ExecuteMsgsMsg {
     pub action_type: String,
@@ -256,22 +889,150 @@ pub struct DistributeFundsMsg {
     pub funds: Vec<AssetUnchecked>,
 }
 
+/// Messages executed on a remote chain by the interchain account (ICS-27) that the governance
+/// controller owns there. The account has to be registered beforehand.
+#[cw_serde]
+pub struct ExecuteInterchainAccountMsgsMsg {
+    /// IBC connection that the interchain account was registered over, e.g. 'connection-0'
+    pub connection_id: String,
+    pub msgs: Vec<InterchainAccountMsg>,
+    /// Optional memo attached to the interchain account packet
+    pub memo: Option<String>,
+    /// Optional timeout for the packet, relative to the time of execution. Formatted in nanoseconds.
+    pub timeout_nanos: Option<u64>,
+}
+
+/// Registers an interchain account for the DAO on the chain at the other end of the connection,
+/// owned by the governance controller. Has to be done before the account can execute messages.
+#[cw_serde]
+pub struct RegisterInterchainAccountMsg {
+    /// IBC connection to register the interchain account over, e.g. 'connection-0'
+    pub connection_id: String,
+    /// Optional JSON-encoded ICS-27 version metadata for the account's channel.
+    /// If None, the version is negotiated with the host chain.
+    pub version: Option<String>,
+}
+
+/// Protobuf-encoded message to be executed by the interchain account on the remote chain.
+#[cw_serde]
+pub struct InterchainAccountMsg {
+    /// Type URL of the message, e.g. '/cosmos.bank.v1beta1.MsgSend'.
+    /// Has to be a fully-qualified protobuf message name prefixed with '/'.
+    pub type_url: String,
+    pub value: Binary,
+}
+
+#[cw_serde]
+pub struct DistributeFromTreasuryMsg {
+    /// Assets taken from the DAO's treasury and sent directly to the funds distributor,
+    /// to be distributed to the DAO's members
+    pub assets: Vec<AssetUnchecked>,
+}
+
+/// Rewards the voters of a resolved proposal with an asset from the DAO's treasury, split
+/// proportionally to the weight each of them voted with. Non-voters receive nothing.
+#[cw_serde]
+pub struct DistributeToVotersMsg {
+    /// Proposal whose voters are rewarded. Has to be resolved, and have at least one voter.
+    pub proposal_id: ProposalId,
+    /// Native or CW20 asset, and the total amount of it split between the voters
+    pub asset: AssetUnchecked,
+}
+
 #[cw_serde]
 pub struct UpdateMinimumWeightForRewardsMsg {
     pub minimum_weight_for_rewards: Uint128,
 }
 
+/// Sets the fee the funds distributor takes from each distribution.
+#[cw_serde]
+pub struct UpdateDistributionFeeMsg {
+    /// Portion of each distribution taken as a fee, in basis points. 0 disables the fee.
+    pub bps: u16,
+    /// Address receiving the fees
+    pub recipient: String,
+}
+
+/// Pauses or resumes claiming rewards from the funds distributor.
+/// Distributions and weight updates keep going while claims are paused.
+#[cw_serde]
+pub struct SetDistributorClaimsPausedMsg {
+    pub paused: bool,
+}
+
+/// Sets or removes the emission schedule of a CW20 token in the funds distributor.
+/// Once set, the token's emissions are distributed whenever anyone pokes them.
+#[cw_serde]
+pub struct SetDistributorEmissionScheduleMsg {
+    /// Address of the CW20 token to emit
+    pub asset: String,
+    /// Schedule to emit the token on, starting from the proposal's execution.
+    /// If None, the token's schedule is removed.
+    pub schedule: Option<DistributorEmissionSchedule>,
+}
+
+#[cw_serde]
+pub struct DistributorEmissionSchedule {
+    /// Amount of the token emitted in each period
+    pub amount_per_period: Uint128,
+    /// Length of a period, in seconds
+    pub period: u64,
+    /// Number of periods the token is emitted for
+    pub periods: u64,
+    pub source: EmissionSource,
+}
+
+/// Where the tokens emitted by the funds distributor come from.
+/// The source has to be able to cover the whole schedule when it is set.
+#[cw_serde]
+pub enum EmissionSource {
+    /// Tokens are minted by the funds distributor, which has to be the token's minter
+    Mint,
+    /// Tokens are pulled from the DAO's treasury, which has to give the funds distributor
+    /// an allowance for them
+    Treasury,
+}
+
+/// Transfers NFTs held by the DAO's treasury to a recipient.
+#[cw_serde]
+pub struct TransferNftMsg {
+    /// CW721 contract of the NFTs
+    pub contract: String,
+    /// Tokens to transfer. Each of them has to be owned by the DAO's treasury.
+    pub token_ids: Vec<NftTokenId>,
+    pub recipient: String,
+}
+
 #[cw_serde]
 pub struct CastVoteMsg {
     pub proposal_id: ProposalId,
     pub outcome: VoteOutcome,
 }
 
+#[cw_serde]
+pub struct VetoProposalMsg {
+    pub proposal_id: ProposalId,
+}
+
+#[cw_serde]
+pub struct ExtendProposalVotingMsg {
+    pub proposal_id: ProposalId,
+    /// How much to extend the proposal's voting period by. Only time durations are supported.
+    pub extra: Duration,
+}
+
 #[cw_serde]
 pub struct ExecuteProposalMsg {
     pub proposal_id: ProposalId,
 }
 
+#[cw_serde]
+pub struct ExecuteProposalActionMsg {
+    pub proposal_id: ProposalId,
+    /// Index of the action in the proposal's actions
+    pub action_index: u32,
+}
+
 #[cw_serde]
 pub struct ConfigResponse {
     pub enterprise_contract: Addr,
@@ -298,16 +1059,63 @@ pub struct ProposalResponse {
     pub results: BTreeMap<u8, u128>,
 
     pub total_votes_available: Uint128,
+
+    /// Links to the proposal's off-chain context, if any were given when it was created
+    pub metadata: Option<ProposalMetadata>,
 }
 
 #[cw_serde]
 pub struct ProposalParams {
     pub proposal_id: ProposalId,
+    /// Address of the user viewing the proposal. In DAOs with private proposals, descriptions
+    /// and actions are only included if the viewer is a member of the DAO.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<String>,
+}
+
+#[cw_serde]
+pub struct ProposalActionOutcomesResponse {
+    /// Outcomes of actions of a best-effort proposal, ordered by action index.
+    /// Empty for atomically executed proposals.
+    pub outcomes: Vec<ProposalActionOutcome>,
+}
+
+#[cw_serde]
+pub struct DecodedProposalActionsResponse {
+    /// Proposal's actions, in the order they are executed in
+    pub proposal_actions: Vec<DecodedProposalAction>,
+}
+
+#[cw_serde]
+pub struct DecodedProposalAction {
+    pub proposal_action: ProposalAction,
+    /// Decoded messages of actions that hold raw JSON messages, in the order they are stored in.
+    /// None for other actions.
+    pub decoded_msgs: Option<Vec<DecodedMsg>>,
+}
+
+#[cw_serde]
+pub struct DecodedMsg {
+    /// The message, if it could be parsed
+    pub msg: Option<CosmosMsg>,
+    /// Error the message failed to parse with, if it could not be parsed
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct ProposalActionOutcome {
+    pub action_index: u32,
+    pub success: bool,
+    /// Error the action failed with, if it failed
+    pub error: Option<String>,
 }
 
 #[cw_serde]
 pub struct ProposalsResponse {
     pub proposals: Vec<ProposalResponse>,
+    /// Set when the scan stopped before reaching the last proposal. Pass it as `start_after`
+    /// to continue, even if fewer than `limit` proposals were returned.
+    pub next_start_after: Option<ProposalId>,
 }
 
 #[cw_serde]
@@ -316,9 +1124,107 @@ pub struct ProposalsParams {
     pub filter: Option<ProposalStatusFilter>,
     pub start_after: Option<ProposalId>,
     pub limit: Option<u32>,
+    /// Address of the user viewing the proposals. In DAOs with private proposals, descriptions
+    /// and actions are only included if the viewer is a member of the DAO.
+    pub viewer: Option<String>,
+    /// Optional text that proposals' titles have to contain, ignoring case.
+    /// There is no index on titles, so proposals are scanned in order until `limit` of them
+    /// match, looking at no more than 500 proposals per query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_contains: Option<String>,
     // TODO: allow ordering
 }
 
+#[cw_serde]
+pub struct VetoCouncilResponse {
+    pub veto_council: Option<VetoCouncilConfig>,
+}
+
+#[cw_serde]
+pub struct PendingCouncilProposalsParams {
+    /// Optional pagination data, will return proposals after the given ID
+    pub start_after: Option<ProposalId>,
+    pub limit: Option<u32>,
+    /// Address of the user viewing the proposals. In DAOs with private proposals, descriptions
+    /// and actions are only included if the viewer is a member of the DAO.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<String>,
+}
+
+#[cw_serde]
+pub struct PendingCouncilProposalsResponse {
+    pub proposals: Vec<CouncilProposalTally>,
+    /// Set when the scan stopped before reaching the last proposal. Pass it as `start_after`
+    /// to continue, even if fewer than `limit` proposals were returned.
+    pub next_start_after: Option<ProposalId>,
+}
+
+#[cw_serde]
+pub struct ExpiringProposalsParams {
+    /// Window from the current time in which the proposals' voting has to end.
+    /// Only time durations are supported, since proposals expire at a given time.
+    pub within: Duration,
+    /// Optional pagination data, will return proposals after the given ID
+    pub start_after: Option<ProposalId>,
+    pub limit: Option<u32>,
+    /// Address of the user viewing the proposals. In DAOs with private proposals, descriptions
+    /// and actions are only included if the viewer is a member of the DAO.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<String>,
+}
+
+#[cw_serde]
+pub struct ExpiringProposalsResponse {
+    pub proposals: Vec<ExpiringProposal>,
+    /// Set when the scan stopped before reaching the last proposal in progress. Pass it as
+    /// `start_after` to continue, even if fewer than `limit` proposals were returned.
+    pub next_start_after: Option<ProposalId>,
+}
+
+#[cw_serde]
+pub struct ExpiringProposal {
+    pub proposal: ProposalResponse,
+    /// Seconds remaining until the proposal's voting ends
+    pub time_remaining: u64,
+}
+
+#[cw_serde]
+pub struct CouncilVotesParams {
+    pub proposal_id: ProposalId,
+    /// Optional pagination data, will return votes of members after the given address
+    pub start_after: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct CouncilVotesResponse {
+    pub votes: Vec<CouncilVote>,
+}
+
+#[cw_serde]
+pub struct CouncilVote {
+    /// Council member that cast the vote. Votes are kept even if the member was removed
+    /// from the council afterwards.
+    pub member: Addr,
+    pub outcome: VoteOutcome,
+    /// Council member's voting weight at the time of voting
+    pub weight: Uint128,
+    pub voted_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct CouncilProposalTally {
+    pub proposal: ProposalResponse,
+    /// Council quorum that the proposal was created with
+    pub quorum: Decimal,
+    /// Council threshold that the proposal was created with
+    pub threshold: Decimal,
+    /// Whether enough council members have voted for the proposal to be valid
+    pub quorum_reached: bool,
+    /// Whether the 'yes' votes currently reach the threshold
+    pub threshold_reached: bool,
+}
+
 #[serde_as]
 #[cw_serde]
 pub struct ProposalStatusResponse {
@@ -331,6 +1237,38 @@ pub struct ProposalStatusResponse {
     pub results: BTreeMap<u8, u128>,
 }
 
+#[serde_as]
+#[cw_serde]
+pub struct ProposalStatusDetailResponse {
+    pub status: ProposalStatus,
+    pub expires: Expiration,
+    /// Seconds left until the voting period ends, 0 if it already ended
+    pub seconds_remaining: u64,
+
+    #[schemars(with = "Vec<(u8, Uint128)>")]
+    #[serde_as(as = "Vec<(_, _)>")]
+    /// Total vote-count (value) for each outcome (key).
+    pub results: BTreeMap<u8, u128>,
+    pub total_votes_available: Uint128,
+    /// Quorum that the proposal was created with
+    pub quorum: Decimal,
+    /// Threshold that the proposal was created with
+    pub threshold: Decimal,
+    /// Portion of total available votes that were cast so far
+    pub current_quorum: Decimal,
+    /// Portion of non-abstaining votes cast so far that were 'yes' votes
+    pub current_threshold: Decimal,
+    /// Outcome the proposal would have if the vote ended right now.
+    /// For proposals that are already resolved, this is their final outcome.
+    pub projected_outcome: ProjectedProposalOutcome,
+}
+
+#[cw_serde]
+pub enum ProjectedProposalOutcome {
+    WouldPass,
+    WouldFail,
+}
+
 #[cw_serde]
 pub enum ProposalStatus {
     InProgress,
@@ -338,6 +1276,14 @@ pub enum ProposalStatus {
     Passed,
     Rejected,
     Executed,
+    Vetoed,
+    /// Proposal passed, but its execution condition did not hold when it was executed,
+    /// so its actions were never executed
+    ConditionNotMet,
+    /// Proposal passed, but could not be executed when it was resolved, so its actions were
+    /// never executed - e.g. a council proposal whose actions the council was no longer allowed
+    /// to perform
+    ExecutionFailed,
 }
 
 #[cw_serde]
@@ -373,6 +1319,58 @@ pub struct MemberVoteResponse {
     pub vote: Option<Vote>,
 }
 
+#[cw_serde]
+pub struct VoterWeightParams {
+    pub proposal_id: ProposalId,
+    pub voter: String,
+}
+
+#[cw_serde]
+pub struct VoterWeightResponse {
+    /// Weight that is (or would be) counted for the voter on the proposal.
+    pub weight: Uint128,
+}
+
+#[cw_serde]
+pub struct IsMemberParams {
+    pub address: String,
+}
+
+#[cw_serde]
+pub struct IsMemberResponse {
+    /// Whether the address has non-zero weight in the DAO's membership.
+    pub is_member: bool,
+    /// The address' current weight in the DAO's membership.
+    pub weight: Uint128,
+}
+
+#[cw_serde]
+pub struct CanExecuteActionParams {
+    pub caller: String,
+    pub action_type: ProposalActionType,
+}
+
+#[cw_serde]
+pub struct CanExecuteActionResponse {
+    /// Whether the caller can currently propose the action type, either through a general
+    /// or a council proposal
+    pub can_execute: bool,
+    /// Which proposal type the caller can use, or why the caller cannot propose the action type
+    pub reason: String,
+}
+
+#[cw_serde]
+pub struct SupportedProposalActionsResponse {
+    pub proposal_actions: Vec<SupportedProposalAction>,
+}
+
+#[cw_serde]
+pub struct SupportedProposalAction {
+    pub action_type: ProposalActionType,
+    /// Whether the action type can be included in the council's allowed proposal action types
+    pub council_allowed: bool,
+}
+
 #[cw_serde]
 pub struct ProposalVotesParams {
     pub proposal_id: ProposalId,
@@ -386,11 +1384,6 @@ pub struct ProposalVotesResponse {
     pub votes: Vec<Vote>,
 }
 
-#[cw_serde]
-pub struct ProposalVotersParams {
-    pub proposal_id: ProposalId,
-}
-
 #[derive(Display)]
 #[cw_serde]
 pub enum ProposalType {
@@ -412,3 +1405,84 @@ pub struct Proposal {
     // TODO: include quorum? difficult because cw3 doesn't support it
     // pub quorum: Decimal,
 }
+
+#[cw_serde]
+pub struct ProposalsAffectingAssetParams {
+    /// Native or CW20 asset referenced by the proposals' actions
+    pub asset: AssetInfoUnchecked,
+    /// Optional pagination data, will return proposals after the given ID
+    pub start_after: Option<ProposalId>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct ProposalsAffectingAssetResponse {
+    /// IDs of the proposals, in ascending order
+    pub proposal_ids: Vec<ProposalId>,
+}
+
+#[cw_serde]
+pub struct ProposerDepositsParams {
+    /// Address whose deposits to list. This is the proposal's deposit owner if one was given,
+    /// and its proposer otherwise.
+    pub depositor: String,
+    /// Optional pagination data, will return deposits of proposals after the given ID
+    pub start_after: Option<ProposalId>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct ProposerDepositsResponse {
+    pub deposits: Vec<ProposerDeposit>,
+}
+
+#[cw_serde]
+pub struct ProposerDeposit {
+    pub proposal_id: ProposalId,
+    pub deposit: ProposalDeposit,
+    pub status: ProposalDepositStatus,
+}
+
+#[cw_serde]
+pub struct VerifyProposalHashParams {
+    pub proposal_id: ProposalId,
+    /// SHA-256 hash of the proposal's actions, serialized as JSON canonicalized according to
+    /// the JSON Canonicalization Scheme (RFC 8785), i.e. compact and with object keys sorted
+    pub expected_hash: Binary,
+}
+
+#[cw_serde]
+pub struct VerifyProposalHashResponse {
+    /// Whether the proposal's stored actions hash to the expected hash
+    pub matches: bool,
+}
+
+#[cw_serde]
+pub enum ProposalDepositStatus {
+    /// The proposal is still being voted on, and the deposit is held by the DAO
+    HeldInProgress,
+    /// Voting has ended, and the deposit will be refunded or slashed once the proposal
+    /// is executed or ended
+    Pending,
+    /// The deposit can be claimed back by the depositor
+    Refundable,
+    /// The deposit was sent back to the depositor
+    Refunded,
+    /// The deposit was sent to the DAO's forfeiture recipient
+    Slashed,
+}
+
+/// Message sent to the DAO's proposal notifier contract, which has to accept it as one of its
+/// execute messages.
+#[cw_serde]
+pub enum ProposalNotifierMsg {
+    ProposalCreated(ProposalCreatedNotification),
+}
+
+#[cw_serde]
+pub struct ProposalCreatedNotification {
+    pub proposal_id: ProposalId,
+    pub proposal_type: ProposalType,
+    pub proposer: Addr,
+    pub title: String,
+}
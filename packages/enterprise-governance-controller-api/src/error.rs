@@ -1,5 +1,5 @@
-use crate::api::ProposalActionType;
-use cosmwasm_std::{OverflowError, StdError, Uint128};
+use crate::api::{ProposalActionType, ProposalId};
+use cosmwasm_std::{Decimal, OverflowError, StdError, Timestamp, Uint128};
 use cw_utils::ParseReplyError;
 use enterprise_outposts_api::error::EnterpriseOutpostsError;
 use enterprise_protocol::error::DaoError;
@@ -32,30 +32,106 @@ pub enum GovernanceControllerError {
     #[error("The DAO does not have a council specified")]
     NoDaoCouncil,
 
+    #[error("The DAO does not have a council election schedule")]
+    NoCouncilElectionSchedule,
+
+    #[error("The next council election cannot be triggered before {next_election}")]
+    CouncilElectionNotDue { next_election: Timestamp },
+
+    #[error("Nominations for the council election closed at {closed_at}")]
+    CouncilNominationsClosed { closed_at: Timestamp },
+
+    #[error(
+        "The council election cannot be triggered before its nominations close at {closes_at}"
+    )]
+    CouncilNominationsOpen { closes_at: Timestamp },
+
+    #[error("No more than {max} candidates can be nominated in a council election")]
+    TooManyCouncilCandidates { max: u32 },
+
+    #[error("The DAO does not have a veto council specified")]
+    NoVetoCouncil,
+
+    #[error("Proposal can only be vetoed after it passed, during its challenge window")]
+    ProposalNotChallengeable,
+
+    #[error("Proposal cannot be executed during its challenge window")]
+    ProposalInChallengeWindow,
+
+    #[error("Proposal was vetoed by the veto council")]
+    ProposalVetoed,
+
     #[error("Cannot perform this while contract migration is ongoing")]
     HasIncompleteV2Migration,
 
     #[error("Proposal action {action} is not supported in council proposals")]
     UnsupportedCouncilProposalAction { action: ProposalActionType },
 
+    #[error(
+        "Council is too small or its threshold too low to be allowed proposal action {action}"
+    )]
+    CouncilTooPowerfulForAction { action: ProposalActionType },
+
+    #[error("Cannot distribute more than {max} assets in a single action")]
+    TooManyDistributionAssets { max: u32 },
+
+    #[error("Proposal's actions would move more {asset} out of the treasury than its execution budget allows")]
+    ExecutionBudgetExceeded { asset: String },
+
+    #[error("Execution budget for {asset} is above the maximum allowed by the DAO")]
+    ExecutionBudgetAboveMaximum { asset: String },
+
+    #[error("Proposals moving assets out of the treasury require an execution budget")]
+    ExecutionBudgetRequired,
+
+    #[error("Proposal action {action} cannot be limited by an execution budget, so it is not allowed when execution budgets apply")]
+    ProposalActionNotBudgetable { action: ProposalActionType },
+
+    #[error("No asset bundle named {name} is registered")]
+    NoSuchAssetBundle { name: String },
+
+    #[error("Value of gov config field {field} changed since the changes were drafted")]
+    GovConfigValueChanged { field: String },
+
+    #[error("Proposal action {action} is no longer allowed in council proposals")]
+    CouncilActionNoLongerAllowed { action: ProposalActionType },
+
     #[error("Proposal exceeds maximum amount of proposal actions, which is {maximum}")]
     MaximumProposalActionsExceeded { maximum: u8 },
 
     #[error("Council members must be unique, however {member} was duplicated")]
     DuplicateCouncilMember { member: String },
 
+    #[error("A council member is present in both add and remove lists")]
+    CouncilMemberPresentInBothAddAndRemove,
+
+    #[error("{member} is not a member of the DAO")]
+    NotDaoMember { member: String },
+
+    #[error("{member} is not a member of the council")]
+    NotCouncilMember { member: String },
+
+    #[error("Editing council members would leave the council with no members")]
+    CouncilWouldBeEmpty,
+
     #[error("{code_id} is not a valid Enterprise code ID")]
     InvalidEnterpriseCodeId { code_id: u64 },
 
     #[error("Attempting to edit a member's weight multiple times")]
     DuplicateMultisigMemberWeightEdit,
 
+    #[error("Modifying multisig membership would leave the multisig with no voting weight")]
+    MultisigWouldBeEmpty,
+
     #[error("Zero-duration voting is not allowed")]
     ZeroVoteDuration,
 
     #[error("To create a proposal, a deposit amount of at least {required_amount} is required")]
     InsufficientProposalDeposit { required_amount: Uint128 },
 
+    #[error("Proposal deposits are not accepted in this asset")]
+    UnacceptedDepositAsset,
+
     #[error("Invalid deposit type")]
     InvalidDepositType,
 
@@ -65,9 +141,18 @@ pub enum GovernanceControllerError {
     #[error("The given proposal was not found in this DAO")]
     NoSuchProposal,
 
+    #[error("Proposal has no deposit that can be claimed")]
+    NoRefundableDeposit,
+
+    #[error("Proposal {proposal_id} has no voters whose unlocking is still to be extended")]
+    NoPendingVoterUnlockingExtension { proposal_id: ProposalId },
+
     #[error("Proposal is of another type")]
     WrongProposalType,
 
+    #[error("Votes cannot be changed to another option in this DAO")]
+    VoteChangesNotAllowed,
+
     #[error("The given proposal has already been executed")]
     ProposalAlreadyExecuted,
 
@@ -80,6 +165,29 @@ pub enum GovernanceControllerError {
     #[error("This user has no voting power to create a proposal")]
     NoVotingPower,
 
+    #[error("This user is not authorized to create proposals in this DAO")]
+    NotAuthorizedToPropose,
+
+    #[error("Proposer already has the maximum number of proposals in progress: {max}")]
+    TooManyOpenProposals { max: u32 },
+
+    #[error(
+        "Proposals with {action_type} actions cannot be created again until {next_allowed_at}"
+    )]
+    ActionTypeCooldownActive {
+        action_type: ProposalActionType,
+        next_allowed_at: Timestamp,
+    },
+
+    #[error("Cannot add the DAO's governance token to the asset whitelist")]
+    GovernanceTokenInWhitelist,
+
+    #[error("Veto threshold cannot be lower than {floor}")]
+    VetoThresholdBelowFloor { floor: Decimal },
+
+    #[error("Veto threshold cannot be higher than {ceiling}")]
+    VetoThresholdAboveCeiling { ceiling: Decimal },
+
     #[error("An asset is added or removed multiple times")]
     DuplicateAssetFound,
 
@@ -89,6 +197,52 @@ pub enum GovernanceControllerError {
     #[error("CW1155 assets are not yet supported for this operation")]
     UnsupportedCw1155Asset,
 
+    #[error("Native assets cannot be burned")]
+    CannotBurnNativeAsset,
+
+    #[error("Spending allowances can only be granted for CW20 tokens")]
+    NativeSpendingAllowanceNotSupported,
+
+    #[error("Proposal voting periods cannot be extended in this DAO")]
+    VotingExtensionNotAllowed,
+
+    #[error("Proposal voting period has already ended")]
+    ProposalVotingEnded,
+
+    #[error("Proposal voting period cannot be extended by more than {max_total_extension} seconds in total")]
+    VotingExtensionTooLong { max_total_extension: u64 },
+
+    #[error("Grantee has no spending allowance to revoke")]
+    NoAllowanceToRevoke,
+
+    #[error("Treasury does not hold enough of {asset} - its balance is {balance}")]
+    InsufficientTreasuryBalance { asset: String, balance: Uint128 },
+
+    #[error("Funds distributor did not receive the distributed {asset} - its balance is {balance}, expected at least {expected}")]
+    DistributionNotReceived {
+        asset: String,
+        balance: Uint128,
+        expected: Uint128,
+    },
+
+    #[error("Denom {denom} is not a token-factory denom")]
+    NotTokenFactoryDenom { denom: String },
+
+    #[error("{address} is not an enterprise factory")]
+    NotAnEnterpriseFactory { address: String },
+
+    #[error("Setting the DAO's admin to anything other than itself would prevent it from being upgraded")]
+    SelfAdminWouldPreventUpgrades,
+
+    #[error("Token-factory denom {denom} is not administered by the DAO")]
+    DenomNotOwnedByDao { denom: String },
+
+    #[error("NFT {token_id} is not owned by the DAO's treasury")]
+    NftNotOwnedByDao { token_id: String },
+
+    #[error("Proposal description contains content that is not allowed: {construct}")]
+    InvalidDescriptionContent { construct: String },
+
     #[error("An NFT is added or removed multiple times")]
     DuplicateNftFound,
 
@@ -98,6 +252,14 @@ pub enum GovernanceControllerError {
     #[error("An NFT is present in both add and remove lists")]
     NftPresentInBothAddAndRemove,
 
+    #[error("No interchain account is registered for the DAO over connection {connection_id}")]
+    NoInterchainAccount { connection_id: String },
+
+    #[error(
+        "An interchain account is already registered for the DAO over connection {connection_id}"
+    )]
+    InterchainAccountAlreadyRegistered { connection_id: String },
+
     #[error("Error parsing message into Cosmos message")]
     InvalidCosmosMessage,
 
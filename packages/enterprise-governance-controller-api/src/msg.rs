@@ -1,9 +1,19 @@
 use crate::api::{
-    CastVoteMsg, ConfigResponse, CreateProposalMsg, CreateProposalWithNftDepositMsg,
-    DaoCouncilSpec, ExecuteProposalMsg, GovConfig, GovConfigResponse, MemberVoteParams,
-    MemberVoteResponse, ProposalId, ProposalInfo, ProposalParams, ProposalResponse,
+    AssetBundlesParams, AssetBundlesResponse, CanExecuteActionParams, CanExecuteActionResponse,
+    CastVoteMsg, ClaimDepositMsg, ConfigResponse, CouncilElectionScheduleResponse,
+    CouncilVotesParams, CouncilVotesResponse, CreateProposalMsg, CreateProposalWithNftDepositMsg,
+    CreateProposalsMsg, DaoCouncilSpec, DecodedProposalActionsResponse, ExecuteProposalActionMsg,
+    ExecuteProposalMsg, ExpiringProposalsParams, ExpiringProposalsResponse,
+    ExtendProposalVotingMsg, ExtendVoterUnlockingMsg, GovConfig, GovConfigResponse, IsMemberParams,
+    IsMemberResponse, MemberVoteParams, MemberVoteResponse, NominateCouncilCandidateMsg,
+    PendingCouncilProposalsParams, PendingCouncilProposalsResponse, ProposalActionOutcomesResponse,
+    ProposalId, ProposalInfo, ProposalParams, ProposalResponse, ProposalStatusDetailResponse,
     ProposalStatusParams, ProposalStatusResponse, ProposalVotesParams, ProposalVotesResponse,
-    ProposalsParams, ProposalsResponse,
+    ProposalsAffectingAssetParams, ProposalsAffectingAssetResponse, ProposalsParams,
+    ProposalsResponse, ProposerDepositsParams, ProposerDepositsResponse,
+    SupportedProposalActionsResponse, TriggerCouncilElectionMsg, VerifyProposalHashParams,
+    VerifyProposalHashResponse, VetoCouncilResponse, VetoProposalMsg, VoterWeightParams,
+    VoterWeightResponse,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw20::Cw20ReceiveMsg;
@@ -24,17 +34,47 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     CreateProposal(CreateProposalMsg),
+    /// Creates several general proposals at once, either all of them or none.
+    /// A native deposit sent along is split evenly between the proposals.
+    /// Responds with CreateProposalsResponse as data.
+    CreateProposals(CreateProposalsMsg),
     CreateProposalWithNftDeposit(CreateProposalWithNftDepositMsg),
+    /// Creates a proposal voted on by the council. Its actions have to be of the council's
+    /// allowed proposal action types.
+    /// It is resolved using the council's quorum and threshold, while other parameters,
+    /// such as vote duration and veto threshold, are taken from the DAO's gov config.
     CreateCouncilProposal(CreateProposalMsg),
     CastVote(CastVoteMsg),
     CastCouncilVote(CastVoteMsg),
     ExecuteProposal(ExecuteProposalMsg),
     Receive(Cw20ReceiveMsg),
     WeightsChanged(WeightsChangedMsg),
+    VetoProposal(VetoProposalMsg),
+    /// Extends a proposal's voting period. Only allowed for council members and the extenders
+    /// configured in the gov config, within the configured maximum total extension.
+    ExtendProposalVoting(ExtendProposalVotingMsg),
+    /// Nominates a candidate in the due council election, while its nominations are open.
+    /// Only members of the DAO can do this. Each member supports a single candidate with their
+    /// weight, and nominating another candidate moves that support.
+    NominateCouncilCandidate(NominateCouncilCandidateMsg),
+    /// Creates the council election proposal electing the best supported candidates, once the
+    /// due election's nominations are closed. Anyone can do this. If fewer candidates than
+    /// there are seats were nominated, the election is skipped and the next one scheduled.
+    TriggerCouncilElection(TriggerCouncilElectionMsg),
+    /// Sends a refundable proposal deposit back to its depositor, when the DAO refunds deposits
+    /// manually. Only the depositor can do this, and only once.
+    ClaimDeposit(ClaimDepositMsg),
+    /// Extends the unlocking of the next page of voters on a passed proposal whose actions
+    /// are configured to extend voters' unlocking. Anyone can do this, until the unlocking of
+    /// all the proposal's voters is extended.
+    ExtendVoterUnlocking(ExtendVoterUnlockingMsg),
 
     /// Only executable by the contract itself. Not part of the public API.
     ExecuteProposalActions(ExecuteProposalMsg),
 
+    /// Only executable by the contract itself. Not part of the public API.
+    ExecuteProposalAction(ExecuteProposalActionMsg),
+
     /// Only executable by the instantiator of this contract, in the same block as the creation.
     DeployInitialCrossChainTreasuries {},
 }
@@ -60,8 +100,71 @@ pub enum QueryMsg {
     Proposals(ProposalsParams),
     #[returns(ProposalStatusResponse)]
     ProposalStatus(ProposalStatusParams),
+    #[returns(ProposalStatusDetailResponse)]
+    ProposalStatusDetail(ProposalStatusParams),
     #[returns(MemberVoteResponse)]
     MemberVote(MemberVoteParams),
+    #[returns(ProposalActionOutcomesResponse)]
+    ProposalActionOutcomes(ProposalParams),
+    /// Lists the proposal's actions, with the raw JSON messages of ExecuteMsgs,
+    /// ExecuteTreasuryMsgs and ExecuteEnterpriseMsgs actions parsed into Cosmos messages.
+    /// Messages that fail to parse are marked as such instead of failing the query.
+    #[returns(DecodedProposalActionsResponse)]
+    DecodedProposalActions(ProposalParams),
+    /// Weight counted for a voter on a proposal.
+    ///
+    /// Votes are not snapshotted - a vote's weight follows the voter's current weight until
+    /// the proposal ends. If the DAO restricts late joiners' votes, users that became members
+    /// after the proposal started have no weight on it. Ended proposals only count the weight
+    /// of votes actually cast.
+    #[returns(VoterWeightResponse)]
+    VoterWeight(VoterWeightParams),
+    /// Checks whether the caller can currently create a proposal containing the given action type.
+    /// Only checks the caller's permissions, not the validity of a specific action's contents.
+    #[returns(CanExecuteActionResponse)]
+    CanExecuteAction(CanExecuteActionParams),
+    /// Lists the proposal's votes ordered by voter address, each with the option voted for and
+    /// the weight counted for it. The weights of all votes sum up to the proposal's results.
     #[returns(ProposalVotesResponse)]
     ProposalVotes(ProposalVotesParams),
+    #[returns(VetoCouncilResponse)]
+    VetoCouncil {},
+    #[returns(PendingCouncilProposalsResponse)]
+    PendingCouncilProposals(PendingCouncilProposalsParams),
+    /// Lists proposals still in progress whose voting ends within the given window from now,
+    /// along with the time remaining until they end.
+    #[returns(ExpiringProposalsResponse)]
+    ExpiringProposals(ExpiringProposalsParams),
+    /// Lists all proposal action types supported by the contract, and whether each of them
+    /// can be allowed for council proposals.
+    #[returns(SupportedProposalActionsResponse)]
+    SupportedProposalActions {},
+    /// Lists votes cast by council members on a council proposal, ordered by member address.
+    #[returns(CouncilVotesResponse)]
+    CouncilVotes(CouncilVotesParams),
+    /// Lists proposals whose actions reference the given asset, e.g. by whitelisting it,
+    /// requesting funding in it, or distributing it. Only proposals created since this query
+    /// was introduced are indexed.
+    #[returns(ProposalsAffectingAssetResponse)]
+    ProposalsAffectingAsset(ProposalsAffectingAssetParams),
+    /// Lists asset bundles registered through governance, ordered by name.
+    #[returns(AssetBundlesResponse)]
+    AssetBundles(AssetBundlesParams),
+    /// Returns the DAO's council election schedule, when the next election is due, and the
+    /// candidates nominated in it so far.
+    #[returns(CouncilElectionScheduleResponse)]
+    CouncilElectionSchedule {},
+    /// Lists the proposals whose deposit was made by the given depositor, along with what
+    /// happened to each deposit. Only proposals created since this query was introduced
+    /// are included.
+    #[returns(ProposerDepositsResponse)]
+    ProposerDeposits(ProposerDepositsParams),
+    /// Checks whether the proposal's stored actions hash to the expected hash, to confirm that
+    /// the proposal matches what was drafted off-chain.
+    #[returns(VerifyProposalHashResponse)]
+    VerifyProposalHash(VerifyProposalHashParams),
+    /// Checks whether the address is currently a member of the DAO, i.e. has non-zero weight
+    /// in its membership - staked tokens, staked NFTs, or multisig weight, depending on DAO type.
+    #[returns(IsMemberResponse)]
+    IsMember(IsMemberParams),
 }
@@ -1,5 +1,5 @@
 use crate::api::{ProposalId, ProposalType};
-use cosmwasm_std::{Response, Uint128};
+use cosmwasm_std::{Response, Timestamp, Uint128};
 use poll_engine_api::api::{PollId, VoteOutcome};
 
 pub fn instantiate_response() -> Response {
@@ -12,6 +12,13 @@ pub fn execute_create_proposal_response(dao_address: String) -> Response {
         .add_attribute("dao_address", dao_address)
 }
 
+pub fn execute_create_proposals_response(dao_address: String, proposals_count: usize) -> Response {
+    Response::new()
+        .add_attribute("action", "create_proposals")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("proposals_count", proposals_count.to_string())
+}
+
 pub fn reply_create_poll_response(poll_id: PollId) -> Response {
     Response::new().add_attribute("proposal_id", poll_id.to_string())
 }
@@ -73,3 +80,76 @@ pub fn execute_weights_changed_response() -> Response {
 pub fn execute_execute_msg_reply_callback_response() -> Response {
     Response::new().add_attribute("action", "execute_msg_reply_callback")
 }
+
+pub fn execute_extend_proposal_voting_response(
+    proposal_id: ProposalId,
+    extender: String,
+    extended_by: u64,
+    total_extension: u64,
+    new_ends_at: Timestamp,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "extend_proposal_voting")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("extender", extender)
+        .add_attribute("extended_by", extended_by.to_string())
+        .add_attribute("total_extension", total_extension.to_string())
+        .add_attribute("new_ends_at", new_ends_at.to_string())
+}
+
+pub fn execute_veto_proposal_response(
+    proposal_id: ProposalId,
+    voter: String,
+    vetoed: bool,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "veto_proposal")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", voter)
+        .add_attribute("vetoed", vetoed.to_string())
+}
+
+pub fn execute_claim_deposit_response(proposal_id: ProposalId, depositor: String) -> Response {
+    Response::new()
+        .add_attribute("action", "claim_deposit")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("depositor", depositor)
+}
+
+pub fn execute_extend_voter_unlocking_response(
+    proposal_id: ProposalId,
+    voters_count: usize,
+    done: bool,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "extend_voter_unlocking")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voters_count", voters_count.to_string())
+        .add_attribute("done", done.to_string())
+}
+
+pub fn execute_nominate_council_candidate_response(
+    dao_address: String,
+    nominator: String,
+    candidate: String,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "nominate_council_candidate")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("nominator", nominator)
+        .add_attribute("candidate", candidate)
+}
+
+pub fn execute_trigger_council_election_response(
+    dao_address: String,
+    triggerer: String,
+    next_election: Timestamp,
+    election_held: bool,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "trigger_council_election")
+        .add_attribute("dao_address", dao_address)
+        .add_attribute("triggerer", triggerer)
+        .add_attribute("next_election", next_election.to_string())
+        .add_attribute("election_held", election_held.to_string())
+}
@@ -8,6 +8,11 @@ fn stuff() -> StdResult<()> {
         description: Some("stuff".to_string()),
         proposal_actions: vec![],
         deposit_owner: Some("stranger".to_string()),
+        execution_atomicity: None,
+        expires_at: None,
+        execution_condition: None,
+        execution_budget: None,
+        metadata: None,
     })?;
 
     println!("{}", json);
@@ -21,6 +26,11 @@ fn stuff() -> StdResult<()> {
             description: Some("stuff".to_string()),
             proposal_actions: vec![],
             deposit_owner: Some("stranger".to_string()),
+            execution_atomicity: None,
+            expires_at: None,
+            execution_condition: None,
+            execution_budget: None,
+            metadata: None,
         }
     );
 
@@ -112,6 +112,12 @@ pub struct ExecuteMsgsMsg {
     pub msgs: Vec<String>,
 }
 
+/// Sets the CosmWasm admin of the enterprise contract itself, or clears it if no admin is given.
+#[cw_serde]
+pub struct SetSelfAdminMsg {
+    pub new_admin: Option<String>,
+}
+
 #[cw_serde]
 pub struct UpdateConfigMsg {
     pub new_versioning_contract: ModifyValue<String>,
@@ -131,6 +137,17 @@ pub struct DaoInfoResponse {
     pub metadata: DaoMetadata,
     pub dao_type: DaoType,
     pub dao_version: Version,
+    // the fields below are None in responses of DAOs from before they were reported, and when
+    // the contracts they are read from cannot be queried
+    /// Membership contract that tracks members' weights
+    pub membership_contract: Option<Addr>,
+    /// Contract of the asset that members stake for their weight - the CW20 token of token DAOs,
+    /// or the NFT contract of NFT DAOs. None for denom and multisig DAOs.
+    pub membership_asset_contract: Option<Addr>,
+    /// Code ID the DAO's enterprise-governance contract currently runs
+    pub enterprise_governance_code_id: Option<u64>,
+    /// Code ID the DAO's funds distributor contract currently runs
+    pub funds_distributor_code_id: Option<u64>,
 }
 
 #[cw_serde]
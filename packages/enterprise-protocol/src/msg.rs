@@ -1,7 +1,7 @@
 use crate::api::{
     ComponentContractsResponse, DaoInfoResponse, DaoMetadata, DaoType, ExecuteMsgsMsg,
     FinalizeInstantiationMsg, IsRestrictedUserParams, IsRestrictedUserResponse, SetAttestationMsg,
-    UpdateConfigMsg, UpdateMetadataMsg, UpgradeDaoMsg,
+    SetSelfAdminMsg, UpdateConfigMsg, UpdateMetadataMsg, UpgradeDaoMsg,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Timestamp;
@@ -28,6 +28,7 @@ pub enum ExecuteMsg {
     ExecuteMsgs(ExecuteMsgsMsg),
 
     UpdateConfig(UpdateConfigMsg),
+    SetSelfAdmin(SetSelfAdminMsg),
 
     // called only right after instantiation
     FinalizeInstantiation(FinalizeInstantiationMsg),
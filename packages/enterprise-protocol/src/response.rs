@@ -57,6 +57,12 @@ pub fn execute_update_config_response(
         .add_attribute("new_factory_addr", new_factory_addr)
 }
 
+pub fn execute_set_self_admin_response(new_admin: Option<String>) -> Response {
+    Response::new()
+        .add_attribute("action", "set_self_admin")
+        .add_attribute("new_admin", new_admin.unwrap_or_else(|| "none".to_string()))
+}
+
 pub fn execute_set_attestation_response() -> Response {
     Response::new().add_attribute("action", "set_attestation")
 }
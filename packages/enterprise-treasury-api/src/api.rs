@@ -41,12 +41,16 @@ pub struct ExecuteCosmosMsgsMsg {
     pub msgs: Vec<String>,
 }
 
+/// Parameters for paginating through the asset whitelist.
+/// Assets are returned in a deterministic order - native denoms first, then CW20s, then CW1155s.
+/// Continue paginating by passing the last asset of the previous page as `start_after`.
 #[cw_serde]
 pub struct AssetWhitelistParams {
     pub start_after: Option<AssetInfoUnchecked>,
     pub limit: Option<u32>,
 }
 
+/// Parameters for paginating through the NFT whitelist, ordered by the NFT contracts' addresses.
 #[cw_serde]
 pub struct NftWhitelistParams {
     pub start_after: Option<String>,
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Binary, Decimal256, Timestamp, Uint128};
 
 #[cw_serde]
 pub struct UpdateUserWeightsMsg {
@@ -13,12 +13,170 @@ pub struct UpdateMinimumEligibleWeightMsg {
     pub minimum_eligible_weight: Uint128,
 }
 
+#[cw_serde]
+pub struct UpdateConfigMsg {
+    /// New admin of the contract. If None, the admin remains unchanged.
+    pub new_admin: Option<String>,
+    /// New enterprise contract. If None, the enterprise contract remains unchanged.
+    pub new_enterprise_contract: Option<String>,
+    /// New retention policy for users' claim history. If None, the policy remains unchanged.
+    pub new_claim_history_retention: Option<ClaimHistoryRetention>,
+    /// New rate limit for distributions of each asset. If None, the rate limit remains unchanged.
+    pub new_distribution_rate_limit: Option<DistributionRateLimit>,
+    /// New mode for making distributed rewards available. If None, the mode remains unchanged.
+    pub new_distribution_mode: Option<DistributionMode>,
+    /// New fee taken from each distribution. If None, the fee remains unchanged.
+    pub new_distribution_fee: Option<DistributionFee>,
+}
+
+/// Maximum fee that can be taken from a distribution - 10%.
+pub const MAX_DISTRIBUTION_FEE_BPS: u16 = 1000;
+
+/// Fee deducted from each distribution before it is distributed to users.
+#[cw_serde]
+pub struct DistributionFee {
+    /// Portion of each distribution taken as a fee, in basis points. 0 disables the fee.
+    pub fee_bps: u16,
+    /// Address receiving the fees
+    pub recipient: String,
+}
+
+#[cw_serde]
+pub struct DistributionFeeResponse {
+    /// Currently configured fee, or None if no fee was ever configured
+    pub distribution_fee: Option<DistributionFee>,
+}
+
+#[cw_serde]
+pub enum DistributionMode {
+    /// Distributed rewards can be claimed right away
+    Immediate,
+    /// Distributed rewards become claimable linearly over the given number of seconds.
+    /// Rewards vesting at any moment go to users holding weight at that moment.
+    Vesting { duration: u64 },
+}
+
+/// Limits how many times a single asset can be distributed within a time window.
+#[cw_serde]
+pub struct DistributionRateLimit {
+    /// Maximum number of distributions of a single asset allowed within the window
+    pub max_distributions: u32,
+    /// Length of the window, in seconds
+    pub window_seconds: u64,
+}
+
+/// Determines how much of each user's claim history is kept.
+#[cw_serde]
+pub enum ClaimHistoryRetention {
+    /// Keep only the given number of most recent claims
+    LastEntries(u32),
+    /// Keep only claims made in the given number of seconds before the latest claim
+    LastSeconds(u64),
+}
+
+#[cw_serde]
+pub struct UpdateWeightOracleMsg {
+    /// New address allowed to update user weights, in addition to the admin.
+    /// If None, only the admin will be able to update user weights.
+    pub weight_oracle: Option<String>,
+}
+
+#[cw_serde]
+pub struct RegisterRewardAssetMsg {
+    pub asset: RewardAsset,
+    /// Display metadata for the asset. If None, any previously registered metadata is removed.
+    pub metadata: Option<RewardAssetMetadata>,
+}
+
+#[cw_serde]
+pub struct SetFeeOnTransferAssetMsg {
+    /// Address of the CW20 token
+    pub asset: String,
+    /// Whether the token takes a fee on transfers, so that distributions should only index
+    /// the amount actually received
+    pub fee_on_transfer: bool,
+}
+
+#[cw_serde]
+pub struct SetAssetWeightBasisMsg {
+    pub asset: RewardAsset,
+    pub weight_basis: WeightBasis,
+}
+
+#[cw_serde]
+pub struct SetAssetClaimPeriodMsg {
+    pub asset: RewardAsset,
+    /// Time after each of the asset's distributions, in seconds, after which its unclaimed
+    /// rewards can be swept to the DAO's treasury
+    pub claim_period: u64,
+}
+
+#[cw_serde]
+pub struct SetAssetIndexPrecisionMsg {
+    pub asset: RewardAsset,
+    /// Additional decimal places the asset's global index is kept with, on top of the 18
+    /// decimal places every index has
+    pub extra_decimals: u8,
+}
+
+/// How an asset's rewards are split between users.
+#[cw_serde]
+pub enum WeightBasis {
+    /// Rewards are split proportionally to users' effective weights
+    ProportionalToWeight,
+    /// Rewards are split equally between all users eligible for rewards,
+    /// regardless of their weights
+    EqualPerUser,
+}
+
+#[cw_serde]
+pub struct AssetWeightBasisParams {
+    pub asset: RewardAsset,
+}
+
+#[cw_serde]
+pub struct AssetWeightBasisResponse {
+    pub weight_basis: WeightBasis,
+}
+
+#[cw_serde]
+pub enum RewardAsset {
+    Native {
+        denom: String,
+    },
+    /// Address of the CW20 token
+    Cw20 {
+        asset: String,
+    },
+}
+
+/// Display-only information about a reward asset. Does not affect any reward calculations.
+#[cw_serde]
+pub struct RewardAssetMetadata {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
 #[cw_serde]
 pub struct UserWeight {
     pub user: String,
     pub weight: Uint128,
 }
 
+#[cw_serde]
+pub struct ReconcileUserMsg {
+    pub user: String,
+    /// Assets whose distribution entries for the user are to be reconciled
+    pub assets: Vec<RewardAsset>,
+}
+
+#[cw_serde]
+pub struct PruneDistributionsMsg {
+    pub user: String,
+    /// Assets whose distribution entries for the user are to be pruned
+    pub assets: Vec<RewardAsset>,
+}
+
 #[cw_serde]
 pub struct ClaimRewardsMsg {
     pub user: String,
@@ -26,6 +184,94 @@ pub struct ClaimRewardsMsg {
     pub native_denoms: Vec<String>,
     /// CW20 asset rewards to be claimed, should be addresses of CW20 tokens
     pub cw20_assets: Vec<String>,
+    /// Optional maximum amounts to be claimed for native denominations.
+    /// Rewards above the maximum remain pending. Denoms without a limit are claimed in full.
+    pub native_claim_limits: Option<Vec<NativeClaimLimit>>,
+    /// Optional maximum amounts to be claimed for CW20 assets.
+    /// Rewards above the maximum remain pending. Assets without a limit are claimed in full.
+    pub cw20_claim_limits: Option<Vec<Cw20ClaimLimit>>,
+    /// Optional contract to send all claimed rewards to, instead of sending them to the user.
+    pub destination: Option<ClaimDestination>,
+    /// Optional contracts to send claimed native rewards to, overriding the global destination.
+    pub native_destinations: Option<Vec<NativeClaimDestination>>,
+    /// Optional contracts to send claimed CW20 rewards to, overriding the global destination.
+    pub cw20_destinations: Option<Vec<Cw20ClaimDestination>>,
+    /// Optional selector of asset classes to claim. Lists of the classes not selected are ignored.
+    /// If None, both classes are claimed.
+    pub asset_class: Option<AssetClass>,
+    /// If true, rewards for every asset of the selected classes are claimed, ignoring the lists.
+    /// Otherwise, only the listed assets are claimed - empty lists claim nothing.
+    pub claim_all: Option<bool>,
+}
+
+/// A claim authorized by the user's signature, instead of by the user sending the transaction.
+/// Lets a relayer submit the claim and pay for its gas, while rewards still go to the user.
+#[cw_serde]
+pub struct ClaimRewardsWithPermitMsg {
+    /// The claim being authorized. Destinations cannot be given, since rewards claimed with
+    /// a permit are always sent to the user.
+    pub claim: ClaimRewardsMsg,
+    /// Has to be the user's current permit nonce, which is incremented by each used permit
+    pub nonce: u64,
+    /// User's compressed secp256k1 public key, from which the user's address is derived
+    pub public_key: Binary,
+    /// Signature over the SHA-256 hash of the JSON-serialized ClaimPermitPayload
+    pub signature: Binary,
+}
+
+/// Canonical payload that users sign to authorize a claim with a permit.
+#[cw_serde]
+pub struct ClaimPermitPayload {
+    pub chain_id: String,
+    /// Address of this funds distributor
+    pub contract: String,
+    pub nonce: u64,
+    pub claim: ClaimRewardsMsg,
+}
+
+#[cw_serde]
+pub enum AssetClass {
+    Native,
+    Cw20,
+    Both,
+}
+
+/// A contract that claimed rewards are sent to, along with a hook message.
+/// CW20 rewards are sent using CW20's Send, and native rewards are attached as funds to
+/// an Execute message on the contract.
+#[cw_serde]
+pub struct ClaimDestination {
+    pub contract: String,
+    /// Base64-encoded JSON message to be sent to the contract along with the rewards
+    pub msg: Binary,
+}
+
+#[cw_serde]
+pub struct NativeClaimDestination {
+    pub denom: String,
+    pub destination: ClaimDestination,
+}
+
+#[cw_serde]
+pub struct Cw20ClaimDestination {
+    /// Address of the CW20 token
+    pub asset: String,
+    pub destination: ClaimDestination,
+}
+
+#[cw_serde]
+pub struct NativeClaimLimit {
+    pub denom: String,
+    /// Maximum amount to be claimed. Zero will only record the rewards as pending.
+    pub max_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct Cw20ClaimLimit {
+    /// Address of the CW20 token
+    pub asset: String,
+    /// Maximum amount to be claimed. Zero will only record the rewards as pending.
+    pub max_amount: Uint128,
 }
 
 #[cw_serde]
@@ -43,15 +289,105 @@ pub struct UserRewardsResponse {
     pub cw20_rewards: Vec<Cw20Reward>,
 }
 
+#[cw_serde]
+pub struct MultiUserRewardsParams {
+    /// Users to be queried for rewards, at most 30 at a time
+    pub users: Vec<String>,
+    /// Native denominations to be queried for rewards
+    pub native_denoms: Vec<String>,
+    /// Addresses of CW20 tokens to be queried for rewards
+    pub cw20_assets: Vec<String>,
+}
+
+#[cw_serde]
+pub struct MultiUserRewardsResponse {
+    pub users: Vec<UserRewards>,
+}
+
+#[cw_serde]
+pub struct UserRewards {
+    pub user: String,
+    pub native_rewards: Vec<NativeReward>,
+    pub cw20_rewards: Vec<Cw20Reward>,
+}
+
+#[cw_serde]
+pub struct ClaimPreviewParams {
+    pub user: String,
+    /// Native denominations to be claimed
+    pub native_denoms: Vec<String>,
+    /// Addresses of CW20 tokens to be claimed
+    pub cw20_assets: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ClaimPreviewResponse {
+    pub native_claims: Vec<NativeClaimPreview>,
+    pub cw20_claims: Vec<Cw20ClaimPreview>,
+}
+
+#[cw_serde]
+pub struct NativeClaimPreview {
+    pub denom: String,
+    /// Whether claiming this denom would produce a transfer
+    pub transfers: bool,
+}
+
+#[cw_serde]
+pub struct Cw20ClaimPreview {
+    /// Address of the CW20 token
+    pub asset: String,
+    /// Whether claiming this token would produce a transfer
+    pub transfers: bool,
+}
+
+#[cw_serde]
+pub struct UserVestingRewardsResponse {
+    pub native_rewards: Vec<NativeVestingReward>,
+    pub cw20_rewards: Vec<Cw20VestingReward>,
+}
+
+#[cw_serde]
+pub struct NativeVestingReward {
+    pub denom: String,
+    /// Rewards that can be claimed right now
+    pub vested: Uint128,
+    /// User's share of rewards still vesting, estimated using the user's current weight
+    pub unvested: Uint128,
+}
+
+#[cw_serde]
+pub struct Cw20VestingReward {
+    /// Address of the CW20 token
+    pub asset: String,
+    /// Rewards that can be claimed right now
+    pub vested: Uint128,
+    /// User's share of rewards still vesting, estimated using the user's current weight
+    pub unvested: Uint128,
+}
+
+#[cw_serde]
+pub struct TotalEffectiveWeightResponse {
+    /// Sum of all users' effective weights, i.e. the weight distributions are divided by.
+    /// Users under the minimum eligible weight do not contribute to it.
+    pub total_effective_weight: Uint128,
+}
+
 #[cw_serde]
 pub struct MinimumEligibleWeightResponse {
     pub minimum_eligible_weight: Uint128,
+    /// Number of users whose weight currently meets the minimum eligible weight
+    pub eligible_users: u64,
+    /// Total weight of users currently eligible for rewards
+    pub total_eligible_weight: Uint128,
 }
 
 #[cw_serde]
 pub struct NativeReward {
     pub denom: String,
     pub amount: Uint128,
+    /// Display metadata, if registered for this denom
+    pub metadata: Option<RewardAssetMetadata>,
 }
 
 #[cw_serde]
@@ -59,4 +395,208 @@ pub struct Cw20Reward {
     /// Address of the CW20 token
     pub asset: String,
     pub amount: Uint128,
+    /// Display metadata, if registered for this token
+    pub metadata: Option<RewardAssetMetadata>,
+}
+
+#[cw_serde]
+pub struct OutstandingRewardsByUserParams {
+    /// Only users with an effective weight of at least this much are included
+    pub min_weight: Uint128,
+    pub asset: RewardAsset,
+    /// Optional pagination data, will return users after the given address
+    pub start_after: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct OutstandingRewardsByUserResponse {
+    pub rewards: Vec<UserOutstandingReward>,
+    /// Set when the scan stopped before reaching the last user. Pass it as `start_after`
+    /// to continue, even if fewer than `limit` users were returned.
+    pub next_start_after: Option<String>,
+}
+
+#[cw_serde]
+pub struct UserOutstandingReward {
+    pub user: String,
+    /// User's current effective weight
+    pub weight: Uint128,
+    /// Rewards accrued by the user for the asset that were not claimed yet
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct ClaimHistoryParams {
+    pub user: String,
+    /// Optional pagination data, will return entries after the given ID
+    pub start_after: Option<u64>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct ClaimHistoryResponse {
+    pub entries: Vec<ClaimHistoryEntry>,
+}
+
+#[cw_serde]
+pub struct ClaimHistoryEntry {
+    pub id: u64,
+    pub timestamp: Timestamp,
+    pub asset: RewardAsset,
+    /// Amount actually transferred out in the claim
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct UserClaimTimesParams {
+    pub user: String,
+    pub assets: Vec<RewardAsset>,
+}
+
+#[cw_serde]
+pub struct UserClaimTimesResponse {
+    pub claim_times: Vec<UserClaimTime>,
+}
+
+#[cw_serde]
+pub struct UserClaimTime {
+    pub asset: RewardAsset,
+    /// When the user last claimed the asset. None if the user never claimed it.
+    pub last_claimed_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct ClaimPermitNonceParams {
+    pub user: String,
+}
+
+#[cw_serde]
+pub struct ClaimPermitNonceResponse {
+    /// Nonce that the user's next claim permit has to use
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct EpochsParams {
+    /// Optional pagination data, will return epochs after the given one
+    pub start_after: Option<u64>,
+    pub limit: Option<u32>,
+}
+
+#[cw_serde]
+pub struct EpochsResponse {
+    /// Number of the epoch that distributions are currently tagged with
+    pub current_epoch: u64,
+    pub epochs: Vec<EpochInfo>,
+}
+
+#[cw_serde]
+pub struct EpochInfo {
+    pub epoch: u64,
+    /// When the epoch was rolled to. None for the initial epoch.
+    pub started_at: Option<Timestamp>,
+    /// When the next epoch was rolled to. None for the current epoch.
+    pub ended_at: Option<Timestamp>,
+    /// Total amounts distributed during the epoch, after distribution fees
+    pub distributed: Vec<EpochDistributedAmount>,
+}
+
+#[cw_serde]
+pub struct EpochDistributedAmount {
+    pub asset: RewardAsset,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct RewardsOptOutParams {
+    pub user: String,
+}
+
+#[cw_serde]
+pub struct RewardsOptOutResponse {
+    /// Whether the user currently forgoes their share of distributions
+    pub opted_out: bool,
+}
+
+#[cw_serde]
+pub struct DistributionRateParams {
+    pub asset: RewardAsset,
+    /// Length of the trailing window to sum distributions over, in seconds
+    pub window: u64,
+}
+
+#[cw_serde]
+pub struct DistributionRateResponse {
+    /// Window that was actually used, in seconds. Windows longer than the retained
+    /// distribution history are shortened to it.
+    pub window: u64,
+    /// Total amount of the asset distributed over the window, after distribution fees
+    pub distributed: Uint128,
+    /// Amount of the asset distributed per unit of weight per year, at the window's rate
+    pub annualized_rate: Decimal256,
+}
+
+#[cw_serde]
+pub struct ClaimsPausedResponse {
+    pub paused: bool,
+}
+
+/// Where the tokens emitted by an emission schedule come from.
+#[cw_serde]
+pub enum EmissionSource {
+    /// Tokens are minted by the distributor, which has to be the token's minter
+    Mint,
+    /// Tokens are pulled from the DAO's treasury, which has to give the distributor
+    /// an allowance for them
+    Treasury,
+}
+
+#[cw_serde]
+pub struct SetEmissionScheduleMsg {
+    /// Address of the CW20 token to emit
+    pub asset: String,
+    /// Schedule to emit the token on, starting now. If None, the token's schedule is removed.
+    pub schedule: Option<EmissionScheduleMsg>,
+}
+
+#[cw_serde]
+pub struct EmissionScheduleMsg {
+    /// Amount of the token emitted in each period
+    pub amount_per_period: Uint128,
+    /// Length of a period, in seconds
+    pub period: u64,
+    /// Number of periods the token is emitted for
+    pub periods: u64,
+    pub source: EmissionSource,
+}
+
+/// Emission of a CW20 token at a constant rate, distributed whenever emissions are poked.
+#[cw_serde]
+pub struct EmissionSchedule {
+    pub amount_per_period: Uint128,
+    /// Length of a period, in seconds
+    pub period: u64,
+    pub periods: u64,
+    pub source: EmissionSource,
+    pub start: Timestamp,
+    /// Time of the last poke, or the schedule's start if it was not poked yet
+    pub last_poke: Timestamp,
+    /// Total amount emitted and distributed so far
+    pub emitted: Uint128,
+}
+
+#[cw_serde]
+pub struct EmissionScheduleParams {
+    /// Address of the CW20 token
+    pub asset: String,
+}
+
+#[cw_serde]
+pub struct EmissionScheduleResponse {
+    pub schedule: Option<EmissionSchedule>,
+    /// Amount accrued since the last poke, that the next poke would emit
+    pub accrued: Uint128,
+    /// Amount scheduled to be emitted that was not emitted yet, including the accrued amount
+    pub remaining: Uint128,
 }
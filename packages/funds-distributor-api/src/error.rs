@@ -1,5 +1,7 @@
 use crate::error::DistributorError::Std;
-use cosmwasm_std::{CheckedMultiplyRatioError, OverflowError, StdError};
+use cosmwasm_std::{
+    CheckedMultiplyRatioError, ConversionOverflowError, OverflowError, StdError, Uint128,
+};
 use thiserror::Error;
 
 pub type DistributorResult<T> = Result<T, DistributorError>;
@@ -12,6 +14,9 @@ pub enum DistributorError {
     #[error("Unauthorized")]
     Unauthorized,
 
+    #[error("Claims are currently paused")]
+    ClaimsPaused,
+
     #[error(
         "The user is restricted from receiving rewards, due to not signing the DAO's attestation"
     )]
@@ -25,6 +30,76 @@ pub enum DistributorError {
 
     #[error("Attempting to distribute an asset that is not whitelisted")]
     DistributingNonWhitelistedAsset,
+
+    #[error("Hook message for claim destination is not valid JSON")]
+    InvalidClaimHookMsg,
+
+    #[error(
+        "Asset can be distributed at most {max_distributions} times every {window_seconds} seconds"
+    )]
+    DistributionRateLimited {
+        max_distributions: u32,
+        window_seconds: u64,
+    },
+
+    #[error("Invalid distribution rate limit - both the maximum distributions and the window must be greater than 0")]
+    InvalidDistributionRateLimit,
+
+    #[error("Vesting duration must be greater than 0")]
+    ZeroVestingDuration,
+
+    #[error("Distribution fee cannot be higher than {max_bps} basis points")]
+    DistributionFeeTooHigh { max_bps: u16 },
+
+    #[error("Weight basis, claim period or index precision of an asset cannot be set after the asset was already distributed")]
+    AssetAlreadyDistributed,
+
+    #[error("Index precision cannot have more than {max} extra decimals")]
+    IndexPrecisionTooHigh { max: u8 },
+
+    #[error("Claim period must be greater than 0")]
+    ZeroClaimPeriod,
+
+    #[error("Assets with a claim period cannot be distributed in vesting mode")]
+    ClaimPeriodInVestingMode,
+
+    #[error("No distributions of the asset have passed their deadline since the last sweep")]
+    NoExpiredRewards,
+
+    #[error("Cannot query rewards of more than {max_users} users at once")]
+    TooManyUsers { max_users: u32 },
+
+    #[error("Invalid claim permit nonce, expected {expected}")]
+    InvalidPermitNonce { expected: u64 },
+
+    #[error("Claim permit is not signed by the user")]
+    InvalidPermitSignature,
+
+    #[error("Rewards claimed with a permit can only be sent to the user")]
+    PermitClaimDestinationNotAllowed,
+
+    #[error("Emission schedule has to emit a non-zero amount over a non-zero number of non-zero periods")]
+    InvalidEmissionSchedule,
+
+    #[error("The distributor is not the token's minter, so it cannot mint emissions")]
+    DistributorNotMinter,
+
+    #[error(
+        "Emission source can only cover {available} of the {required} scheduled to be emitted"
+    )]
+    InsufficientEmissionFunding {
+        required: Uint128,
+        available: Uint128,
+    },
+
+    #[error("The asset has no emission schedule")]
+    NoEmissionSchedule,
+
+    #[error("No emissions have accrued since the last poke")]
+    NoEmissionsAccrued,
+
+    #[error("Tokens taking a fee on transfers cannot be emitted, as the amount received is not known when distributing them")]
+    FeeOnTransferEmission,
 }
 
 impl From<OverflowError> for DistributorError {
@@ -39,6 +114,12 @@ impl From<CheckedMultiplyRatioError> for DistributorError {
     }
 }
 
+impl From<ConversionOverflowError> for DistributorError {
+    fn from(e: ConversionOverflowError) -> Self {
+        Std(StdError::generic_err(e.to_string()))
+    }
+}
+
 impl DistributorError {
     /// Converts this DistributorError into a StdError.
     pub fn std_err(&self) -> StdError {
@@ -1,6 +1,17 @@
 use crate::api::{
-    ClaimRewardsMsg, MinimumEligibleWeightResponse, UpdateMinimumEligibleWeightMsg,
-    UpdateUserWeightsMsg, UserRewardsParams, UserRewardsResponse, UserWeight,
+    AssetWeightBasisParams, AssetWeightBasisResponse, ClaimHistoryParams, ClaimHistoryResponse,
+    ClaimPermitNonceParams, ClaimPermitNonceResponse, ClaimPreviewParams, ClaimPreviewResponse,
+    ClaimRewardsMsg, ClaimRewardsWithPermitMsg, ClaimsPausedResponse, DistributionFeeResponse,
+    DistributionRateParams, DistributionRateResponse, EmissionScheduleParams,
+    EmissionScheduleResponse, EpochsParams, EpochsResponse, MinimumEligibleWeightResponse,
+    MultiUserRewardsParams, MultiUserRewardsResponse, OutstandingRewardsByUserParams,
+    OutstandingRewardsByUserResponse, PruneDistributionsMsg, ReconcileUserMsg,
+    RegisterRewardAssetMsg, RewardAsset, RewardsOptOutParams, RewardsOptOutResponse,
+    SetAssetClaimPeriodMsg, SetAssetIndexPrecisionMsg, SetAssetWeightBasisMsg,
+    SetEmissionScheduleMsg, SetFeeOnTransferAssetMsg, TotalEffectiveWeightResponse,
+    UpdateConfigMsg, UpdateMinimumEligibleWeightMsg, UpdateUserWeightsMsg, UpdateWeightOracleMsg,
+    UserClaimTimesParams, UserClaimTimesResponse, UserRewardsParams, UserRewardsResponse,
+    UserVestingRewardsResponse, UserWeight,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
@@ -13,6 +24,8 @@ pub struct InstantiateMsg {
     pub initial_weights: Vec<UserWeight>,
     /// Optional minimum weight that the user must have to be eligible for rewards distributions
     pub minimum_eligible_weight: Option<Uint128>,
+    /// Optional address, in addition to the admin, that is allowed to update user weights
+    pub weight_oracle: Option<String>,
 }
 
 #[cw_serde]
@@ -22,6 +35,66 @@ pub enum ExecuteMsg {
     DistributeNative {},
     ClaimRewards(ClaimRewardsMsg),
     Receive(Cw20ReceiveMsg),
+    RegisterRewardAsset(RegisterRewardAssetMsg),
+    UpdateConfig(UpdateConfigMsg),
+    UpdateWeightOracle(UpdateWeightOracleMsg),
+    /// Brings a user's distribution entries back in line with current global indices,
+    /// e.g. after a manual weight correction. Nothing is paid out. Only the admin can do this.
+    ReconcileUser(ReconcileUserMsg),
+    /// Sets how an asset's rewards are split between users. Can only be set before the asset
+    /// is first distributed. Assets without a weight basis are distributed proportionally
+    /// to users' weights. Only the admin can do this.
+    SetAssetWeightBasis(SetAssetWeightBasisMsg),
+    /// Deletes the user's distribution entries that no longer owe them anything, to reclaim
+    /// storage. Entries of users with weight for the asset are never pruned. Anyone can do this.
+    PruneDistributions(PruneDistributionsMsg),
+    /// Marks a CW20 token as taking a fee on transfers, so that its distributions index only
+    /// the amount actually received, instead of the amount stated. Only the admin can do this.
+    SetFeeOnTransferAsset(SetFeeOnTransferAssetMsg),
+    /// Claims rewards on the user's behalf, authorized by the user's signature instead of
+    /// the user sending the transaction. Rewards are always sent to the user.
+    ClaimRewardsWithPermit(ClaimRewardsWithPermitMsg),
+    /// Starts a new reward epoch. Distributions made from now on are reported under it.
+    /// Has no effect on how rewards are split. Only the admin can do this.
+    RollEpoch {},
+    /// Sets a claim period for an asset. Rewards of each of the asset's distributions that are
+    /// still unclaimed after the claim period can be swept to the DAO's treasury.
+    /// Can only be set before the asset is first distributed, and the asset then cannot be
+    /// distributed in vesting mode. Only the admin can do this.
+    SetAssetClaimPeriod(SetAssetClaimPeriodMsg),
+    /// Sends the asset's rewards left unclaimed past their distributions' deadlines to the
+    /// treasury address configured in the DAO's governance, or the DAO's treasury if none is.
+    /// Rewards still within their deadline are never swept. Only the admin can do this.
+    /// Sweeps at most 30 distributions at once; the rest are swept by sending this again.
+    SweepExpiredRewards {
+        asset: RewardAsset,
+    },
+    /// Makes the asset's global index more precise, so that small distributions spread over a
+    /// large total weight do not round down to nothing.
+    /// Can only be set before the asset is first distributed. Only the admin can do this.
+    SetAssetIndexPrecision(SetAssetIndexPrecisionMsg),
+    /// Opts the sender out of rewards, or back into them. While opted out, the sender's weight
+    /// is left out of distributions, so their share goes to the other users. Rewards accrued
+    /// before opting out stay claimable.
+    OptOutOfRewards {
+        opted_out: bool,
+    },
+    /// Pauses or resumes claims of rewards, e.g. while investigating an incident.
+    /// Distributions and weight updates still proceed while claims are paused.
+    /// Only the admin can do this.
+    SetClaimsPaused {
+        paused: bool,
+    },
+    /// Sets or removes the emission schedule of a CW20 token. The schedule's source has to be
+    /// able to cover all of its emissions, and the token cannot take a fee on transfers.
+    /// Only the admin can do this.
+    SetEmissionSchedule(SetEmissionScheduleMsg),
+    /// Mints or pulls the token's emissions accrued since the last poke, and distributes them
+    /// like any other distribution. Fails for tokens marked as taking a fee on transfers.
+    /// Anyone can do this.
+    PokeEmissions {
+        asset: String,
+    },
 }
 
 #[cw_serde]
@@ -34,8 +107,49 @@ pub enum Cw20HookMsg {
 pub enum QueryMsg {
     #[returns(UserRewardsResponse)]
     UserRewards(UserRewardsParams),
+    /// Rewards of multiple users in a single query, e.g. for leaderboards.
+    #[returns(MultiUserRewardsResponse)]
+    MultiUserRewards(MultiUserRewardsParams),
     #[returns(MinimumEligibleWeightResponse)]
     MinimumEligibleWeight {},
+    #[returns(TotalEffectiveWeightResponse)]
+    TotalEffectiveWeight {},
+    #[returns(ClaimHistoryResponse)]
+    ClaimHistory(ClaimHistoryParams),
+    /// Times at which the user last claimed each of the given assets.
+    #[returns(UserClaimTimesResponse)]
+    UserClaimTimes(UserClaimTimesParams),
+    #[returns(UserVestingRewardsResponse)]
+    UserVestingRewards(UserRewardsParams),
+    /// Shows which of the assets would produce a transfer if claimed now, to help estimate
+    /// the gas needed for the claim.
+    #[returns(ClaimPreviewResponse)]
+    ClaimPreview(ClaimPreviewParams),
+    #[returns(DistributionFeeResponse)]
+    DistributionFee {},
+    /// Lists rewards accrued and not yet claimed for an asset by each user above the given
+    /// weight, to help estimate outstanding claim liabilities.
+    #[returns(OutstandingRewardsByUserResponse)]
+    OutstandingRewardsByUser(OutstandingRewardsByUserParams),
+    #[returns(AssetWeightBasisResponse)]
+    AssetWeightBasis(AssetWeightBasisParams),
+    /// Nonce that the user's next claim permit has to be signed with.
+    #[returns(ClaimPermitNonceResponse)]
+    ClaimPermitNonce(ClaimPermitNonceParams),
+    /// Lists reward epochs with their boundaries and the amounts distributed in each.
+    #[returns(EpochsResponse)]
+    Epochs(EpochsParams),
+    #[returns(RewardsOptOutResponse)]
+    RewardsOptOut(RewardsOptOutParams),
+    #[returns(ClaimsPausedResponse)]
+    ClaimsPaused {},
+    /// Total amount of an asset distributed over a trailing window, and the annualized rate
+    /// it was distributed at relative to the asset's current total weight.
+    #[returns(DistributionRateResponse)]
+    DistributionRate(DistributionRateParams),
+    /// The token's emission schedule, with its accrued and remaining emissions.
+    #[returns(EmissionScheduleResponse)]
+    EmissionSchedule(EmissionScheduleParams),
 }
 
 #[cw_serde]
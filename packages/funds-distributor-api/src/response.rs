@@ -43,3 +43,118 @@ pub fn cw20_hook_distribute_cw20_response(
         .add_attribute("cw20_asset", cw20_asset)
         .add_attribute("amount_distributed", amount.to_string())
 }
+
+pub fn execute_register_reward_asset_response(asset: String) -> Response {
+    Response::new()
+        .add_attribute("action", "register_reward_asset")
+        .add_attribute("asset", asset)
+}
+
+pub fn execute_set_asset_weight_basis_response(asset: String, weight_basis: String) -> Response {
+    Response::new()
+        .add_attribute("action", "set_asset_weight_basis")
+        .add_attribute("asset", asset)
+        .add_attribute("weight_basis", weight_basis)
+}
+
+pub fn execute_update_config_response(admin: String, enterprise_contract: String) -> Response {
+    Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("admin", admin)
+        .add_attribute("enterprise_contract", enterprise_contract)
+}
+
+pub fn execute_update_weight_oracle_response(weight_oracle: Option<String>) -> Response {
+    Response::new()
+        .add_attribute("action", "update_weight_oracle")
+        .add_attribute(
+            "weight_oracle",
+            weight_oracle.unwrap_or_else(|| "none".to_string()),
+        )
+}
+
+pub fn execute_reconcile_user_response(admin: String, user: String) -> Response {
+    Response::new()
+        .add_attribute("action", "reconcile_user")
+        .add_attribute("admin", admin)
+        .add_attribute("user", user)
+}
+
+pub fn execute_prune_distributions_response(user: String, pruned_assets: String) -> Response {
+    Response::new()
+        .add_attribute("action", "prune_distributions")
+        .add_attribute("user", user)
+        .add_attribute("pruned_assets", pruned_assets)
+}
+
+pub fn execute_set_fee_on_transfer_asset_response(
+    asset: String,
+    fee_on_transfer: bool,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "set_fee_on_transfer_asset")
+        .add_attribute("asset", asset)
+        .add_attribute("fee_on_transfer", fee_on_transfer.to_string())
+}
+
+pub fn execute_roll_epoch_response(epoch: u64) -> Response {
+    Response::new()
+        .add_attribute("action", "roll_epoch")
+        .add_attribute("epoch", epoch.to_string())
+}
+
+pub fn execute_set_asset_claim_period_response(asset: String, claim_period: u64) -> Response {
+    Response::new()
+        .add_attribute("action", "set_asset_claim_period")
+        .add_attribute("asset", asset)
+        .add_attribute("claim_period", claim_period.to_string())
+}
+
+pub fn execute_set_asset_index_precision_response(asset: String, extra_decimals: u8) -> Response {
+    Response::new()
+        .add_attribute("action", "set_asset_index_precision")
+        .add_attribute("asset", asset)
+        .add_attribute("extra_decimals", extra_decimals.to_string())
+}
+
+pub fn execute_sweep_expired_rewards_response(
+    asset: String,
+    amount: Uint128,
+    last_swept_distribution: u64,
+) -> Response {
+    Response::new()
+        .add_attribute("action", "sweep_expired_rewards")
+        .add_attribute("asset", asset)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute(
+            "last_swept_distribution",
+            last_swept_distribution.to_string(),
+        )
+}
+
+pub fn execute_opt_out_of_rewards_response(user: String, opted_out: bool) -> Response {
+    Response::new()
+        .add_attribute("action", "opt_out_of_rewards")
+        .add_attribute("user", user)
+        .add_attribute("opted_out", opted_out.to_string())
+}
+
+pub fn execute_set_claims_paused_response(paused: bool) -> Response {
+    Response::new()
+        .add_attribute("action", "set_claims_paused")
+        .add_attribute("paused", paused.to_string())
+}
+
+pub fn execute_set_emission_schedule_response(asset: String, scheduled: Uint128) -> Response {
+    Response::new()
+        .add_attribute("action", "set_emission_schedule")
+        .add_attribute("asset", asset)
+        .add_attribute("scheduled", scheduled.to_string())
+}
+
+pub fn execute_poke_emissions_response(asset: String, amount: Uint128) -> Response {
+    Response::new()
+        .add_attribute("action", "poke_emissions")
+        .add_attribute("asset", asset)
+        .add_attribute("amount", amount.to_string())
+}
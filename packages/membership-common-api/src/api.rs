@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use cw_utils::Expiration;
 
 #[cw_serde]
@@ -38,6 +38,13 @@ pub struct WeightChangeHookMsg {
     pub hook_addr: String,
 }
 
+#[cw_serde]
+pub struct ExtendUnlockingMsg {
+    pub users: Vec<String>,
+    /// Moment until which the users' unstaked assets will not be released.
+    pub until: Timestamp,
+}
+
 #[cw_serde]
 pub struct WeightsChangedMsg {
     pub weight_changes: Vec<UserWeightChange>,
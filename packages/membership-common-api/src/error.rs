@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp};
 use thiserror::Error;
 
 pub type MembershipResult<T> = Result<T, MembershipError>;
@@ -13,6 +13,9 @@ pub enum MembershipError {
 
     #[error("User did not sign the attestation, and they're restricted from this functionality")]
     RestrictedUser,
+
+    #[error("Unstaking is locked until {until}")]
+    UnstakingLocked { until: Timestamp },
 }
 
 impl MembershipError {
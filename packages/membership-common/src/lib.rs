@@ -2,5 +2,6 @@ pub mod admin;
 pub mod enterprise_contract;
 pub mod member_weights;
 pub mod total_weight;
+pub mod unlocking_extensions;
 pub mod validate;
 pub mod weight_change_hooks;
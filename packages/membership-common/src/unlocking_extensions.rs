@@ -0,0 +1,53 @@
+use crate::validate::enterprise_governance_controller_only;
+use common::cw::{Context, ReleaseAt};
+use cosmwasm_std::{Addr, BlockInfo, Response, Storage, Timestamp};
+use cw_storage_plus::Map;
+use membership_common_api::api::ExtendUnlockingMsg;
+use membership_common_api::error::MembershipError::UnstakingLocked;
+use membership_common_api::error::MembershipResult;
+
+/// Moments until which users' unstaked assets cannot be released.
+pub const UNLOCKING_EXTENSIONS: Map<Addr, Timestamp> =
+    Map::new("membership_common__unlocking_extensions");
+
+/// Extend the unlocking of the given users' unstaked assets until the given moment.
+/// Existing extensions lasting longer are kept. Only the governance controller can execute this.
+pub fn extend_unlocking(ctx: &mut Context, msg: ExtendUnlockingMsg) -> MembershipResult<Response> {
+    // only governance controller can execute this
+    enterprise_governance_controller_only(ctx, None)?;
+
+    for user in &msg.users {
+        let user = ctx.deps.api.addr_validate(user)?;
+
+        let existing = UNLOCKING_EXTENSIONS.may_load(ctx.deps.storage, user.clone())?;
+
+        if existing.is_none_or(|existing| existing < msg.until) {
+            UNLOCKING_EXTENSIONS.save(ctx.deps.storage, user, &msg.until)?;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "extend_unlocking")
+        .add_attribute("users_count", msg.users.len().to_string())
+        .add_attribute("until", msg.until.to_string()))
+}
+
+/// Applies the user's unlocking extension, if any, to the release moment of their unstaked assets.
+/// Time-based releases are pushed back to the end of the extension, while height-based ones
+/// cannot be compared to it, so unstaking is rejected until the extension ends.
+pub fn apply_unlocking_extension(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    user: Addr,
+    release_at: ReleaseAt,
+) -> MembershipResult<ReleaseAt> {
+    let until = match UNLOCKING_EXTENSIONS.may_load(storage, user)? {
+        Some(until) if until > block.time => until,
+        _ => return Ok(release_at),
+    };
+
+    match release_at {
+        ReleaseAt::Timestamp(timestamp) => Ok(ReleaseAt::Timestamp(timestamp.max(until))),
+        ReleaseAt::Height(_) => Err(UnstakingLocked { until }),
+    }
+}
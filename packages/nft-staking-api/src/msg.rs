@@ -7,8 +7,8 @@ use common::cw::ReleaseAt;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw_utils::Duration;
 use membership_common_api::api::{
-    MembersParams, MembersResponse, TotalWeightCheckpoint, TotalWeightParams, TotalWeightResponse,
-    UserWeightParams, UserWeightResponse, WeightChangeHookMsg,
+    ExtendUnlockingMsg, MembersParams, MembersResponse, TotalWeightCheckpoint, TotalWeightParams,
+    TotalWeightResponse, UserWeightParams, UserWeightResponse, WeightChangeHookMsg,
 };
 
 #[cw_serde]
@@ -29,6 +29,7 @@ pub enum ExecuteMsg {
     ReceiveNft(ReceiveNftMsg),
     AddWeightChangeHook(WeightChangeHookMsg),
     RemoveWeightChangeHook(WeightChangeHookMsg),
+    ExtendUnlocking(ExtendUnlockingMsg),
 }
 
 #[cw_serde]
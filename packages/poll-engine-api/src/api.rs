@@ -44,6 +44,10 @@ pub struct Poll {
     /// Calculated as (veto votes) / (total available votes - abstaining votes).
     /// If None, regular threshold will be used for veto option.
     pub veto_threshold: Option<Decimal>,
+    /// Optional minimum amount of 'yes' votes required for the poll to pass,
+    /// regardless of the quorum and threshold being reached.
+    #[serde(default)]
+    pub minimum_yes_votes: Option<Uint128>,
 
     #[schemars(with = "Vec<(u8, Uint128)>")]
     #[serde_as(as = "Vec<(_, _)>")]
@@ -132,6 +136,10 @@ pub struct CreatePollParams {
     /// Calculated as (veto votes) / (total available votes - abstaining votes).
     /// If None, regular threshold will be used for veto option.
     pub veto_threshold: Option<Decimal>,
+    /// Optional minimum amount of 'yes' votes required for the poll to pass,
+    /// regardless of the quorum and threshold being reached.
+    #[serde(default)]
+    pub minimum_yes_votes: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -156,6 +164,15 @@ pub struct UpdateVotesParams {
     pub new_amount: Uint128,
 }
 
+#[cw_serde]
+/// Params for extending the voting period of a poll.
+pub struct ExtendPollParams {
+    /// Unique identifier for the poll
+    pub poll_id: Uint64,
+    /// New end-time of the poll. Has to be after the poll's current end-time.
+    pub new_ends_at: Timestamp,
+}
+
 #[cw_serde]
 /// Params for ending a poll.
 pub struct EndPollParams {
@@ -185,6 +202,7 @@ pub enum PollRejectionReason {
     IsRejectingOutcome,
     IsVetoOutcome,
     OutcomeDraw(u8, u8, Uint128),
+    MinimumYesVotesNotReached,
 }
 
 #[derive(Display)]
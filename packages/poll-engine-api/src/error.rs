@@ -38,6 +38,9 @@ pub enum PollError {
     #[error("Threshold not reached, unable to end the poll early")]
     EndingEarlyThresholdNotReached {},
 
+    #[error("Minimum amount of 'yes' votes not reached, unable to end the poll early")]
+    EndingEarlyMinimumYesVotesNotReached {},
+
     #[error("Invalid argument: {msg}")]
     InvalidArgument { msg: String },
 }
@@ -8,9 +8,10 @@ use crate::validate::{
     validate_voting_period_ended, validate_within_voting_period,
 };
 use poll_engine_api::api::{
-    CastVoteParams, CreatePollParams, EndPollParams, Poll, Vote, VoteOutcome,
+    CastVoteParams, CreatePollParams, EndPollParams, ExtendPollParams, Poll, PollStatus, Vote,
+    VoteOutcome,
 };
-use poll_engine_api::error::PollError::PollNotFound;
+use poll_engine_api::error::PollError::{InvalidArgument, PollNotFound};
 use poll_engine_api::error::*;
 
 /// Initializes library state.
@@ -92,6 +93,36 @@ pub fn cast_vote(
     Ok(())
 }
 
+/// Extends a poll's voting period. Must be within the voting period.
+pub fn extend_poll(
+    ctx: &mut Context,
+    ExtendPollParams {
+        poll_id,
+        new_ends_at,
+    }: ExtendPollParams,
+) -> PollResult<Poll> {
+    let mut poll = polls()
+        .may_load(ctx.deps.storage, poll_id.into())?
+        .ok_or(PollNotFound { poll_id })?;
+
+    validate_not_already_ended(&poll)?;
+    validate_within_voting_period(ctx.env.block.time, (poll.started_at, poll.ends_at))?;
+
+    if new_ends_at <= poll.ends_at {
+        return Err(InvalidArgument {
+            msg: "New poll end-time must be after its current end-time".to_string(),
+        });
+    }
+
+    poll.ends_at = new_ends_at;
+    poll.status = PollStatus::InProgress {
+        ends_at: new_ends_at,
+    };
+    polls().save(ctx.deps.storage, poll_id.into(), &poll)?;
+
+    Ok(poll)
+}
+
 /// Ends a poll. Must be outside of the voting period.
 pub fn end_poll(
     ctx: &mut Context,
@@ -134,19 +165,19 @@ mod tests {
     use PollRejectionReason::OutcomeDraw;
     use VoteOutcome::{No, Veto};
 
-    use crate::execute::{cast_vote, create_poll, end_poll, initialize_poll_engine};
+    use crate::execute::{cast_vote, create_poll, end_poll, extend_poll, initialize_poll_engine};
     use crate::helpers::mock_poll;
     use crate::query::query_poll_status;
     use crate::state::{polls, GovState, GOV_STATE};
     use poll_engine_api::api::VoteOutcome::{Abstain, Yes};
     use poll_engine_api::api::{
-        CastVoteParams, CreatePollParams, EndPollParams, Poll, PollRejectionReason, PollStatus,
-        PollStatusFilter, VoteOutcome, VotingScheme,
+        CastVoteParams, CreatePollParams, EndPollParams, ExtendPollParams, Poll,
+        PollRejectionReason, PollStatus, PollStatusFilter, VoteOutcome, VotingScheme,
     };
     use poll_engine_api::error::PollError;
     use poll_engine_api::error::PollError::{
-        EndingEarlyQuorumNotReached, EndingEarlyThresholdNotReached, PollAlreadyEnded,
-        WithinVotingPeriod,
+        EndingEarlyQuorumNotReached, EndingEarlyThresholdNotReached, InvalidArgument,
+        PollAlreadyEnded, WithinVotingPeriod,
     };
 
     #[test]
@@ -183,6 +214,7 @@ mod tests {
             quorum: quorum.clone(),
             threshold: threshold.clone(),
             veto_threshold: None,
+            minimum_yes_votes: None,
         };
 
         create_poll(&mut ctx, params).unwrap();
@@ -225,6 +257,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extends_poll() {
+        let mut deps = mock_dependencies();
+        let mut ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+        ctx.env.block.time = Timestamp::from_nanos(0);
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.ends_at = ctx.env.block.time.plus_seconds(1000u64);
+        polls().save(ctx.deps.storage, poll.id, &poll).unwrap();
+
+        let result = extend_poll(
+            &mut ctx,
+            ExtendPollParams {
+                poll_id: poll.id.into(),
+                new_ends_at: poll.ends_at,
+            },
+        );
+        assert!(matches!(result, Err(InvalidArgument { .. })));
+
+        let new_ends_at = poll.ends_at.plus_seconds(500u64);
+        extend_poll(
+            &mut ctx,
+            ExtendPollParams {
+                poll_id: poll.id.into(),
+                new_ends_at,
+            },
+        )
+        .unwrap();
+
+        let poll = polls().load(ctx.deps.storage, poll.id).unwrap();
+        assert_eq!(new_ends_at, poll.ends_at);
+        assert_eq!(
+            PollStatus::InProgress {
+                ends_at: new_ends_at
+            },
+            poll.status
+        );
+    }
+
     #[test]
     fn casts_new_vote() {
         let mut deps = mock_dependencies();
@@ -9,7 +9,9 @@ use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
 use itertools::Itertools;
 
 use common::cw::RangeArgs;
-use PollRejectionReason::{IsVetoOutcome, OutcomeDraw, QuorumNotReached, ThresholdNotReached};
+use PollRejectionReason::{
+    IsVetoOutcome, MinimumYesVotesNotReached, OutcomeDraw, QuorumNotReached, ThresholdNotReached,
+};
 
 use poll_engine_api::api::PollRejectionReason::IsRejectingOutcome;
 use poll_engine_api::api::VoteOutcome::{Abstain, No, Veto, Yes};
@@ -415,6 +417,7 @@ pub fn new_poll(
         quorum,
         threshold,
         veto_threshold,
+        minimum_yes_votes: None,
         results: BTreeMap::new(),
     })
 }
@@ -459,6 +462,7 @@ pub fn new_poll(
 /// #     quorum: quorum.clone(),
 /// #     threshold: threshold.clone(),
 /// #     veto_threshold: veto_threshold.clone(),
+/// #     minimum_yes_votes: None,
 /// # };
 /// # let expected = Poll {
 /// #     id: poll_id.into(),
@@ -474,6 +478,7 @@ pub fn new_poll(
 /// #     quorum,
 /// #     threshold,
 /// #     veto_threshold,
+/// #     minimum_yes_votes: None,
 /// #     results: Default::default(),
 /// #     deposit_amount: 1000
 /// # };
@@ -487,7 +492,7 @@ pub fn new_poll(
 /// # }
 /// ```
 pub fn poll_from(deps: &mut DepsMut, env: &Env, params: CreatePollParams) -> PollResult<Poll> {
-    new_poll(
+    let poll = new_poll(
         deps,
         deps.api.addr_validate(&params.proposer)?,
         params.deposit_amount.u128(),
@@ -499,7 +504,12 @@ pub fn poll_from(deps: &mut DepsMut, env: &Env, params: CreatePollParams) -> Pol
         params.quorum,
         params.threshold,
         params.veto_threshold,
-    )
+    )?;
+
+    Ok(Poll {
+        minimum_yes_votes: params.minimum_yes_votes,
+        ..poll
+    })
 }
 
 pub trait PollHelpers {
@@ -513,6 +523,8 @@ pub trait PollHelpers {
 
     fn quorum_reached(&self, quorum: &Decimal, maximum_available_votes: u128) -> bool;
 
+    fn minimum_yes_votes_reached(&self) -> bool;
+
     fn total_votes(&self) -> u128;
 
     fn votes_for(&self, outcome: VoteOutcome) -> u128;
@@ -677,6 +689,17 @@ impl PollHelpers for Poll {
             .ge(quorum)
     }
 
+    /// Whether the poll received at least its minimum amount of 'yes' votes, if it has one.
+    fn minimum_yes_votes_reached(&self) -> bool {
+        match self.minimum_yes_votes {
+            None => true,
+            Some(minimum_yes_votes) => {
+                let yes_votes = self.results.get(&(Yes as u8)).copied().unwrap_or_default();
+                yes_votes >= minimum_yes_votes.u128()
+            }
+        }
+    }
+
     /// Returns the total vote count of the poll.
     ///
     /// # Example
@@ -827,7 +850,11 @@ impl PollHelpers for Poll {
                     reason: ThresholdNotReached,
                 },
                 MostVoted::Some((outcome, count)) => {
-                    if outcome == Yes as u8 {
+                    if outcome == Yes as u8 && !self.minimum_yes_votes_reached() {
+                        PollStatus::Rejected {
+                            reason: MinimumYesVotesNotReached,
+                        }
+                    } else if outcome == Yes as u8 {
                         PollStatus::Passed {
                             outcome,
                             count: count.into(),
@@ -861,7 +888,8 @@ mod tests {
 
     use common::cw::testing::mock_ctx;
     use poll_engine_api::api::PollRejectionReason::{
-        IsVetoOutcome, OutcomeDraw, QuorumNotReached, ThresholdNotReached,
+        IsVetoOutcome, MinimumYesVotesNotReached, OutcomeDraw, QuorumNotReached,
+        ThresholdNotReached,
     };
     use poll_engine_api::api::VoteOutcome::{Abstain, No, Veto, Yes};
 
@@ -1030,6 +1058,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn final_status_rejected_minimum_yes_votes_not_reached() {
+        let mut deps = mock_dependencies();
+        let ctx = mock_ctx(deps.as_mut());
+        let state = GovState::default();
+        GOV_STATE.save(ctx.deps.storage, &state).unwrap();
+
+        let mut poll = mock_poll(ctx.deps.storage);
+        poll.quorum = Decimal::percent(10);
+        poll.threshold = Decimal::percent(50);
+        poll.minimum_yes_votes = Some(Uint128::new(4));
+        poll.results = BTreeMap::from([(0, 3), (1, 1)]);
+
+        assert_eq!(
+            PollStatus::Rejected {
+                reason: MinimumYesVotesNotReached
+            },
+            poll.final_status(10u8.into()).unwrap()
+        );
+
+        poll.results = BTreeMap::from([(0, 4), (1, 1)]);
+
+        assert_eq!(
+            PollStatus::Passed {
+                outcome: Yes as u8,
+                count: Uint128::new(4),
+            },
+            poll.final_status(10u8.into()).unwrap()
+        );
+    }
+
     #[test]
     fn final_status_rejected_outcome_draw() {
         let mut deps = mock_dependencies();
@@ -5,6 +5,7 @@ use cosmwasm_std::{Decimal, Timestamp, Uint128};
 use common::cw::Context;
 
 use crate::state::{MostVoted, PollHelpers};
+use poll_engine_api::api::VoteOutcome::Yes;
 use poll_engine_api::api::{CreatePollParams, Poll, PollStatus};
 use poll_engine_api::error::PollError::{
     OutsideVotingPeriod, PollAlreadyEnded, WithinVotingPeriod,
@@ -80,6 +81,12 @@ pub fn validate_can_end_early(
         return Err(PollError::EndingEarlyThresholdNotReached {});
     }
 
+    // a poll that would pass can't end early before reaching its minimum amount of 'yes' votes
+    let yes_is_most_voted = matches!(poll.most_voted_over_threshold(), MostVoted::Some((outcome, _)) if outcome == Yes as u8);
+    if yes_is_most_voted && !poll.minimum_yes_votes_reached() {
+        return Err(PollError::EndingEarlyMinimumYesVotesNotReached {});
+    }
+
     Ok(())
 }
 
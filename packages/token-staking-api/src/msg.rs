@@ -6,8 +6,8 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cw20::Cw20ReceiveMsg;
 use cw_utils::Duration;
 use membership_common_api::api::{
-    MembersParams, MembersResponse, TotalWeightCheckpoint, TotalWeightParams, TotalWeightResponse,
-    UserWeightParams, UserWeightResponse, WeightChangeHookMsg,
+    ExtendUnlockingMsg, MembersParams, MembersResponse, TotalWeightCheckpoint, TotalWeightParams,
+    TotalWeightResponse, UserWeightParams, UserWeightResponse, WeightChangeHookMsg,
 };
 
 #[cw_serde]
@@ -28,6 +28,7 @@ pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
     AddWeightChangeHook(WeightChangeHookMsg),
     RemoveWeightChangeHook(WeightChangeHookMsg),
+    ExtendUnlocking(ExtendUnlockingMsg),
 }
 
 #[cw_serde]
@@ -8,6 +8,7 @@ use membership_common::member_weights::{
     decrement_member_weight, get_member_weight, increment_member_weight, set_member_weight,
 };
 use membership_common::total_weight::{decrement_total_weight, increment_total_weight};
+use membership_common::unlocking_extensions::apply_unlocking_extension;
 use membership_common::validate::{
     enterprise_governance_controller_only, validate_user_not_restricted,
 };
@@ -154,6 +155,8 @@ pub fn unstake(ctx: &mut Context, msg: UnstakeMsg) -> TokenStakingResult<Respons
     let new_total_staked = decrement_total_weight(ctx, unstaked_amount)?;
 
     let release_at = calculate_release_at(ctx)?;
+    let release_at =
+        apply_unlocking_extension(ctx.deps.storage, &ctx.env.block, user.clone(), release_at)?;
 
     let claim = add_claim(ctx.deps.storage, user.clone(), unstaked_amount, release_at)?;
 